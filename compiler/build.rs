@@ -0,0 +1,23 @@
+//! Passes the commit date of the active rustc to the macros, which only emit
+//! the lang items and the attributes this rustc knows (see
+//! `src/toolchain.rs`).
+
+use std::env;
+use std::process::Command;
+
+fn main() {
+    println!("cargo:rerun-if-env-changed=RUSTC");
+
+    let rustc = env::var_os("RUSTC").unwrap_or_else(|| "rustc".into());
+    let Ok(output) = Command::new(rustc).arg("-vV").output() else {
+        return;
+    };
+    let version = String::from_utf8_lossy(&output.stdout);
+    let date = version
+        .lines()
+        .find_map(|line| line.strip_prefix("commit-date: "))
+        .filter(|date| *date != "unknown");
+    if let Some(date) = date {
+        println!("cargo:rustc-env=COMPILER_RUSTC_COMMIT_DATE={date}");
+    }
+}
@@ -1,60 +1,86 @@
+use std::borrow::Cow;
+
 use quote::{ToTokens, quote};
 
-use crate::symbol::{C, REPR, Symbol, TRANSPARENT};
+use crate::repr::{Repr, parse_repr};
+use crate::symbol::{
+    CONST_TRAIT, FUNDAMENTAL, REPR, RUSTC_COINDUCTIVE, RUSTC_DENY_EXPLICIT_IMPL,
+    RUSTC_DO_NOT_IMPLEMENT_VIA_OBJECT, RUSTC_PUB_TRANSPARENT, Symbol, TRACK_CALLER,
+};
+use crate::toolchain::Bounds;
 use crate::{
-    coinductive_symbols, const_trait_symbols, fundamental_symbols, object_unimplementable_symbols,
-    unimplementable_symbols,
+    coinductive_symbols, const_trait_symbols, full_transparent_symbols, fundamental_symbols,
+    object_unimplementable_symbols, unimplementable_symbols,
 };
 
+/// An attribute of an item checked by the constraints of its lang item.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum ItemAttribut {
-    Unknown,
-
     ConstTrait,
     Fundamental,
-    Transparent,
-    CLayout,
     Coinductive,
-    Unimplementable,
+    /// `rustc_deny_explicit_impl`, an unimplementable trait also has
+    /// `ObjectUnimplementable`.
+    DenyExplicitImpl,
     ObjectUnimplementable,
+    PubTransparent,
+    TrackCaller,
+    Repr(Repr),
 }
 
-impl From<syn::Meta> for ItemAttribut {
-    fn from(meta: syn::Meta) -> Self {
-        let containt_at_least_one_of =
-            |path: &syn::Path, symbols: &[Symbol]| symbols.iter().any(|symbol| symbol == path);
-
-        match meta {
-            syn::Meta::Path(path) if containt_at_least_one_of(&path, &const_trait_symbols) => {
-                Self::ConstTrait
-            }
-            syn::Meta::Path(path) if containt_at_least_one_of(&path, &fundamental_symbols) => {
-                Self::Fundamental
-            }
-            syn::Meta::Path(path) if containt_at_least_one_of(&path, &coinductive_symbols) => {
-                Self::Coinductive
-            }
-            syn::Meta::Path(path)
-                if containt_at_least_one_of(&path, &object_unimplementable_symbols) =>
-            {
-                Self::Unimplementable
-            }
-            syn::Meta::Path(path) if containt_at_least_one_of(&path, &unimplementable_symbols) => {
-                Self::Unimplementable
+impl ItemAttribut {
+    /// Returns the attributes `meta` stands for, none if it is unknown.
+    ///
+    /// The raw attributes, like `#[rustc_coinductive]`, are written by hand or
+    /// emitted by the `compiler::` rules written before `compiler::item`. The
+    /// rules written after it are not expanded yet, they stand for the
+    /// attributes they emit.
+    pub fn parse(meta: &syn::Meta) -> syn::Result<Vec<Self>> {
+        let attribute = match meta {
+            syn::Meta::Path(path) if CONST_TRAIT == *path => Self::ConstTrait,
+            syn::Meta::Path(path) if FUNDAMENTAL == *path => Self::Fundamental,
+            syn::Meta::Path(path) if RUSTC_COINDUCTIVE == *path => Self::Coinductive,
+            syn::Meta::Path(path) if RUSTC_DENY_EXPLICIT_IMPL == *path => Self::DenyExplicitImpl,
+            syn::Meta::Path(path) if RUSTC_DO_NOT_IMPLEMENT_VIA_OBJECT == *path => {
+                Self::ObjectUnimplementable
             }
+            syn::Meta::Path(path) if RUSTC_PUB_TRANSPARENT == *path => Self::PubTransparent,
+            syn::Meta::Path(path) if TRACK_CALLER == *path => Self::TrackCaller,
             syn::Meta::List(list) if REPR == list.path => {
-                // SAFETY: repr cannot take anything else but ident
-                let ident = unsafe { syn::parse2::<syn::Ident>(list.tokens).unwrap_unchecked() };
-                if TRANSPARENT == ident {
-                    Self::Transparent
-                } else if C == ident {
-                    Self::CLayout
-                } else {
-                    Self::Unknown
-                }
+                let hints = parse_repr(list.tokens.clone())?;
+                return Ok(hints.into_iter().map(Self::Repr).collect());
             }
-            _ => Self::Unknown,
+            syn::Meta::Path(path) => return Self::parse_rule(path),
+            _ => return Ok(Vec::new()),
+        };
+        Ok(vec![attribute])
+    }
+
+    /// Returns the attributes emitted by the `compiler::` rule `path`.
+    fn parse_rule(path: &syn::Path) -> syn::Result<Vec<Self>> {
+        // the name of the rule, its `compiler::` path, then its attributes
+        let rules: [&[Symbol]; 6] = [
+            &const_trait_symbols,
+            &fundamental_symbols,
+            &full_transparent_symbols,
+            &coinductive_symbols,
+            &unimplementable_symbols,
+            &object_unimplementable_symbols,
+        ];
+        let Some(rule) = rules
+            .into_iter()
+            .find(|rule| rule[..2].iter().any(|symbol| *symbol == *path))
+        else {
+            return Ok(Vec::new());
+        };
+
+        let mut attributes = Vec::new();
+        for attribute in &rule[2..] {
+            let meta = syn::parse_str::<syn::Meta>(attribute.0)
+                .unwrap_or_else(|_| panic!("invalid attribute `{attribute}` of `{}`", rule[1]));
+            attributes.extend(Self::parse(&meta)?);
         }
+        Ok(attributes)
     }
 }
 
@@ -67,8 +93,9 @@ pub enum Target {
     Fn,
     Macro,
 
-    Type,
-    EnumVariant,
+    AssocType,
+    AssocFn,
+    Variant,
 }
 
 impl Target {
@@ -80,8 +107,9 @@ impl Target {
             Self::Union => "union",
             Self::Fn => "function",
             Self::Macro => "macro",
-            Self::Type => "type",
-            Self::EnumVariant => "enum variant",
+            Self::AssocType => "associated type",
+            Self::AssocFn => "associated function",
+            Self::Variant => "enum variant",
         }
     }
 }
@@ -96,31 +124,58 @@ pub enum Constraint {
     Coinductive,
     Unimplementable,
     ObjectUnimplementable,
+    /// The expected supertraits, associated types and methods written as a
+    /// trait declaration, see [`crate::shape::check_trait_shape`].
+    Shape(&'static str),
 
-    // Struct/Union constraints
+    // Struct/Union/Enum constraints
     CLayout,
     Transparent,
+    // the `repr` constraints below are only required by test rows for now
+    #[cfg_attr(not(feature = "test_lang_item"), allow(dead_code))]
+    Simd,
+    /// `repr(packed(N))`, `repr(packed)` being `Packed(1)`.
+    #[cfg_attr(not(feature = "test_lang_item"), allow(dead_code))]
+    Packed(u32),
+    #[cfg_attr(not(feature = "test_lang_item"), allow(dead_code))]
+    Align(u32),
+    /// `repr` of a primitive integer, like `Integer("u8")`.
+    #[cfg_attr(not(feature = "test_lang_item"), allow(dead_code))]
+    Integer(&'static str),
     Fundamental,
 
+    // Function constraints
+    TrackCaller,
+    /// The expected signature written as a function pointer type, see
+    /// [`crate::signature::check_signature`].
+    Signature(&'static str),
+
     Generics(usize),
     Arguments(usize),
 }
 
 impl Constraint {
-    pub fn name(self) -> &'static str {
-        match self {
+    pub fn name(self) -> Cow<'static, str> {
+        Cow::Borrowed(match self {
             Self::Auto => "auto",
             Self::Unsafety => "unsafe",
             Self::Constness => "const",
             Self::Coinductive => "coinductive",
             Self::Unimplementable => "unimplementable",
             Self::ObjectUnimplementable => "object unimplementable",
+            Self::Shape(_) => "shape",
             Self::CLayout => "repr(C)",
             Self::Transparent => "repr(transparent)",
+            Self::Simd => "repr(simd)",
+            Self::Packed(n) => return format!("repr(packed({n}))").into(),
+            Self::Align(n) => return format!("repr(align({n}))").into(),
+            Self::Integer(integer) => return format!("repr({integer})").into(),
             Self::Fundamental => "fundamental",
+            Self::TrackCaller => "#[track_caller]",
+            Self::Signature(_) => "signature",
             Self::Generics(_) => "generics",
             Self::Arguments(_) => "arguments",
-        }
+        })
     }
 }
 
@@ -135,10 +190,33 @@ enum DialogItem {
     Inherited,
     /// Does not generate the dialog or lang item attribute.
     No,
+    /// The name was changed by rustc: `old` before the rustc of `since`,
+    /// `new` since (see [`Bounds`]).
+    Renamed {
+        old: &'static str,
+        new: &'static str,
+        since: &'static str,
+    },
+}
+
+impl DialogItem {
+    /// The name given to the compiler by the active rustc, `inherited` being
+    /// the name of the internal item.
+    fn resolve(self, inherited: &'static str) -> Option<&'static str> {
+        match self {
+            Named(name) => Some(name),
+            Inherited => Some(inherited),
+            No => None,
+            Renamed { old, new, since } => match Bounds::ALWAYS.since(since).is_active() {
+                true => Some(new),
+                false => Some(old),
+            },
+        }
+    }
 }
 
 macro_rules! lang_item_table {
-    ($($(#[$attr:meta])* $variant:ident, $name:literal, $target:expr, [$($constraint:expr),*], $diag_item:expr, $lang_item:expr;)*) => {
+    ($($(#[$attr:meta])* $variant:ident, $name:literal, $target:expr, [$($constraint:expr),*], $diag_item:expr, $lang_item:expr $(, since $since:literal)? $(, until $until:literal)?;)*) => {
         #[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
         pub enum LangItem {
             $($(#[$attr])* $variant,)*
@@ -150,7 +228,7 @@ macro_rules! lang_item_table {
             fn try_from(value: syn::LitStr) -> Result<Self, Self::Error> {
                 match value.value().as_str() {
                     $($(#[$attr])* $name => Ok(Self::$variant),)*
-                    lit => Err(syn::Error::new_spanned(value, format!("\"{lit}\" is not a valid compiler lang item."))),
+                    _ => Err(Self::unknown(&value)),
                 }
             }
         }
@@ -160,29 +238,23 @@ macro_rules! lang_item_table {
                 match self {$(
                     $(#[$attr])*
                     Self::$variant => {
-                        let diag_item = match $diag_item {
-                        	Named(name) => Some(quote! { #[rustc_diagnostic_item = #name] }),
-                         	Inherited => {
-                              let variant = stringify!($variant);
-                              Some(quote! { #[rustc_diagnostic_item = #variant] })
-                          	},
-                        	No => None,
-                        };
-                        let lang_item = match $lang_item {
-                        	Named(name) => Some(quote! { #[rustc_diagnostic_item = #name] }),
-                         	Inherited => Some(quote! { #[rustc_diagnostic_item = $name] }),
-                        	No => None,
-                        };
-                        *tokens = quote! {
+                        let diag_item = DialogItem::resolve($diag_item, stringify!($variant))
+                            .map(|name| quote! { #[rustc_diagnostic_item = #name] });
+                        let lang_item = self
+                            .rustc_name()
+                            .map(|name| quote! { #[lang = #name] });
+                        tokens.extend(quote! {
                             #diag_item
                             #lang_item
-                        };
+                        });
                     },
                 )*}
             }
         }
 
         impl LangItem {
+            const ALL: &[LangItem] = &[$($(#[$attr])* Self::$variant,)*];
+
             pub fn name(self) -> &'static str {
                 match self {$(
                     $(#[$attr])*
@@ -190,6 +262,23 @@ macro_rules! lang_item_table {
                 )*}
             }
 
+            /// The name given to the compiler through `#[lang = "..."]`.
+            pub fn rustc_name(self) -> Option<&'static str> {
+                match self {$(
+                    $(#[$attr])*
+                    Self::$variant => DialogItem::resolve($lang_item, $name),
+                )*}
+            }
+
+            /// The rustc versions knowing the lang item, if it is given to
+            /// the compiler.
+            pub fn bounds(self) -> Bounds {
+                match self {$(
+                    $(#[$attr])*
+                    Self::$variant => Bounds::ALWAYS $(.since($since))? $(.until($until))?,
+                )*}
+            }
+
             pub fn target(self) -> Target {
                 match self {$(
                     $(#[$attr])*
@@ -207,122 +296,268 @@ macro_rules! lang_item_table {
     };
 }
 
-// TODO: add items related on Ranges and all #[rustc_diagnostic_item]
+/// Lang items of rustc which are not in the table, with the reason.
+const UPSTREAM_HINTS: &[(&str, &str)] = &[
+    (
+        "owned_box",
+        "`Box` is defined by `alloc`, whose lang items are not supported yet",
+    ),
+    (
+        "exchange_malloc",
+        "the allocation lang items are defined by `alloc`, which is not supported yet",
+    ),
+    (
+        "global_alloc_ty",
+        "the allocation lang items are defined by `alloc`, which is not supported yet",
+    ),
+    (
+        "String",
+        "`String` is defined by `alloc`, whose lang items are not supported yet",
+    ),
+    (
+        "start",
+        "`start` is the entry point of the programs using `std`, which is not supported yet",
+    ),
+    (
+        "eh_personality",
+        "the unwinding lang items are defined by the panic runtime, not by the core",
+    ),
+    (
+        "eh_catch_typeinfo",
+        "the unwinding lang items are defined by the panic runtime, not by the core",
+    ),
+];
+
+impl LangItem {
+    fn unknown(value: &syn::LitStr) -> syn::Error {
+        let name = value.value();
+        let mut message = format!("\"{name}\" is not a valid compiler lang item.");
+
+        if let Some(item) = Self::ALL
+            .iter()
+            .find(|item| item.rustc_name() == Some(name.as_str()))
+        {
+            message += &format!(
+                "\n help: \"{name}\" is the name given to the compiler, the item is named \"{}\"",
+                item.name()
+            );
+        } else if let Some((_, hint)) = UPSTREAM_HINTS
+            .iter()
+            .find(|(upstream, _)| *upstream == name)
+        {
+            message += &format!("\n note: {hint}");
+        } else {
+            let max_distance = (name.chars().count() / 3).max(1);
+            let mut suggestions = Self::ALL
+                .iter()
+                .filter_map(|item| {
+                    let distance = item
+                        .rustc_name()
+                        .into_iter()
+                        .chain([item.name()])
+                        .map(|candidate| edit_distance(&name, candidate))
+                        .min()?;
+                    (distance <= max_distance).then_some((distance, item.name()))
+                })
+                .collect::<Vec<_>>();
+            suggestions.sort();
+
+            let suggestions = suggestions
+                .iter()
+                .take(3)
+                .map(|(_, name)| format!("\"{name}\""))
+                .collect::<Vec<_>>();
+            if let [rest @ .., last] = suggestions.as_slice() {
+                message += &match rest {
+                    [] => format!("\n help: did you mean {last}?"),
+                    rest => format!("\n help: did you mean {} or {last}?", rest.join(", ")),
+                };
+            }
+        }
+
+        syn::Error::new_spanned(value, message)
+    }
+
+    /// Checks that the lang item is known by the active rustc, `value` being
+    /// the name given to `compiler::item`.
+    pub fn check_toolchain(self, value: &syn::LitStr) -> syn::Result<()> {
+        let bounds = self.bounds();
+        match bounds.is_active() {
+            true => Ok(()),
+            false => Err(syn::Error::new_spanned(
+                value,
+                format!(
+                    "\"{}\" is not a lang item of the active toolchain: {}",
+                    self.name(),
+                    bounds.explain()
+                ),
+            )),
+        }
+    }
+
+    /// Adds the list of the lang items that can be applied to a `target` to
+    /// the error of an unknown lang item.
+    pub fn with_valid_items(error: syn::Error, target: Target) -> syn::Error {
+        let names = Self::ALL
+            .iter()
+            .filter(|item| item.target() == target && item.bounds().is_active())
+            .map(|item| format!("\"{}\"", item.name()))
+            .collect::<Vec<_>>();
+
+        syn::Error::new(
+            error.span(),
+            format!(
+                "{error}\n note: the valid {} lang items are: {}",
+                target.name(),
+                names.join(", ")
+            ),
+        )
+    }
+}
+
+/// The Levenshtein distance between `a` and `b`.
+fn edit_distance(a: &str, b: &str) -> usize {
+    let b = b.chars().collect::<Vec<_>>();
+    let mut row = (0..=b.len()).collect::<Vec<_>>();
+
+    for (i, a) in a.chars().enumerate() {
+        let mut previous = row[0];
+        row[0] = i + 1;
+        for (j, b) in b.iter().enumerate() {
+            let current = row[j + 1];
+            row[j + 1] = if a == *b {
+                previous
+            } else {
+                1 + previous.min(row[j]).min(current)
+            };
+            previous = current;
+        }
+    }
+
+    row[b.len()]
+}
+
+// TODO: add all #[rustc_diagnostic_item]
 lang_item_table! {
     // Macro format:
-    // Variant name, Item name, Item target, Constraints, Is diag item, Is lang item
+    // Variant name, Item name, Item target, Constraints, Is diag item, Is lang item[, since "date"][, until "date"]
+    //
+    // The optional bounds are the commit dates of the first rustc knowing the
+    // lang item and of the first one not knowing it anymore (see
+    // `toolchain::Bounds`). The lang items only known to be newer than the
+    // pinned toolchain are bounded by the day after it.
 
     // Lang item based on trait and forced by the compiler to have a custom behavior.
     Sized, "sized", Target::Trait, [Coinductive, Fundamental, Unimplementable], No, Inherited;
     Unsize, "unsize", Target::Trait, [Generics(1), Unimplementable], No, Inherited;
-    CoerceUnsized, "coerce_unsized", Target::Trait, [], No, Inherited;
+    CoerceUnsized, "coerce_unsized", Target::Trait, [Generics(1)], No, Inherited;
     ConstParamTy, "const_param_ty", Target::Trait, [], No, Inherited;
     UnsizedConstParamTy, "unsized_const_param_ty", Target::Trait, [], No, Inherited;
 
     TupleTrait, "tuple_trait", Target::Trait, [Unimplementable], No, Inherited;
     StructuralPartialEq, "structural_peq", Target::Trait, [], No, Inherited;
 
-    DiscriminantKind, "discriminant_kind", Target::Trait, [Unimplementable], No, Inherited;
-    DiscriminantType, "discriminant_type", Target::Type, [], No, Inherited;
+    DiscriminantKind, "discriminant_kind", Target::Trait, [Unimplementable, Shape("trait DiscriminantKind { type Discriminant; }")], No, Inherited;
+    DiscriminantType, "discriminant_type", Target::AssocType, [], No, Inherited;
 
-    Send, "send", Target::Trait, [Unsafety, Auto], No, No;
+    Send, "send", Target::Trait, [Unsafety, Auto], Inherited, No;
     Sync, "sync", Target::Trait, [Unsafety, Auto], No, Inherited;
     Freeze, "freeze", Target::Trait, [Unsafety, Auto], No, Inherited;
     Unpin, "unpin", Target::Trait, [Auto], No, Inherited;
 
-    // TODO: keep track of use_cloned
+    Copy, "copy", Target::Trait, [Shape("trait Copy: Clone {}")], Inherited, Inherited;
+    Clone, "clone", Target::Trait, [Shape("trait Clone: Sized { fn clone(&self) -> Self; }")], Inherited, Inherited;
+    CloneFn, "clone_fn", Target::AssocFn, [Arguments(1), Signature("fn(&Self) -> Self")], No, Inherited;
     // https://github.com/rust-lang/rust/issues/132290
-    Copy, "copy", Target::Trait, [], Inherited, Inherited;
-    Clone, "clone", Target::Trait, [], Inherited, Inherited;
-    CloneFn, "clone_fn", Target::Fn, [Arguments(1)], No, Inherited;
+    UseCloned, "use_cloned", Target::Trait, [Shape("trait UseCloned: Clone {}")], No, Inherited, since "2025-02-05";
 
-    Drop, "drop", Target::Trait, [Constness], No, Inherited;
+    Drop, "drop", Target::Trait, [Constness, Shape("trait Drop { fn drop(&mut self); }")], No, Inherited;
     Destruct, "destruct", Target::Trait, [Constness, Unimplementable], No, Inherited;
-    BikeshedGuaranteedNoDrop, "bikeshed_guaranteed_no_drop", Target::Trait, [Unimplementable], No, Inherited;
+    BikeshedGuaranteedNoDrop, "bikeshed_guaranteed_no_drop", Target::Trait, [Unimplementable], No, Inherited, since "2025-02-05";
 
     AsyncDrop, "async_drop", Target::Trait, [], No, Inherited;
-    FutureTrait, "future_trait", Target::Trait, [], No, Inherited;
-    FutureOutput, "future_output", Target::Type, [], No, Inherited;
-    FuturePoll, "future_poll", Target::Fn, [Arguments(2)], No, Named("poll");
-    IntoFutureTrait, "into_future_trait", Target::Trait, [], Named("IntoFuture"), No;
-    IntoFutureFn, "into_future_fn", Target::Fn, [Arguments(1)], No, Named("into_future");
-
-    IntoIterTrait, "into_iterator_trait", Target::Trait, [], Named("IntoIterator"), No;
-    IntoIterFn, "into_iterator_fn", Target::Fn, [Arguments(1)], No, Named("into_iter");
+    FutureTrait, "future_trait", Target::Trait, [Shape("trait Future { type Output; fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output>; }")], No, Inherited;
+    FutureOutput, "future_output", Target::AssocType, [], No, Inherited;
+    FuturePoll, "future_poll", Target::AssocFn, [Arguments(2), Signature("fn(Pin<&mut Self>, &mut Context<'_>) -> Poll<Self::Output>")], No, Named("poll");
+    IntoFutureTrait, "into_future_trait", Target::Trait, [Shape("trait IntoFuture { type Output; type IntoFuture: Future<Output = Self::Output>; fn into_future(self) -> Self::IntoFuture; }")], Named("IntoFuture"), No;
+    IntoFutureFn, "into_future_fn", Target::AssocFn, [Arguments(1), Signature("fn(Self) -> Self::IntoFuture")], No, Named("into_future");
+
+    IntoIterTrait, "into_iterator_trait", Target::Trait, [Shape("trait IntoIterator { type Item; type IntoIter: Iterator<Item = Self::Item>; fn into_iter(self) -> Self::IntoIter; }")], Named("IntoIterator"), No;
+    IntoIterFn, "into_iterator_fn", Target::AssocFn, [Arguments(1), Signature("fn(Self) -> Self::IntoIter")], No, Named("into_iter");
     FusedIterator, "fused_iterator", Target::Trait, [], No, Inherited;
-    Iterator, "iterator", Target::Trait, [], Inherited, Inherited;
-    IteratorItem, "iterator_item", Target::Type, [], Inherited, No;
-    IteratorNext, "iterator_next", Target::Fn, [Arguments(1)], No, Inherited;
+    Iterator, "iterator", Target::Trait, [Shape("trait Iterator { type Item; fn next(&mut self) -> Option<Self::Item>; }")], Inherited, Inherited;
+    IteratorItem, "iterator_item", Target::AssocType, [], Inherited, No;
+    IteratorNext, "iterator_next", Target::AssocFn, [Arguments(1), Signature("fn(&mut Self) -> Option<Self::Item>")], No, Named("next");
 
     TransmuteTrait, "transmute_trait", Target::Trait, [Generics(2), Unsafety, Coinductive, Unimplementable], No, Inherited;
     TransmuteAssumption, "transmute_assumption", Target::Struct, [], No, Named("transmute_opts");
 
-    Deref, "deref", Target::Trait, [Constness], Inherited, Inherited;
-    DerefMut, "deref_mut", Target::Trait, [Constness], Inherited, Inherited;
+    Deref, "deref", Target::Trait, [Constness, Shape("trait Deref { type Target: ?Sized; fn deref(&self) -> &Self::Target; }")], Inherited, Inherited;
+    DerefTarget, "deref_target", Target::AssocType, [], No, Inherited;
+    DerefMut, "deref_mut", Target::Trait, [Constness, Shape("trait DerefMut: Deref { fn deref_mut(&mut self) -> &mut Self::Target; }")], Inherited, Inherited;
     DerefPure, "deref_pure", Target::Trait, [Unsafety], No, Inherited;
-    DerefTarget, "deref_target", Target::Type, [], No, Inherited;
-    LegacyReceiver, "legacy_receiver", Target::Trait, [], No, Inherited;
-    Receiver, "receiver", Target::Trait, [], No, Inherited;
-    ReceiverTarget, "receiver_target", Target::Type, [], Named("receiver_target"), Inherited;
+    LegacyReceiver, "legacy_receiver", Target::Trait, [], No, Renamed { old: "receiver", new: "legacy_receiver", since: "2024-10-25" };
+    Receiver, "receiver", Target::Trait, [Shape("trait Receiver { type Target: ?Sized; }")], No, Inherited, since "2024-12-11";
+    ReceiverTarget, "receiver_target", Target::AssocType, [], Named("receiver_target"), Inherited, since "2024-12-11";
 
     DispatchFromDyn, "dispatch_from_dyn", Target::Trait, [Generics(1)], No, Inherited;
 
     DynMetadata, "dyn_metadata", Target::Struct, [Generics(1)], No, Inherited;
-    PointeeTrait, "pointee", Target::Trait, [Unimplementable], No, Named("pointee_trait");
-    PointeeMetadata, "pointee_metadata", Target::Type, [], No, Named("metadata_type");
+    PointeeTrait, "pointee", Target::Trait, [Unimplementable, Shape("trait Pointee { type Metadata; }")], No, Named("pointee_trait");
+    PointeeMetadata, "pointee_metadata", Target::AssocType, [], No, Named("metadata_type");
 
     PointerLike, "pointer_like", Target::Trait, [ObjectUnimplementable], No, Inherited;
-    CoercePointeeValidated, "coerce_pointee_validated", Target::Trait, [], No, Inherited;
-    FnPtrTrait, "fn_ptr_trait", Target::Trait, [Unimplementable], No, Inherited;
-    FnPtrAddr, "fn_ptr_addr", Target::Fn, [Arguments(1)], No, Inherited;
+    CoercePointeeValidated, "coerce_pointee_validated", Target::Trait, [], No, Inherited, since "2025-02-05";
+    FnPtrTrait, "fn_ptr_trait", Target::Trait, [Unimplementable, Shape("trait FnPtr: Copy + Clone { fn addr(self) -> *const (); }")], No, Inherited;
+    FnPtrAddr, "fn_ptr_addr", Target::AssocFn, [Arguments(1), Signature("fn(Self) -> *const ()")], No, Inherited;
 
     AsyncFn, "async_fn", Target::Trait, [Generics(1)], No, Inherited;
     AsyncFnMut, "async_fn_mut", Target::Trait, [Generics(1)], No, Inherited;
-    AsyncFnCallRefFuture, "async_fn_call_ref_future", Target::Type, [Generics(1) /* lifetime */], No, Named("call_ref_future");
+    AsyncFnCallRefFuture, "async_fn_call_ref_future", Target::AssocType, [Generics(1) /* lifetime */], No, Named("call_ref_future");
     AsyncFnOnce, "async_fn_once", Target::Trait, [Generics(1)], No, Inherited;
-    AsyncFnCallOnceFuture, "async_fn_call_once_future", Target::Type, [Generics(1) /* lifetime */], No, Named("call_once_future");
-    AsyncFnOnceOutput, "async_fn_once_output", Target::Type, [], No, Inherited;
+    AsyncFnCallOnceFuture, "async_fn_call_once_future", Target::AssocType, [], No, Named("call_once_future");
+    AsyncFnOnceOutput, "async_fn_once_output", Target::AssocType, [], No, Inherited;
     AsyncFnKindHelper, "async_fn_kind_helper", Target::Trait, [Generics(1)], No, Inherited;
-    AsyncFnKindUpvars, "async_fn_kind_upvars", Target::Type, [Generics(4) /* 1 lifetime and 3 generics */], No, Inherited;
-
-    Fn, "fn", Target::Trait, [Generics(1), Fundamental], No, Inherited;
-    FnMut, "fn_mut", Target::Trait, [Generics(1), Fundamental], No, Inherited;
-    FnOnce, "fn_once", Target::Trait, [Generics(1), Fundamental], No, Inherited;
-    FnOnceOutput, "fn_once_output", Target::Type, [], No, Inherited;
-
-    Try, "try", Target::Trait, [], No, Named("Try");
-    TryBranchFn, "try_branch_fn", Target::Fn, [Arguments(1)], No, Named("branch");
-    TryFromOutputFn, "try_from_output_fn", Target::Fn, [Arguments(1)], No, Named("from_output");
-    FromResidual, "from_residual", Target::Trait, [Generics(1)], Inherited, No;
-    FromResidualFn, "from_residual_fn", Target::Trait, [], No, Named("from_residual");
-    FromYeet, "from_yeet", Target::Fn, [Arguments(1)], No, Inherited;
-
-    Add, "add", Target::Trait, [Generics(1)], No, Inherited;
-    Sub, "sub", Target::Trait, [Generics(1)], No, Inherited;
-    Mul, "mul", Target::Trait, [Generics(1)], No, Inherited;
-    Div, "div", Target::Trait, [Generics(1)], No, Inherited;
-    Rem, "rem", Target::Trait, [Generics(1)], No, Inherited;
-    BitXor, "bit_xor", Target::Trait, [Generics(1)], No, Inherited;
-    BitAnd, "bit_and", Target::Trait, [Generics(1)], No, Inherited;
-    BitOr, "bit_or", Target::Trait, [Generics(1)], No, Inherited;
-    Shl, "shl", Target::Trait, [Generics(1)], No, Inherited;
-    Shr, "shr", Target::Trait, [Generics(1)], No, Inherited;
-    AddAssign, "add_assign", Target::Trait, [Generics(1)], No, Inherited;
-    SubAssign, "sub_assign", Target::Trait, [Generics(1)], No, Inherited;
-    MulAssign, "mul_assign", Target::Trait, [Generics(1)], No, Inherited;
-    DivAssign, "div_assign", Target::Trait, [Generics(1)], No, Inherited;
-    RemAssign, "rem_assign", Target::Trait, [Generics(1)], No, Inherited;
-    BitXorAssign, "bit_xor_assign", Target::Trait, [Generics(1)], No, Inherited;
-    BitAndAssign, "bit_and_assign", Target::Trait, [Generics(1)], No, Inherited;
-    BitOrAssign, "bit_or_assign", Target::Trait, [Generics(1)], No, Inherited;
-    ShlAssign, "shl_assign", Target::Trait, [Generics(1)], No, Inherited;
-    ShrAssign, "shr_assign", Target::Trait, [Generics(1)], No, Inherited;
-    Index, "index", Target::Trait, [Generics(1)], No, Inherited;
-    IndexMut, "index_mut", Target::Trait, [Generics(1)], No, Inherited;
-    PartialEq, "partial_eq", Target::Trait, [Generics(1)], Inherited, Named("eq");
-    PartialOrd, "partial_ord", Target::Trait, [Generics(1)], Inherited, Inherited;
-    Neg, "neg", Target::Trait, [], No, Inherited;
-    Not, "not", Target::Trait, [], No, Inherited;
+    AsyncFnKindUpvars, "async_fn_kind_upvars", Target::AssocType, [Generics(4) /* 1 lifetime and 3 generics */], No, Inherited;
+
+    Fn, "fn", Target::Trait, [Generics(1), Fundamental, Shape("trait Fn<Args>: FnMut<Args> { extern \"rust-call\" fn call(&self, args: Args) -> Self::Output; }")], No, Inherited;
+    FnMut, "fn_mut", Target::Trait, [Generics(1), Fundamental, Shape("trait FnMut<Args>: FnOnce<Args> { extern \"rust-call\" fn call_mut(&mut self, args: Args) -> Self::Output; }")], No, Inherited;
+    FnOnce, "fn_once", Target::Trait, [Generics(1), Fundamental, Shape("trait FnOnce<Args> { type Output; extern \"rust-call\" fn call_once(self, args: Args) -> Self::Output; }")], No, Inherited;
+    FnOnceOutput, "fn_once_output", Target::AssocType, [], No, Inherited;
+
+    Try, "try", Target::Trait, [Shape("trait Try: FromResidual { type Output; type Residual; fn from_output(output: Self::Output) -> Self; fn branch(self) -> ControlFlow<Self::Residual, Self::Output>; }")], No, Named("Try");
+    TryBranchFn, "try_branch_fn", Target::AssocFn, [Arguments(1), Signature("fn(Self) -> ControlFlow<Self::Residual, Self::Output>")], No, Named("branch");
+    TryFromOutputFn, "try_from_output_fn", Target::AssocFn, [Arguments(1), Signature("fn(Self::Output) -> Self")], No, Named("from_output");
+    FromResidual, "from_residual", Target::Trait, [Generics(1), Shape("trait FromResidual<R> { fn from_residual(residual: R) -> Self; }")], Inherited, No;
+    FromResidualFn, "from_residual_fn", Target::AssocFn, [Arguments(1), Signature("fn(_) -> Self")], No, Named("from_residual");
+    FromYeet, "from_yeet", Target::Fn, [Generics(2), Arguments(1), Signature("for<T, Y> fn(Y) -> T")], No, Inherited;
+
+    Add, "add", Target::Trait, [Generics(1), Shape("trait Add<Rhs> { type Output; fn add(self, rhs: Rhs) -> Self::Output; }")], No, Inherited;
+    Sub, "sub", Target::Trait, [Generics(1), Shape("trait Sub<Rhs> { type Output; fn sub(self, rhs: Rhs) -> Self::Output; }")], No, Inherited;
+    Mul, "mul", Target::Trait, [Generics(1), Shape("trait Mul<Rhs> { type Output; fn mul(self, rhs: Rhs) -> Self::Output; }")], No, Inherited;
+    Div, "div", Target::Trait, [Generics(1), Shape("trait Div<Rhs> { type Output; fn div(self, rhs: Rhs) -> Self::Output; }")], No, Inherited;
+    Rem, "rem", Target::Trait, [Generics(1), Shape("trait Rem<Rhs> { type Output; fn rem(self, rhs: Rhs) -> Self::Output; }")], No, Inherited;
+    BitXor, "bit_xor", Target::Trait, [Generics(1), Shape("trait BitXor<Rhs> { type Output; fn bitxor(self, rhs: Rhs) -> Self::Output; }")], No, Named("bitxor");
+    BitAnd, "bit_and", Target::Trait, [Generics(1), Shape("trait BitAnd<Rhs> { type Output; fn bitand(self, rhs: Rhs) -> Self::Output; }")], No, Named("bitand");
+    BitOr, "bit_or", Target::Trait, [Generics(1), Shape("trait BitOr<Rhs> { type Output; fn bitor(self, rhs: Rhs) -> Self::Output; }")], No, Named("bitor");
+    Shl, "shl", Target::Trait, [Generics(1), Shape("trait Shl<Rhs> { type Output; fn shl(self, rhs: Rhs) -> Self::Output; }")], No, Inherited;
+    Shr, "shr", Target::Trait, [Generics(1), Shape("trait Shr<Rhs> { type Output; fn shr(self, rhs: Rhs) -> Self::Output; }")], No, Inherited;
+    AddAssign, "add_assign", Target::Trait, [Generics(1), Shape("trait AddAssign<Rhs> { fn add_assign(&mut self, rhs: Rhs); }")], No, Inherited;
+    SubAssign, "sub_assign", Target::Trait, [Generics(1), Shape("trait SubAssign<Rhs> { fn sub_assign(&mut self, rhs: Rhs); }")], No, Inherited;
+    MulAssign, "mul_assign", Target::Trait, [Generics(1), Shape("trait MulAssign<Rhs> { fn mul_assign(&mut self, rhs: Rhs); }")], No, Inherited;
+    DivAssign, "div_assign", Target::Trait, [Generics(1), Shape("trait DivAssign<Rhs> { fn div_assign(&mut self, rhs: Rhs); }")], No, Inherited;
+    RemAssign, "rem_assign", Target::Trait, [Generics(1), Shape("trait RemAssign<Rhs> { fn rem_assign(&mut self, rhs: Rhs); }")], No, Inherited;
+    BitXorAssign, "bit_xor_assign", Target::Trait, [Generics(1), Shape("trait BitXorAssign<Rhs> { fn bitxor_assign(&mut self, rhs: Rhs); }")], No, Named("bitxor_assign");
+    BitAndAssign, "bit_and_assign", Target::Trait, [Generics(1), Shape("trait BitAndAssign<Rhs> { fn bitand_assign(&mut self, rhs: Rhs); }")], No, Named("bitand_assign");
+    BitOrAssign, "bit_or_assign", Target::Trait, [Generics(1), Shape("trait BitOrAssign<Rhs> { fn bitor_assign(&mut self, rhs: Rhs); }")], No, Named("bitor_assign");
+    ShlAssign, "shl_assign", Target::Trait, [Generics(1), Shape("trait ShlAssign<Rhs> { fn shl_assign(&mut self, rhs: Rhs); }")], No, Inherited;
+    ShrAssign, "shr_assign", Target::Trait, [Generics(1), Shape("trait ShrAssign<Rhs> { fn shr_assign(&mut self, rhs: Rhs); }")], No, Inherited;
+    Index, "index", Target::Trait, [Generics(1), Shape("trait Index<Idx> { type Output: ?Sized; fn index(&self, index: Idx) -> &Self::Output; }")], No, Inherited;
+    IndexMut, "index_mut", Target::Trait, [Generics(1), Shape("trait IndexMut<Idx>: Index<Idx> { fn index_mut(&mut self, index: Idx) -> &mut Self::Output; }")], No, Inherited;
+    PartialEq, "partial_eq", Target::Trait, [Generics(1), Shape("trait PartialEq<Rhs> { fn eq(&self, other: &Rhs) -> bool; }")], Inherited, Named("eq");
+    PartialOrd, "partial_ord", Target::Trait, [Generics(1), Shape("trait PartialOrd<Rhs>: PartialEq<Rhs> { fn partial_cmp(&self, other: &Rhs) -> Option<Ordering>; }")], Inherited, Inherited;
+    Neg, "neg", Target::Trait, [Shape("trait Neg { type Output; fn neg(self) -> Self::Output; }")], No, Inherited;
+    Not, "not", Target::Trait, [Shape("trait Not { type Output; fn not(self) -> Self::Output; }")], No, Inherited;
 
     // Lang item not based on traits.
     ManuallyDrop, "manually_drop", Target::Struct, [Generics(1), Transparent], No, Inherited;
@@ -333,44 +568,52 @@ lang_item_table! {
 
     ResumeTy, "resume", Target::Struct, [], No, Named("ResumeTy");
     Context, "context", Target::Struct, [Generics(1) /* lifetime */], No, Named("Context");
-    GetContext, "get_context", Target::Fn, [Generics(2) /* lifetimes */, Arguments(1), Unsafety], No, Named("get_context");
+    GetContext, "get_context", Target::Fn, [Generics(2) /* lifetimes */, Arguments(1), Unsafety, Signature("fn(ResumeTy) -> &mut Context<'_>")], No, Named("get_context");
 
-    AsyncDropInPlace, "async_drop_in_place", Target::Fn, [Generics(1), Arguments(1), Unsafety], Named("ptr_drop_in_place"), Inherited;
-    DropInPlace, "drop_in_place", Target::Fn, [Generics(1), Arguments(1), Unsafety], Named("ptr_drop_in_place"), Inherited;
+    AsyncDropInPlace, "async_drop_in_place", Target::Fn, [Generics(1), Arguments(1), Unsafety, Signature("for<T> fn(*mut T) -> _")], Named("ptr_drop_in_place"), Inherited;
+    DropInPlace, "drop_in_place", Target::Fn, [Generics(1), Arguments(1), Unsafety, Signature("for<T> fn(*mut T)")], Named("ptr_drop_in_place"), Inherited;
 
     AllocLayout, "layout", Target::Struct, [], No, Named("alloc_layout");
     Unique, "unique", Target::Struct, [Generics(1), Transparent], No, Named("ptr_unique");
     Pin, "pin", Target::Struct, [Generics(1), Fundamental, Transparent], No, Inherited;
-    PinNewUnchecked, "pin_new_unchecked", Target::Struct, [Constness, Unsafety], No, Named("new_unchecked");
+    PinNewUnchecked, "pin_new_unchecked", Target::AssocFn, [Constness, Unsafety, Arguments(1), Signature("fn(_) -> Pin<_>")], No, Named("new_unchecked");
 
     Option, "option", Target::Enum, [Generics(1)], Inherited, Named("Option");
-    OptionNone, "option_none", Target::EnumVariant, [], No, Named("None");
-    OptionSome, "option_some", Target::EnumVariant, [], No, Named("Some");
+    OptionNone, "option_none", Target::Variant, [], No, Named("None");
+    OptionSome, "option_some", Target::Variant, [], No, Named("Some");
 
-    Result, "result", Target::Enum, [Generics(2)], Inherited, Named("Result");
-    ResultOk, "result_ok", Target::EnumVariant, [], No, Named("Ok");
-    ResultErr, "result_err", Target::EnumVariant, [], No, Named("Err");
+    Result, "result", Target::Enum, [Generics(2)], Inherited, No;
+    ResultOk, "result_ok", Target::Variant, [], No, Named("Ok");
+    ResultErr, "result_err", Target::Variant, [], No, Named("Err");
+
+    RangeFull, "range_full", Target::Struct, [], No, Named("RangeFull");
+    Range, "range", Target::Struct, [Generics(1)], No, Named("Range");
+    RangeFrom, "range_from", Target::Struct, [Generics(1)], No, Named("RangeFrom");
+    RangeTo, "range_to", Target::Struct, [Generics(1)], No, Named("RangeTo");
+    RangeInclusive, "range_inclusive", Target::Struct, [Generics(1)], No, Named("RangeInclusive");
+    RangeInclusiveNew, "range_inclusive_new", Target::AssocFn, [Arguments(2), Constness, Signature("fn(_, _) -> Self")], No, Inherited;
+    RangeToInclusive, "range_to_inclusive", Target::Struct, [Generics(1)], No, Named("RangeToInclusive");
 
     ControlFlow, "control_flow", Target::Enum, [], Inherited, No;
-    ControlFlowBreak, "control_flow_break", Target::EnumVariant, [], No, Named("Break");
-    ControlFlowContinue, "control_flow_continue", Target::EnumVariant, [], No, Named("Continue");
+    ControlFlowBreak, "control_flow_break", Target::Variant, [], No, Named("Break");
+    ControlFlowContinue, "control_flow_continue", Target::Variant, [], No, Named("Continue");
 
     Poll, "poll", Target::Enum, [Generics(1)], No, Named("Poll");
-    PollReady, "poll_ready", Target::EnumVariant, [], No, Named("Ready");
-    PollPending, "poll_pending", Target::EnumVariant, [], No, Named("Pending");
+    PollReady, "poll_ready", Target::Variant, [], No, Named("Ready");
+    PollPending, "poll_pending", Target::Variant, [], No, Named("Pending");
 
     CoroutineState, "coroutine_state", Target::Enum, [], No, Inherited;
-    CoroutineTrait, "coroutine_trait", Target::Trait, [Generics(1), Fundamental], No, Named("coroutine");
-    CoroutineYield, "coroutine_yield", Target::Type, [], No, Inherited;
-    CoroutineReturn, "coroutine_return", Target::Type, [], No, Inherited;
-    CoroutineResume, "coroutine_resume", Target::Fn, [Arguments(2)], No, Inherited;
+    CoroutineTrait, "coroutine_trait", Target::Trait, [Generics(1), Fundamental, Shape("trait Coroutine<R> { type Yield; type Return; fn resume(self: Pin<&mut Self>, arg: R) -> CoroutineState<Self::Yield, Self::Return>; }")], No, Named("coroutine");
+    CoroutineYield, "coroutine_yield", Target::AssocType, [], No, Inherited;
+    CoroutineReturn, "coroutine_return", Target::AssocType, [], No, Inherited;
+    CoroutineResume, "coroutine_resume", Target::AssocFn, [Arguments(2), Signature("fn(Pin<&mut Self>, _) -> CoroutineState<Self::Yield, Self::Return>")], No, Inherited;
 
-    SliceLen, "slice_len_fn", Target::Fn, [Arguments(1)], No, Inherited;
+    SliceLen, "slice_len_fn", Target::AssocFn, [Arguments(1), Signature("fn(&Self) -> usize")], No, Inherited;
 
     // contracts
-    ContractBuildCheckEnsures, "contract_build_check_ensures", Target::Fn, [Generics(2), Arguments(1), Constness], No, Inherited;
-    ContractCheckRequires, "contract_check_requires", Target::Fn, [Generics(1), Arguments(1), Constness], No, Inherited;
-    ContractCheckEnsures, "contract_check_ensures", Target::Fn, [Generics(2), Arguments(2), Constness], No, Inherited;
+    ContractBuildCheckEnsures, "contract_build_check_ensures", Target::Fn, [Generics(2), Arguments(1), Constness, Signature("for<Ret, C> fn(C) -> _")], No, Inherited, since "2025-02-05";
+    ContractCheckRequires, "contract_check_requires", Target::Fn, [Generics(1), Arguments(1), Constness, Signature("for<C> fn(C)")], No, Inherited, since "2025-02-05";
+    ContractCheckEnsures, "contract_check_ensures", Target::Fn, [Generics(2), Arguments(2), Constness, Signature("for<Ret, C> fn(Ret, C) -> Ret")], No, Inherited, since "2025-02-05";
 
     // formating
     FormatArguments, "format_arguments", Target::Struct, [Generics(1) /* lifetime */], No, Inherited;
@@ -382,16 +625,16 @@ lang_item_table! {
     // panicking
     PanicLocation, "panic_location", Target::Struct, [Generics(1) /* lifetime */], No, Inherited;
     PanicInfo, "panic_info", Target::Struct, [Generics(1) /* lifetime */], No, Inherited;
-    Panic, "panic", Target::Fn, [Arguments(1), Constness], No, Inherited;
-    PanicImpl, "panic_impl", Target::Fn, [Arguments(1)], No, Inherited;
-    PanicFmt, "panic_fmt", Target::Fn, [Arguments(1), Constness], No, Inherited;
-    PanicNounwind, "panic_nounwind", Target::Fn, [Arguments(1), Constness], No, Inherited;
-    PanicBoundsCheck, "panic_bounds_check", Target::Fn, [Arguments(2)], No, Inherited;
-    PanicMisalignedPointerDereference, "panic_misaligned_pointer_dereference", Target::Fn, [Arguments(2)], No, Inherited;
-    PanicNullPointerDereference, "panic_null_pointer_dereference", Target::Fn, [Arguments(0)], No, Inherited;
-    PanicCannotUnwind, "panic_cannot_unwind", Target::Fn, [Arguments(0)], No, Inherited;
-    PanicInCleanup, "panic_in_cleanup", Target::Fn, [Arguments(0)], No, Inherited;
-    ConstPanicFmt, "const_panic_fmt", Target::Fn, [Arguments(1), Constness], No, Inherited;
+    Panic, "panic", Target::Fn, [Arguments(1), Constness, TrackCaller, Signature("fn(&'static str) -> !")], No, Inherited;
+    PanicImpl, "panic_impl", Target::Fn, [Arguments(1), Signature("fn(&PanicInfo<'_>) -> !")], No, Inherited;
+    PanicFmt, "panic_fmt", Target::Fn, [Arguments(1), Constness, TrackCaller, Signature("fn(Arguments<'_>) -> !")], No, Inherited;
+    PanicNounwind, "panic_nounwind", Target::Fn, [Arguments(1), Constness, Signature("fn(&'static str) -> !")], No, Inherited;
+    PanicBoundsCheck, "panic_bounds_check", Target::Fn, [Arguments(2), TrackCaller, Signature("fn(usize, usize) -> !")], No, Inherited;
+    PanicMisalignedPointerDereference, "panic_misaligned_pointer_dereference", Target::Fn, [Arguments(2), TrackCaller, Signature("fn(usize, usize) -> !")], No, Inherited;
+    PanicNullPointerDereference, "panic_null_pointer_dereference", Target::Fn, [Arguments(0), TrackCaller, Signature("fn() -> !")], No, Inherited;
+    PanicCannotUnwind, "panic_cannot_unwind", Target::Fn, [Arguments(0), Signature("fn() -> !")], No, Inherited;
+    PanicInCleanup, "panic_in_cleanup", Target::Fn, [Arguments(0), Signature("fn() -> !")], No, Inherited;
+    ConstPanicFmt, "const_panic_fmt", Target::Fn, [Arguments(1), Constness, Signature("fn(Arguments<'_>) -> !")], No, Inherited;
 
     // macros
     MacroPanic, "macro_panic", Target::Macro, [], Named("core_panic_macro"), No;
@@ -408,7 +651,37 @@ lang_item_table! {
     #[cfg(feature = "test_lang_item")]
     TestUnsafety, "foo_unsafe", Target::Trait, [Unsafety], No, No;
     #[cfg(feature = "test_lang_item")]
+    TestShape, "foo_shape", Target::Trait, [Generics(1), Shape("trait Foo<T>: Clone { type Output: ?Sized; fn foo(&mut self, value: T) -> Option<Self::Output>; }")], No, No;
+    #[cfg(feature = "test_lang_item")]
     TestFnUnsafety, "foo_fn_unsafe", Target::Fn, [Unsafety], No, No;
     #[cfg(feature = "test_lang_item")]
     TestFn, "foo_fn", Target::Fn, [Arguments(1)], No, No;
+    #[cfg(feature = "test_lang_item")]
+    TestFnSignature, "foo_fn_signature", Target::Fn, [Generics(1), Arguments(2), TrackCaller, Signature("for<T> fn(*mut T, &[usize]) -> !")], No, No;
+    #[cfg(feature = "test_lang_item")]
+    TestAssocType, "foo_assoc_type", Target::AssocType, [], No, No;
+    #[cfg(feature = "test_lang_item")]
+    TestAssocFn, "foo_assoc_fn", Target::AssocFn, [Arguments(1), Signature("fn(&Self) -> usize")], No, No;
+    #[cfg(feature = "test_lang_item")]
+    TestVariant, "foo_variant", Target::Variant, [], No, No;
+    #[cfg(feature = "test_lang_item")]
+    TestFnAbi, "foo_fn_abi", Target::Fn, [Arguments(1), Signature("extern \"C\" fn(u8) -> u8")], No, No;
+    #[cfg(feature = "test_lang_item")]
+    TestRepr, "foo_repr", Target::Struct, [CLayout, Packed(2)], No, No;
+    #[cfg(feature = "test_lang_item")]
+    TestReprAlign, "foo_repr_align", Target::Struct, [CLayout, Align(8)], No, No;
+    #[cfg(feature = "test_lang_item")]
+    TestReprInteger, "foo_repr_integer", Target::Enum, [Integer("u8")], No, No;
+    #[cfg(feature = "test_lang_item")]
+    TestReprSimd, "foo_repr_simd", Target::Struct, [Simd], No, No;
+    #[cfg(feature = "test_lang_item")]
+    TestTransparent, "foo_transparent", Target::Struct, [Generics(1), Transparent], No, No;
+    #[cfg(feature = "test_lang_item")]
+    TestUnimplementable, "foo_unimplementable", Target::Trait, [Unimplementable], No, No;
+    #[cfg(feature = "test_lang_item")]
+    TestObjectUnimplementable, "foo_object_unimplementable", Target::Trait, [ObjectUnimplementable], No, No;
+    #[cfg(feature = "test_lang_item")]
+    TestSince, "foo_since", Target::Fn, [], No, No, since "9999-01-01";
+    #[cfg(feature = "test_lang_item")]
+    TestUntil, "foo_until", Target::Fn, [], No, No, until "2000-01-01";
 }
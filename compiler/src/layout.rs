@@ -0,0 +1,189 @@
+use proc_macro2::{Literal, TokenStream};
+use quote::quote;
+use syn::parse::Parser;
+use syn::punctuated::Punctuated;
+use syn::{Error, Expr, Lit, Token, Type, UnOp};
+
+use crate::repr::INTEGERS;
+use crate::symbol::{
+    COMPILER_VALID_RANGE, RUSTC_LAYOUT_SCALAR_VALID_RANGE_END,
+    RUSTC_LAYOUT_SCALAR_VALID_RANGE_START, VALID_RANGE,
+};
+
+/// A scalar type whose valid range can be restricted.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Scalar {
+    /// The name of the type, a pointer is checked as a `usize`.
+    name: &'static str,
+    signed: bool,
+    /// The largest valid unsigned value, `char` stops at `0x10FFFF`.
+    max: u128,
+}
+
+impl Scalar {
+    /// The scalar of `ty`: a primitive integer, `char` or a raw pointer.
+    ///
+    /// The pointer-sized types are checked as 64 bits types, rustc checks them
+    /// against the pointer width of the target.
+    fn of(ty: &Type) -> Option<Self> {
+        let (name, signed, bits) = match ty {
+            Type::Ptr(_) => ("usize", false, 64),
+            Type::Path(path) if path.qself.is_none() => {
+                let ident = path.path.get_ident()?;
+                if ident == "char" {
+                    return Some(Self {
+                        name: "char",
+                        signed: false,
+                        max: 0x10FFFF,
+                    });
+                }
+                let integer = INTEGERS.iter().find(|integer| **integer == *ident)?.0;
+                let bits = match &integer[1..] {
+                    "size" => 64,
+                    bits => bits.parse().ok()?,
+                };
+                (integer, integer.starts_with('i'), bits)
+            }
+            _ => return None,
+        };
+        Some(Self {
+            name,
+            signed,
+            max: u128::MAX >> (128 - bits),
+        })
+    }
+
+    /// Returns the bits of `value`, the value being checked to fit the type.
+    fn encode(self, value: &Expr) -> syn::Result<u128> {
+        // `-1` is parsed as a negative literal in attributes
+        let (negated, literal) = match value {
+            Expr::Unary(unary) if matches!(unary.op, UnOp::Neg(_)) => (true, &*unary.expr),
+            value => (false, value),
+        };
+        let Expr::Lit(syn::ExprLit {
+            lit: Lit::Int(literal),
+            ..
+        }) = literal
+        else {
+            return Err(Error::new_spanned(value, "expected an integer literal"));
+        };
+        let digits = literal.base10_digits();
+        let negative = negated != digits.starts_with('-');
+        let magnitude = digits
+            .trim_start_matches('-')
+            .parse::<u128>()
+            .map_err(|error| Error::new_spanned(value, error))?;
+
+        let signed_max = self.max >> 1;
+        match (negative, self.signed) {
+            (false, false) if magnitude <= self.max => Ok(magnitude),
+            (false, true) if magnitude <= signed_max => Ok(magnitude),
+            // two's complement, truncated to the size of the type
+            (true, true) if magnitude <= signed_max + 1 => Ok(magnitude.wrapping_neg() & self.max),
+            _ => Err(Error::new_spanned(
+                value,
+                format!("the value does not fit in `{}`", self.name),
+            )),
+        }
+    }
+}
+
+/// Returns the scalar type of the single field of `item`.
+pub fn scalar_field(item: &syn::ItemStruct, rule: &str) -> syn::Result<Scalar> {
+    let mut fields = item.fields.iter();
+    let (Some(field), None) = (fields.next(), fields.next()) else {
+        return Err(Error::new_spanned(
+            item,
+            format!("`{rule}` must be applied to a struct with a single field"),
+        ));
+    };
+    Scalar::of(&field.ty).ok_or_else(|| {
+        Error::new_spanned(
+            &field.ty,
+            format!(
+                "`{rule}` expects a field of a scalar type: an integer, `char` or a raw pointer"
+            ),
+        )
+    })
+}
+
+/// The bounds of `compiler::valid_range`, both inclusive.
+pub struct ValidRange {
+    start: Option<u128>,
+    end: Option<u128>,
+}
+
+impl ValidRange {
+    /// Parses `start = ..., end = ...`, at least one of them, the values being
+    /// checked against `scalar`.
+    pub fn parse(tokens: TokenStream, scalar: Scalar) -> syn::Result<Self> {
+        let args = Punctuated::<syn::MetaNameValue, Token![,]>::parse_terminated.parse2(tokens)?;
+        let mut range = Self {
+            start: None,
+            end: None,
+        };
+        for arg in &args {
+            let bound = match arg.path.get_ident() {
+                Some(ident) if ident == "start" => &mut range.start,
+                Some(ident) if ident == "end" => &mut range.end,
+                _ => return Err(Error::new_spanned(&arg.path, "expected `start` or `end`")),
+            };
+            if bound.is_some() {
+                return Err(Error::new_spanned(&arg.path, "duplicate bound"));
+            }
+            *bound = Some(scalar.encode(&arg.value)?);
+        }
+        if range.start.is_none() && range.end.is_none() {
+            return Err(Error::new_spanned(
+                &args,
+                "must be of the form: `#[compiler::valid_range(start = ..., end = ...)]`",
+            ));
+        }
+        Ok(range)
+    }
+
+    /// Returns the valid range given to the struct by its attributes, either
+    /// the raw `rustc_layout_scalar_valid_range_*` or a `compiler::valid_range`
+    /// not expanded yet.
+    pub fn of(attrs: &[syn::Attribute], scalar: Scalar) -> syn::Result<Self> {
+        let mut range = Self {
+            start: None,
+            end: None,
+        };
+        for attr in attrs {
+            let syn::Meta::List(list) = &attr.meta else {
+                continue;
+            };
+            let bound = if RUSTC_LAYOUT_SCALAR_VALID_RANGE_START == list.path {
+                &mut range.start
+            } else if RUSTC_LAYOUT_SCALAR_VALID_RANGE_END == list.path {
+                &mut range.end
+            } else if VALID_RANGE == list.path || COMPILER_VALID_RANGE == list.path {
+                let ValidRange { start, end } = Self::parse(list.tokens.clone(), scalar)?;
+                range.start = start.or(range.start);
+                range.end = end.or(range.end);
+                continue;
+            } else {
+                continue;
+            };
+            *bound = Some(syn::parse2::<syn::LitInt>(list.tokens.clone())?.base10_parse()?);
+        }
+        Ok(range)
+    }
+
+    /// Whether `0` is outside of the range, a range whose start is after its
+    /// end wraps around and always contains `0`.
+    pub fn excludes_zero(&self) -> bool {
+        let start = self.start.unwrap_or(0);
+        start > 0 && self.end.is_none_or(|end| start <= end)
+    }
+
+    pub fn to_attributes(&self) -> TokenStream {
+        let start = self.start.map(Literal::u128_unsuffixed).into_iter();
+        let end = self.end.map(Literal::u128_unsuffixed).into_iter();
+        quote! {
+            #(#[rustc_layout_scalar_valid_range_start(#start)])*
+            #(#[rustc_layout_scalar_valid_range_end(#end)])*
+        }
+    }
+}
@@ -8,14 +8,21 @@ use std::mem::MaybeUninit;
 use lang_item::{Constraint, ItemAttribut, LangItem, Target};
 use paste::paste;
 use proc_macro::TokenStream;
-use quote::quote;
-use symbol::Symbol;
+use quote::{ToTokens, quote};
+use repr::Repr;
+use symbol::{COMPILER_ITEM, Symbol};
 use syn::parse::Parser;
 use syn::punctuated::Punctuated;
 use syn::{Error, Generics, Ident, Token, parse_macro_input};
+use toolchain::Bounds;
 
 mod lang_item;
+mod layout;
+mod repr;
+mod shape;
+mod signature;
 mod symbol;
+mod toolchain;
 
 macro_rules! count_metas {
     ($first:meta, $($rest:meta),+) => { 1 + count_metas!($($rest),+) };
@@ -24,8 +31,8 @@ macro_rules! count_metas {
 }
 
 macro_rules! simple_compiler_rule {
-    ($($(#[$attr:meta])* $($idents:ident)+ $(=> [$($compile_rule:meta),+])?,)+) => {$(
-        simple_compiler_rule!($(#[$attr])* $($idents)* $(=> [$($compile_rule),+])?);
+    ($($(#[$attr:meta])* $($idents:ident)+ $(=> [$($compile_rule:meta $(since $since:literal)? $(until $until:literal)?),+])?,)+) => {$(
+        simple_compiler_rule!($(#[$attr])* $($idents)* $(=> [$($compile_rule $(since $since)? $(until $until)?),+])?);
     )+};
     ($(#[$attr:meta])* remarkable $name:ident) => {paste! {
         simple_compiler_rule!($(#[$attr])* remarkable $name => [[<rustc_ $name>]]);
@@ -33,16 +40,16 @@ macro_rules! simple_compiler_rule {
     ($(#[$attr:meta])* $name:ident) => {paste! {
         simple_compiler_rule!($(#[$attr])* $name => [[<rustc_ $name>]]);
     }};
-    ($(#[$attr:meta])* remarkable $rule_name:ident => [$($compile_rule:meta),+]) => {
+    ($(#[$attr:meta])* remarkable $rule_name:ident => [$($compile_rule:meta $(since $since:literal)? $(until $until:literal)?),+]) => {
         paste! {
             #[allow(non_upper_case_globals)]
             #[allow(dead_code)]
             pub(crate) const [<$rule_name _symbols>]: [Symbol; 2 + count_metas!($($compile_rule),+)] = [Symbol(stringify!($rule_name)), Symbol(concat!("compiler::", stringify!($rule_name))), $(Symbol(stringify!($compile_rule))),+];
         }
 
-        simple_compiler_rule!($(#[$attr])* $rule_name => [$($compile_rule),+]);
+        simple_compiler_rule!($(#[$attr])* $rule_name => [$($compile_rule $(since $since)? $(until $until)?),+]);
     };
-    ($(#[$attr:meta])* $rule_name:ident => [$($compile_rule:meta),+]) => {
+    ($(#[$attr:meta])* $rule_name:ident => [$($compile_rule:meta $(since $since:literal)? $(until $until:literal)?),+]) => {
         $(#[$attr])*
         #[proc_macro_attribute]
         pub fn $rule_name(attr: TokenStream, item: TokenStream) -> TokenStream {
@@ -51,9 +58,18 @@ macro_rules! simple_compiler_rule {
 
             match attr.is_empty() {
                 false => Error::new_spanned(attr, concat!("must be of the form: `#[compiler::", stringify!($rule_name), "]`")).into_compile_error(),
-                true => quote! {
-                    $(#[$compile_rule])+
-                    #item
+                true => {
+                    // only the attributes known by the active rustc are emitted
+                    let compile_rules = [$(
+                        (Bounds::ALWAYS $(.since($since))? $(.until($until))?, quote! { #[$compile_rule] }),
+                    )+];
+                    let compile_rules = compile_rules
+                        .into_iter()
+                        .filter_map(|(bounds, compile_rule)| bounds.is_active().then_some(compile_rule));
+                    quote! {
+                        #(#compile_rules)*
+                        #item
+                    }
                 }
             }.into()
         }
@@ -140,6 +156,10 @@ macro_rules! semi_complex_compiler_rule {
 }
 
 simple_compiler_rule! {
+    // A compile rule can be followed by `since "date"` and `until "date"`
+    // bounds (see `toolchain::Bounds`), it is then only emitted for the rustc
+    // versions knowing it.
+
     // PURELY REEXPORTED RULES
 
     /// The `const_trait` attribute is used to tell the compiler that the targeted
@@ -270,7 +290,11 @@ simple_compiler_rule! {
     nounwind,
     /// The `pure_intrinsic` attribute is used to tell the compiler that a
     /// function is an intrinsic function of the compiler.
-    pure_intrinsic => [rustc_intrinsic_must_be_overridden, rustc_intrinsic],
+    ///
+    /// rustc does not know `rustc_intrinsic_must_be_overridden` anymore since
+    /// 2025-02-25, the body of the function is then only a fallback for the
+    /// backends which do not implement the intrinsic.
+    pure_intrinsic => [rustc_intrinsic_must_be_overridden until "2025-02-25", rustc_intrinsic],
 }
 
 semi_complex_compiler_rule! {
@@ -283,6 +307,76 @@ semi_complex_compiler_rule! {
     },
 }
 
+/// The `valid_range` attribute is used to tell the compiler that the single
+/// field of the targeted struct only takes the values of an inclusive range,
+/// the values outside of it are a niche used by the layout of enums (like
+/// `Option`).
+///
+/// The field must be a primitive integer, a `char` or a raw pointer, and the
+/// bounds must fit its type. Creating the struct or mutating its field is
+/// unsafe.
+///
+/// # Example
+///
+/// ```ignore
+/// #[compiler::valid_range(start = 1)]
+/// #[repr(transparent)]
+/// pub struct NonZeroU32(u32);
+///
+/// #[compiler::valid_range(start = -128, end = 127)]
+/// struct Small(i16);
+/// ```
+#[proc_macro_attribute]
+pub fn valid_range(attr: TokenStream, item: TokenStream) -> TokenStream {
+    let item = parse_macro_input!(item as syn::ItemStruct);
+    let range = layout::scalar_field(&item, "valid_range")
+        .and_then(|scalar| layout::ValidRange::parse(attr.into(), scalar));
+    match range {
+        Ok(range) => {
+            let attributes = range.to_attributes();
+            quote! {
+                #attributes
+                #item
+            }
+        }
+        Err(error) => error.into_compile_error(),
+    }
+    .into()
+}
+
+/// The `niche_guaranteed` attribute is used to tell the compiler that the
+/// niche of the targeted struct is guaranteed: `Option<T>` is as large as `T`
+/// and is FFI-safe, `None` being `0`.
+///
+/// The struct must have a single scalar field whose valid range excludes `0`,
+/// see [`valid_range`].
+#[proc_macro_attribute]
+pub fn niche_guaranteed(attr: TokenStream, item: TokenStream) -> TokenStream {
+    let attr = proc_macro2::TokenStream::from(attr);
+    if !attr.is_empty() {
+        return Error::new_spanned(attr, "must be of the form: `#[compiler::niche_guaranteed]`")
+            .into_compile_error()
+            .into();
+    }
+
+    let item = parse_macro_input!(item as syn::ItemStruct);
+    let range = layout::scalar_field(&item, "niche_guaranteed")
+        .and_then(|scalar| layout::ValidRange::of(&item.attrs, scalar));
+    match range {
+        Ok(range) if range.excludes_zero() => quote! {
+            #[rustc_nonnull_optimization_guaranteed]
+            #item
+        },
+        Ok(_) => Error::new_spanned(
+            &item,
+            "`niche_guaranteed` requires a valid range excluding 0, like `#[compiler::valid_range(start = 1)]`",
+        )
+        .into_compile_error(),
+        Err(error) => error.into_compile_error(),
+    }
+    .into()
+}
+
 /// The `item` attribute is used to tell the compiler that the target is special.
 ///
 /// Due to the compiler's operation and rust's team choice, a part of the core
@@ -293,20 +387,48 @@ semi_complex_compiler_rule! {
 ///   - desugaring (like with the operator overloading, [`Range`][std::ops::Range] for example)
 ///   - optimise (by override the implemention by an more optimized version)
 ///   - alter default behavior (e.g. allow a structure to have special semantics that cannot be expressed by default)
+///
+/// The attribute can also be applied to the associated types and functions of
+/// traits and impls. As attribute macros are not allowed on enum variants, the
+/// `#[compiler::item("...")]` of the variants are expanded by the attribute of
+/// their enum, or by [`variants`] if the enum is not a lang item.
 #[proc_macro_attribute]
 pub fn item(attr: TokenStream, item: TokenStream) -> TokenStream {
-    let lang_item = match LangItem::try_from(parse_macro_input!(attr as syn::LitStr)) {
+    let name = parse_macro_input!(attr as syn::LitStr);
+    let lang_item = match LangItem::try_from(name.clone()) {
         Ok(attrs) => attrs,
-        Err(error) => return error.into_compile_error().into(),
+        Err(error) => {
+            let error = match target_of(item.into()) {
+                Some(target) => LangItem::with_valid_items(error, target),
+                None => error,
+            };
+            return error.into_compile_error().into();
+        }
+    };
+    if let Err(error) = lang_item.check_toolchain(&name) {
+        return error.into_compile_error().into();
+    }
+    let target = lang_item.target();
+    let mut item = match target {
+        Target::AssocType | Target::AssocFn => match AnyItem::parse_associated(item.into()) {
+            Ok(item) => item,
+            Err(error) => return error.into_compile_error().into(),
+        },
+        _ => AnyItem::Item(parse_macro_input!(item as syn::Item)),
     };
-    let item = parse_macro_input!(item as syn::Item);
+    let mut variants = Vec::new();
+    if let AnyItem::Item(syn::Item::Enum(item)) = &mut item {
+        match expand_variants(item) {
+            Ok(lang_items) => variants = lang_items,
+            Err(error) => return error.into_compile_error().into(),
+        }
+    }
 
     let mut constraints = lang_item
         .constraints()
         .iter()
         .map(|constraint| (*constraint, false))
         .collect::<HashMap<_, bool>>();
-    let target = lang_item.target();
 
     macro_rules! check_constraint {
         ($constraint:path, $token:expr) => {
@@ -327,19 +449,12 @@ pub fn item(attr: TokenStream, item: TokenStream) -> TokenStream {
             constraints.insert($constraint, true);
         };
     }
-    let (generics, attrs) = match (target, &item) {
-        (Target::Trait, syn::Item::Trait(item)) => {
-            check_constraint!(Constraint::Auto, item.auto_token);
-            check_constraint!(Constraint::Unsafety, item.unsafety);
-
-            (&item.generics, &item.attrs)
-        }
-        (Target::Struct, syn::Item::Struct(item)) => (&item.generics, &item.attrs),
-        (Target::Fn, syn::Item::Fn(item)) => {
-            check_constraint!(Constraint::Constness, item.sig.constness);
-            check_constraint!(Constraint::Unsafety, item.sig.unsafety);
+    macro_rules! check_function {
+        ($sig:expr) => {
+            check_constraint!(Constraint::Constness, $sig.constness);
+            check_constraint!(Constraint::Unsafety, $sig.unsafety);
 
-            let args_len = item.sig.inputs.len();
+            let args_len = $sig.inputs.len();
             let argument_constraint = constraints
                 .iter()
                 .find(|(constraint, _)| matches!(constraint, Constraint::Arguments(_)))
@@ -350,17 +465,64 @@ pub fn item(attr: TokenStream, item: TokenStream) -> TokenStream {
                 && n != args_len
             {
                 // TODO: change msg to avoid '1 arguments'
-                return syn::Error::new_spanned(&item.sig, format!("`{}` item expected to have {1} arguments but the definition has {2} arguments\n please force the function to have {1} arguments", lang_item.name(), n, args_len)).into_compile_error().into();
+                return syn::Error::new_spanned(&$sig, format!("`{}` item expected to have {1} arguments but the definition has {2} arguments\n please force the function to have {1} arguments", lang_item.name(), n, args_len)).into_compile_error().into();
             }
             constraints.insert(argument_constraint, true);
 
+            let signature_constraint = constraints
+                .keys()
+                .find(|constraint| matches!(constraint, Constraint::Signature(_)))
+                .copied();
+            if let Some(constraint @ Constraint::Signature(schema)) = signature_constraint {
+                if let Err(error) = signature::check_signature(lang_item, schema, &$sig) {
+                    return error.into_compile_error().into();
+                }
+                constraints.insert(constraint, true);
+            }
+        };
+    }
+    let (generics, attrs) = match (target, &item) {
+        (Target::Trait, AnyItem::Item(syn::Item::Trait(item))) => {
+            check_constraint!(Constraint::Auto, item.auto_token);
+            check_constraint!(Constraint::Unsafety, item.unsafety);
+
+            let shape_constraint = constraints
+                .keys()
+                .find(|constraint| matches!(constraint, Constraint::Shape(_)))
+                .copied();
+            if let Some(constraint @ Constraint::Shape(schema)) = shape_constraint {
+                if let Err(error) = shape::check_trait_shape(lang_item, schema, item) {
+                    return error.into_compile_error().into();
+                }
+                constraints.insert(constraint, true);
+            }
+
+            (&item.generics, &item.attrs)
+        }
+        (Target::Struct, AnyItem::Item(syn::Item::Struct(item))) => (&item.generics, &item.attrs),
+        (Target::Fn, AnyItem::Item(syn::Item::Fn(item))) => {
+            check_function!(item.sig);
+            (&item.sig.generics, &item.attrs)
+        }
+        (Target::AssocFn, AnyItem::Trait(syn::TraitItem::Fn(item))) => {
+            check_function!(item.sig);
             (&item.sig.generics, &item.attrs)
         }
-        (Target::Enum, syn::Item::Enum(item)) => (&item.generics, &item.attrs),
-        (Target::Union, syn::Item::Union(item)) => (&item.generics, &item.attrs),
-        (Target::Macro, syn::Item::Macro(item)) => (&Generics::default(), &item.attrs),
-        (Target::Type, syn::Item::Type(item)) => (&item.generics, &item.attrs),
-        (Target::EnumVariant, syn::Item::Verbatim(_)) => (&Generics::default(), &Vec::new()),
+        (Target::AssocFn, AnyItem::Impl(syn::ImplItem::Fn(item))) => {
+            check_function!(item.sig);
+            (&item.sig.generics, &item.attrs)
+        }
+        (Target::Enum, AnyItem::Item(syn::Item::Enum(item))) => (&item.generics, &item.attrs),
+        (Target::Union, AnyItem::Item(syn::Item::Union(item))) => (&item.generics, &item.attrs),
+        (Target::Macro, AnyItem::Item(syn::Item::Macro(item))) => {
+            (&Generics::default(), &item.attrs)
+        }
+        (Target::AssocType, AnyItem::Trait(syn::TraitItem::Type(item))) => {
+            (&item.generics, &item.attrs)
+        }
+        (Target::AssocType, AnyItem::Impl(syn::ImplItem::Type(item))) => {
+            (&item.generics, &item.attrs)
+        }
         (target, _) => {
             return syn::Error::new_spanned(
                 item,
@@ -387,12 +549,15 @@ pub fn item(attr: TokenStream, item: TokenStream) -> TokenStream {
         // TODO: change msg to avoid '1 generics'
         return syn::Error::new_spanned(item, format!("`{}` item expected to have {1} generics but the definition has {2} generics\n please force the {3} item to have {1} generics", lang_item.name(), n, generic_len, target.name())).into_compile_error().into();
     }
+    constraints.insert(generic_constraint, true);
 
-    let items_attrs = attrs
-        .iter()
-        .map(|attr| ItemAttribut::from(attr.meta.clone()))
-        .filter(|item| item != &ItemAttribut::Unknown)
-        .collect::<HashSet<_>>();
+    let mut items_attrs = HashSet::new();
+    for attr in attrs {
+        match ItemAttribut::parse(&attr.meta) {
+            Ok(attributes) => items_attrs.extend(attributes),
+            Err(error) => return error.into_compile_error().into(),
+        }
+    }
     for (constraint, checked) in constraints {
         if checked {
             continue;
@@ -402,16 +567,28 @@ pub fn item(attr: TokenStream, item: TokenStream) -> TokenStream {
             Constraint::Auto
             | Constraint::Unsafety
             | Constraint::Generics(_)
-            | Constraint::Arguments(_) => false,
+            | Constraint::Arguments(_)
+            | Constraint::Signature(_)
+            | Constraint::Shape(_) => false,
             Constraint::Constness => items_attrs.contains(&ItemAttribut::ConstTrait),
             Constraint::Fundamental => items_attrs.contains(&ItemAttribut::Fundamental),
-            Constraint::CLayout => items_attrs.contains(&ItemAttribut::CLayout),
-            Constraint::Transparent => items_attrs.contains(&ItemAttribut::Transparent),
+            Constraint::CLayout => items_attrs.contains(&ItemAttribut::Repr(Repr::C)),
+            Constraint::Transparent => items_attrs.contains(&ItemAttribut::Repr(Repr::Transparent)),
+            Constraint::Simd => items_attrs.contains(&ItemAttribut::Repr(Repr::Simd)),
+            Constraint::Packed(n) => items_attrs.contains(&ItemAttribut::Repr(Repr::Packed(n))),
+            Constraint::Align(n) => items_attrs.contains(&ItemAttribut::Repr(Repr::Align(n))),
+            Constraint::Integer(integer) => {
+                items_attrs.contains(&ItemAttribut::Repr(Repr::Integer(integer)))
+            }
             Constraint::Coinductive => items_attrs.contains(&ItemAttribut::Coinductive),
             Constraint::ObjectUnimplementable => {
                 items_attrs.contains(&ItemAttribut::ObjectUnimplementable)
             }
-            Constraint::Unimplementable => items_attrs.contains(&ItemAttribut::Unimplementable),
+            Constraint::Unimplementable => {
+                items_attrs.contains(&ItemAttribut::DenyExplicitImpl)
+                    && items_attrs.contains(&ItemAttribut::ObjectUnimplementable)
+            }
+            Constraint::TrackCaller => items_attrs.contains(&ItemAttribut::TrackCaller),
         };
 
         if !checked {
@@ -429,9 +606,204 @@ pub fn item(attr: TokenStream, item: TokenStream) -> TokenStream {
         }
     }
 
+    // associated items cannot be followed by an item, their duplicates are
+    // only reported by the compiler
+    let cfgs = match cfgs(attrs) {
+        Ok(cfgs) => cfgs,
+        Err(error) => return error.into_compile_error().into(),
+    };
+    let markers = match target {
+        Target::AssocType | Target::AssocFn => Vec::new(),
+        _ => [(lang_item, Vec::new())]
+            .into_iter()
+            .chain(variants)
+            .map(|(lang_item, variant_cfgs)| marker(lang_item, cfgs.iter().chain(&variant_cfgs)))
+            .collect(),
+    };
+
     quote! {
         #lang_item
         #item
+        #(#markers)*
     }
     .into()
 }
+
+/// The `variants` attribute expands the `#[compiler::item("...")]` attributes
+/// of the variants of an enum which is not a lang item itself.
+#[proc_macro_attribute]
+pub fn variants(attr: TokenStream, item: TokenStream) -> TokenStream {
+    let attr = proc_macro2::TokenStream::from(attr);
+    if !attr.is_empty() {
+        return Error::new_spanned(attr, "must be of the form: `#[compiler::variants]`")
+            .into_compile_error()
+            .into();
+    }
+
+    let mut item = parse_macro_input!(item as syn::ItemEnum);
+    match expand_variants(&mut item).and_then(|variants| Ok((cfgs(&item.attrs)?, variants))) {
+        Ok((cfgs, variants)) => {
+            let markers = variants.into_iter().map(|(lang_item, variant_cfgs)| {
+                marker(lang_item, cfgs.iter().chain(&variant_cfgs))
+            });
+            quote! {
+                #item
+                #(#markers)*
+            }
+        }
+        Err(error) => error.into_compile_error(),
+    }
+    .into()
+}
+
+/// The kind of an item the `item` attribute is applied to, if it is one of the
+/// lang items targets.
+fn target_of(item: proc_macro2::TokenStream) -> Option<Target> {
+    match syn::parse2::<syn::Item>(item.clone()) {
+        Ok(syn::Item::Trait(_)) => Some(Target::Trait),
+        Ok(syn::Item::Struct(_)) => Some(Target::Struct),
+        Ok(syn::Item::Enum(_)) => Some(Target::Enum),
+        Ok(syn::Item::Union(_)) => Some(Target::Union),
+        Ok(syn::Item::Macro(_)) => Some(Target::Macro),
+        // a method with a body is also a valid function item
+        Ok(syn::Item::Fn(item)) => match item.sig.receiver() {
+            Some(_) => Some(Target::AssocFn),
+            None => Some(Target::Fn),
+        },
+        _ => match AnyItem::parse_associated(item) {
+            Ok(AnyItem::Trait(syn::TraitItem::Type(_)) | AnyItem::Impl(syn::ImplItem::Type(_))) => {
+                Some(Target::AssocType)
+            }
+            Ok(AnyItem::Trait(syn::TraitItem::Fn(_)) | AnyItem::Impl(syn::ImplItem::Fn(_))) => {
+                Some(Target::AssocFn)
+            }
+            _ => None,
+        },
+    }
+}
+
+/// An item the `item` attribute can be applied to.
+enum AnyItem {
+    Item(syn::Item),
+    Trait(syn::TraitItem),
+    Impl(syn::ImplItem),
+}
+
+impl AnyItem {
+    /// Parses an associated type or function, of a trait or of an impl.
+    fn parse_associated(tokens: proc_macro2::TokenStream) -> syn::Result<Self> {
+        // items with a visibility or without a default type are only valid in
+        // impls
+        match syn::parse2::<syn::TraitItem>(tokens.clone()) {
+            Ok(item @ (syn::TraitItem::Type(_) | syn::TraitItem::Fn(_))) => Ok(Self::Trait(item)),
+            _ => syn::parse2::<syn::ImplItem>(tokens).map(Self::Impl),
+        }
+    }
+}
+
+impl ToTokens for AnyItem {
+    fn to_tokens(&self, tokens: &mut proc_macro2::TokenStream) {
+        match self {
+            Self::Item(item) => item.to_tokens(tokens),
+            Self::Trait(item) => item.to_tokens(tokens),
+            Self::Impl(item) => item.to_tokens(tokens),
+        }
+    }
+}
+
+/// A hidden macro named after `lang_item`, exported at the root of the crate
+/// so that a lang item defined twice fails with a clear error, wherever the
+/// two definitions are. It is gated by the `cfgs` of the item (see [`cfgs`]),
+/// so that two definitions excluding each other are not duplicates.
+fn marker<'a>(
+    lang_item: LangItem,
+    cfgs: impl Iterator<Item = &'a syn::Attribute>,
+) -> proc_macro2::TokenStream {
+    let name = quote::format_ident!("__lang_item_{}", lang_item.name());
+    quote! {
+        #(#cfgs)*
+        #[doc(hidden)]
+        #[macro_export]
+        macro_rules! #name {
+            () => {};
+        }
+    }
+}
+
+/// Returns the `#[cfg(...)]` of `attrs` and their `#[cfg_attr(...)]` giving
+/// a `cfg`, with only their `cfg` attributes (the other ones may not apply
+/// to the marker of [`marker`]).
+fn cfgs(attrs: &[syn::Attribute]) -> syn::Result<Vec<syn::Attribute>> {
+    let mut cfgs = Vec::new();
+    for attr in attrs {
+        if let Some(meta) = cfg_meta(&attr.meta)? {
+            cfgs.extend(syn::Attribute::parse_outer.parse2(quote!(#[#meta]))?);
+        }
+    }
+    Ok(cfgs)
+}
+
+/// Returns `meta` if it is a `cfg`, or the `cfg_attr` it is with only its
+/// `cfg` attributes, if any.
+fn cfg_meta(meta: &syn::Meta) -> syn::Result<Option<syn::Meta>> {
+    if meta.path().is_ident("cfg") {
+        return Ok(Some(meta.clone()));
+    }
+    if !meta.path().is_ident("cfg_attr") {
+        return Ok(None);
+    }
+    let metas = Punctuated::<syn::Meta, Token![,]>::parse_terminated
+        .parse2(meta.require_list()?.tokens.clone())?;
+    let mut metas = metas.into_iter();
+    let predicate = metas
+        .next()
+        .ok_or_else(|| Error::new_spanned(meta, "expected a predicate"))?;
+    let mut cfgs = Vec::new();
+    for meta in metas {
+        cfgs.extend(cfg_meta(&meta)?);
+    }
+    Ok((!cfgs.is_empty()).then(|| syn::parse_quote!(cfg_attr(#predicate, #(#cfgs),*))))
+}
+
+/// Replaces the `#[compiler::item("...")]` attributes of the variants of
+/// `item` by the attributes of their lang item, which are returned with the
+/// `cfg`s of their variant (see [`cfgs`]).
+fn expand_variants(item: &mut syn::ItemEnum) -> syn::Result<Vec<(LangItem, Vec<syn::Attribute>)>> {
+    let mut lang_items = Vec::new();
+    for variant in &mut item.variants {
+        let mut attrs = Vec::with_capacity(variant.attrs.len());
+        let mut variant_items = Vec::new();
+        for attr in variant.attrs.drain(..) {
+            if COMPILER_ITEM != *attr.path() {
+                attrs.push(attr);
+                continue;
+            }
+
+            let name = attr.parse_args::<syn::LitStr>()?;
+            let lang_item = LangItem::try_from(name.clone())
+                .map_err(|error| LangItem::with_valid_items(error, Target::Variant))?;
+            lang_item.check_toolchain(&name)?;
+            if lang_item.target() != Target::Variant {
+                return Err(Error::new_spanned(
+                    attr,
+                    format!(
+                        "`{}` item must be applied to a {} item",
+                        lang_item.name(),
+                        lang_item.target().name()
+                    ),
+                ));
+            }
+            attrs.extend(syn::Attribute::parse_outer.parse2(lang_item.into_token_stream())?);
+            variant_items.push(lang_item);
+        }
+        let cfgs = cfgs(&attrs)?;
+        lang_items.extend(
+            variant_items
+                .into_iter()
+                .map(|lang_item| (lang_item, cfgs.clone())),
+        );
+        variant.attrs = attrs;
+    }
+
+    Ok(lang_items)
+}
@@ -0,0 +1,94 @@
+use syn::parse::Parser;
+use syn::punctuated::Punctuated;
+use syn::{Error, Token};
+
+use crate::symbol::{ALIGN, C, PACKED, RUST, SIMD, Symbol, TRANSPARENT};
+
+/// The primitive integers accepted by `#[repr(...)]`.
+pub const INTEGERS: [Symbol; 12] = [
+    Symbol("u8"),
+    Symbol("u16"),
+    Symbol("u32"),
+    Symbol("u64"),
+    Symbol("u128"),
+    Symbol("usize"),
+    Symbol("i8"),
+    Symbol("i16"),
+    Symbol("i32"),
+    Symbol("i64"),
+    Symbol("i128"),
+    Symbol("isize"),
+];
+
+/// One hint of a `#[repr(...)]` attribute.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Repr {
+    Rust,
+    C,
+    Transparent,
+    Simd,
+    /// `packed(N)`, `packed` being `packed(1)`.
+    Packed(u32),
+    Align(u32),
+    /// A primitive integer, like `u8`.
+    Integer(&'static str),
+}
+
+/// Parses the hints of `#[repr(...)]`, `tokens` being the content of the
+/// parentheses.
+///
+/// The hints are checked like rustc does: an unknown hint, a missing or
+/// superfluous argument, or an alignment which is not a power of two is an
+/// error.
+pub fn parse_repr(tokens: proc_macro2::TokenStream) -> syn::Result<Vec<Repr>> {
+    let hints = Punctuated::<syn::Meta, Token![,]>::parse_terminated.parse2(tokens)?;
+    hints.iter().map(parse_hint).collect()
+}
+
+fn parse_hint(hint: &syn::Meta) -> syn::Result<Repr> {
+    match hint {
+        syn::Meta::Path(path) if RUST == *path => Ok(Repr::Rust),
+        syn::Meta::Path(path) if C == *path => Ok(Repr::C),
+        syn::Meta::Path(path) if TRANSPARENT == *path => Ok(Repr::Transparent),
+        syn::Meta::Path(path) if SIMD == *path => Ok(Repr::Simd),
+        syn::Meta::Path(path) if PACKED == *path => Ok(Repr::Packed(1)),
+        syn::Meta::Path(path) => INTEGERS
+            .iter()
+            .find(|integer| **integer == *path)
+            .map(|integer| Repr::Integer(integer.0))
+            .ok_or_else(|| Error::new_spanned(path, "unrecognized representation hint")),
+        syn::Meta::List(list) if PACKED == list.path => {
+            Ok(Repr::Packed(parse_alignment(list, "packed")?))
+        }
+        syn::Meta::List(list) if ALIGN == list.path => {
+            Ok(Repr::Align(parse_alignment(list, "align")?))
+        }
+        syn::Meta::List(list) => Err(Error::new_spanned(
+            list,
+            "only `packed` and `align` representation hints take an argument",
+        )),
+        syn::Meta::NameValue(name_value) => Err(Error::new_spanned(
+            name_value,
+            "representation hints cannot be assigned a value",
+        )),
+    }
+}
+
+/// Parses the `N` of `packed(N)` or `align(N)`.
+fn parse_alignment(list: &syn::MetaList, hint: &str) -> syn::Result<u32> {
+    let literal = syn::parse2::<syn::LitInt>(list.tokens.clone()).map_err(|_| {
+        Error::new_spanned(
+            list,
+            format!("`{hint}` expects a literal integer as argument"),
+        )
+    })?;
+    let alignment = literal.base10_parse::<u32>()?;
+    // rustc accepts alignments up to 2^29
+    match alignment.is_power_of_two() && alignment <= 1 << 29 {
+        true => Ok(alignment),
+        false => Err(Error::new_spanned(
+            literal,
+            format!("invalid `{hint}` argument: not a power of two, or larger than 2^29"),
+        )),
+    }
+}
@@ -0,0 +1,142 @@
+use std::collections::HashMap;
+
+use syn::{Error, Ident, ItemTrait, TraitBoundModifier, TraitItem, TypeParamBound};
+
+use crate::lang_item::LangItem;
+use crate::signature::{
+    display, input_type, match_signature, path_matches, placeholders, tokens_eq,
+};
+
+/// Checks the structure of a trait lang item against its schema.
+///
+/// The schema is written as a trait declaration, e.g.
+/// `trait Index<Idx>: Sized { type Output: ?Sized; fn index(&self, index: Idx) -> &Self::Output; }`
+/// where the name of the trait does not matter and:
+/// - every supertrait must be a supertrait of the item,
+/// - every associated type must be declared by the item, with at least the
+///   given bounds,
+/// - every method must be declared by the item, and is matched like the
+///   signature of a function lang item (see
+///   [`crate::signature::check_signature`]), the receiver included.
+///
+/// The generics of the trait and of the methods stand for the ones of the
+/// item, in the same order. The item can declare more than the schema.
+pub fn check_trait_shape(
+    lang_item: LangItem,
+    schema: &'static str,
+    item: &ItemTrait,
+) -> syn::Result<()> {
+    let expected = syn::parse_str::<ItemTrait>(schema)
+        .unwrap_or_else(|_| panic!("invalid trait schema for `{}`", lang_item.name()));
+    let generics = placeholders(expected.generics.params.iter(), &item.generics);
+
+    for bound in &expected.supertraits {
+        if !item
+            .supertraits
+            .iter()
+            .any(|found| bound_matches(bound, found, &generics))
+        {
+            return Err(Error::new_spanned(
+                &item.ident,
+                format!(
+                    "`{}` item expected `{}` as supertrait",
+                    lang_item.name(),
+                    display(bound)
+                ),
+            ));
+        }
+    }
+
+    for expected_item in &expected.items {
+        match expected_item {
+            TraitItem::Type(expected) => {
+                let Some(found) = item.items.iter().find_map(|found| match found {
+                    TraitItem::Type(found) if found.ident == expected.ident => Some(found),
+                    _ => None,
+                }) else {
+                    return Err(Error::new_spanned(
+                        &item.ident,
+                        format!(
+                            "`{}` item expected an associated type `{}`",
+                            lang_item.name(),
+                            expected.ident
+                        ),
+                    ));
+                };
+
+                for bound in &expected.bounds {
+                    if !found
+                        .bounds
+                        .iter()
+                        .any(|found| bound_matches(bound, found, &generics))
+                    {
+                        return Err(Error::new_spanned(
+                            found,
+                            format!(
+                                "`{}` item expected the associated type `{}` to be bounded by `{}`",
+                                lang_item.name(),
+                                expected.ident,
+                                display(bound)
+                            ),
+                        ));
+                    }
+                }
+            }
+            TraitItem::Fn(expected) => {
+                let Some(found) = item.items.iter().find_map(|found| match found {
+                    TraitItem::Fn(found) if found.sig.ident == expected.sig.ident => Some(found),
+                    _ => None,
+                }) else {
+                    return Err(Error::new_spanned(
+                        &item.ident,
+                        format!(
+                            "`{}` item expected a method `{}`",
+                            lang_item.name(),
+                            expected.sig.ident
+                        ),
+                    ));
+                };
+
+                let mut generics = generics.clone();
+                generics.extend(placeholders(
+                    expected.sig.generics.params.iter(),
+                    &found.sig.generics,
+                ));
+                let inputs = expected
+                    .sig
+                    .inputs
+                    .iter()
+                    .map(input_type)
+                    .collect::<Vec<_>>();
+                match_signature(
+                    lang_item,
+                    &display(&expected.sig),
+                    (expected.sig.abi.as_ref(), &inputs, &expected.sig.output),
+                    &generics,
+                    &found.sig,
+                )?;
+            }
+            _ => panic!(
+                "only associated types and methods are allowed in the trait schema of `{}`",
+                lang_item.name()
+            ),
+        }
+    }
+
+    Ok(())
+}
+
+fn bound_matches(
+    expected: &TypeParamBound,
+    found: &TypeParamBound,
+    generics: &HashMap<&Ident, &Ident>,
+) -> bool {
+    match (expected, found) {
+        (TypeParamBound::Trait(expected), TypeParamBound::Trait(found)) => {
+            matches!(expected.modifier, TraitBoundModifier::Maybe(_))
+                == matches!(found.modifier, TraitBoundModifier::Maybe(_))
+                && path_matches(&expected.path, &found.path, generics)
+        }
+        (expected, found) => tokens_eq(expected, found),
+    }
+}
@@ -0,0 +1,267 @@
+use std::collections::HashMap;
+
+use quote::ToTokens;
+use syn::{
+    Error, FnArg, GenericArgument, GenericParam, Ident, Path, PathArguments, ReturnType, Type,
+    TypeBareFn,
+};
+
+use crate::lang_item::LangItem;
+
+/// Checks the signature of a function lang item against its schema.
+///
+/// The schema is written as a function pointer type, e.g.
+/// `for<T> fn(*mut T, usize) -> !`:
+/// - the type parameters of the `for<...>` binder stand for the type
+///   parameters of the item, in the same order,
+/// - `_` matches any type,
+/// - the receiver of a method is matched as its type (`Self`, `&Self`,
+///   `Pin<&mut Self>`, ...),
+/// - a path matches any longer path with the same trailing segments, so
+///   `Arguments<'_>` matches `fmt::Arguments<'a>`,
+/// - lifetimes are not checked, nor is `unsafe` which is already a
+///   `Constraint::Unsafety`.
+pub fn check_signature(
+    lang_item: LangItem,
+    schema: &'static str,
+    sig: &syn::Signature,
+) -> syn::Result<()> {
+    let expected = syn::parse_str::<TypeBareFn>(schema)
+        .unwrap_or_else(|_| panic!("invalid signature schema for `{}`", lang_item.name()));
+    let generics = placeholders(
+        expected
+            .lifetimes
+            .iter()
+            .flat_map(|binder| &binder.lifetimes),
+        &sig.generics,
+    );
+    let inputs = expected
+        .inputs
+        .iter()
+        .map(|input| &input.ty)
+        .collect::<Vec<_>>();
+
+    match_signature(
+        lang_item,
+        schema,
+        (expected.abi.as_ref(), &inputs, &expected.output),
+        &generics,
+        sig,
+    )
+}
+
+/// Maps the type parameters of a schema to the type parameters of the item,
+/// in the same order.
+pub(crate) fn placeholders<'a>(
+    schema: impl Iterator<Item = &'a GenericParam>,
+    generics: &'a syn::Generics,
+) -> HashMap<&'a Ident, &'a Ident> {
+    schema
+        .filter_map(|param| match param {
+            GenericParam::Type(param) => Some(&param.ident),
+            _ => None,
+        })
+        .zip(generics.type_params().map(|param| &param.ident))
+        .collect()
+}
+
+/// Checks the ABI, the arguments and the return type of `sig` against the
+/// expected ones, `schema` being only used in the error messages.
+pub(crate) fn match_signature(
+    lang_item: LangItem,
+    schema: &str,
+    (abi, inputs, output): (Option<&syn::Abi>, &[&Type], &ReturnType),
+    generics: &HashMap<&Ident, &Ident>,
+    sig: &syn::Signature,
+) -> syn::Result<()> {
+    let mismatch = |tokens: proc_macro2::TokenStream, what: &str| {
+        Error::new_spanned(
+            tokens,
+            format!(
+                "`{}` item expected {what} to match the signature `{schema}`",
+                lang_item.name()
+            ),
+        )
+    };
+
+    if abi_name(abi) != abi_name(sig.abi.as_ref()) {
+        let tokens = match &sig.abi {
+            Some(abi) => abi.to_token_stream(),
+            None => sig.fn_token.to_token_stream(),
+        };
+        return Err(mismatch(tokens, "the ABI"));
+    }
+
+    if inputs.len() != sig.inputs.len() {
+        return Err(mismatch(sig.to_token_stream(), "the arguments"));
+    }
+    for (expected, found) in inputs.iter().zip(&sig.inputs) {
+        if !type_matches(expected, input_type(found), generics) {
+            return Err(mismatch(found.to_token_stream(), "this argument"));
+        }
+    }
+
+    let unit = Type::Tuple(syn::TypeTuple {
+        paren_token: Default::default(),
+        elems: Default::default(),
+    });
+    let return_type = |output: &ReturnType| match output {
+        ReturnType::Default => unit.clone(),
+        ReturnType::Type(_, ty) => (**ty).clone(),
+    };
+    if !type_matches(&return_type(output), &return_type(&sig.output), generics) {
+        let tokens = match &sig.output {
+            ReturnType::Default => sig.to_token_stream(),
+            output => output.to_token_stream(),
+        };
+        return Err(mismatch(tokens, "the return type"));
+    }
+
+    Ok(())
+}
+
+/// The type of an argument, `Self`, `&Self`, ... for a receiver.
+pub(crate) fn input_type(input: &FnArg) -> &Type {
+    match input {
+        FnArg::Receiver(receiver) => &receiver.ty,
+        FnArg::Typed(pat) => &pat.ty,
+    }
+}
+
+fn abi_name(abi: Option<&syn::Abi>) -> String {
+    match abi {
+        None => "Rust".to_string(),
+        Some(syn::Abi { name: None, .. }) => "C".to_string(),
+        Some(syn::Abi {
+            name: Some(name), ..
+        }) => name.value(),
+    }
+}
+
+/// Prints tokens close to how they are written in the source, for the error
+/// messages.
+pub(crate) fn display(tokens: &impl ToTokens) -> String {
+    [
+        (" :: ", "::"),
+        (" < ", "<"),
+        (" >", ">"),
+        (" :", ":"),
+        (" ,", ","),
+        ("& ", "&"),
+        ("* ", "*"),
+        ("? ", "?"),
+        (" (", "("),
+    ]
+    .iter()
+    .fold(tokens.to_token_stream().to_string(), |acc, (from, to)| {
+        acc.replace(from, to)
+    })
+}
+
+pub(crate) fn tokens_eq(expected: &impl ToTokens, found: &impl ToTokens) -> bool {
+    expected.to_token_stream().to_string() == found.to_token_stream().to_string()
+}
+
+pub(crate) fn type_matches(
+    expected: &Type,
+    found: &Type,
+    generics: &HashMap<&Ident, &Ident>,
+) -> bool {
+    match (expected, found) {
+        (Type::Infer(_), _) => true,
+        (Type::Paren(expected), _) => type_matches(&expected.elem, found, generics),
+        (_, Type::Paren(found)) => type_matches(expected, &found.elem, generics),
+        (Type::Group(expected), _) => type_matches(&expected.elem, found, generics),
+        (_, Type::Group(found)) => type_matches(expected, &found.elem, generics),
+        (Type::Never(_), Type::Never(_)) => true,
+        (Type::Tuple(expected), Type::Tuple(found)) => {
+            expected.elems.len() == found.elems.len()
+                && expected
+                    .elems
+                    .iter()
+                    .zip(&found.elems)
+                    .all(|(expected, found)| type_matches(expected, found, generics))
+        }
+        (Type::Ptr(expected), Type::Ptr(found)) => {
+            expected.mutability.is_some() == found.mutability.is_some()
+                && type_matches(&expected.elem, &found.elem, generics)
+        }
+        (Type::Reference(expected), Type::Reference(found)) => {
+            expected.mutability.is_some() == found.mutability.is_some()
+                && type_matches(&expected.elem, &found.elem, generics)
+        }
+        (Type::Slice(expected), Type::Slice(found)) => {
+            type_matches(&expected.elem, &found.elem, generics)
+        }
+        (Type::Array(expected), Type::Array(found)) => {
+            tokens_eq(&expected.len, &found.len)
+                && type_matches(&expected.elem, &found.elem, generics)
+        }
+        (Type::Path(expected), Type::Path(found))
+            if expected.qself.is_none() && found.qself.is_none() =>
+        {
+            path_matches(&expected.path, &found.path, generics)
+        }
+        (expected, found) => tokens_eq(expected, found),
+    }
+}
+
+pub(crate) fn path_matches(
+    expected: &Path,
+    found: &Path,
+    generics: &HashMap<&Ident, &Ident>,
+) -> bool {
+    if let Some(placeholder) = expected.get_ident()
+        && let Some(param) = generics.get(placeholder)
+    {
+        return found.get_ident() == Some(*param);
+    }
+
+    let (expected, found) = (&expected.segments, &found.segments);
+    expected.len() <= found.len()
+        && expected
+            .iter()
+            .rev()
+            .zip(found.iter().rev())
+            .all(|(expected, found)| {
+                expected.ident == found.ident
+                    && arguments_match(&expected.arguments, &found.arguments, generics)
+            })
+}
+
+fn arguments_match(
+    expected: &PathArguments,
+    found: &PathArguments,
+    generics: &HashMap<&Ident, &Ident>,
+) -> bool {
+    // lifetimes are not part of the schema
+    let arguments = |arguments: &PathArguments| match arguments {
+        PathArguments::AngleBracketed(arguments) => arguments
+            .args
+            .iter()
+            .filter(|argument| !matches!(argument, GenericArgument::Lifetime(_)))
+            .cloned()
+            .collect::<Vec<_>>(),
+        _ => Vec::new(),
+    };
+
+    if matches!(expected, PathArguments::Parenthesized(_))
+        || matches!(found, PathArguments::Parenthesized(_))
+    {
+        return tokens_eq(expected, found);
+    }
+    let (expected, found) = (arguments(expected), arguments(found));
+    expected.len() == found.len()
+        && expected
+            .iter()
+            .zip(&found)
+            .all(|(expected, found)| match (expected, found) {
+                (GenericArgument::Type(expected), GenericArgument::Type(found)) => {
+                    type_matches(expected, found, generics)
+                }
+                (GenericArgument::AssocType(expected), GenericArgument::AssocType(found)) => {
+                    expected.ident == found.ident && type_matches(&expected.ty, &found.ty, generics)
+                }
+                (expected, found) => tokens_eq(expected, found),
+            })
+}
@@ -1,8 +1,26 @@
 use std::fmt::Display;
 
 pub const REPR: Symbol = Symbol("repr");
+pub const RUST: Symbol = Symbol("Rust");
 pub const C: Symbol = Symbol("C");
 pub const TRANSPARENT: Symbol = Symbol("transparent");
+pub const SIMD: Symbol = Symbol("simd");
+pub const PACKED: Symbol = Symbol("packed");
+pub const ALIGN: Symbol = Symbol("align");
+pub const TRACK_CALLER: Symbol = Symbol("track_caller");
+pub const CONST_TRAIT: Symbol = Symbol("const_trait");
+pub const FUNDAMENTAL: Symbol = Symbol("fundamental");
+pub const RUSTC_COINDUCTIVE: Symbol = Symbol("rustc_coinductive");
+pub const RUSTC_DENY_EXPLICIT_IMPL: Symbol = Symbol("rustc_deny_explicit_impl");
+pub const RUSTC_DO_NOT_IMPLEMENT_VIA_OBJECT: Symbol = Symbol("rustc_do_not_implement_via_object");
+pub const RUSTC_PUB_TRANSPARENT: Symbol = Symbol("rustc_pub_transparent");
+pub const COMPILER_ITEM: Symbol = Symbol("compiler::item");
+pub const VALID_RANGE: Symbol = Symbol("valid_range");
+pub const COMPILER_VALID_RANGE: Symbol = Symbol("compiler::valid_range");
+pub const RUSTC_LAYOUT_SCALAR_VALID_RANGE_START: Symbol =
+    Symbol("rustc_layout_scalar_valid_range_start");
+pub const RUSTC_LAYOUT_SCALAR_VALID_RANGE_END: Symbol =
+    Symbol("rustc_layout_scalar_valid_range_end");
 
 #[derive(Debug, Clone, Copy)]
 pub struct Symbol(pub &'static str);
@@ -0,0 +1,63 @@
+/// The `commit-date` of the rustc compiling the crate, as printed by
+/// `rustc -vV`, set by the build script. `None` if it is unknown, in which
+/// case every lang item and attribute is considered available.
+pub const COMMIT_DATE: Option<&str> = option_env!("COMPILER_RUSTC_COMMIT_DATE");
+
+/// The range of rustc versions knowing a lang item or an attribute.
+///
+/// The bounds are the commit dates of rustc (the `commit-date` of
+/// `rustc -vV`, usually the day before the date of the nightly): `since` is
+/// the first one knowing the item, `until` the first one not knowing it
+/// anymore.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Bounds {
+    since: Option<&'static str>,
+    until: Option<&'static str>,
+}
+
+impl Bounds {
+    /// Known by every rustc.
+    pub const ALWAYS: Self = Self {
+        since: None,
+        until: None,
+    };
+
+    pub const fn since(self, date: &'static str) -> Self {
+        Self {
+            since: Some(date),
+            ..self
+        }
+    }
+
+    pub const fn until(self, date: &'static str) -> Self {
+        Self {
+            until: Some(date),
+            ..self
+        }
+    }
+
+    /// Whether the item is known by the rustc of `date`.
+    pub fn contains(self, date: &str) -> bool {
+        // the dates are `YYYY-MM-DD`, so they can be compared as strings
+        self.since.is_none_or(|since| since <= date) && self.until.is_none_or(|until| date < until)
+    }
+
+    /// Whether the item is known by the rustc compiling the crate.
+    pub fn is_active(self) -> bool {
+        COMMIT_DATE.is_none_or(|date| self.contains(date))
+    }
+
+    /// Explains why the item is not known by the rustc compiling the crate.
+    pub fn explain(self) -> String {
+        let date = COMMIT_DATE.unwrap_or("unknown");
+        match (self.since, self.until) {
+            (Some(since), _) if date < since => format!(
+                "it was introduced by the rustc of {since}, the active rustc is the one of {date}"
+            ),
+            (_, Some(until)) => format!(
+                "it was removed by the rustc of {until}, the active rustc is the one of {date}"
+            ),
+            _ => format!("it is not known by the active rustc of {date}"),
+        }
+    }
+}
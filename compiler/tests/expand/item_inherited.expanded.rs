@@ -0,0 +1,26 @@
+#![feature(lang_items)]
+#![feature(rustc_attrs)]
+#![allow(dead_code)]
+#![allow(internal_features)]
+#[lang = "add"]
+trait Add<Rhs> {
+    type Output;
+    fn add(self, rhs: Rhs) -> Self::Output;
+}
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __lang_item_add {
+    () => {};
+}
+#[rustc_diagnostic_item = "Iterator"]
+#[lang = "iterator"]
+trait Iterator {
+    type Item;
+    fn next(&mut self) -> Option<Self::Item>;
+}
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __lang_item_iterator {
+    () => {};
+}
+fn main() {}
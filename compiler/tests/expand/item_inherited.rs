@@ -0,0 +1,19 @@
+#![feature(lang_items)]
+#![feature(rustc_attrs)]
+
+#![allow(dead_code)]
+#![allow(internal_features)]
+
+#[compiler::item("add")]
+trait Add<Rhs> {
+    type Output;
+    fn add(self, rhs: Rhs) -> Self::Output;
+}
+
+#[compiler::item("iterator")]
+trait Iterator {
+    type Item;
+    fn next(&mut self) -> Option<Self::Item>;
+}
+
+fn main() {}
@@ -0,0 +1,27 @@
+#![feature(lang_items)]
+#![feature(rustc_attrs)]
+#![allow(dead_code)]
+#![allow(internal_features)]
+#[lang = "Ordering"]
+enum Ordering {
+    Less,
+    Equal,
+    Greater,
+}
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __lang_item_ordering {
+    () => {};
+}
+#[rustc_diagnostic_item = "cstr_type"]
+#[lang = "CStr"]
+#[repr(transparent)]
+struct CStr {
+    inner: [u8],
+}
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __lang_item_cstr {
+    () => {};
+}
+fn main() {}
@@ -0,0 +1,20 @@
+#![feature(lang_items)]
+#![feature(rustc_attrs)]
+
+#![allow(dead_code)]
+#![allow(internal_features)]
+
+#[compiler::item("ordering")]
+enum Ordering {
+    Less,
+    Equal,
+    Greater,
+}
+
+#[compiler::item("cstr")]
+#[repr(transparent)]
+struct CStr {
+    inner: [u8],
+}
+
+fn main() {}
@@ -0,0 +1,24 @@
+#![feature(rustc_attrs)]
+#![allow(dead_code)]
+#![allow(internal_features)]
+#[rustc_diagnostic_item = "IntoIterator"]
+trait IntoIterator {
+    type Item;
+    type IntoIter: Iterator<Item = Self::Item>;
+    fn into_iter(self) -> Self::IntoIter;
+}
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __lang_item_into_iterator_trait {
+    () => {};
+}
+#[rustc_diagnostic_item = "FromResidual"]
+trait FromResidual<R> {
+    fn from_residual(residual: R) -> Self;
+}
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __lang_item_from_residual {
+    () => {};
+}
+fn main() {}
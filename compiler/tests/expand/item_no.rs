@@ -0,0 +1,18 @@
+#![feature(rustc_attrs)]
+
+#![allow(dead_code)]
+#![allow(internal_features)]
+
+#[compiler::item("into_iterator_trait")]
+trait IntoIterator {
+    type Item;
+    type IntoIter: Iterator<Item = Self::Item>;
+    fn into_iter(self) -> Self::IntoIter;
+}
+
+#[compiler::item("from_residual")]
+trait FromResidual<R> {
+    fn from_residual(residual: R) -> Self;
+}
+
+fn main() {}
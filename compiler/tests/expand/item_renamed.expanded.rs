@@ -0,0 +1,11 @@
+#![feature(lang_items)]
+#![allow(dead_code)]
+#![allow(internal_features)]
+#[lang = "legacy_receiver"]
+trait LegacyReceiver {}
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __lang_item_legacy_receiver {
+    () => {};
+}
+fn main() {}
@@ -0,0 +1,9 @@
+#![feature(lang_items)]
+
+#![allow(dead_code)]
+#![allow(internal_features)]
+
+#[compiler::item("legacy_receiver")]
+trait LegacyReceiver {}
+
+fn main() {}
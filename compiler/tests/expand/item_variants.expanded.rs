@@ -0,0 +1,28 @@
+#![feature(lang_items)]
+#![feature(rustc_attrs)]
+#![allow(dead_code)]
+#![allow(internal_features)]
+#[rustc_diagnostic_item = "Option"]
+#[lang = "Option"]
+enum Option<T> {
+    #[lang = "None"]
+    None,
+    #[lang = "Some"]
+    Some(T),
+}
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __lang_item_option {
+    () => {};
+}
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __lang_item_option_none {
+    () => {};
+}
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __lang_item_option_some {
+    () => {};
+}
+fn main() {}
@@ -0,0 +1,15 @@
+#![feature(lang_items)]
+#![feature(rustc_attrs)]
+
+#![allow(dead_code)]
+#![allow(internal_features)]
+
+#[compiler::item("option")]
+enum Option<T> {
+    #[compiler::item("option_none")]
+    None,
+    #[compiler::item("option_some")]
+    Some(T),
+}
+
+fn main() {}
@@ -0,0 +1,8 @@
+#![feature(rustc_attrs)]
+#![allow(dead_code)]
+#![allow(internal_features)]
+#[rustc_nonnull_optimization_guaranteed]
+#[rustc_layout_scalar_valid_range_start(156)]
+#[rustc_layout_scalar_valid_range_end(254)]
+struct Negative(i8);
+fn main() {}
@@ -0,0 +1,10 @@
+#![feature(rustc_attrs)]
+
+#![allow(dead_code)]
+#![allow(internal_features)]
+
+#[compiler::valid_range(start = -100, end = -2)]
+#[compiler::niche_guaranteed]
+struct Negative(i8);
+
+fn main() {}
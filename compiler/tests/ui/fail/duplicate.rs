@@ -0,0 +1,11 @@
+mod a {
+    #[compiler::item("foo_fn")]
+    fn foo(_: u8) {}
+}
+
+mod b {
+    #[compiler::item("foo_fn")]
+    fn foo(_: u8) {}
+}
+
+fn main() {}
@@ -0,0 +1,6 @@
+trait Foo {
+    #[compiler::item("foo_assoc_fn")]
+    fn len(&mut self) -> usize;
+}
+
+fn main() {}
@@ -0,0 +1,6 @@
+trait Foo {
+    #[compiler::item("foo_assoc_type")]
+    fn bar(&self);
+}
+
+fn main() {}
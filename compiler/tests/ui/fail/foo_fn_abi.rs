@@ -0,0 +1,6 @@
+#[compiler::item("foo_fn_abi")]
+fn foo(x: u8) -> u8 {
+    x
+}
+
+fn main() {}
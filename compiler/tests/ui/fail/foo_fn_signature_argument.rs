@@ -0,0 +1,7 @@
+#[compiler::item("foo_fn_signature")]
+#[track_caller]
+fn foo<U>(_: *const U, _: &[usize]) -> ! {
+    loop {}
+}
+
+fn main() {}
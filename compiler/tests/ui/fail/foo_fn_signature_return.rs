@@ -0,0 +1,5 @@
+#[compiler::item("foo_fn_signature")]
+#[track_caller]
+fn foo<U>(_: *mut U, _: &[usize]) {}
+
+fn main() {}
@@ -0,0 +1,6 @@
+#[compiler::item("foo_fn_signature")]
+fn foo<U>(_: *mut U, _: &[usize]) -> ! {
+    loop {}
+}
+
+fn main() {}
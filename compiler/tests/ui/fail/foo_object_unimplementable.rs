@@ -0,0 +1,5 @@
+#[compiler::item("foo_object_unimplementable")]
+#[rustc_deny_explicit_impl]
+trait Foo {}
+
+fn main() {}
@@ -0,0 +1,8 @@
+#[compiler::item("foo_repr")]
+#[repr(C, packed)]
+struct Foo {
+    a: u8,
+    b: u32,
+}
+
+fn main() {}
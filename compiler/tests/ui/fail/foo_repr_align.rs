@@ -0,0 +1,7 @@
+#[compiler::item("foo_repr_align")]
+#[repr(align(8))]
+struct Foo {
+    a: u8,
+}
+
+fn main() {}
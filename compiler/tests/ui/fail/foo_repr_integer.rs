@@ -0,0 +1,8 @@
+#[compiler::item("foo_repr_integer")]
+#[repr(u16)]
+enum Foo {
+    A,
+    B,
+}
+
+fn main() {}
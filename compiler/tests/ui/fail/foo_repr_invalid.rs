@@ -0,0 +1,19 @@
+#[compiler::item("foo_repr")]
+#[repr(C, packed(3))]
+struct Foo {
+    a: u8,
+}
+
+#[compiler::item("foo_repr_integer")]
+#[repr(u7)]
+enum Bar {
+    A,
+}
+
+#[compiler::item("foo_repr_align")]
+#[repr(C, align = 8)]
+struct Baz {
+    a: u8,
+}
+
+fn main() {}
@@ -0,0 +1,8 @@
+#[compiler::item("foo_shape")]
+trait Foo<U>: Clone {
+    type Output: ?Sized;
+
+    fn foo(&self, value: U) -> Option<Self::Output>;
+}
+
+fn main() {}
@@ -0,0 +1,8 @@
+#[compiler::item("foo_shape")]
+trait Foo<U> {
+    type Output: ?Sized;
+
+    fn foo(&mut self, value: U) -> Option<Self::Output>;
+}
+
+fn main() {}
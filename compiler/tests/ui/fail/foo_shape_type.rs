@@ -0,0 +1,6 @@
+#[compiler::item("foo_shape")]
+trait Foo<U>: Clone {
+    fn foo(&mut self, value: U) -> Option<u8>;
+}
+
+fn main() {}
@@ -0,0 +1,4 @@
+#[compiler::item("foo_since")]
+fn foo() {}
+
+fn main() {}
@@ -0,0 +1,5 @@
+#[compiler::item("foo_transparent")]
+#[rustc_pub_transparent]
+struct Foo<T>(T);
+
+fn main() {}
@@ -0,0 +1,5 @@
+#[compiler::item("foo_unimplementable")]
+#[compiler::object_unimplementable]
+trait Foo {}
+
+fn main() {}
@@ -0,0 +1,4 @@
+#[compiler::item("foo_until")]
+fn foo() {}
+
+fn main() {}
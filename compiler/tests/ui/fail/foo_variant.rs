@@ -0,0 +1,7 @@
+#[compiler::variants]
+enum Foo {
+    #[compiler::item("foo_fn")]
+    Bar,
+}
+
+fn main() {}
@@ -0,0 +1,16 @@
+#[compiler::niche_guaranteed]
+struct NoRange(u32);
+
+#[compiler::niche_guaranteed]
+#[compiler::valid_range(start = -1, end = 1)]
+struct Zero(i8);
+
+#[compiler::niche_guaranteed]
+#[compiler::valid_range(start = 2, end = 1)]
+struct Wrapping(u8);
+
+#[compiler::niche_guaranteed(null)]
+#[compiler::valid_range(start = 1)]
+struct Argument(u8);
+
+fn main() {}
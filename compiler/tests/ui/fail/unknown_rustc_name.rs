@@ -0,0 +1,6 @@
+trait Foo {
+    #[compiler::item("into_iter")]
+    fn into_iter(self) -> Self;
+}
+
+fn main() {}
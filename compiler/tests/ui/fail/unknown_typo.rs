@@ -0,0 +1,4 @@
+#[compiler::item("foo_fm")]
+fn foo(_: u8) {}
+
+fn main() {}
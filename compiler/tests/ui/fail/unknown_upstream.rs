@@ -0,0 +1,4 @@
+#[compiler::item("owned_box")]
+struct Box<T>(T);
+
+fn main() {}
@@ -0,0 +1,28 @@
+#[compiler::valid_range(start = 1)]
+struct Two(u32, u32);
+
+#[compiler::valid_range(start = 1)]
+struct Array([u8; 4]);
+
+#[compiler::valid_range(start = 1, end = 256)]
+struct Byte(u8);
+
+#[compiler::valid_range(start = -1)]
+struct Unsigned(u16);
+
+#[compiler::valid_range(start = -129)]
+struct Signed(i8);
+
+#[compiler::valid_range(end = 0x110000)]
+struct Char(char);
+
+#[compiler::valid_range(start = 1, start = 2)]
+struct Duplicate(u8);
+
+#[compiler::valid_range(begin = 1)]
+struct Unknown(u8);
+
+#[compiler::valid_range]
+struct Empty(u8);
+
+fn main() {}
@@ -0,0 +1,30 @@
+// the definitions exclude each other, their markers are not duplicates
+mod a {
+    #[compiler::item("foo_fn")]
+    #[cfg(unix)]
+    #[cfg_attr(unix, inline)]
+    pub fn foo(_: u8) {}
+}
+
+mod b {
+    #[compiler::item("foo_fn")]
+    #[cfg_attr(unix, cfg(any()), inline)]
+    pub fn foo(_: u8) {}
+}
+
+#[compiler::variants]
+enum Foo {
+    #[compiler::item("foo_variant")]
+    #[cfg(unix)]
+    Bar,
+    #[compiler::item("foo_variant")]
+    #[cfg(not(unix))]
+    Baz,
+}
+
+fn main() {
+    #[cfg(unix)]
+    let _ = Foo::Bar;
+    #[cfg(not(unix))]
+    let _ = Foo::Baz;
+}
@@ -0,0 +1,27 @@
+trait Foo {
+    #[compiler::item("foo_assoc_type")]
+    type Bar;
+
+    #[compiler::item("foo_assoc_fn")]
+    fn len(&self) -> usize;
+}
+
+struct Baz;
+
+impl Baz {
+    #[compiler::item("foo_assoc_fn")]
+    pub fn len(&self) -> usize {
+        0
+    }
+}
+
+impl Foo for Baz {
+    #[compiler::item("foo_assoc_type")]
+    type Bar = ();
+
+    fn len(&self) -> usize {
+        0
+    }
+}
+
+fn main() {}
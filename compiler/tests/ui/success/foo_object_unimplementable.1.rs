@@ -0,0 +1,8 @@
+#![feature(rustc_attrs)]
+#![allow(internal_features)]
+
+#[compiler::item("foo_object_unimplementable")]
+#[compiler::object_unimplementable]
+trait Foo {}
+
+fn main() {}
@@ -0,0 +1,8 @@
+#![feature(rustc_attrs)]
+#![allow(internal_features)]
+
+#[compiler::item("foo_object_unimplementable")]
+#[rustc_do_not_implement_via_object]
+trait Foo {}
+
+fn main() {}
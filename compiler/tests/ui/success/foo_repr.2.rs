@@ -0,0 +1,9 @@
+#[repr(packed(2))]
+#[compiler::item("foo_repr")]
+#[repr(C)]
+struct Foo {
+    a: u8,
+    b: u32,
+}
+
+fn main() {}
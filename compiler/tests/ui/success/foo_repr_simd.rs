@@ -0,0 +1,7 @@
+#![feature(repr_simd)]
+
+#[compiler::item("foo_repr_simd")]
+#[repr(simd)]
+struct Foo([f32; 4]);
+
+fn main() {}
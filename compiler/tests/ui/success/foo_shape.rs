@@ -0,0 +1,11 @@
+#[compiler::item("foo_shape")]
+trait Foo<U>: Clone {
+    type Output: ?Sized + Copy;
+    type Other;
+
+    fn foo(&mut self, value: U) -> Option<Self::Output>;
+
+    fn bar(&self) {}
+}
+
+fn main() {}
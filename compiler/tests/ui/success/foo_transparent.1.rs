@@ -0,0 +1,5 @@
+#[compiler::item("foo_transparent")]
+#[repr(transparent)]
+struct Foo<T>(T);
+
+fn main() {}
@@ -0,0 +1,8 @@
+#![feature(rustc_attrs)]
+#![allow(internal_features)]
+
+#[compiler::item("foo_transparent")]
+#[compiler::full_transparent]
+struct Foo<T>(T);
+
+fn main() {}
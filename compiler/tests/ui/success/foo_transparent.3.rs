@@ -0,0 +1,8 @@
+#![feature(rustc_attrs)]
+#![allow(internal_features)]
+
+#[compiler::full_transparent]
+#[compiler::item("foo_transparent")]
+struct Foo<T>(T);
+
+fn main() {}
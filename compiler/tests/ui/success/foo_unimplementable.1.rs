@@ -0,0 +1,8 @@
+#![feature(rustc_attrs)]
+#![allow(internal_features)]
+
+#[compiler::item("foo_unimplementable")]
+#[compiler::unimplementable]
+trait Foo {}
+
+fn main() {}
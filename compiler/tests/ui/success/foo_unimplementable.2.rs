@@ -0,0 +1,9 @@
+#![feature(rustc_attrs)]
+#![allow(internal_features)]
+
+#[rustc_deny_explicit_impl]
+#[compiler::item("foo_unimplementable")]
+#[rustc_do_not_implement_via_object]
+trait Foo {}
+
+fn main() {}
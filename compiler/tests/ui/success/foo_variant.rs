@@ -0,0 +1,11 @@
+#[compiler::variants]
+enum Foo {
+    #[compiler::item("foo_variant")]
+    Bar,
+    #[allow(dead_code)]
+    Baz(u8),
+}
+
+fn main() {
+    let _ = Foo::Bar;
+}
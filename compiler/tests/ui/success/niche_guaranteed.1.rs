@@ -0,0 +1,13 @@
+#![feature(rustc_attrs)]
+#![allow(internal_features)]
+
+#[compiler::valid_range(start = 1)]
+#[compiler::niche_guaranteed]
+#[repr(transparent)]
+struct NonZero(u32);
+
+unsafe extern "C" {
+    fn foo(value: Option<NonZero>);
+}
+
+fn main() {}
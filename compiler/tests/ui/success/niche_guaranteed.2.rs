@@ -0,0 +1,13 @@
+#![feature(rustc_attrs)]
+#![allow(internal_features)]
+
+#[compiler::niche_guaranteed]
+#[compiler::valid_range(start = 1, end = 255)]
+#[repr(transparent)]
+struct NonZero(u32);
+
+unsafe extern "C" {
+    fn foo(value: Option<NonZero>);
+}
+
+fn main() {}
@@ -0,0 +1,13 @@
+#![feature(rustc_attrs)]
+#![allow(internal_features)]
+
+#[compiler::niche_guaranteed]
+#[rustc_layout_scalar_valid_range_start(1)]
+#[repr(transparent)]
+struct NonNull(*const u8);
+
+unsafe extern "C" {
+    fn foo(value: Option<NonNull>);
+}
+
+fn main() {}
@@ -0,0 +1,31 @@
+#![feature(rustc_attrs)]
+#![allow(internal_features)]
+
+use std::mem::size_of;
+
+#[compiler::valid_range(start = 1)]
+struct NonZero(u32);
+
+#[compiler::valid_range(start = -128, end = 127)]
+struct Small(i16);
+
+#[compiler::valid_range(end = 0xFFFF)]
+struct Bmp {
+    value: char,
+}
+
+#[compiler::valid_range(start = 1)]
+#[repr(transparent)]
+struct NonNull(*const u8);
+
+const _: () = assert!(size_of::<Option<NonZero>>() == size_of::<NonZero>());
+const _: () = assert!(size_of::<Option<Small>>() == size_of::<Small>());
+const _: () = assert!(size_of::<Option<Bmp>>() == size_of::<Bmp>());
+const _: () = assert!(size_of::<Option<NonNull>>() == size_of::<NonNull>());
+
+fn main() {
+    let _ = unsafe { NonZero(1) }.0;
+    let _ = unsafe { Small(-1) }.0;
+    let _ = unsafe { Bmp { value: 'a' } }.value;
+    let _ = unsafe { NonNull(&0) }.0;
+}
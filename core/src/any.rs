@@ -0,0 +1,283 @@
+//! Utilities for dynamic typing or type reflection.
+//!
+//! The [`Any`] trait enables dynamic typing of any `'static` type through
+//! runtime reflection: `&dyn Any` can be tested with [`is`] and downcast with
+//! [`downcast_ref`] to a concrete type, the same goes for `&mut dyn Any` with
+//! [`downcast_mut`].
+//!
+//! [`is`]: ../../core/any/trait.Any.html#method.is
+//! [`downcast_ref`]: ../../core/any/trait.Any.html#method.downcast_ref
+//! [`downcast_mut`]: ../../core/any/trait.Any.html#method.downcast_mut
+
+use crate::clone::Clone;
+use crate::cmp::{Eq, Ord, Ordering, PartialEq, PartialOrd};
+use crate::fmt::{self, Debug, Formatter};
+use crate::hash::{Hash, Hasher};
+use crate::marker::{Copy, Send, Sized, Sync};
+use crate::option::Option::{self, None, Some};
+use crate::{intrinsics, str};
+
+/// A trait to emulate dynamic typing.
+///
+/// Most types implement `Any`. However, any type which contains a non-`'static`
+/// reference does not.
+#[rustc_diagnostic_item = "Any"]
+pub trait Any: 'static {
+    /// Gets the `TypeId` of `self`.
+    fn type_id(&self) -> TypeId;
+}
+
+impl<T: 'static + ?Sized> Any for T {
+    fn type_id(&self) -> TypeId {
+        TypeId::of::<T>()
+    }
+}
+
+impl Debug for dyn Any {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Any").finish_non_exhaustive()
+    }
+}
+
+// Ensure that the result of e.g., joining a thread can be printed and
+// hence used with `unwrap`. May eventually no longer be needed if
+// dispatch works with upcasting.
+impl Debug for dyn Any + Send {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Any").finish_non_exhaustive()
+    }
+}
+
+impl Debug for dyn Any + Send + Sync {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Any").finish_non_exhaustive()
+    }
+}
+
+impl dyn Any {
+    /// Returns `true` if the inner type is the same as `T`.
+    #[inline]
+    pub fn is<T: Any>(&self) -> bool {
+        // Get `TypeId` of the type this function is instantiated with.
+        let t = TypeId::of::<T>();
+
+        // Get `TypeId` of the type in the trait object (`self`).
+        let concrete = self.type_id();
+
+        // Compare both `TypeId`s on equality.
+        t == concrete
+    }
+
+    /// Returns some reference to the inner value if it is of type `T`, or
+    /// `None` if it isn't.
+    #[inline]
+    pub fn downcast_ref<T: Any>(&self) -> Option<&T> {
+        if self.is::<T>() {
+            // SAFETY: just checked whether we are pointing to the correct type.
+            unsafe { Some(self.downcast_ref_unchecked()) }
+        } else {
+            None
+        }
+    }
+
+    /// Returns some mutable reference to the inner value if it is of type `T`,
+    /// or `None` if it isn't.
+    #[inline]
+    pub fn downcast_mut<T: Any>(&mut self) -> Option<&mut T> {
+        if self.is::<T>() {
+            // SAFETY: just checked whether we are pointing to the correct type.
+            unsafe { Some(self.downcast_mut_unchecked()) }
+        } else {
+            None
+        }
+    }
+
+    /// Returns a reference to the inner value as type `dyn T`.
+    ///
+    /// # Safety
+    ///
+    /// The contained value must be of type `T`. Calling this method with the
+    /// incorrect type is *undefined behavior*.
+    #[inline]
+    pub unsafe fn downcast_ref_unchecked<T: Any>(&self) -> &T {
+        // SAFETY: caller guarantees that T is the correct type
+        unsafe { &*(self as *const dyn Any as *const T) }
+    }
+
+    /// Returns a mutable reference to the inner value as type `dyn T`.
+    ///
+    /// # Safety
+    ///
+    /// The contained value must be of type `T`. Calling this method with the
+    /// incorrect type is *undefined behavior*.
+    #[inline]
+    pub unsafe fn downcast_mut_unchecked<T: Any>(&mut self) -> &mut T {
+        // SAFETY: caller guarantees that T is the correct type
+        unsafe { &mut *(self as *mut dyn Any as *mut T) }
+    }
+}
+
+macro_rules! forward_to_dyn_any {
+    ($($t:ty),*) => {$(
+        impl $t {
+            /// Forwards to the method defined on the type `dyn Any`.
+            #[inline]
+            pub fn is<T: Any>(&self) -> bool {
+                <dyn Any>::is::<T>(self)
+            }
+
+            /// Forwards to the method defined on the type `dyn Any`.
+            #[inline]
+            pub fn downcast_ref<T: Any>(&self) -> Option<&T> {
+                <dyn Any>::downcast_ref::<T>(self)
+            }
+
+            /// Forwards to the method defined on the type `dyn Any`.
+            #[inline]
+            pub fn downcast_mut<T: Any>(&mut self) -> Option<&mut T> {
+                <dyn Any>::downcast_mut::<T>(self)
+            }
+
+            /// Forwards to the method defined on the type `dyn Any`.
+            ///
+            /// # Safety
+            ///
+            /// Same as the method on the type `dyn Any`.
+            #[inline]
+            pub unsafe fn downcast_ref_unchecked<T: Any>(&self) -> &T {
+                // SAFETY: guaranteed by caller
+                unsafe { <dyn Any>::downcast_ref_unchecked::<T>(self) }
+            }
+
+            /// Forwards to the method defined on the type `dyn Any`.
+            ///
+            /// # Safety
+            ///
+            /// Same as the method on the type `dyn Any`.
+            #[inline]
+            pub unsafe fn downcast_mut_unchecked<T: Any>(&mut self) -> &mut T {
+                // SAFETY: guaranteed by caller
+                unsafe { <dyn Any>::downcast_mut_unchecked::<T>(self) }
+            }
+        }
+    )*};
+}
+
+forward_to_dyn_any! { dyn Any + Send, dyn Any + Send + Sync }
+
+/// A `TypeId` represents a globally unique identifier for a type.
+///
+/// Each `TypeId` is an opaque object which does not allow inspection of what's
+/// inside but does allow basic operations such as cloning, comparison,
+/// printing, and showing.
+///
+/// While `TypeId` implements `Hash`, `PartialOrd`, and `Ord`, it is worth
+/// noting that the hashes and ordering will vary between Rust releases.
+pub struct TypeId {
+    // We avoid using `u128` because that imposes higher alignment requirements
+    // on many platforms.
+    t: (u64, u64),
+}
+
+impl TypeId {
+    /// Returns the `TypeId` of the type this generic function has been
+    /// instantiated with.
+    #[must_use]
+    pub const fn of<T: ?Sized + 'static>() -> TypeId {
+        let t: u128 = intrinsics::type_id::<T>();
+
+        let t1 = (t >> 64) as u64;
+        let t2 = t as u64;
+        TypeId { t: (t1, t2) }
+    }
+
+    fn as_u128(self) -> u128 {
+        ((self.t.0 as u128) << 64) | (self.t.1 as u128)
+    }
+}
+
+impl Clone for TypeId {
+    #[inline]
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl Copy for TypeId {}
+
+impl PartialEq for TypeId {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.t.0 == other.t.0 && self.t.1 == other.t.1
+    }
+}
+
+impl Eq for TypeId {}
+
+impl PartialOrd for TypeId {
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for TypeId {
+    #[inline]
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.t.0.cmp(&other.t.0).then(self.t.1.cmp(&other.t.1))
+    }
+}
+
+impl Hash for TypeId {
+    #[inline]
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        // We only hash the lower 64 bits of our (128 bit) internal numeric ID,
+        // the hashing algorithm which backs `TypeId` is expected to be
+        // unbiased and high quality, and `Hasher::finish` returns a u64
+        // anyway.
+        self.t.1.hash(state);
+    }
+}
+
+impl Debug for TypeId {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        const HEX_DIGITS: &[u8; 16] = b"0123456789abcdef";
+
+        // `TypeId(0x...)` with the 32 hexadecimal digits of the id, like the
+        // `{:#034x}` format spec would do.
+        let mut buf = [0u8; 34];
+        buf[0] = b'0';
+        buf[1] = b'x';
+        let id = self.as_u128();
+        let mut i = 0;
+        while i < 32 {
+            buf[33 - i] = HEX_DIGITS[(id >> (i * 4)) as usize & 0xf];
+            i += 1;
+        }
+
+        f.write_str("TypeId(")?;
+        // SAFETY: `buf` contains only ASCII.
+        f.write_str(unsafe { str::from_utf8_unchecked(&buf) })?;
+        f.write_str(")")
+    }
+}
+
+/// Returns the name of a type as a string slice.
+///
+/// This is intended for diagnostic use. The exact contents and format of the
+/// string returned are not specified, other than being a best-effort
+/// description of the type.
+#[must_use]
+pub const fn type_name<T: ?Sized>() -> &'static str {
+    intrinsics::type_name::<T>()
+}
+
+/// Returns the type name of the pointed-to value as a string slice.
+///
+/// This is the same as `type_name::<T>()`, but can be used where the type of a
+/// variable is not easily available. It does not resolve trait objects:
+/// `type_name_of_val(&7u32 as &dyn Debug)` may return `"dyn Debug"`.
+#[must_use]
+pub const fn type_name_of_val<T: ?Sized>(_val: &T) -> &'static str {
+    type_name::<T>()
+}
@@ -0,0 +1,88 @@
+use crate::cmp::PartialEq;
+use crate::convert::TryInto;
+use crate::result::Result::{self, Err, Ok};
+
+impl<T, U, const N: usize> PartialEq<[U; N]> for [T; N]
+where
+    T: PartialEq<U>,
+{
+    #[inline]
+    fn eq(&self, other: &[U; N]) -> bool {
+        let mut i = 0;
+        while i < N {
+            if self[i] != other[i] {
+                return false;
+            }
+            i += 1;
+        }
+        true
+    }
+}
+
+impl<T, U, const N: usize> PartialEq<[U]> for [T; N]
+where
+    T: PartialEq<U>,
+{
+    #[inline]
+    fn eq(&self, other: &[U]) -> bool {
+        let b: Result<&[U; N], _> = other.try_into();
+        match b {
+            Ok(b) => *self == *b,
+            Err(_) => false,
+        }
+    }
+}
+
+impl<T, U, const N: usize> PartialEq<[U; N]> for [T]
+where
+    T: PartialEq<U>,
+{
+    #[inline]
+    fn eq(&self, other: &[U; N]) -> bool {
+        let b: Result<&[T; N], _> = self.try_into();
+        match b {
+            Ok(b) => *b == *other,
+            Err(_) => false,
+        }
+    }
+}
+
+impl<T, U, const N: usize> PartialEq<&[U]> for [T; N]
+where
+    T: PartialEq<U>,
+{
+    #[inline]
+    fn eq(&self, other: &&[U]) -> bool {
+        *self == **other
+    }
+}
+
+impl<T, U, const N: usize> PartialEq<[U; N]> for &[T]
+where
+    T: PartialEq<U>,
+{
+    #[inline]
+    fn eq(&self, other: &[U; N]) -> bool {
+        **self == *other
+    }
+}
+
+impl<T, U, const N: usize> PartialEq<&mut [U]> for [T; N]
+where
+    T: PartialEq<U>,
+{
+    #[inline]
+    fn eq(&self, other: &&mut [U]) -> bool {
+        *self == **other
+    }
+}
+
+impl<T, U, const N: usize> PartialEq<[U; N]> for &mut [T]
+where
+    T: PartialEq<U>,
+{
+    #[inline]
+    fn eq(&self, other: &[U; N]) -> bool {
+        **self == *other
+    }
+}
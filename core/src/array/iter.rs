@@ -0,0 +1,132 @@
+//! Defines the `IntoIter` owned iterator for arrays.
+
+use crate::clone::Clone;
+use crate::iter::{IntoIterator, Iterator};
+use crate::mem::MaybeUninit;
+use crate::ops::Drop;
+use crate::option::Option::{self, None, Some};
+use crate::slice::{from_raw_parts, from_raw_parts_mut};
+
+/// A by-value [array] iterator.
+#[rustc_insignificant_dtor]
+#[rustc_diagnostic_item = "ArrayIntoIter"]
+pub struct IntoIter<T, const N: usize> {
+    /// This is the array we are iterating over.
+    ///
+    /// Elements with index `i` where `start <= i < end` have not been yielded
+    /// yet and are valid array entries. The other ones have been yielded
+    /// already and must not be accessed anymore, they might even be in a
+    /// completely uninitialized state.
+    data: [MaybeUninit<T>; N],
+
+    /// The elements of `data` that have not been yielded yet, with
+    /// `start <= end <= N`.
+    // TODO: use an `IndexRange` like upstream once it exists.
+    start: usize,
+    end: usize,
+}
+
+impl<T, const N: usize> IntoIterator for [T; N] {
+    type IntoIter = IntoIter<T, N>;
+    type Item = T;
+
+    /// Creates a consuming iterator, that is, one that moves each value out of
+    /// the array (from start to end).
+    fn into_iter(self) -> Self::IntoIter {
+        IntoIter {
+            data: MaybeUninit::new(self).transpose(),
+            start: 0,
+            end: N,
+        }
+    }
+}
+
+impl<T, const N: usize> IntoIter<T, N> {
+    /// Creates a new iterator over the given `array`.
+    pub fn new(array: [T; N]) -> Self {
+        IntoIterator::into_iter(array)
+    }
+
+    /// Creates an iterator over `T` which returns no elements.
+    pub const fn empty() -> Self {
+        IntoIter {
+            data: MaybeUninit::uninit_array(),
+            start: 0,
+            end: 0,
+        }
+    }
+
+    /// Returns an immutable slice of all elements that have not been yielded
+    /// yet.
+    pub fn as_slice(&self) -> &[T] {
+        // SAFETY: `data[start..end]` is alive, and `MaybeUninit<T>` has the
+        // same layout as `T`.
+        unsafe {
+            from_raw_parts(
+                self.data.as_ptr().add(self.start) as *const T,
+                self.end - self.start,
+            )
+        }
+    }
+
+    /// Returns a mutable slice of all elements that have not been yielded yet.
+    pub fn as_mut_slice(&mut self) -> &mut [T] {
+        // SAFETY: `data[start..end]` is alive, and `MaybeUninit<T>` has the
+        // same layout as `T`.
+        unsafe {
+            from_raw_parts_mut(
+                self.data.as_mut_ptr().add(self.start) as *mut T,
+                self.end - self.start,
+            )
+        }
+    }
+}
+
+impl<T, const N: usize> Iterator for IntoIter<T, N> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.start == self.end {
+            return None;
+        }
+        let index = self.start;
+        self.start += 1;
+        // SAFETY: `index` was in the alive range, and is now out of it so the
+        // element will not be read again.
+        Some(unsafe { self.data[index].assume_init_read() })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.end - self.start;
+        (len, Some(len))
+    }
+}
+
+impl<T, const N: usize> Drop for IntoIter<T, N> {
+    fn drop(&mut self) {
+        let mut i = self.start;
+        while i < self.end {
+            // SAFETY: the elements of the alive range have not been yielded.
+            unsafe { self.data[i].assume_init_drop() };
+            i += 1;
+        }
+    }
+}
+
+impl<T: Clone, const N: usize> Clone for IntoIter<T, N> {
+    fn clone(&self) -> Self {
+        // Only the alive elements are cloned, to the start of the new array.
+        let mut new = Self::empty();
+        let mut i = self.start;
+        while i < self.end {
+            // SAFETY: `i` is in the alive range.
+            let value = unsafe { &*self.data[i].as_ptr() };
+            new.data[new.end].write(value.clone());
+            // the clone is only alive once written, so that a panic in the
+            // next `clone` does not drop an uninitialized element
+            new.end += 1;
+            i += 1;
+        }
+        new
+    }
+}
@@ -0,0 +1,422 @@
+//! Utilities for the array primitive type.
+
+use crate::borrow::{Borrow, BorrowMut};
+use crate::clone::Clone;
+use crate::cmp::{Eq, Ord, Ordering, PartialOrd};
+use crate::convert::{AsMut, AsRef, From, Infallible, TryFrom};
+use crate::default::Default;
+use crate::fmt::{self, Debug, Display, Formatter};
+use crate::hash::{Hash, Hasher};
+use crate::marker::Copy;
+use crate::mem::MaybeUninit;
+use crate::ops::{FnMut, Index, IndexMut};
+use crate::option::Option::{self, Some};
+use crate::panicking::panic;
+use crate::result::Result::{self, Err, Ok};
+
+mod equality;
+mod iter;
+
+pub use iter::IntoIter;
+
+/// Creates an array of type `[T; N]`, where each element `T` is the returned
+/// value from `cb` using that element's index.
+///
+/// # Example
+///
+/// ```ignore (core has no test harness)
+/// let array: [usize; 5] = core::array::from_fn(|i| i * 2);
+/// assert_eq!(array, [0, 2, 4, 6, 8]);
+/// ```
+#[inline]
+pub fn from_fn<T, const N: usize, F>(mut cb: F) -> [T; N]
+where
+    F: FnMut(usize) -> T,
+{
+    // TODO: drop the already built elements if `cb` panics, it only leaks them
+    // for now (panics abort anyway).
+    let mut array = MaybeUninit::<T>::uninit_array::<N>();
+    let mut i = 0;
+    while i < N {
+        array[i].write(cb(i));
+        i += 1;
+    }
+    // SAFETY: every element of `array` was initialized by the loop above.
+    unsafe { array.transpose().assume_init() }
+}
+
+/// Converts a reference to `T` into a reference to an array of length 1
+/// (without copying).
+pub const fn from_ref<T>(s: &T) -> &[T; 1] {
+    // SAFETY: converting `&T` to `&[T; 1]` is sound.
+    unsafe { &*(s as *const T as *const [T; 1]) }
+}
+
+/// Converts a mutable reference to `T` into a mutable reference to an array
+/// of length 1 (without copying).
+pub const fn from_mut<T>(s: &mut T) -> &mut [T; 1] {
+    // SAFETY: converting `&mut T` to `&mut [T; 1]` is sound.
+    unsafe { &mut *(s as *mut T as *mut [T; 1]) }
+}
+
+/// The error type returned when a conversion from a slice to an array fails.
+pub struct TryFromSliceError(());
+
+impl Clone for TryFromSliceError {
+    #[inline]
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl Copy for TryFromSliceError {}
+
+impl Debug for TryFromSliceError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("TryFromSliceError").field(&()).finish()
+    }
+}
+
+impl Display for TryFromSliceError {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_str("could not convert slice to array")
+    }
+}
+
+impl From<Infallible> for TryFromSliceError {
+    fn from(x: Infallible) -> TryFromSliceError {
+        match x {}
+    }
+}
+
+impl<T, const N: usize> AsRef<[T]> for [T; N] {
+    #[inline]
+    fn as_ref(&self) -> &[T] {
+        &self[..]
+    }
+}
+
+impl<T, const N: usize> AsMut<[T]> for [T; N] {
+    #[inline]
+    fn as_mut(&mut self) -> &mut [T] {
+        &mut self[..]
+    }
+}
+
+impl<T, const N: usize> Borrow<[T]> for [T; N] {
+    fn borrow(&self) -> &[T] {
+        self
+    }
+}
+
+impl<T, const N: usize> BorrowMut<[T]> for [T; N] {
+    fn borrow_mut(&mut self) -> &mut [T] {
+        self
+    }
+}
+
+/// Tries to create an array `[T; N]` by copying from a slice `&[T]`.
+/// Succeeds if `slice.len() == N`.
+impl<T, const N: usize> TryFrom<&[T]> for [T; N]
+where
+    T: Copy,
+{
+    type Error = TryFromSliceError;
+
+    #[inline]
+    fn try_from(slice: &[T]) -> Result<[T; N], TryFromSliceError> {
+        match <&Self>::try_from(slice) {
+            Ok(array) => Ok(*array),
+            Err(error) => Err(error),
+        }
+    }
+}
+
+/// Tries to create an array `[T; N]` by copying from a mutable slice
+/// `&mut [T]`. Succeeds if `slice.len() == N`.
+impl<T, const N: usize> TryFrom<&mut [T]> for [T; N]
+where
+    T: Copy,
+{
+    type Error = TryFromSliceError;
+
+    #[inline]
+    fn try_from(slice: &mut [T]) -> Result<[T; N], TryFromSliceError> {
+        <Self>::try_from(&*slice)
+    }
+}
+
+/// Tries to create an array ref `&[T; N]` from a slice ref `&[T]`. Succeeds if
+/// `slice.len() == N`.
+impl<'a, T, const N: usize> TryFrom<&'a [T]> for &'a [T; N] {
+    type Error = TryFromSliceError;
+
+    #[inline]
+    fn try_from(slice: &'a [T]) -> Result<&'a [T; N], TryFromSliceError> {
+        if slice.len() == N {
+            // SAFETY: `slice` has exactly `N` elements.
+            Ok(unsafe { &*(slice.as_ptr() as *const [T; N]) })
+        } else {
+            Err(TryFromSliceError(()))
+        }
+    }
+}
+
+/// Tries to create a mutable array ref `&mut [T; N]` from a mutable slice ref
+/// `&mut [T]`. Succeeds if `slice.len() == N`.
+impl<'a, T, const N: usize> TryFrom<&'a mut [T]> for &'a mut [T; N] {
+    type Error = TryFromSliceError;
+
+    #[inline]
+    fn try_from(slice: &'a mut [T]) -> Result<&'a mut [T; N], TryFromSliceError> {
+        if slice.len() == N {
+            // SAFETY: `slice` has exactly `N` elements.
+            Ok(unsafe { &mut *(slice.as_mut_ptr() as *mut [T; N]) })
+        } else {
+            Err(TryFromSliceError(()))
+        }
+    }
+}
+
+/// The hash of an array is the same as that of the corresponding slice,
+/// as required by the `Borrow` implementation.
+impl<T: Hash, const N: usize> Hash for [T; N] {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        Hash::hash(&self[..], state)
+    }
+}
+
+// TODO: `Debug` for arrays and slices, it needs a `DebugList` builder.
+
+impl<T, I, const N: usize> Index<I> for [T; N]
+where
+    [T]: Index<I>,
+{
+    type Output = <[T] as Index<I>>::Output;
+
+    #[inline]
+    fn index(&self, index: I) -> &Self::Output {
+        Index::index(self as &[T], index)
+    }
+}
+
+impl<T, I, const N: usize> IndexMut<I> for [T; N]
+where
+    [T]: IndexMut<I>,
+{
+    #[inline]
+    fn index_mut(&mut self, index: I) -> &mut Self::Output {
+        IndexMut::index_mut(self as &mut [T], index)
+    }
+}
+
+/// Implements comparison of arrays [lexicographically](Ord#lexicographical-comparison).
+impl<T: PartialOrd, const N: usize> PartialOrd for [T; N] {
+    #[inline]
+    fn partial_cmp(&self, other: &[T; N]) -> Option<Ordering> {
+        let mut i = 0;
+        while i < N {
+            match self[i].partial_cmp(&other[i]) {
+                Some(Ordering::Equal) => {}
+                non_eq => return non_eq,
+            }
+            i += 1;
+        }
+        Some(Ordering::Equal)
+    }
+}
+
+/// Implements comparison of arrays [lexicographically](Ord#lexicographical-comparison).
+impl<T: Ord, const N: usize> Ord for [T; N] {
+    #[inline]
+    fn cmp(&self, other: &[T; N]) -> Ordering {
+        let mut i = 0;
+        while i < N {
+            match self[i].cmp(&other[i]) {
+                Ordering::Equal => {}
+                non_eq => return non_eq,
+            }
+            i += 1;
+        }
+        Ordering::Equal
+    }
+}
+
+impl<T: Eq, const N: usize> Eq for [T; N] {}
+
+impl<T: Copy, const N: usize> Copy for [T; N] {}
+
+impl<T: Clone, const N: usize> Clone for [T; N] {
+    fn clone(&self) -> Self {
+        // TODO: drop the already cloned elements if a `clone` panics, it only
+        // leaks them for now (panics abort anyway).
+        let mut array = MaybeUninit::<T>::uninit_array::<N>();
+        let mut i = 0;
+        while i < N {
+            array[i].write(self[i].clone());
+            i += 1;
+        }
+        // SAFETY: every element of `array` was initialized by the loop above.
+        unsafe { array.transpose().assume_init() }
+    }
+
+    fn clone_from(&mut self, other: &Self) {
+        let mut i = 0;
+        while i < N {
+            self[i].clone_from(&other[i]);
+            i += 1;
+        }
+    }
+}
+
+impl<T: Default, const N: usize> Default for [T; N] {
+    fn default() -> Self {
+        // TODO: drop the already built elements if a `default` panics, it only
+        // leaks them for now (panics abort anyway).
+        let mut array = MaybeUninit::<T>::uninit_array::<N>();
+        let mut i = 0;
+        while i < N {
+            array[i].write(T::default());
+            i += 1;
+        }
+        // SAFETY: every element of `array` was initialized by the loop above.
+        unsafe { array.transpose().assume_init() }
+    }
+}
+
+impl<T, const N: usize> [T; N] {
+    /// Returns an array of the same size as `self`, with function `f` applied
+    /// to each element in order.
+    ///
+    /// # Example
+    ///
+    /// ```ignore (core has no test harness)
+    /// let x = [1, 2, 3];
+    /// let y = x.map(|v| v + 1);
+    /// assert_eq!(y, [2, 3, 4]);
+    /// ```
+    pub fn map<F, U>(self, mut f: F) -> [U; N]
+    where
+        F: FnMut(T) -> U,
+    {
+        // TODO: drop the elements not mapped yet if `f` panics, it only leaks
+        // them for now (panics abort anyway).
+        let source = MaybeUninit::new(self).transpose();
+        let mut array = MaybeUninit::<U>::uninit_array::<N>();
+        let mut i = 0;
+        while i < N {
+            // SAFETY: every element of `source` is initialized and read once.
+            array[i].write(f(unsafe { source[i].assume_init_read() }));
+            i += 1;
+        }
+        // SAFETY: every element of `array` was initialized by the loop above.
+        unsafe { array.transpose().assume_init() }
+    }
+
+    /// Returns a slice containing the entire array. Equivalent to `&s[..]`.
+    pub const fn as_slice(&self) -> &[T] {
+        self
+    }
+
+    /// Returns a mutable slice containing the entire array. Equivalent to
+    /// `&mut s[..]`.
+    pub const fn as_mut_slice(&mut self) -> &mut [T] {
+        self
+    }
+
+    /// Borrows each element and returns an array of references with the same
+    /// size as `self`.
+    pub fn each_ref(&self) -> [&T; N] {
+        let mut array = MaybeUninit::<&T>::uninit_array::<N>();
+        let mut i = 0;
+        while i < N {
+            array[i].write(&self[i]);
+            i += 1;
+        }
+        // SAFETY: every element of `array` was initialized by the loop above.
+        unsafe { array.transpose().assume_init() }
+    }
+
+    /// Borrows each element mutably and returns an array of mutable
+    /// references with the same size as `self`.
+    pub fn each_mut(&mut self) -> [&mut T; N] {
+        let mut array = MaybeUninit::<&mut T>::uninit_array::<N>();
+        let ptr = self.as_mut_ptr();
+        let mut i = 0;
+        while i < N {
+            // SAFETY: `i < N`, and each element is borrowed only once.
+            array[i].write(unsafe { &mut *ptr.add(i) });
+            i += 1;
+        }
+        // SAFETY: every element of `array` was initialized by the loop above.
+        unsafe { array.transpose().assume_init() }
+    }
+
+    /// Divides one array reference into two at an index.
+    ///
+    /// The first will contain all indices from `[0, M)` (excluding the index
+    /// `M` itself) and the second will contain all indices from `[M, N)`
+    /// (excluding the index `N` itself).
+    ///
+    /// # Panics
+    ///
+    /// Panics if `M > N`.
+    #[inline]
+    #[track_caller]
+    pub fn split_array_ref<const M: usize>(&self) -> (&[T; M], &[T]) {
+        let (left, right) = self.as_slice().split_at(M);
+        // SAFETY: `left` has exactly `M` elements.
+        (unsafe { &*(left.as_ptr() as *const [T; M]) }, right)
+    }
+
+    /// Divides one mutable array reference into two at an index.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `M > N`.
+    #[inline]
+    #[track_caller]
+    pub fn split_array_mut<const M: usize>(&mut self) -> (&mut [T; M], &mut [T]) {
+        let (left, right) = self.as_mut_slice().split_at_mut(M);
+        // SAFETY: `left` has exactly `M` elements.
+        (unsafe { &mut *(left.as_mut_ptr() as *mut [T; M]) }, right)
+    }
+
+    /// Divides one array reference into two at an index from the end.
+    ///
+    /// The first will contain all indices from `[0, N - M)` (excluding the
+    /// index `N - M` itself) and the second will contain all indices from
+    /// `[N - M, N)` (excluding the index `N` itself).
+    ///
+    /// # Panics
+    ///
+    /// Panics if `M > N`.
+    #[inline]
+    #[track_caller]
+    pub fn rsplit_array_ref<const M: usize>(&self) -> (&[T], &[T; M]) {
+        if M > N {
+            panic("M > N")
+        }
+        let (left, right) = self.as_slice().split_at(N - M);
+        // SAFETY: `right` has exactly `M` elements.
+        (left, unsafe { &*(right.as_ptr() as *const [T; M]) })
+    }
+
+    /// Divides one mutable array reference into two at an index from the
+    /// end.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `M > N`.
+    #[inline]
+    #[track_caller]
+    pub fn rsplit_array_mut<const M: usize>(&mut self) -> (&mut [T], &mut [T; M]) {
+        if M > N {
+            panic("M > N")
+        }
+        let (left, right) = self.as_mut_slice().split_at_mut(N - M);
+        // SAFETY: `right` has exactly `M` elements.
+        (left, unsafe { &mut *(right.as_mut_ptr() as *mut [T; M]) })
+    }
+}
@@ -0,0 +1,55 @@
+//! Utilities for working with borrowed data.
+
+use crate::marker::Sized;
+
+/// A trait for borrowing data.
+///
+/// Unlike [`AsRef`], `Borrow` requires the borrowed form to behave the same
+/// as the owned one: `Eq`, `Ord` and `Hash` must be equivalent for both.
+///
+/// [`AsRef`]: crate::convert::AsRef
+#[rustc_diagnostic_item = "Borrow"]
+pub trait Borrow<Borrowed: ?Sized> {
+    /// Immutably borrows from an owned value.
+    fn borrow(&self) -> &Borrowed;
+}
+
+/// A trait for mutably borrowing data.
+///
+/// As a companion to [`Borrow<T>`] this trait allows a type to borrow as an
+/// underlying type by providing a mutable reference.
+pub trait BorrowMut<Borrowed: ?Sized>: Borrow<Borrowed> {
+    /// Mutably borrows from an owned value.
+    fn borrow_mut(&mut self) -> &mut Borrowed;
+}
+
+impl<T: ?Sized> Borrow<T> for T {
+    #[rustc_diagnostic_item = "noop_method_borrow"]
+    fn borrow(&self) -> &T {
+        self
+    }
+}
+
+impl<T: ?Sized> BorrowMut<T> for T {
+    fn borrow_mut(&mut self) -> &mut T {
+        self
+    }
+}
+
+impl<T: ?Sized> Borrow<T> for &T {
+    fn borrow(&self) -> &T {
+        &**self
+    }
+}
+
+impl<T: ?Sized> Borrow<T> for &mut T {
+    fn borrow(&self) -> &T {
+        &**self
+    }
+}
+
+impl<T: ?Sized> BorrowMut<T> for &mut T {
+    fn borrow_mut(&mut self) -> &mut T {
+        &mut **self
+    }
+}
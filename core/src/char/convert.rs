@@ -0,0 +1,59 @@
+//! Character conversions.
+
+use crate::intrinsics::transmute;
+use crate::option::Option::{self, None, Some};
+use crate::panicking::panic;
+
+/// Converts a `u32` to a `char`.
+///
+/// Returns [`None`] if `i` is a surrogate (`U+D800` to `U+DFFF`) or is
+/// greater than [`char::MAX`].
+#[inline]
+#[must_use]
+pub const fn from_u32(i: u32) -> Option<char> {
+    if i > char::MAX as u32 || (i >= 0xD800 && i <= 0xDFFF) {
+        None
+    } else {
+        // SAFETY: checked that `i` is a valid Unicode scalar value.
+        Some(unsafe { from_u32_unchecked(i) })
+    }
+}
+
+/// Converts a `u32` to a `char`, ignoring validity.
+///
+/// # Safety
+///
+/// `i` must be a valid Unicode scalar value: not a surrogate and not greater
+/// than [`char::MAX`].
+#[inline]
+#[must_use]
+pub const unsafe fn from_u32_unchecked(i: u32) -> char {
+    // SAFETY: the caller must guarantee that `i` is a valid char value.
+    unsafe { transmute(i) }
+}
+
+/// Converts a digit in the given radix to a `char`.
+///
+/// Returns [`None`] if `num` is not a digit in `radix`.
+///
+/// # Panics
+///
+/// Panics if given a radix smaller than 2 or larger than 36.
+#[inline]
+#[must_use]
+pub fn from_digit(num: u32, radix: u32) -> Option<char> {
+    if radix < 2 || radix > 36 {
+        panic("from_digit: radix is too high (maximum 36)");
+    }
+
+    if num < radix {
+        let num = num as u8;
+        if num < 10 {
+            Some((b'0' + num) as char)
+        } else {
+            Some((b'a' + num - 10) as char)
+        }
+    } else {
+        None
+    }
+}
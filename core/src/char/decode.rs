@@ -0,0 +1,136 @@
+//! UTF-16 decoding.
+
+use super::from_u32_unchecked;
+use crate::clone::Clone;
+use crate::cmp::{Eq, PartialEq};
+use crate::fmt::{self, Debug, Display, Formatter};
+use crate::iter::{IntoIterator, Iterator};
+use crate::marker::Copy;
+use crate::option::Option::{self, None, Some};
+use crate::result::Result::{self, Err, Ok};
+
+/// An iterator that decodes UTF-16 encoded code points from an iterator of
+/// `u16`s.
+///
+/// This `struct` is created by the [`decode_utf16`] method on [`char`].
+///
+/// [`decode_utf16`]: char::decode_utf16
+pub struct DecodeUtf16<I>
+where
+    I: Iterator<Item = u16>,
+{
+    iter: I,
+    buf: Option<u16>,
+}
+
+/// An error that can be returned when decoding UTF-16 code points.
+///
+/// This `struct` is created when using the [`DecodeUtf16`] type.
+pub struct DecodeUtf16Error {
+    code: u16,
+}
+
+/// Creates an iterator over the UTF-16 encoded code points in `iter`,
+/// returning unpaired surrogates as `Err`s.
+#[inline]
+pub fn decode_utf16<I: IntoIterator<Item = u16>>(iter: I) -> DecodeUtf16<I::IntoIter> {
+    DecodeUtf16 {
+        iter: iter.into_iter(),
+        buf: None,
+    }
+}
+
+impl<I: Iterator<Item = u16>> Iterator for DecodeUtf16<I> {
+    type Item = Result<char, DecodeUtf16Error>;
+
+    fn next(&mut self) -> Option<Result<char, DecodeUtf16Error>> {
+        let u = match self.buf.take() {
+            Some(buf) => buf,
+            None => self.iter.next()?,
+        };
+
+        if (u & 0xF800) != 0xD800 {
+            // SAFETY: not a surrogate
+            Some(Ok(unsafe { from_u32_unchecked(u as u32) }))
+        } else if u >= 0xDC00 {
+            // a trailing surrogate
+            Some(Err(DecodeUtf16Error { code: u }))
+        } else {
+            let u2 = match self.iter.next() {
+                Some(u2) => u2,
+                // eof
+                None => return Some(Err(DecodeUtf16Error { code: u })),
+            };
+            if u2 < 0xDC00 || u2 > 0xDFFF {
+                // not a trailing surrogate so we're not a valid surrogate
+                // pair, so rewind to redecode `u2` next time.
+                self.buf = Some(u2);
+                return Some(Err(DecodeUtf16Error { code: u }));
+            }
+
+            // all ok, so lets decode it.
+            let c = (((u & 0x3ff) as u32) << 10 | (u2 & 0x3ff) as u32) + 0x1_0000;
+            // SAFETY: we checked that it's a legal unicode value
+            Some(Ok(unsafe { from_u32_unchecked(c) }))
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (low, high) = self.iter.size_hint();
+        let buf = match self.buf {
+            Some(_) => 1,
+            None => 0,
+        };
+
+        // every `char` takes one or two `u16`s, a buffered `u16` yields at
+        // least one more item
+        let low = low / 2 + buf;
+        let high = match high {
+            Some(high) if high != !0 || buf == 0 => Some(high + buf),
+            _ => None,
+        };
+        (low, high)
+    }
+}
+
+impl DecodeUtf16Error {
+    /// Returns the unpaired surrogate which caused this error.
+    #[must_use]
+    pub fn unpaired_surrogate(&self) -> u16 {
+        self.code
+    }
+}
+
+impl Clone for DecodeUtf16Error {
+    #[inline]
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl Copy for DecodeUtf16Error {}
+
+impl PartialEq for DecodeUtf16Error {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.code == other.code
+    }
+}
+
+impl Eq for DecodeUtf16Error {}
+
+impl Debug for DecodeUtf16Error {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("DecodeUtf16Error")
+            .field("code", &self.code)
+            .finish()
+    }
+}
+
+impl Display for DecodeUtf16Error {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        // TODO: add the code of the surrogate, `unpaired surrogate found: {:x}`
+        f.write_str("unpaired surrogate found")
+    }
+}
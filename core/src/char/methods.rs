@@ -0,0 +1,457 @@
+//! The inherent methods of `char`.
+
+use super::{CaseMappingIter, EscapeDebug, EscapeDefault, EscapeUnicode, ToLowercase, ToUppercase};
+use crate::iter::IntoIterator;
+use crate::option::Option::{self, None, Some};
+use crate::panicking::panic;
+use crate::{slice, str, unicode};
+
+// UTF-8 ranges and tags for encoding characters
+const TAG_CONT: u8 = 0b1000_0000;
+const TAG_TWO_B: u8 = 0b1100_0000;
+const TAG_THREE_B: u8 = 0b1110_0000;
+const TAG_FOUR_B: u8 = 0b1111_0000;
+const MAX_ONE_B: u32 = 0x80;
+const MAX_TWO_B: u32 = 0x800;
+const MAX_THREE_B: u32 = 0x10000;
+
+impl char {
+    /// The highest valid code point a `char` can have, `'\u{10FFFF}'`.
+    pub const MAX: char = '\u{10FFFF}';
+    /// The lowest valid code point a `char` can have, `'\0'`.
+    pub const MIN: char = '\0';
+    /// `U+FFFD REPLACEMENT CHARACTER` (�) is used in Unicode to represent a
+    /// decoding error.
+    pub const REPLACEMENT_CHARACTER: char = '\u{FFFD}';
+    /// The version of [Unicode](https://www.unicode.org/) that the Unicode
+    /// parts of `char` and `str` methods are based on.
+    pub const UNICODE_VERSION: (u8, u8, u8) = unicode::UNICODE_VERSION;
+
+    /// Creates an iterator over the UTF-16 encoded code points in `iter`,
+    /// returning unpaired surrogates as `Err`s.
+    #[inline]
+    pub fn decode_utf16<I: IntoIterator<Item = u16>>(iter: I) -> super::DecodeUtf16<I::IntoIter> {
+        super::decode_utf16(iter)
+    }
+
+    /// Converts a `u32` to a `char`, see [`char::from_u32`](super::from_u32).
+    #[inline]
+    #[must_use]
+    pub const fn from_u32(i: u32) -> Option<char> {
+        super::from_u32(i)
+    }
+
+    /// Converts a `u32` to a `char`, ignoring validity.
+    ///
+    /// # Safety
+    ///
+    /// `i` must be a valid Unicode scalar value.
+    #[inline]
+    #[must_use]
+    pub const unsafe fn from_u32_unchecked(i: u32) -> char {
+        // SAFETY: the safety contract must be upheld by the caller.
+        unsafe { super::from_u32_unchecked(i) }
+    }
+
+    /// Converts a digit in the given radix to a `char`, see
+    /// [`char::from_digit`](super::from_digit).
+    #[inline]
+    #[must_use]
+    pub fn from_digit(num: u32, radix: u32) -> Option<char> {
+        super::from_digit(num, radix)
+    }
+
+    /// Checks if a `char` is a digit in the given radix.
+    ///
+    /// # Panics
+    ///
+    /// Panics if given a radix smaller than 2 or larger than 36.
+    #[inline]
+    pub fn is_digit(self, radix: u32) -> bool {
+        self.to_digit(radix).is_some()
+    }
+
+    /// Converts a `char` to a digit in the given radix, digits above 9 are
+    /// the letters `a-z` or `A-Z`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if given a radix smaller than 2 or larger than 36.
+    #[inline]
+    pub fn to_digit(self, radix: u32) -> Option<u32> {
+        if radix < 2 || radix > 36 {
+            panic("to_digit: invalid radix -- radix must be in the range 2 to 36 inclusive");
+        }
+
+        let digit = match self {
+            '0'..='9' => self as u32 - '0' as u32,
+            'a'..='z' => self as u32 - 'a' as u32 + 10,
+            'A'..='Z' => self as u32 - 'A' as u32 + 10,
+            _ => return None,
+        };
+        if digit < radix {
+            Some(digit)
+        } else {
+            None
+        }
+    }
+
+    /// Returns an iterator that yields the hexadecimal Unicode escape of a
+    /// character as `char`s, like `\u{1f600}`.
+    #[inline]
+    pub fn escape_unicode(self) -> EscapeUnicode {
+        EscapeUnicode::new(self)
+    }
+
+    /// An extended version of `escape_debug` that optionally permits escaping
+    /// Extended Grapheme codepoints, single quotes, and double quotes.
+    pub(crate) fn escape_debug_ext(self, args: EscapeDebugExtArgs) -> EscapeDebug {
+        match self {
+            '\0' => EscapeDebug::backslash(b'0'),
+            '\t' => EscapeDebug::backslash(b't'),
+            '\r' => EscapeDebug::backslash(b'r'),
+            '\n' => EscapeDebug::backslash(b'n'),
+            '\\' => EscapeDebug::backslash(b'\\'),
+            '"' if args.escape_double_quote => EscapeDebug::backslash(b'"'),
+            '\'' if args.escape_single_quote => EscapeDebug::backslash(b'\''),
+            _ if args.escape_grapheme_extended && self.is_grapheme_extended() => {
+                EscapeDebug::unicode(self)
+            }
+            _ if is_printable(self) => EscapeDebug::printable(self),
+            _ => EscapeDebug::unicode(self),
+        }
+    }
+
+    /// Returns an iterator that yields the literal escape code of a character
+    /// as `char`s.
+    ///
+    /// This will escape the characters similar to the `Debug` implementations
+    /// of `str` or `char`.
+    #[inline]
+    pub fn escape_debug(self) -> EscapeDebug {
+        self.escape_debug_ext(EscapeDebugExtArgs::ESCAPE_ALL)
+    }
+
+    /// Returns an iterator that yields the literal escape code of a character
+    /// as `char`s.
+    ///
+    /// The printable ASCII characters other than `\`, `'` and `"` are not
+    /// escaped, `\t`, `\r` and `\n` use their backslash escape and every other
+    /// character its Unicode escape.
+    #[inline]
+    pub fn escape_default(self) -> EscapeDefault {
+        match self {
+            '\t' => EscapeDefault::backslash(b't'),
+            '\r' => EscapeDefault::backslash(b'r'),
+            '\n' => EscapeDefault::backslash(b'n'),
+            '\\' | '\'' | '"' => EscapeDefault::backslash(self as u8),
+            '\x20'..='\x7e' => EscapeDefault::printable(self as u8),
+            _ => EscapeDefault::unicode(self),
+        }
+    }
+
+    /// Returns the number of bytes this `char` would need if encoded in
+    /// UTF-8.
+    #[inline]
+    #[must_use]
+    pub const fn len_utf8(self) -> usize {
+        len_utf8(self as u32)
+    }
+
+    /// Returns the number of 16-bit code units this `char` would need if
+    /// encoded in UTF-16.
+    #[inline]
+    #[must_use]
+    pub const fn len_utf16(self) -> usize {
+        if (self as u32 & 0xFFFF) == self as u32 {
+            1
+        } else {
+            2
+        }
+    }
+
+    /// Encodes this character as UTF-8 into the provided byte buffer, and
+    /// then returns the subslice of the buffer that contains the encoded
+    /// character.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the buffer is not large enough. A buffer of length four is
+    /// large enough to encode any `char`.
+    #[inline]
+    pub fn encode_utf8(self, dst: &mut [u8]) -> &mut str {
+        // SAFETY: `char` is not a surrogate, so this is valid UTF-8.
+        unsafe { str::from_utf8_unchecked_mut(encode_utf8_raw(self as u32, dst)) }
+    }
+
+    /// Encodes this character as UTF-16 into the provided `u16` buffer, and
+    /// then returns the subslice of the buffer that contains the encoded
+    /// character.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the buffer is not large enough. A buffer of length 2 is
+    /// large enough to encode any `char`.
+    #[inline]
+    pub fn encode_utf16(self, dst: &mut [u16]) -> &mut [u16] {
+        encode_utf16_raw(self as u32, dst)
+    }
+
+    /// Returns `true` if this `char` has the `Alphabetic` property.
+    #[inline]
+    #[must_use]
+    pub fn is_alphabetic(self) -> bool {
+        match self {
+            'a'..='z' | 'A'..='Z' => true,
+            c => c > '\x7f' && unicode::Alphabetic(c),
+        }
+    }
+
+    /// Returns `true` if this `char` has the `Lowercase` property.
+    #[inline]
+    #[must_use]
+    pub fn is_lowercase(self) -> bool {
+        match self {
+            'a'..='z' => true,
+            c => c > '\x7f' && unicode::Lowercase(c),
+        }
+    }
+
+    /// Returns `true` if this `char` has the `Uppercase` property.
+    #[inline]
+    #[must_use]
+    pub fn is_uppercase(self) -> bool {
+        match self {
+            'A'..='Z' => true,
+            c => c > '\x7f' && unicode::Uppercase(c),
+        }
+    }
+
+    /// Returns `true` if this `char` has the `White_Space` property.
+    #[inline]
+    #[must_use]
+    pub fn is_whitespace(self) -> bool {
+        match self {
+            ' ' | '\x09'..='\x0d' => true,
+            c => c > '\x7f' && unicode::White_Space(c),
+        }
+    }
+
+    /// Returns `true` if this `char` satisfies either
+    /// [`is_alphabetic()`](char::is_alphabetic) or
+    /// [`is_numeric()`](char::is_numeric).
+    #[inline]
+    #[must_use]
+    pub fn is_alphanumeric(self) -> bool {
+        self.is_alphabetic() || self.is_numeric()
+    }
+
+    /// Returns `true` if this `char` has the general category for control
+    /// codes (`Cc`).
+    #[inline]
+    #[must_use]
+    pub const fn is_control(self) -> bool {
+        match self {
+            '\0'..='\x1f' | '\x7f'..='\u{9f}' => true,
+            _ => false,
+        }
+    }
+
+    /// Returns `true` if this `char` has the `Grapheme_Extend` property.
+    #[inline]
+    #[must_use]
+    pub(crate) fn is_grapheme_extended(self) -> bool {
+        self > '\u{02ff}' && unicode::Grapheme_Extend(self)
+    }
+
+    /// Returns `true` if this `char` has one of the general categories for
+    /// numbers (`Nd`, `Nl` or `No`).
+    #[inline]
+    #[must_use]
+    pub fn is_numeric(self) -> bool {
+        match self {
+            '0'..='9' => true,
+            c => c > '\x7f' && unicode::N(c),
+        }
+    }
+
+    /// Returns an iterator that yields the lowercase mapping of this `char`
+    /// as one or more `char`s.
+    #[inline]
+    #[must_use = "this returns the lowercase character as a new iterator, \
+                  without modifying the original"]
+    pub fn to_lowercase(self) -> ToLowercase {
+        let chars = match self {
+            'A'..='Z' => [self.to_ascii_lowercase(), '\0', '\0'],
+            c if c > '\x7f' => unicode::conversions::to_lower(c),
+            c => [c, '\0', '\0'],
+        };
+        ToLowercase(CaseMappingIter::new(chars))
+    }
+
+    /// Returns an iterator that yields the uppercase mapping of this `char`
+    /// as one or more `char`s.
+    #[inline]
+    #[must_use = "this returns the uppercase character as a new iterator, \
+                  without modifying the original"]
+    pub fn to_uppercase(self) -> ToUppercase {
+        let chars = match self {
+            'a'..='z' => [self.to_ascii_uppercase(), '\0', '\0'],
+            c if c > '\x7f' => unicode::conversions::to_upper(c),
+            c => [c, '\0', '\0'],
+        };
+        ToUppercase(CaseMappingIter::new(chars))
+    }
+
+    /// Checks if the value is within the ASCII range.
+    #[inline]
+    #[must_use]
+    pub const fn is_ascii(&self) -> bool {
+        *self as u32 <= 0x7F
+    }
+
+    /// Makes a copy of the value in its ASCII upper case equivalent.
+    #[inline]
+    #[must_use = "to uppercase the value in-place, use `make_ascii_uppercase()`"]
+    pub const fn to_ascii_uppercase(&self) -> char {
+        match self {
+            'a'..='z' => (*self as u8 - b'a' + b'A') as char,
+            _ => *self,
+        }
+    }
+
+    /// Makes a copy of the value in its ASCII lower case equivalent.
+    #[inline]
+    #[must_use = "to lowercase the value in-place, use `make_ascii_lowercase()`"]
+    pub const fn to_ascii_lowercase(&self) -> char {
+        match self {
+            'A'..='Z' => (*self as u8 - b'A' + b'a') as char,
+            _ => *self,
+        }
+    }
+
+    /// Converts this type to its ASCII upper case equivalent in-place.
+    #[inline]
+    pub const fn make_ascii_uppercase(&mut self) {
+        *self = self.to_ascii_uppercase();
+    }
+
+    /// Converts this type to its ASCII lower case equivalent in-place.
+    #[inline]
+    pub const fn make_ascii_lowercase(&mut self) {
+        *self = self.to_ascii_lowercase();
+    }
+}
+
+pub(crate) struct EscapeDebugExtArgs {
+    /// Escape Extended Grapheme codepoints?
+    pub(crate) escape_grapheme_extended: bool,
+
+    /// Escape single quotes?
+    pub(crate) escape_single_quote: bool,
+
+    /// Escape double quotes?
+    pub(crate) escape_double_quote: bool,
+}
+
+impl EscapeDebugExtArgs {
+    pub(crate) const ESCAPE_ALL: Self = Self {
+        escape_grapheme_extended: true,
+        escape_single_quote: true,
+        escape_double_quote: true,
+    };
+}
+
+/// Returns `true` if `c` does not need to be escaped by `escape_debug`.
+fn is_printable(c: char) -> bool {
+    match c {
+        '\x20'..='\x7e' => true,
+        c => c > '\x7f' && unicode::Printable(c),
+    }
+}
+
+#[inline]
+const fn len_utf8(code: u32) -> usize {
+    if code < MAX_ONE_B {
+        1
+    } else if code < MAX_TWO_B {
+        2
+    } else if code < MAX_THREE_B {
+        3
+    } else {
+        4
+    }
+}
+
+/// Encodes a raw `u32` value as UTF-8 into the provided byte buffer, and then
+/// returns the subslice of the buffer that contains the encoded character.
+///
+/// Unlike `char::encode_utf8`, this method also handles codepoints in the
+/// surrogate range. (Creating a `char` in the surrogate range is UB.) The
+/// result is valid generalized UTF-8 but not valid UTF-8.
+///
+/// # Panics
+///
+/// Panics if the buffer is not large enough. A buffer of length four is large
+/// enough to encode any `char`.
+#[inline]
+pub(crate) fn encode_utf8_raw(code: u32, dst: &mut [u8]) -> &mut [u8] {
+    let len = len_utf8(code);
+    if dst.len() < len {
+        panic("encode_utf8: the buffer is too small to encode the character");
+    }
+
+    match len {
+        1 => {
+            dst[0] = code as u8;
+        }
+        2 => {
+            dst[0] = (code >> 6 & 0x1F) as u8 | TAG_TWO_B;
+            dst[1] = (code & 0x3F) as u8 | TAG_CONT;
+        }
+        3 => {
+            dst[0] = (code >> 12 & 0x0F) as u8 | TAG_THREE_B;
+            dst[1] = (code >> 6 & 0x3F) as u8 | TAG_CONT;
+            dst[2] = (code & 0x3F) as u8 | TAG_CONT;
+        }
+        _ => {
+            dst[0] = (code >> 18 & 0x07) as u8 | TAG_FOUR_B;
+            dst[1] = (code >> 12 & 0x3F) as u8 | TAG_CONT;
+            dst[2] = (code >> 6 & 0x3F) as u8 | TAG_CONT;
+            dst[3] = (code & 0x3F) as u8 | TAG_CONT;
+        }
+    }
+
+    // SAFETY: `dst` is at least `len` bytes long.
+    unsafe { slice::from_raw_parts_mut(dst.as_mut_ptr(), len) }
+}
+
+/// Encodes a raw `u32` value as UTF-16 into the provided `u16` buffer, and
+/// then returns the subslice of the buffer that contains the encoded
+/// character.
+///
+/// Unlike `char::encode_utf16`, this method also handles codepoints in the
+/// surrogate range. (Creating a `char` in the surrogate range is UB.)
+///
+/// # Panics
+///
+/// Panics if the buffer is not large enough. A buffer of length 2 is large
+/// enough to encode any `char`.
+#[inline]
+pub(crate) fn encode_utf16_raw(mut code: u32, dst: &mut [u16]) -> &mut [u16] {
+    let len = if (code & 0xFFFF) == code { 1 } else { 2 };
+    if dst.len() < len {
+        panic("encode_utf16: the buffer is too small to encode the character");
+    }
+
+    if len == 1 {
+        dst[0] = code as u16;
+    } else {
+        code -= 0x1_0000;
+        dst[0] = 0xD800 | ((code >> 10) as u16);
+        dst[1] = 0xDC00 | ((code as u16) & 0x3FF);
+    }
+
+    // SAFETY: `dst` is at least `len` units long.
+    unsafe { slice::from_raw_parts_mut(dst.as_mut_ptr(), len) }
+}
@@ -0,0 +1,328 @@
+//! Utilities for the `char` primitive type.
+//!
+//! The Unicode properties (`is_alphabetic`, `to_uppercase`, ...) are looked
+//! up in the tables of `core::unicode`, see `tools/unicode-table-generator`
+//! for how they are built.
+
+mod convert;
+mod decode;
+mod methods;
+
+pub use convert::{from_digit, from_u32, from_u32_unchecked};
+pub use decode::{decode_utf16, DecodeUtf16, DecodeUtf16Error};
+pub(crate) use methods::EscapeDebugExtArgs;
+
+use crate::fmt::{self, Display, Formatter};
+use crate::intrinsics::offset;
+use crate::iter::Iterator;
+use crate::option::Option::{self, None, Some};
+use crate::result::Result::Ok;
+use crate::{slice, str};
+
+/// The highest valid code point a `char` can have, `'\u{10FFFF}'`.
+pub const MAX: char = char::MAX;
+
+/// `U+FFFD REPLACEMENT CHARACTER` (�) is used in Unicode to represent a
+/// decoding error.
+pub const REPLACEMENT_CHARACTER: char = char::REPLACEMENT_CHARACTER;
+
+/// The version of [Unicode](https://www.unicode.org/) that the Unicode parts
+/// of `char` and `str` methods are based on.
+pub const UNICODE_VERSION: (u8, u8, u8) = char::UNICODE_VERSION;
+
+/// The longest escape is `\u{10ffff}`.
+const ESCAPE_LEN: usize = 10;
+
+/// The ASCII bytes of an escape sequence, stored at the end of `data` and
+/// yielded from `start` to `ESCAPE_LEN`.
+struct EscapeIterInner {
+    data: [u8; ESCAPE_LEN],
+    start: usize,
+}
+
+impl EscapeIterInner {
+    /// A printable ASCII character, not escaped.
+    fn ascii(c: u8) -> Self {
+        let mut data = [0; ESCAPE_LEN];
+        data[ESCAPE_LEN - 1] = c;
+        EscapeIterInner {
+            data,
+            start: ESCAPE_LEN - 1,
+        }
+    }
+
+    /// A backslash escape, like `\n`.
+    fn backslash(c: u8) -> Self {
+        let mut data = [0; ESCAPE_LEN];
+        data[ESCAPE_LEN - 2] = b'\\';
+        data[ESCAPE_LEN - 1] = c;
+        EscapeIterInner {
+            data,
+            start: ESCAPE_LEN - 2,
+        }
+    }
+
+    /// A Unicode escape, `\u{NNNN}` with the shortest hexadecimal
+    /// representation of `c`.
+    fn unicode(c: char) -> Self {
+        const HEX_DIGITS: &[u8; 16] = b"0123456789abcdef";
+
+        let mut data = [0; ESCAPE_LEN];
+        let mut start = ESCAPE_LEN - 1;
+        data[start] = b'}';
+        let mut code = c as u32;
+        loop {
+            start -= 1;
+            data[start] = HEX_DIGITS[(code & 0xf) as usize];
+            code >>= 4;
+            if code == 0 {
+                break;
+            }
+        }
+        data[start - 1] = b'{';
+        data[start - 2] = b'u';
+        data[start - 3] = b'\\';
+        EscapeIterInner {
+            data,
+            start: start - 3,
+        }
+    }
+
+    /// An exhausted escape.
+    fn empty() -> Self {
+        EscapeIterInner {
+            data: [0; ESCAPE_LEN],
+            start: ESCAPE_LEN,
+        }
+    }
+
+    fn as_str(&self) -> &str {
+        // SAFETY: `data[start..]` is in bounds and contains only ASCII.
+        unsafe {
+            let data = offset(self.data.as_ptr(), self.start);
+            str::from_utf8_unchecked(slice::from_raw_parts(data, self.len()))
+        }
+    }
+
+    fn len(&self) -> usize {
+        ESCAPE_LEN - self.start
+    }
+
+    fn next(&mut self) -> Option<char> {
+        if self.start == ESCAPE_LEN {
+            return None;
+        }
+
+        let c = self.data[self.start] as char;
+        self.start += 1;
+        Some(c)
+    }
+}
+
+macro_rules! escape_iterator {
+    ($($name:ident),*) => {$(
+        impl Iterator for $name {
+            type Item = char;
+
+            #[inline]
+            fn next(&mut self) -> Option<char> {
+                self.0.next()
+            }
+
+            #[inline]
+            fn size_hint(&self) -> (usize, Option<usize>) {
+                let n = self.0.len();
+                (n, Some(n))
+            }
+        }
+
+        impl Display for $name {
+            #[inline]
+            fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+                f.write_str(self.0.as_str())
+            }
+        }
+    )*};
+}
+
+/// Returns an iterator that yields the hexadecimal Unicode escape of a
+/// character, as `char`s.
+///
+/// This `struct` is created by the [`escape_unicode`] method on [`char`].
+///
+/// [`escape_unicode`]: char::escape_unicode
+pub struct EscapeUnicode(EscapeIterInner);
+
+impl EscapeUnicode {
+    fn new(c: char) -> Self {
+        EscapeUnicode(EscapeIterInner::unicode(c))
+    }
+}
+
+/// An iterator that yields the literal escape code of a `char`.
+///
+/// This `struct` is created by the [`escape_default`] method on [`char`].
+///
+/// [`escape_default`]: char::escape_default
+pub struct EscapeDefault(EscapeIterInner);
+
+impl EscapeDefault {
+    fn printable(c: u8) -> Self {
+        EscapeDefault(EscapeIterInner::ascii(c))
+    }
+
+    fn backslash(c: u8) -> Self {
+        EscapeDefault(EscapeIterInner::backslash(c))
+    }
+
+    fn unicode(c: char) -> Self {
+        EscapeDefault(EscapeIterInner::unicode(c))
+    }
+}
+
+escape_iterator! { EscapeUnicode, EscapeDefault }
+
+/// An iterator that yields the literal escape code of a `char`.
+///
+/// This `struct` is created by the [`escape_debug`] method on [`char`].
+///
+/// [`escape_debug`]: char::escape_debug
+pub struct EscapeDebug(EscapeDebugInner);
+
+enum EscapeDebugInner {
+    Bytes(EscapeIterInner),
+    Char(char),
+}
+
+impl EscapeDebug {
+    fn printable(c: char) -> Self {
+        EscapeDebug(EscapeDebugInner::Char(c))
+    }
+
+    fn backslash(c: u8) -> Self {
+        EscapeDebug(EscapeDebugInner::Bytes(EscapeIterInner::backslash(c)))
+    }
+
+    fn unicode(c: char) -> Self {
+        EscapeDebug(EscapeDebugInner::Bytes(EscapeIterInner::unicode(c)))
+    }
+}
+
+impl Iterator for EscapeDebug {
+    type Item = char;
+
+    fn next(&mut self) -> Option<char> {
+        match self.0 {
+            EscapeDebugInner::Bytes(ref mut bytes) => bytes.next(),
+            EscapeDebugInner::Char(c) => {
+                self.0 = EscapeDebugInner::Bytes(EscapeIterInner::empty());
+                Some(c)
+            }
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let n = match self.0 {
+            EscapeDebugInner::Bytes(ref bytes) => bytes.len(),
+            EscapeDebugInner::Char(_) => 1,
+        };
+        (n, Some(n))
+    }
+}
+
+impl Display for EscapeDebug {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self.0 {
+            EscapeDebugInner::Bytes(ref bytes) => f.write_str(bytes.as_str()),
+            EscapeDebugInner::Char(c) => f.write_str(c.encode_utf8(&mut [0; 4])),
+        }
+    }
+}
+
+/// The up to three `char`s of a case mapping, the unused ones being `'\0'`.
+struct CaseMappingIter {
+    chars: [char; 3],
+    start: usize,
+    end: usize,
+}
+
+impl CaseMappingIter {
+    fn new(chars: [char; 3]) -> CaseMappingIter {
+        // the first `char` is always part of the mapping, `'\0'` being
+        // mapped to itself
+        let end = if chars[2] != '\0' {
+            3
+        } else if chars[1] != '\0' {
+            2
+        } else {
+            1
+        };
+        CaseMappingIter {
+            chars,
+            start: 0,
+            end,
+        }
+    }
+
+    fn next(&mut self) -> Option<char> {
+        if self.start == self.end {
+            return None;
+        }
+        let c = self.chars[self.start];
+        self.start += 1;
+        Some(c)
+    }
+
+    fn len(&self) -> usize {
+        self.end - self.start
+    }
+
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        for c in &self.chars[self.start..self.end] {
+            f.write_str(c.encode_utf8(&mut [0; 4]))?;
+        }
+        Ok(())
+    }
+}
+
+/// Returns an iterator that yields the uppercase equivalent of a `char`.
+///
+/// This `struct` is created by the [`to_uppercase`] method on [`char`].
+///
+/// [`to_uppercase`]: char::to_uppercase
+pub struct ToUppercase(CaseMappingIter);
+
+/// Returns an iterator that yields the lowercase equivalent of a `char`.
+///
+/// This `struct` is created by the [`to_lowercase`] method on [`char`].
+///
+/// [`to_lowercase`]: char::to_lowercase
+pub struct ToLowercase(CaseMappingIter);
+
+macro_rules! case_mapping_iterator {
+    ($($name:ident),*) => {$(
+        impl Iterator for $name {
+            type Item = char;
+
+            #[inline]
+            fn next(&mut self) -> Option<char> {
+                self.0.next()
+            }
+
+            #[inline]
+            fn size_hint(&self) -> (usize, Option<usize>) {
+                let n = self.0.len();
+                (n, Some(n))
+            }
+        }
+
+        impl Display for $name {
+            #[inline]
+            fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+                self.0.fmt(f)
+            }
+        }
+    )*};
+}
+
+case_mapping_iterator! { ToUppercase, ToLowercase }
@@ -1,7 +1,9 @@
-use super::marker::Sized;
+use super::marker::{Copy, PhantomData, Sized};
 
 #[lang = "clone"]
 pub trait Clone: Sized {
+    #[lang = "clone_fn"]
+    #[must_use = "cloning is often expensive and is not expected to have side effects"]
     fn clone(&self) -> Self;
 
     fn clone_from(&mut self, source: &Self) {
@@ -9,6 +11,32 @@ pub trait Clone: Sized {
     }
 }
 
+/// Derive macro generating an impl of the trait `Clone`.
+#[rustc_builtin_macro]
+#[allow_internal_unstable(core_intrinsics, derive_clone_copy)]
+pub macro Clone($item:item) {
+    // compiler built-in
+}
+
+// The two following structs are used by the derive macros `Clone` and `Copy`
+// to assert that every field of a type implements the derived trait. They are
+// not meant to be used by user code.
+
+#[doc(hidden)]
+#[allow(missing_debug_implementations)]
+pub struct AssertParamIsClone<T: Clone + ?Sized> {
+    _field: PhantomData<T>,
+}
+
+#[doc(hidden)]
+#[allow(missing_debug_implementations)]
+pub struct AssertParamIsCopy<T: Copy + ?Sized> {
+    _field: PhantomData<T>,
+}
+
+// Tuples, closures and function pointers implement `Clone` through builtin
+// impls of the compiler (when all their components are `Clone`), writing them
+// here would make the trait selection ambiguous.
 macro_rules! impl_clone {
     ($($t:ty,)*) => {
         $(
@@ -0,0 +1,550 @@
+use self::Ordering::*;
+use crate::clone::Clone;
+use crate::marker::{Copy, Sized};
+use crate::ops::FnOnce;
+use crate::option::Option::{self, None, Some};
+
+/// Trait for comparisons using the equality operator.
+///
+/// Implementing this trait for types provides the `==` and `!=` operators for
+/// those types. Unlike [`Eq`], this trait does not require the equality to be
+/// reflexive, this is why floating point numbers can implement it (`NaN != NaN`).
+#[lang = "eq"]
+#[doc(alias = "==")]
+#[doc(alias = "!=")]
+#[rustc_diagnostic_item = "PartialEq"]
+pub trait PartialEq<Rhs: ?Sized = Self> {
+    /// Tests for `self` and `other` values to be equal, and is used by `==`.
+    #[must_use]
+    #[rustc_diagnostic_item = "cmp_partialeq_eq"]
+    fn eq(&self, other: &Rhs) -> bool;
+
+    /// Tests for `!=`. The default implementation is almost always sufficient,
+    /// and should not be overridden without very good reason.
+    #[inline]
+    #[must_use]
+    #[rustc_diagnostic_item = "cmp_partialeq_ne"]
+    fn ne(&self, other: &Rhs) -> bool {
+        !self.eq(other)
+    }
+}
+
+/// Trait for comparisons corresponding to [equivalence relations].
+///
+/// This property cannot be checked by the compiler, and therefore `Eq` is
+/// only a marker over [`PartialEq`] telling that `a == a` always holds.
+///
+/// [equivalence relations]: https://en.wikipedia.org/wiki/Equivalence_relation
+#[doc(alias = "==")]
+#[doc(alias = "!=")]
+#[rustc_diagnostic_item = "Eq"]
+pub trait Eq: PartialEq<Self> {
+    // This method is used solely by `#[derive(Eq)]` to assert that every
+    // component of a type implements `Eq` itself.
+    #[doc(hidden)]
+    #[inline]
+    fn assert_receiver_is_total_eq(&self) {}
+}
+
+/// An `Ordering` is the result of a comparison between two values.
+// This is a lang item only so that `BinOp::Cmp` in MIR can return it, the
+// discriminants of the variants are part of this contract.
+#[lang = "Ordering"]
+#[repr(i8)]
+pub enum Ordering {
+    /// An ordering where a compared value is less than another.
+    Less = -1,
+    /// An ordering where a compared value is equal to another.
+    Equal = 0,
+    /// An ordering where a compared value is greater than another.
+    Greater = 1,
+}
+
+impl Ordering {
+    #[inline]
+    const fn as_raw(self) -> i8 {
+        self as i8
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn is_eq(self) -> bool {
+        self.as_raw() == 0
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn is_ne(self) -> bool {
+        self.as_raw() != 0
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn is_lt(self) -> bool {
+        self.as_raw() < 0
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn is_gt(self) -> bool {
+        self.as_raw() > 0
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn is_le(self) -> bool {
+        self.as_raw() <= 0
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn is_ge(self) -> bool {
+        self.as_raw() >= 0
+    }
+
+    /// Reverses the `Ordering`: `Less` becomes `Greater` and vice versa.
+    #[inline]
+    #[must_use]
+    pub const fn reverse(self) -> Ordering {
+        match self {
+            Less => Greater,
+            Equal => Equal,
+            Greater => Less,
+        }
+    }
+
+    /// Chains two orderings: returns `self` when it's not `Equal`, otherwise
+    /// returns `other`.
+    #[inline]
+    #[must_use]
+    pub const fn then(self, other: Ordering) -> Ordering {
+        match self {
+            Equal => other,
+            _ => self,
+        }
+    }
+
+    /// Chains the ordering with the given function: returns `self` when it's
+    /// not `Equal`, otherwise returns the result of `f`.
+    #[inline]
+    #[must_use]
+    pub fn then_with<F: FnOnce() -> Ordering>(self, f: F) -> Ordering {
+        match self {
+            Equal => f(),
+            _ => self,
+        }
+    }
+}
+
+impl Clone for Ordering {
+    #[inline(always)]
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl Copy for Ordering {}
+
+impl PartialEq for Ordering {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.as_raw() == other.as_raw()
+    }
+}
+
+impl Eq for Ordering {}
+
+impl PartialOrd for Ordering {
+    #[inline]
+    fn partial_cmp(&self, other: &Ordering) -> Option<Ordering> {
+        self.as_raw().partial_cmp(&other.as_raw())
+    }
+}
+
+impl Ord for Ordering {
+    #[inline]
+    fn cmp(&self, other: &Ordering) -> Ordering {
+        self.as_raw().cmp(&other.as_raw())
+    }
+}
+
+/// Trait for types that form a [total order].
+///
+/// [total order]: https://en.wikipedia.org/wiki/Total_order
+#[doc(alias = "<")]
+#[doc(alias = ">")]
+#[doc(alias = "<=")]
+#[doc(alias = ">=")]
+#[rustc_diagnostic_item = "Ord"]
+pub trait Ord: Eq + PartialOrd<Self> {
+    /// This method returns an [`Ordering`] between `self` and `other`.
+    #[must_use]
+    #[rustc_diagnostic_item = "ord_cmp_method"]
+    fn cmp(&self, other: &Self) -> Ordering;
+
+    /// Compares and returns the maximum of two values.
+    ///
+    /// Returns the second argument if the comparison determines them to be equal.
+    #[inline]
+    #[must_use]
+    fn max(self, other: Self) -> Self
+    where
+        Self: Sized,
+    {
+        match other.lt(&self) {
+            true => self,
+            false => other,
+        }
+    }
+
+    /// Compares and returns the minimum of two values.
+    ///
+    /// Returns the first argument if the comparison determines them to be equal.
+    #[inline]
+    #[must_use]
+    fn min(self, other: Self) -> Self
+    where
+        Self: Sized,
+    {
+        match other.lt(&self) {
+            true => other,
+            false => self,
+        }
+    }
+
+    /// Restrict a value to a certain interval.
+    ///
+    /// Returns `max` if `self` is greater than `max`, and `min` if `self` is
+    /// less than `min`. Otherwise this returns `self`.
+    #[inline]
+    #[must_use]
+    fn clamp(self, min: Self, max: Self) -> Self
+    where
+        Self: Sized,
+    {
+        // TODO: assert!(min <= max) once panicking can print its message
+        if self < min {
+            min
+        } else if self > max {
+            max
+        } else {
+            self
+        }
+    }
+}
+
+/// Trait for types that form a [partial order].
+///
+/// The `lt`, `le`, `gt`, and `ge` methods of this trait can be called using
+/// the `<`, `<=`, `>`, and `>=` operators, respectively.
+///
+/// [partial order]: https://en.wikipedia.org/wiki/Partial_order
+#[lang = "partial_ord"]
+#[doc(alias = ">")]
+#[doc(alias = "<")]
+#[doc(alias = "<=")]
+#[doc(alias = ">=")]
+#[rustc_diagnostic_item = "PartialOrd"]
+pub trait PartialOrd<Rhs: ?Sized = Self>: PartialEq<Rhs> {
+    /// This method returns an ordering between `self` and `other` values if
+    /// one exists.
+    #[must_use]
+    #[rustc_diagnostic_item = "cmp_partialord_cmp"]
+    fn partial_cmp(&self, other: &Rhs) -> Option<Ordering>;
+
+    /// Tests less than (for `self` and `other`) and is used by the `<` operator.
+    #[inline]
+    #[must_use]
+    #[rustc_diagnostic_item = "cmp_partialord_lt"]
+    fn lt(&self, other: &Rhs) -> bool {
+        match self.partial_cmp(other) {
+            Some(ordering) => ordering.is_lt(),
+            None => false,
+        }
+    }
+
+    /// Tests less than or equal to (for `self` and `other`) and is used by the
+    /// `<=` operator.
+    #[inline]
+    #[must_use]
+    #[rustc_diagnostic_item = "cmp_partialord_le"]
+    fn le(&self, other: &Rhs) -> bool {
+        match self.partial_cmp(other) {
+            Some(ordering) => ordering.is_le(),
+            None => false,
+        }
+    }
+
+    /// Tests greater than (for `self` and `other`) and is used by the `>`
+    /// operator.
+    #[inline]
+    #[must_use]
+    #[rustc_diagnostic_item = "cmp_partialord_gt"]
+    fn gt(&self, other: &Rhs) -> bool {
+        match self.partial_cmp(other) {
+            Some(ordering) => ordering.is_gt(),
+            None => false,
+        }
+    }
+
+    /// Tests greater than or equal to (for `self` and `other`) and is used by
+    /// the `>=` operator.
+    #[inline]
+    #[must_use]
+    #[rustc_diagnostic_item = "cmp_partialord_ge"]
+    fn ge(&self, other: &Rhs) -> bool {
+        match self.partial_cmp(other) {
+            Some(ordering) => ordering.is_ge(),
+            None => false,
+        }
+    }
+}
+
+/// Compares and returns the minimum of two values.
+#[inline]
+#[must_use]
+#[rustc_diagnostic_item = "cmp_min"]
+pub fn min<T: Ord>(v1: T, v2: T) -> T {
+    v1.min(v2)
+}
+
+/// Compares and returns the maximum of two values.
+#[inline]
+#[must_use]
+#[rustc_diagnostic_item = "cmp_max"]
+pub fn max<T: Ord>(v1: T, v2: T) -> T {
+    v1.max(v2)
+}
+
+macro_rules! impl_partial_eq {
+    ($($t:ty),* $(,)?) => {$(
+        impl PartialEq for $t {
+            #[inline]
+            fn eq(&self, other: &$t) -> bool {
+                (*self) == (*other)
+            }
+
+            #[inline]
+            fn ne(&self, other: &$t) -> bool {
+                (*self) != (*other)
+            }
+        }
+    )*};
+}
+
+macro_rules! impl_eq {
+    ($($t:ty),* $(,)?) => {$(
+        impl Eq for $t {}
+    )*};
+}
+
+macro_rules! impl_partial_ord {
+    ($($t:ty),* $(,)?) => {$(
+        impl PartialOrd for $t {
+            #[inline]
+            fn partial_cmp(&self, other: &$t) -> Option<Ordering> {
+                match (*self <= *other, *self >= *other) {
+                    (false, false) => None,
+                    (false, true) => Some(Greater),
+                    (true, false) => Some(Less),
+                    (true, true) => Some(Equal),
+                }
+            }
+
+            #[inline(always)]
+            fn lt(&self, other: &$t) -> bool {
+                (*self) < (*other)
+            }
+
+            #[inline(always)]
+            fn le(&self, other: &$t) -> bool {
+                (*self) <= (*other)
+            }
+
+            #[inline(always)]
+            fn gt(&self, other: &$t) -> bool {
+                (*self) > (*other)
+            }
+
+            #[inline(always)]
+            fn ge(&self, other: &$t) -> bool {
+                (*self) >= (*other)
+            }
+        }
+    )*};
+}
+
+macro_rules! impl_ord {
+    ($($t:ty),* $(,)?) => {$(
+        impl Ord for $t {
+            #[inline]
+            fn cmp(&self, other: &$t) -> Ordering {
+                if *self < *other {
+                    Less
+                } else if *self == *other {
+                    Equal
+                } else {
+                    Greater
+                }
+            }
+        }
+    )*};
+}
+
+impl_partial_eq! {
+    bool, char,
+    usize, u8, u16, u32, u64, u128,
+    isize, i8, i16, i32, i64, i128,
+    f32, f64,
+}
+impl_eq! {
+    (), bool, char,
+    usize, u8, u16, u32, u64, u128,
+    isize, i8, i16, i32, i64, i128,
+}
+impl_partial_ord! {
+    char,
+    usize, u8, u16, u32, u64, u128,
+    isize, i8, i16, i32, i64, i128,
+    f32, f64,
+}
+impl_ord! {
+    char,
+    usize, u8, u16, u32, u64, u128,
+    isize, i8, i16, i32, i64, i128,
+}
+
+impl PartialEq for () {
+    #[inline]
+    fn eq(&self, _other: &()) -> bool {
+        true
+    }
+
+    #[inline]
+    fn ne(&self, _other: &()) -> bool {
+        false
+    }
+}
+
+impl PartialOrd for () {
+    #[inline]
+    fn partial_cmp(&self, _: &()) -> Option<Ordering> {
+        Some(Equal)
+    }
+}
+
+impl Ord for () {
+    #[inline]
+    fn cmp(&self, _other: &()) -> Ordering {
+        Equal
+    }
+}
+
+impl PartialOrd for bool {
+    #[inline]
+    fn partial_cmp(&self, other: &bool) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for bool {
+    #[inline]
+    fn cmp(&self, other: &bool) -> Ordering {
+        (*self as u8).cmp(&(*other as u8))
+    }
+}
+
+impl PartialEq for ! {
+    #[inline]
+    fn eq(&self, _: &!) -> bool {
+        *self
+    }
+}
+
+impl Eq for ! {}
+
+impl PartialOrd for ! {
+    #[inline]
+    fn partial_cmp(&self, _: &!) -> Option<Ordering> {
+        *self
+    }
+}
+
+impl Ord for ! {
+    #[inline]
+    fn cmp(&self, _: &!) -> Ordering {
+        *self
+    }
+}
+
+// & pointers
+
+impl<A: ?Sized, B: ?Sized> PartialEq<&B> for &A
+where
+    A: PartialEq<B>,
+{
+    #[inline]
+    fn eq(&self, other: &&B) -> bool {
+        PartialEq::eq(*self, *other)
+    }
+
+    #[inline]
+    fn ne(&self, other: &&B) -> bool {
+        PartialEq::ne(*self, *other)
+    }
+}
+
+impl<A: ?Sized, B: ?Sized> PartialOrd<&B> for &A
+where
+    A: PartialOrd<B>,
+{
+    #[inline]
+    fn partial_cmp(&self, other: &&B) -> Option<Ordering> {
+        PartialOrd::partial_cmp(*self, *other)
+    }
+}
+
+impl<A: ?Sized + Ord> Ord for &A {
+    #[inline]
+    fn cmp(&self, other: &Self) -> Ordering {
+        Ord::cmp(*self, *other)
+    }
+}
+
+impl<A: ?Sized + Eq> Eq for &A {}
+
+// &mut pointers
+
+impl<A: ?Sized, B: ?Sized> PartialEq<&mut B> for &mut A
+where
+    A: PartialEq<B>,
+{
+    #[inline]
+    fn eq(&self, other: &&mut B) -> bool {
+        PartialEq::eq(*self, *other)
+    }
+
+    #[inline]
+    fn ne(&self, other: &&mut B) -> bool {
+        PartialEq::ne(*self, *other)
+    }
+}
+
+impl<A: ?Sized, B: ?Sized> PartialOrd<&mut B> for &mut A
+where
+    A: PartialOrd<B>,
+{
+    #[inline]
+    fn partial_cmp(&self, other: &&mut B) -> Option<Ordering> {
+        PartialOrd::partial_cmp(*self, *other)
+    }
+}
+
+impl<A: ?Sized + Ord> Ord for &mut A {
+    #[inline]
+    fn cmp(&self, other: &Self) -> Ordering {
+        Ord::cmp(*self, *other)
+    }
+}
+
+impl<A: ?Sized + Eq> Eq for &mut A {}
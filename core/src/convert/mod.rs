@@ -0,0 +1,206 @@
+use crate::clone::Clone;
+use crate::marker::{Copy, Sized};
+use crate::result::Result::{self, Ok};
+
+/// The error type for errors that can never happen.
+///
+/// Since this enum has no variant, a value of this type can never actually
+/// exist. This is useful for generic APIs that use [`Result`] and parameterize
+/// the error type, to indicate that the result is always [`Ok`], or for the
+/// residual of a [`Try`] type that can only short-circuit in one way (like
+/// `Option<Infallible>` for [`Option`]).
+///
+/// [`Result`]: crate::result::Result
+/// [`Ok`]: crate::result::Result::Ok
+/// [`Option`]: crate::option::Option
+/// [`Try`]: crate::ops::Try
+pub enum Infallible {}
+
+impl Clone for Infallible {
+    fn clone(&self) -> Infallible {
+        match *self {}
+    }
+}
+
+impl Copy for Infallible {}
+
+/// Used to do value-to-value conversions while consuming the input value.
+/// It is the reciprocal of [`Into`].
+///
+/// One should always prefer implementing `From` over [`Into`] because
+/// implementing `From` automatically provides one with an implementation of
+/// [`Into`] thanks to the blanket implementation below.
+#[rustc_diagnostic_item = "From"]
+pub trait From<T>: Sized {
+    #[rustc_diagnostic_item = "from_fn"]
+    fn from(value: T) -> Self;
+}
+
+/// A value-to-value conversion that consumes the input value. The opposite of
+/// [`From`].
+#[rustc_diagnostic_item = "Into"]
+pub trait Into<T>: Sized {
+    fn into(self) -> T;
+}
+
+// From implies Into
+impl<T, U> Into<U> for T
+where
+    U: From<T>,
+{
+    #[inline]
+    #[track_caller]
+    fn into(self) -> U {
+        U::from(self)
+    }
+}
+
+// From (and thus Into) is reflexive
+impl<T> From<T> for T {
+    #[inline(always)]
+    fn from(value: T) -> T {
+        value
+    }
+}
+
+/// Used to do a cheap reference-to-reference conversion.
+///
+/// This trait is similar to [`AsMut`] which is used for converting between
+/// mutable references. Unlike [`Borrow`], it makes no promise about the
+/// `Eq`, `Ord` and `Hash` implementations of the two types.
+///
+/// [`Borrow`]: crate::borrow::Borrow
+#[rustc_diagnostic_item = "AsRef"]
+pub trait AsRef<T: ?Sized> {
+    /// Converts this type into a shared reference of the (usually inferred)
+    /// input type.
+    fn as_ref(&self) -> &T;
+}
+
+/// Used to do a cheap mutable-to-mutable reference conversion.
+///
+/// This trait is similar to [`AsRef`] but used for converting between
+/// mutable references.
+#[rustc_diagnostic_item = "AsMut"]
+pub trait AsMut<T: ?Sized> {
+    /// Converts this type into a mutable reference of the (usually inferred)
+    /// input type.
+    fn as_mut(&mut self) -> &mut T;
+}
+
+/// Simple and safe type conversions that may fail in a controlled way under
+/// some circumstances. It is the reciprocal of [`TryFrom`].
+///
+/// Implement [`TryFrom`] instead, `TryInto` is provided by the blanket
+/// implementation below.
+#[rustc_diagnostic_item = "TryInto"]
+pub trait TryInto<T>: Sized {
+    /// The type returned in the event of a conversion error.
+    type Error;
+
+    /// Performs the conversion.
+    fn try_into(self) -> Result<T, Self::Error>;
+}
+
+/// Simple and safe type conversions that may fail in a controlled way under
+/// some circumstances. It is the reciprocal of [`TryInto`].
+///
+/// Every [`Into`] conversion is a `TryFrom` one whose error is
+/// [`Infallible`].
+#[rustc_diagnostic_item = "TryFrom"]
+pub trait TryFrom<T>: Sized {
+    /// The type returned in the event of a conversion error.
+    type Error;
+
+    /// Performs the conversion.
+    #[rustc_diagnostic_item = "try_from_fn"]
+    fn try_from(value: T) -> Result<Self, Self::Error>;
+}
+
+// As lifts over &
+impl<T: ?Sized, U: ?Sized> AsRef<U> for &T
+where
+    T: AsRef<U>,
+{
+    #[inline]
+    fn as_ref(&self) -> &U {
+        <T as AsRef<U>>::as_ref(*self)
+    }
+}
+
+// As lifts over &mut
+impl<T: ?Sized, U: ?Sized> AsRef<U> for &mut T
+where
+    T: AsRef<U>,
+{
+    #[inline]
+    fn as_ref(&self) -> &U {
+        <T as AsRef<U>>::as_ref(*self)
+    }
+}
+
+// AsMut lifts over &mut
+impl<T: ?Sized, U: ?Sized> AsMut<U> for &mut T
+where
+    T: AsMut<U>,
+{
+    #[inline]
+    fn as_mut(&mut self) -> &mut U {
+        (*self).as_mut()
+    }
+}
+
+impl<T> AsRef<[T]> for [T] {
+    #[inline(always)]
+    fn as_ref(&self) -> &[T] {
+        self
+    }
+}
+
+impl<T> AsMut<[T]> for [T] {
+    #[inline(always)]
+    fn as_mut(&mut self) -> &mut [T] {
+        self
+    }
+}
+
+impl AsRef<str> for str {
+    #[inline(always)]
+    fn as_ref(&self) -> &str {
+        self
+    }
+}
+
+impl AsMut<str> for str {
+    #[inline(always)]
+    fn as_mut(&mut self) -> &mut str {
+        self
+    }
+}
+
+// TryFrom implies TryInto
+impl<T, U> TryInto<U> for T
+where
+    U: TryFrom<T>,
+{
+    type Error = U::Error;
+
+    #[inline]
+    fn try_into(self) -> Result<U, U::Error> {
+        U::try_from(self)
+    }
+}
+
+// Infallible conversions are semantically equivalent to fallible conversions
+// with an uninhabited error type.
+impl<T, U> TryFrom<U> for T
+where
+    U: Into<T>,
+{
+    type Error = Infallible;
+
+    #[inline]
+    fn try_from(value: U) -> Result<Self, Self::Error> {
+        Ok(U::into(value))
+    }
+}
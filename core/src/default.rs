@@ -6,6 +6,20 @@ pub trait Default: Sized {
     fn default() -> Self;
 }
 
+/// Derive macro generating an impl of the trait `Default`.
+///
+/// On enums, the variant used as default value must be a unit variant marked
+/// with the `#[default]` attribute.
+#[rustc_builtin_macro(Default, attributes(default))]
+#[allow_internal_unstable(core_intrinsics)]
+pub macro Default($item:item) {
+    // compiler built-in
+}
+
+// Out of scope until `core::cell` exists: the `Default` impls of `Cell`,
+// `RefCell`, `UnsafeCell`, `SyncUnsafeCell`, `OnceCell` and `LazyCell`.
+// `ManuallyDrop` is the only wrapper for now.
+
 macro_rules! default_impl {
     ($t:ty, $v:expr, $doc:tt) => {
         impl Default for $t {
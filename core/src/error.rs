@@ -0,0 +1,465 @@
+//! Interfaces for working with errors.
+//!
+//! Besides the [`Error`] trait, this module provides the [`Request`] API
+//! which lets an error provide generic access to some context, like a
+//! backtrace, retrieved with [`request_ref`] and [`request_value`].
+
+use crate::any::TypeId;
+use crate::fmt::{self, Debug, Display, Formatter};
+use crate::iter::Iterator;
+use crate::marker::{Send, Sized, Sync};
+use crate::ops::FnOnce;
+use crate::option::Option::{self, None, Some};
+
+/// `Error` is a trait representing the basic expectations for error values,
+/// i.e., values of type `E` in [`Result<T, E>`](crate::result::Result).
+///
+/// Errors must describe themselves through the [`Display`] and [`Debug`]
+/// traits. Error messages are typically concise lowercase sentences without
+/// trailing punctuation.
+#[rustc_diagnostic_item = "Error"]
+#[rustc_has_incoherent_inherent_impls]
+pub trait Error: Debug + Display {
+    /// Returns the lower-level source of this error, if any.
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        None
+    }
+
+    /// Gets the `TypeId` of `self`.
+    #[doc(hidden)]
+    fn type_id(&self, _: private::Internal) -> TypeId
+    where
+        Self: 'static,
+    {
+        TypeId::of::<Self>()
+    }
+
+    #[stable(feature = "rust1", since = "1.0.0")]
+    #[deprecated(since = "1.42.0", note = "use the Display impl or to_string()")]
+    fn description(&self) -> &str {
+        "description() is deprecated; use Display"
+    }
+
+    #[stable(feature = "rust1", since = "1.0.0")]
+    #[deprecated(
+        since = "1.33.0",
+        note = "replaced by Error::source, which can support downcasting"
+    )]
+    #[allow(missing_docs)]
+    fn cause(&self) -> Option<&dyn Error> {
+        self.source()
+    }
+
+    /// Provides type-based access to context intended for error reports.
+    ///
+    /// Used in conjunction with [`Request::provide_value`] and
+    /// [`Request::provide_ref`] to extract references to member variables
+    /// from `dyn Error` trait objects.
+    #[allow(unused_variables)]
+    fn provide<'a>(&'a self, request: &mut Request<'a>) {}
+}
+
+mod private {
+    // This is a hack to prevent `type_id` from being overridden by `Error`
+    // implementations, since that can enable unsound downcasting.
+    pub struct Internal;
+}
+
+impl Error for ! {}
+
+// Copied from `any.rs`.
+impl dyn Error + 'static {
+    /// Returns `true` if the inner type is the same as `T`.
+    #[inline]
+    pub fn is<T: Error + 'static>(&self) -> bool {
+        // Get `TypeId` of the type this function is instantiated with.
+        let t = TypeId::of::<T>();
+
+        // Get `TypeId` of the type in the trait object (`self`).
+        let concrete = self.type_id(private::Internal);
+
+        // Compare both `TypeId`s on equality.
+        t == concrete
+    }
+
+    /// Returns some reference to the inner value if it is of type `T`, or
+    /// `None` if it isn't.
+    #[inline]
+    pub fn downcast_ref<T: Error + 'static>(&self) -> Option<&T> {
+        if self.is::<T>() {
+            // SAFETY: `is` ensures this type cast is correct
+            unsafe { Some(&*(self as *const dyn Error as *const T)) }
+        } else {
+            None
+        }
+    }
+
+    /// Returns some mutable reference to the inner value if it is of type `T`,
+    /// or `None` if it isn't.
+    #[inline]
+    pub fn downcast_mut<T: Error + 'static>(&mut self) -> Option<&mut T> {
+        if self.is::<T>() {
+            // SAFETY: `is` ensures this type cast is correct
+            unsafe { Some(&mut *(self as *mut dyn Error as *mut T)) }
+        } else {
+            None
+        }
+    }
+}
+
+macro_rules! forward_to_dyn_error {
+    ($($t:ty),*) => {$(
+        impl $t {
+            /// Forwards to the method defined on the type `dyn Error`.
+            #[inline]
+            pub fn is<T: Error + 'static>(&self) -> bool {
+                <dyn Error + 'static>::is::<T>(self)
+            }
+
+            /// Forwards to the method defined on the type `dyn Error`.
+            #[inline]
+            pub fn downcast_ref<T: Error + 'static>(&self) -> Option<&T> {
+                <dyn Error + 'static>::downcast_ref::<T>(self)
+            }
+
+            /// Forwards to the method defined on the type `dyn Error`.
+            #[inline]
+            pub fn downcast_mut<T: Error + 'static>(&mut self) -> Option<&mut T> {
+                <dyn Error + 'static>::downcast_mut::<T>(self)
+            }
+        }
+    )*};
+}
+
+forward_to_dyn_error! { dyn Error + 'static + Send, dyn Error + 'static + Send + Sync }
+
+impl dyn Error {
+    /// Returns an iterator starting with the current error and continuing
+    /// with recursively calling [`Error::source`].
+    #[inline]
+    pub fn sources(&self) -> Source<'_> {
+        Source {
+            current: Some(self),
+        }
+    }
+}
+
+/// Requests a value of type `T` from the given `impl Error`.
+#[inline]
+pub fn request_value<'a, T>(err: &'a (impl Error + ?Sized)) -> Option<T>
+where
+    T: 'static,
+{
+    request_by_type_tag::<'a, tags::Value<T>>(err)
+}
+
+/// Requests a reference of type `T` from the given `impl Error`.
+#[inline]
+pub fn request_ref<'a, T>(err: &'a (impl Error + ?Sized)) -> Option<&'a T>
+where
+    T: 'static + ?Sized,
+{
+    request_by_type_tag::<'a, tags::Ref<tags::MaybeSizedValue<T>>>(err)
+}
+
+/// Requests a specific value by tag from the `Error`.
+fn request_by_type_tag<'a, I>(err: &'a (impl Error + ?Sized)) -> Option<I::Reified>
+where
+    I: tags::Type<'a>,
+{
+    let mut tagged = Tagged {
+        tag_id: TypeId::of::<I>(),
+        value: TaggedOption::<'a, I>(None),
+    };
+    err.provide(tagged.as_request());
+    tagged.value.0
+}
+
+/// `Request` supports generic, type-driven access to data. Its use is
+/// currently restricted to the standard library in cases where trait authors
+/// wish to allow trait implementors to share generic information across trait
+/// boundaries.
+///
+/// A data provider provides values by calling this type's provide methods,
+/// a consumer requests data with [`request_ref`] or [`request_value`].
+#[repr(transparent)]
+pub struct Request<'a>(Tagged<dyn Erased<'a> + 'a>);
+
+impl<'a> Request<'a> {
+    /// Provides a value or other type with only static lifetimes.
+    pub fn provide_value<T>(&mut self, value: T) -> &mut Self
+    where
+        T: 'static,
+    {
+        self.provide::<tags::Value<T>>(value)
+    }
+
+    /// Provides a value or other type with only static lifetimes computed
+    /// using a closure.
+    pub fn provide_value_with<T>(&mut self, fulfil: impl FnOnce() -> T) -> &mut Self
+    where
+        T: 'static,
+    {
+        self.provide_with::<tags::Value<T>>(fulfil)
+    }
+
+    /// Provides a reference. The referee type must be bounded by `'static`,
+    /// but may be unsized.
+    pub fn provide_ref<T: ?Sized + 'static>(&mut self, value: &'a T) -> &mut Self {
+        self.provide::<tags::Ref<tags::MaybeSizedValue<T>>>(value)
+    }
+
+    /// Provides a reference computed using a closure. The referee type must
+    /// be bounded by `'static`, but may be unsized.
+    pub fn provide_ref_with<T: ?Sized + 'static>(
+        &mut self,
+        fulfil: impl FnOnce() -> &'a T,
+    ) -> &mut Self {
+        self.provide_with::<tags::Ref<tags::MaybeSizedValue<T>>>(fulfil)
+    }
+
+    /// Provides a value with the given `Type` tag.
+    fn provide<I>(&mut self, value: I::Reified) -> &mut Self
+    where
+        I: tags::Type<'a>,
+    {
+        if let Some(res @ TaggedOption(None)) = self.0.downcast_mut::<I>() {
+            res.0 = Some(value);
+        }
+        self
+    }
+
+    /// Provides a value with the given `Type` tag, using a closure to prevent
+    /// unnecessary work.
+    fn provide_with<I>(&mut self, fulfil: impl FnOnce() -> I::Reified) -> &mut Self
+    where
+        I: tags::Type<'a>,
+    {
+        if let Some(res @ TaggedOption(None)) = self.0.downcast_mut::<I>() {
+            res.0 = Some(fulfil());
+        }
+        self
+    }
+
+    /// Checks if the `Request` would be satisfied if provided with a value of
+    /// the specified type. If the type does not match or has already been
+    /// provided, returns false.
+    pub fn would_be_satisfied_by_value_of<T>(&self) -> bool
+    where
+        T: 'static,
+    {
+        self.would_be_satisfied_by::<tags::Value<T>>()
+    }
+
+    /// Checks if the `Request` would be satisfied if provided with a reference
+    /// to a value of the specified type. If the type does not match or has
+    /// already been provided, returns false.
+    pub fn would_be_satisfied_by_ref_of<T>(&self) -> bool
+    where
+        T: ?Sized + 'static,
+    {
+        self.would_be_satisfied_by::<tags::Ref<tags::MaybeSizedValue<T>>>()
+    }
+
+    fn would_be_satisfied_by<I>(&self) -> bool
+    where
+        I: tags::Type<'a>,
+    {
+        match self.0.downcast::<I>() {
+            Some(TaggedOption(None)) => true,
+            _ => false,
+        }
+    }
+}
+
+impl<'a> Debug for Request<'a> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Request").finish_non_exhaustive()
+    }
+}
+
+/// Type tags used to identify a type using a separate value. This module
+/// includes type tags for some very common types.
+pub(crate) mod tags {
+    use crate::marker::{PhantomData, Sized};
+
+    /// This trait is implemented by specific tag types in order to allow
+    /// describing a type which can be requested for a given lifetime `'a`.
+    pub(crate) trait Type<'a>: Sized + 'static {
+        /// The type of values which may be tagged by this tag for the given
+        /// lifetime.
+        type Reified: 'a;
+    }
+
+    /// Similar to the [`Type`] trait, but represents a type which may be
+    /// unsized (i.e., has a `?Sized` bound).
+    pub(crate) trait MaybeSizedType<'a>: Sized + 'static {
+        type Reified: 'a + ?Sized;
+    }
+
+    impl<'a, T: Type<'a>> MaybeSizedType<'a> for T {
+        type Reified = T::Reified;
+    }
+
+    /// Type-based tag for types bounded by `'static`, i.e., with no borrowed
+    /// elements.
+    pub(crate) struct Value<T: 'static>(PhantomData<T>);
+
+    impl<'a, T: 'static> Type<'a> for Value<T> {
+        type Reified = T;
+    }
+
+    /// Type-based tag similar to [`Value`] but which may be unsized (i.e., has
+    /// a `?Sized` bound).
+    pub(crate) struct MaybeSizedValue<T: ?Sized + 'static>(PhantomData<T>);
+
+    impl<'a, T: ?Sized + 'static> MaybeSizedType<'a> for MaybeSizedValue<T> {
+        type Reified = T;
+    }
+
+    /// Type-based tag for reference types (`&'a T`, where T is represented by
+    /// `<I as MaybeSizedType<'a>>::Reified`.
+    pub(crate) struct Ref<I>(PhantomData<I>);
+
+    impl<'a, I: MaybeSizedType<'a>> Type<'a> for Ref<I> {
+        type Reified = &'a I::Reified;
+    }
+}
+
+/// An `Option` with a type tag `I`.
+///
+/// Since this struct implements `Erased`, the type can be erased to make a
+/// dynamically typed option. The type can be checked dynamically using
+/// `Tagged::tag_id` and since this is statically checked for the concrete
+/// type, there is some degree of type safety.
+#[repr(transparent)]
+pub(crate) struct TaggedOption<'a, I: tags::Type<'a>>(pub Option<I::Reified>);
+
+impl<'a, I: tags::Type<'a>> Tagged<TaggedOption<'a, I>> {
+    pub(crate) fn as_request(&mut self) -> &mut Request<'a> {
+        let erased = self as &mut Tagged<dyn Erased<'a> + 'a>;
+        // SAFETY: transmuting `&mut Tagged<dyn Erased<'a> + 'a>` to
+        // `&mut Request<'a>` is safe since `Request` is repr(transparent).
+        unsafe { &mut *(erased as *mut Tagged<dyn Erased<'a>> as *mut Request<'a>) }
+    }
+}
+
+/// Represents a type-erased but identifiable object.
+///
+/// This trait is exclusively implemented by the `TaggedOption` type.
+unsafe trait Erased<'a>: 'a {}
+
+unsafe impl<'a, I: tags::Type<'a>> Erased<'a> for TaggedOption<'a, I> {}
+
+struct Tagged<E: ?Sized> {
+    tag_id: TypeId,
+    value: E,
+}
+
+impl<'a> Tagged<dyn Erased<'a> + 'a> {
+    /// Returns some reference to the dynamic value if it is tagged with `I`,
+    /// or `None` otherwise.
+    #[inline]
+    fn downcast<I>(&self) -> Option<&TaggedOption<'a, I>>
+    where
+        I: tags::Type<'a>,
+    {
+        if self.tag_id == TypeId::of::<I>() {
+            // SAFETY: Just checked whether we're pointing to an I.
+            Some(&unsafe { &*(self as *const Self as *const Tagged<TaggedOption<'a, I>>) }.value)
+        } else {
+            None
+        }
+    }
+
+    /// Returns some mutable reference to the dynamic value if it is tagged
+    /// with `I`, or `None` otherwise.
+    #[inline]
+    fn downcast_mut<I>(&mut self) -> Option<&mut TaggedOption<'a, I>>
+    where
+        I: tags::Type<'a>,
+    {
+        if self.tag_id == TypeId::of::<I>() {
+            Some(
+                // SAFETY: Just checked whether we're pointing to an I.
+                &mut unsafe { &mut *(self as *mut Self as *mut Tagged<TaggedOption<'a, I>>) }.value,
+            )
+        } else {
+            None
+        }
+    }
+}
+
+/// An iterator over an [`Error`] and its sources.
+///
+/// If you want to omit the initial error and only process its sources, use
+/// `skip(1)`.
+pub struct Source<'a> {
+    current: Option<&'a (dyn Error + 'static)>,
+}
+
+impl<'a> Iterator for Source<'a> {
+    type Item = &'a (dyn Error + 'static);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let current = self.current;
+        self.current = self.current.and_then(Error::source);
+        current
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.current.is_some() {
+            (1, None)
+        } else {
+            (0, Some(0))
+        }
+    }
+}
+
+impl<'a, T: Error + ?Sized> Error for &'a T {
+    #[allow(deprecated)]
+    fn description(&self) -> &str {
+        Error::description(&**self)
+    }
+
+    #[allow(deprecated)]
+    fn cause(&self) -> Option<&dyn Error> {
+        Error::cause(&**self)
+    }
+
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        Error::source(&**self)
+    }
+
+    fn provide<'b>(&'b self, request: &mut Request<'b>) {
+        Error::provide(&**self, request);
+    }
+}
+
+impl Error for fmt::Error {
+    #[allow(deprecated)]
+    fn description(&self) -> &str {
+        "an error occurred when formatting an argument"
+    }
+}
+
+impl Error for crate::array::TryFromSliceError {
+    #[allow(deprecated)]
+    fn description(&self) -> &str {
+        "could not convert slice to array"
+    }
+}
+
+impl Error for crate::time::TryFromFloatSecsError {
+    #[allow(deprecated)]
+    fn description(&self) -> &str {
+        self.description()
+    }
+}
+
+impl Error for crate::char::DecodeUtf16Error {
+    #[allow(deprecated)]
+    fn description(&self) -> &str {
+        "unpaired surrogate found"
+    }
+}
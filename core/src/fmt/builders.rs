@@ -0,0 +1,140 @@
+use crate::fmt::{self, Debug, Formatter};
+use crate::ops::FnOnce;
+
+// TODO: support the pretty printing asked by the alternate flag (`{:#?}`),
+// it requires a writer adapter indenting every line written by the fields.
+
+/// A struct to help with [`fmt::Debug`] implementations.
+///
+/// This is useful when you wish to output a formatted tuple as a part of your
+/// [`Debug::fmt`] implementation, it is created by [`Formatter::debug_tuple`].
+#[must_use = "must eventually call `finish()` on Debug builders"]
+pub struct DebugTuple<'a, 'b: 'a> {
+    fmt: &'a mut Formatter<'b>,
+    result: fmt::Result,
+    fields: usize,
+    empty_name: bool,
+}
+
+pub(super) fn debug_tuple_new<'a, 'b>(
+    fmt: &'a mut Formatter<'b>,
+    name: &str,
+) -> DebugTuple<'a, 'b> {
+    let result = fmt.write_str(name);
+    DebugTuple {
+        fmt,
+        result,
+        fields: 0,
+        empty_name: name.is_empty(),
+    }
+}
+
+impl<'a, 'b: 'a> DebugTuple<'a, 'b> {
+    /// Adds a new field to the generated tuple struct output.
+    pub fn field(&mut self, value: &dyn Debug) -> &mut Self {
+        self.field_with(|f| value.fmt(f))
+    }
+
+    /// Adds a new field to the generated tuple struct output, formatted by
+    /// the given closure.
+    pub fn field_with<F>(&mut self, value_fmt: F) -> &mut Self
+    where
+        F: FnOnce(&mut Formatter<'_>) -> fmt::Result,
+    {
+        self.result = self.result.and_then(|_| {
+            let prefix = match self.fields {
+                0 => "(",
+                _ => ", ",
+            };
+            self.fmt.write_str(prefix)?;
+            value_fmt(self.fmt)
+        });
+
+        self.fields += 1;
+        self
+    }
+
+    /// Finishes output and returns any error encountered.
+    pub fn finish(&mut self) -> fmt::Result {
+        if self.fields > 0 {
+            self.result = self.result.and_then(|_| {
+                // a 1-tuple with an empty name needs a trailing comma to not
+                // be mistaken for a parenthesized value: `(1,)`
+                if self.fields == 1 && self.empty_name {
+                    self.fmt.write_str(",")?;
+                }
+                self.fmt.write_str(")")
+            });
+        }
+        self.result
+    }
+}
+
+/// A struct to help with [`fmt::Debug`] implementations.
+///
+/// This is useful when you wish to output a formatted struct as a part of your
+/// [`Debug::fmt`] implementation, it is created by [`Formatter::debug_struct`].
+#[must_use = "must eventually call `finish()` on Debug builders"]
+pub struct DebugStruct<'a, 'b: 'a> {
+    fmt: &'a mut Formatter<'b>,
+    result: fmt::Result,
+    has_fields: bool,
+}
+
+pub(super) fn debug_struct_new<'a, 'b>(
+    fmt: &'a mut Formatter<'b>,
+    name: &str,
+) -> DebugStruct<'a, 'b> {
+    let result = fmt.write_str(name);
+    DebugStruct {
+        fmt,
+        result,
+        has_fields: false,
+    }
+}
+
+impl<'a, 'b: 'a> DebugStruct<'a, 'b> {
+    /// Adds a new field to the generated struct output.
+    pub fn field(&mut self, name: &str, value: &dyn Debug) -> &mut Self {
+        self.field_with(name, |f| value.fmt(f))
+    }
+
+    /// Adds a new field to the generated struct output, formatted by the given
+    /// closure.
+    pub fn field_with<F>(&mut self, name: &str, value_fmt: F) -> &mut Self
+    where
+        F: FnOnce(&mut Formatter<'_>) -> fmt::Result,
+    {
+        self.result = self.result.and_then(|_| {
+            let prefix = match self.has_fields {
+                true => ", ",
+                false => " { ",
+            };
+            self.fmt.write_str(prefix)?;
+            self.fmt.write_str(name)?;
+            self.fmt.write_str(": ")?;
+            value_fmt(self.fmt)
+        });
+
+        self.has_fields = true;
+        self
+    }
+
+    /// Marks the struct as non-exhaustive, indicating to the reader that there
+    /// are some other fields that are not shown in the debug representation.
+    pub fn finish_non_exhaustive(&mut self) -> fmt::Result {
+        self.result = self.result.and_then(|_| match self.has_fields {
+            true => self.fmt.write_str(", .. }"),
+            false => self.fmt.write_str(" { .. }"),
+        });
+        self.result
+    }
+
+    /// Finishes output and returns any error encountered.
+    pub fn finish(&mut self) -> fmt::Result {
+        if self.has_fields {
+            self.result = self.result.and_then(|_| self.fmt.write_str(" }"));
+        }
+        self.result
+    }
+}
@@ -0,0 +1,398 @@
+//! Utilities for formatting and printing strings.
+//!
+//! Only the trait side of the formatting machinery is available for now: the
+//! `format_args!` builtin (and so `write!` and `{}` placeholders) needs the
+//! `format_*` lang items of `fmt::rt` which are not implemented yet, so
+//! implementations of [`Debug`] and [`Display`] drive the [`Formatter`] by
+//! hand.
+
+use crate::char::EscapeDebugExtArgs;
+use crate::clone::Clone;
+use crate::default::Default;
+use crate::marker::{Copy, Sized};
+use crate::option::Option::{self, None, Some};
+use crate::result;
+use crate::result::Result::Ok;
+
+mod builders;
+mod num;
+
+pub use builders::{DebugStruct, DebugTuple};
+
+/// The type returned by formatter methods.
+pub type Result = result::Result<(), Error>;
+
+/// The error type which is returned from formatting a message into a stream.
+///
+/// This type does not support transmission of an error other than that an
+/// error occurred.
+pub struct Error;
+
+impl Clone for Error {
+    #[inline(always)]
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl Copy for Error {}
+
+impl Default for Error {
+    #[inline(always)]
+    fn default() -> Self {
+        Error
+    }
+}
+
+impl Debug for Error {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        f.write_str("Error")
+    }
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        f.write_str("an error occurred when formatting an argument")
+    }
+}
+
+/// A trait for writing or formatting into Unicode-accepting buffers or
+/// streams.
+pub trait Write {
+    /// Writes a string slice into this writer, returning whether the write
+    /// succeeded.
+    fn write_str(&mut self, s: &str) -> Result;
+}
+
+impl<W: Write + ?Sized> Write for &mut W {
+    fn write_str(&mut self, s: &str) -> Result {
+        (**self).write_str(s)
+    }
+}
+
+/// Options which can be used to configure how a [`Formatter`] writes a value.
+///
+/// This is the programmatic equivalent of the flags of a format spec, like
+/// `#` (alternate), `+` (sign), the width and the precision in `{:+#8.3?}`.
+pub struct FormattingOptions {
+    alternate: bool,
+    sign_plus: bool,
+    width: Option<usize>,
+    precision: Option<usize>,
+}
+
+impl FormattingOptions {
+    /// Constructs options equivalent to the `{}` formatting specifier (no
+    /// flags, no width and no precision).
+    pub const fn new() -> Self {
+        Self {
+            alternate: false,
+            sign_plus: false,
+            width: None,
+            precision: None,
+        }
+    }
+
+    /// Sets or removes the alternate flag (`#`).
+    pub fn alternate(&mut self, alternate: bool) -> &mut Self {
+        self.alternate = alternate;
+        self
+    }
+
+    /// Sets or removes the plus sign flag (`+`).
+    pub fn sign_plus(&mut self, sign_plus: bool) -> &mut Self {
+        self.sign_plus = sign_plus;
+        self
+    }
+
+    /// Sets or removes the minimum width of the output.
+    pub fn width(&mut self, width: Option<usize>) -> &mut Self {
+        self.width = width;
+        self
+    }
+
+    /// Sets or removes the precision of the output.
+    pub fn precision(&mut self, precision: Option<usize>) -> &mut Self {
+        self.precision = precision;
+        self
+    }
+}
+
+impl Clone for FormattingOptions {
+    #[inline(always)]
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl Copy for FormattingOptions {}
+
+impl Default for FormattingOptions {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Configuration for formatting.
+///
+/// A `Formatter` represents both where to write the output of the formatting
+/// and the options requested for it. Implementations of [`Debug`] and
+/// [`Display`] receive one and write themselves into it.
+#[rustc_diagnostic_item = "Formatter"]
+pub struct Formatter<'a> {
+    options: FormattingOptions,
+    buf: &'a mut (dyn Write + 'a),
+}
+
+impl<'a> Formatter<'a> {
+    /// Creates a new formatter writing into `buf` with the given options.
+    pub fn new(buf: &'a mut (dyn Write + 'a), options: FormattingOptions) -> Self {
+        Formatter { options, buf }
+    }
+
+    /// Writes some data to the underlying buffer contained within this
+    /// formatter, ignoring the width and the precision.
+    #[inline]
+    pub fn write_str(&mut self, data: &str) -> Result {
+        self.buf.write_str(data)
+    }
+
+    /// Writes `s` to the underlying buffer, padding it with spaces up to the
+    /// requested width.
+    pub fn pad(&mut self, s: &str) -> Result {
+        // TODO: truncate `s` to the precision once `str` can be split on char
+        // boundaries.
+        let padding = missing_width(self.options.width, char_count(s));
+        self.write_str(s)?;
+        self.write_padding(padding)
+    }
+
+    /// Writes an integer already converted into its digits, taking care of the
+    /// sign, the `prefix` requested by the alternate flag and the width.
+    pub fn pad_integral(&mut self, is_nonnegative: bool, prefix: &str, digits: &str) -> Result {
+        let mut width = digits.len();
+
+        let sign = match (is_nonnegative, self.sign_plus()) {
+            (false, _) => Some("-"),
+            (true, true) => Some("+"),
+            (true, false) => None,
+        };
+        if sign.is_some() {
+            width += 1;
+        }
+        let prefix = match self.alternate() {
+            true => {
+                width += prefix.len();
+                Some(prefix)
+            }
+            false => None,
+        };
+
+        let padding = missing_width(self.options.width, width);
+        self.write_padding(padding)?;
+        if let Some(sign) = sign {
+            self.write_str(sign)?;
+        }
+        if let Some(prefix) = prefix {
+            self.write_str(prefix)?;
+        }
+        self.write_str(digits)
+    }
+
+    fn write_padding(&mut self, mut padding: usize) -> Result {
+        while padding > 0 {
+            self.write_str(" ")?;
+            padding -= 1;
+        }
+        Ok(())
+    }
+
+    /// Returns the options this formatter was created with.
+    #[inline]
+    #[must_use]
+    pub fn options(&self) -> FormattingOptions {
+        self.options
+    }
+
+    /// Determines if the `#` flag was specified.
+    #[inline]
+    #[must_use]
+    pub fn alternate(&self) -> bool {
+        self.options.alternate
+    }
+
+    /// Determines if the `+` flag was specified.
+    #[inline]
+    #[must_use]
+    pub fn sign_plus(&self) -> bool {
+        self.options.sign_plus
+    }
+
+    /// Returns the optionally specified integer width that the output should
+    /// be.
+    #[inline]
+    #[must_use]
+    pub fn width(&self) -> Option<usize> {
+        self.options.width
+    }
+
+    /// Returns the optionally specified precision for numeric types.
+    /// Alternatively, the maximum width for string types.
+    #[inline]
+    #[must_use]
+    pub fn precision(&self) -> Option<usize> {
+        self.options.precision
+    }
+
+    /// Creates a [`DebugTuple`] builder designed to assist with creation of
+    /// [`Debug`] implementations for tuple structs.
+    pub fn debug_tuple<'b>(&'b mut self, name: &str) -> DebugTuple<'b, 'a> {
+        builders::debug_tuple_new(self, name)
+    }
+
+    /// Creates a [`DebugStruct`] builder designed to assist with creation of
+    /// [`Debug`] implementations for structs.
+    pub fn debug_struct<'b>(&'b mut self, name: &str) -> DebugStruct<'b, 'a> {
+        builders::debug_struct_new(self, name)
+    }
+}
+
+impl Write for Formatter<'_> {
+    fn write_str(&mut self, s: &str) -> Result {
+        self.buf.write_str(s)
+    }
+}
+
+/// Returns how many characters are missing to `len` to reach the requested
+/// `width`.
+fn missing_width(width: Option<usize>, len: usize) -> usize {
+    match width {
+        Some(width) if width > len => width - len,
+        _ => 0,
+    }
+}
+
+/// Counts the `char`s of `s` by skipping the UTF-8 continuation bytes.
+fn char_count(s: &str) -> usize {
+    let bytes = s.as_bytes();
+    let mut count = 0;
+    let mut i = 0;
+    while i < bytes.len() {
+        if bytes[i] & 0xC0 != 0x80 {
+            count += 1;
+        }
+        i += 1;
+    }
+    count
+}
+
+/// `?` formatting.
+///
+/// `Debug` should format the output in a programmer-facing, debugging context.
+#[doc(alias = "{:?}")]
+#[rustc_diagnostic_item = "Debug"]
+#[rustc_trivial_field_reads]
+pub trait Debug {
+    /// Formats the value using the given formatter.
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result;
+}
+
+/// Format trait for an empty format, `{}`.
+///
+/// `Display` is similar to [`Debug`], but `Display` is for user-facing output.
+#[doc(alias = "{}")]
+#[rustc_diagnostic_item = "Display"]
+pub trait Display {
+    /// Formats the value using the given formatter.
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result;
+}
+
+macro_rules! fmt_refs {
+    ($($tr:ident),*) => {$(
+        impl<T: ?Sized + $tr> $tr for &T {
+            fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+                $tr::fmt(&**self, f)
+            }
+        }
+
+        impl<T: ?Sized + $tr> $tr for &mut T {
+            fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+                $tr::fmt(&**self, f)
+            }
+        }
+    )*};
+}
+
+fmt_refs! { Debug, Display }
+
+impl Debug for ! {
+    #[inline]
+    fn fmt(&self, _: &mut Formatter<'_>) -> Result {
+        *self
+    }
+}
+
+impl Display for ! {
+    #[inline]
+    fn fmt(&self, _: &mut Formatter<'_>) -> Result {
+        *self
+    }
+}
+
+impl Debug for () {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        f.pad("()")
+    }
+}
+
+impl Debug for bool {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        Display::fmt(self, f)
+    }
+}
+
+impl Display for bool {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        f.pad(match self {
+            true => "true",
+            false => "false",
+        })
+    }
+}
+
+impl Debug for char {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        f.write_str("'")?;
+        let esc = self.escape_debug_ext(EscapeDebugExtArgs {
+            escape_grapheme_extended: true,
+            escape_single_quote: true,
+            escape_double_quote: false,
+        });
+        Display::fmt(&esc, f)?;
+        f.write_str("'")
+    }
+}
+
+impl Display for char {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        f.pad(self.encode_utf8(&mut [0; 4]))
+    }
+}
+
+impl Debug for str {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        // TODO: escape the content with `char::escape_debug`
+        f.write_str("\"")?;
+        f.write_str(self)?;
+        f.write_str("\"")
+    }
+}
+
+impl Display for str {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        f.pad(self)
+    }
+}
@@ -0,0 +1,78 @@
+//! Integer formatting.
+
+use crate::fmt::{Debug, Display, Formatter, Result};
+use crate::{slice, str};
+
+/// The number of digits of `u128::MAX` in base 10.
+const MAX_DIGITS: usize = 39;
+
+fn fmt_u128(mut n: u128, is_nonnegative: bool, f: &mut Formatter<'_>) -> Result {
+    let mut buf = [0u8; MAX_DIGITS];
+    let mut len = 0;
+    loop {
+        buf[len] = b'0' + (n % 10) as u8;
+        len += 1;
+        n /= 10;
+        if n == 0 {
+            break;
+        }
+    }
+
+    // the digits were written starting from the least significant one, put
+    // them back in the reading order
+    let mut i = 0;
+    while i < len / 2 {
+        let tmp = buf[i];
+        buf[i] = buf[len - 1 - i];
+        buf[len - 1 - i] = tmp;
+        i += 1;
+    }
+
+    // SAFETY: `buf[..len]` is initialized with ASCII digits only.
+    let digits = unsafe { str::from_utf8_unchecked(slice::from_raw_parts(buf.as_ptr(), len)) };
+    f.pad_integral(is_nonnegative, "", digits)
+}
+
+macro_rules! impl_unsigned_fmt {
+    ($($t:ty),*) => {$(
+        impl Display for $t {
+            fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+                fmt_u128(*self as u128, true, f)
+            }
+        }
+
+        impl Debug for $t {
+            #[inline]
+            fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+                Display::fmt(self, f)
+            }
+        }
+    )*};
+}
+
+macro_rules! impl_signed_fmt {
+    ($($t:ty as $u:ty),*) => {$(
+        impl Display for $t {
+            fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+                let is_nonnegative = *self >= 0;
+                let n = match is_nonnegative {
+                    true => *self as $u,
+                    // two's complement negation, it cannot overflow even for
+                    // `MIN` once reinterpreted as unsigned
+                    false => !(*self as $u) + 1,
+                };
+                fmt_u128(n as u128, is_nonnegative, f)
+            }
+        }
+
+        impl Debug for $t {
+            #[inline]
+            fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+                Display::fmt(self, f)
+            }
+        }
+    )*};
+}
+
+impl_unsigned_fmt! { usize, u8, u16, u32, u64, u128 }
+impl_signed_fmt! { isize as usize, i8 as u8, i16 as u16, i32 as u32, i64 as u64, i128 as u128 }
@@ -0,0 +1,61 @@
+use crate::marker::{Sized, Unpin};
+use crate::ops::DerefMut;
+use crate::pin::Pin;
+use crate::task::{Context, Poll};
+
+/// A future represents an asynchronous computation, obtained by use of
+/// `async`.
+///
+/// A future is a value that might not have finished computing yet. This kind
+/// of "asynchronous value" makes it possible for a thread to continue doing
+/// useful work while it waits for the value to become available.
+///
+/// The core method of future, `poll`, *attempts* to resolve the future into
+/// a final value. This method does not block if the value is not ready.
+/// Instead, the current task is scheduled to be woken up when it's possible
+/// to make further progress by `poll`ing again.
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+#[lang = "future_trait"]
+#[diagnostic::on_unimplemented(
+    label = "`{Self}` is not a future",
+    message = "`{Self}` is not a future"
+)]
+pub trait Future {
+    /// The type of value produced on completion.
+    #[lang = "future_output"]
+    type Output;
+
+    /// Attempts to resolve the future to a final value, registering the
+    /// current task for wakeup if the value is not yet available.
+    ///
+    /// This function returns:
+    ///
+    /// - [`Poll::Pending`] if the future is not ready yet
+    /// - [`Poll::Ready(val)`] with the result `val` of this future if it
+    ///   finished successfully.
+    ///
+    /// Once a future has finished, clients should not `poll` it again.
+    ///
+    /// [`Poll::Ready(val)`]: Poll::Ready
+    #[lang = "poll"]
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output>;
+}
+
+impl<F: ?Sized + Future + Unpin> Future for &mut F {
+    type Output = F::Output;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        F::poll(Pin::new(&mut **self), cx)
+    }
+}
+
+impl<P> Future for Pin<P>
+where
+    P: DerefMut<Target: Future>,
+{
+    type Output = <P::Target as Future>::Output;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        <P::Target as Future>::poll(self.as_deref_mut(), cx)
+    }
+}
@@ -0,0 +1,33 @@
+use crate::future::Future;
+
+/// Conversion into a `Future`.
+///
+/// By implementing `IntoFuture` for a type, you define how it will be
+/// converted to a future. This is what `.await` calls on its operand before
+/// polling it.
+#[rustc_diagnostic_item = "IntoFuture"]
+#[diagnostic::on_unimplemented(
+    label = "`{Self}` is not a future",
+    message = "`{Self}` is not a future",
+    note = "{Self} must be a future or must implement `IntoFuture` to be awaited"
+)]
+pub trait IntoFuture {
+    /// The output that the future will produce on completion.
+    type Output;
+
+    /// Which kind of future are we turning this into?
+    type IntoFuture: Future<Output = Self::Output>;
+
+    /// Creates a future from a value.
+    #[lang = "into_future"]
+    fn into_future(self) -> Self::IntoFuture;
+}
+
+impl<F: Future> IntoFuture for F {
+    type IntoFuture = F;
+    type Output = F::Output;
+
+    fn into_future(self) -> Self::IntoFuture {
+        self
+    }
+}
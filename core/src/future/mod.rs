@@ -0,0 +1,62 @@
+//! Asynchronous basic functionality.
+//!
+//! Please see the fundamental [`async`] and [`await`] keywords and the [async
+//! book] for more information on asynchronous programming in Rust.
+//!
+//! [`async`]: ../../std/keyword.async.html
+//! [`await`]: ../../std/keyword.await.html
+//! [async book]: https://rust-lang.github.io/async-book/
+
+use crate::clone::Clone;
+use crate::marker::{Copy, Send, Sync};
+use crate::ptr::non_null::NonNull;
+use crate::task::Context;
+
+mod future;
+mod into_future;
+mod pending;
+mod poll_fn;
+mod ready;
+
+pub use into_future::IntoFuture;
+pub use pending::{pending, Pending};
+pub use poll_fn::{poll_fn, PollFn};
+pub use ready::{ready, Ready};
+
+pub use self::future::Future;
+
+/// This type is needed because:
+///
+/// a) Coroutines cannot implement `for<'a, 'b> Coroutine<&'a mut Context<'b>>`,
+///    so we need to pass a raw pointer (see
+///    <https://github.com/rust-lang/rust/issues/68923>).
+/// b) Raw pointers and `NonNull` aren't `Send` or `Sync`, so that would make
+///    every single future non-`Send`/`Sync` as well, and we don't want that.
+///
+/// It also simplifies the HIR lowering of `.await`.
+#[lang = "ResumeTy"]
+#[doc(hidden)]
+pub struct ResumeTy(NonNull<Context<'static>>);
+
+impl Clone for ResumeTy {
+    #[inline]
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl Copy for ResumeTy {}
+
+unsafe impl Send for ResumeTy {}
+
+unsafe impl Sync for ResumeTy {}
+
+#[lang = "get_context"]
+#[doc(hidden)]
+#[must_use]
+#[inline]
+pub unsafe fn get_context<'a, 'b>(cx: ResumeTy) -> &'a mut Context<'b> {
+    // SAFETY: the caller must guarantee that `cx.0` is a valid pointer
+    // that fulfills all the requirements for a mutable reference.
+    unsafe { &mut *(cx.0.as_ptr() as *mut Context<'b>) }
+}
@@ -0,0 +1,41 @@
+use crate::clone::Clone;
+use crate::fmt::{self, Debug, Formatter};
+use crate::future::Future;
+use crate::marker::PhantomData;
+use crate::pin::Pin;
+use crate::task::{Context, Poll};
+
+/// Creates a future which never resolves, representing a computation that
+/// never finishes.
+///
+/// This `struct` is created by [`pending()`]. See its documentation for more.
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+pub struct Pending<T> {
+    _data: PhantomData<fn() -> T>,
+}
+
+/// Creates a future which never resolves, representing a computation that
+/// never finishes.
+pub fn pending<T>() -> Pending<T> {
+    Pending { _data: PhantomData }
+}
+
+impl<T> Future for Pending<T> {
+    type Output = T;
+
+    fn poll(self: Pin<&mut Self>, _: &mut Context<'_>) -> Poll<T> {
+        Poll::Pending
+    }
+}
+
+impl<T> Debug for Pending<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Pending").finish()
+    }
+}
+
+impl<T> Clone for Pending<T> {
+    fn clone(&self) -> Self {
+        pending()
+    }
+}
@@ -0,0 +1,47 @@
+use crate::fmt::{self, Debug, Formatter};
+use crate::future::Future;
+use crate::marker::Unpin;
+use crate::ops::FnMut;
+use crate::pin::Pin;
+use crate::task::{Context, Poll};
+
+/// Creates a future that wraps a function returning [`Poll`].
+///
+/// Polling the future delegates to the wrapped function. If the returned
+/// future is pinned, then the captured environment of the wrapped function
+/// is also pinned in-place, so as long as the closure does not move out of
+/// its captures it can soundly create pinned references to them.
+pub fn poll_fn<T, F>(f: F) -> PollFn<F>
+where
+    F: FnMut(&mut Context<'_>) -> Poll<T>,
+{
+    PollFn { f }
+}
+
+/// A Future that wraps a function returning [`Poll`].
+///
+/// This `struct` is created by [`poll_fn()`]. See its documentation for more.
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+pub struct PollFn<F> {
+    f: F,
+}
+
+impl<F: Unpin> Unpin for PollFn<F> {}
+
+impl<F> Debug for PollFn<F> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("PollFn").finish()
+    }
+}
+
+impl<T, F> Future for PollFn<F>
+where
+    F: FnMut(&mut Context<'_>) -> Poll<T>,
+{
+    type Output = T;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<T> {
+        // SAFETY: We are not moving out of the pinned field.
+        (unsafe { &mut self.get_unchecked_mut().f })(cx)
+    }
+}
@@ -0,0 +1,60 @@
+use crate::clone::Clone;
+use crate::fmt::{self, Debug, Formatter};
+use crate::future::Future;
+use crate::marker::Unpin;
+use crate::option::Option::{self, Some};
+use crate::pin::Pin;
+use crate::task::{Context, Poll};
+
+/// A future that is immediately ready with a value.
+///
+/// This `struct` is created by [`ready()`]. See its documentation for more.
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+pub struct Ready<T>(Option<T>);
+
+impl<T> Unpin for Ready<T> {}
+
+impl<T> Future for Ready<T> {
+    type Output = T;
+
+    #[inline]
+    fn poll(mut self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<T> {
+        Poll::Ready(self.0.take().expect("`Ready` polled after completion"))
+    }
+}
+
+impl<T> Ready<T> {
+    /// Consumes the `Ready`, returning the wrapped value.
+    ///
+    /// # Panics
+    ///
+    /// Will panic if this [`Ready`] was already polled to completion.
+    #[must_use]
+    #[inline]
+    pub fn into_inner(self) -> T {
+        self.0
+            .expect("Called `into_inner()` on `Ready` after completion")
+    }
+}
+
+impl<T: Clone> Clone for Ready<T> {
+    #[inline]
+    fn clone(&self) -> Self {
+        Ready(self.0.clone())
+    }
+}
+
+impl<T: Debug> Debug for Ready<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("Ready").field(&self.0).finish()
+    }
+}
+
+/// Creates a future that is immediately ready with a value.
+///
+/// Futures created through this function are functionally similar to those
+/// created through `async {}`. The main difference is that futures created
+/// through this function are named and implement `Unpin`.
+pub fn ready<T>(t: T) -> Ready<T> {
+    Ready(Some(t))
+}
@@ -0,0 +1,237 @@
+//! Generic hashing support.
+//!
+//! This module provides a generic way to compute the hash of a value through
+//! the [`Hash`] trait, the hashing algorithm itself being provided by an
+//! implementation of [`Hasher`].
+
+use crate::intrinsics::transmute;
+use crate::marker::Sized;
+
+/// A hashable type.
+///
+/// Types implementing `Hash` are able to be hashed with an instance of
+/// [`Hasher`].
+///
+/// When implementing both `Hash` and [`Eq`], it is important that the
+/// following property holds:
+///
+/// ```text
+/// k1 == k2 -> hash(k1) == hash(k2)
+/// ```
+///
+/// [`Eq`]: crate::cmp::Eq
+#[rustc_diagnostic_item = "Hash"]
+pub trait Hash {
+    /// Feeds this value into the given [`Hasher`].
+    fn hash<H: Hasher>(&self, state: &mut H);
+
+    /// Feeds a slice of this type into the given [`Hasher`].
+    fn hash_slice<H: Hasher>(data: &[Self], state: &mut H)
+    where
+        Self: Sized,
+    {
+        let mut i = 0;
+        while i < data.len() {
+            data[i].hash(state);
+            i += 1;
+        }
+    }
+}
+
+macro_rules! hasher_write_integer {
+    ($($method:ident: $t:ty => [u8; $n:literal],)*) => {$(
+        /// Writes a single integer of the type of the method into this hasher.
+        #[inline]
+        fn $method(&mut self, i: $t) {
+            // SAFETY: integers are plain old data, any of them can be seen as
+            // an array of bytes of the same size.
+            self.write(&unsafe { transmute::<$t, [u8; $n]>(i) })
+        }
+    )*};
+}
+
+/// A trait for hashing an arbitrary stream of bytes.
+///
+/// Instances of `Hasher` usually represent state that is changed while hashing
+/// data. `Hasher` provides a fairly basic interface for retrieving the
+/// generated hash (with [`finish`]), and writing integers as well as slices of
+/// bytes into an instance (with [`write`] and [`write_u8`] etc.).
+///
+/// [`finish`]: Hasher::finish
+/// [`write`]: Hasher::write
+/// [`write_u8`]: Hasher::write_u8
+pub trait Hasher {
+    /// Returns the hash value for the values written so far.
+    #[must_use]
+    fn finish(&self) -> u64;
+
+    /// Writes some data into this `Hasher`.
+    fn write(&mut self, bytes: &[u8]);
+
+    hasher_write_integer! {
+        write_u8: u8 => [u8; 1],
+        write_u16: u16 => [u8; 2],
+        write_u32: u32 => [u8; 4],
+        write_u64: u64 => [u8; 8],
+        write_u128: u128 => [u8; 16],
+        write_i8: i8 => [u8; 1],
+        write_i16: i16 => [u8; 2],
+        write_i32: i32 => [u8; 4],
+        write_i64: i64 => [u8; 8],
+        write_i128: i128 => [u8; 16],
+    }
+
+    /// Writes a single `usize` into this hasher.
+    #[inline]
+    fn write_usize(&mut self, i: usize) {
+        self.write_u64(i as u64)
+    }
+
+    /// Writes a single `isize` into this hasher.
+    #[inline]
+    fn write_isize(&mut self, i: isize) {
+        self.write_usize(i as usize)
+    }
+
+    /// Writes a length prefix into this hasher, as part of being prefix-free.
+    #[inline]
+    fn write_length_prefix(&mut self, len: usize) {
+        self.write_usize(len);
+    }
+}
+
+impl<H: Hasher + ?Sized> Hasher for &mut H {
+    fn finish(&self) -> u64 {
+        (**self).finish()
+    }
+
+    fn write(&mut self, bytes: &[u8]) {
+        (**self).write(bytes)
+    }
+
+    fn write_u8(&mut self, i: u8) {
+        (**self).write_u8(i)
+    }
+
+    fn write_u16(&mut self, i: u16) {
+        (**self).write_u16(i)
+    }
+
+    fn write_u32(&mut self, i: u32) {
+        (**self).write_u32(i)
+    }
+
+    fn write_u64(&mut self, i: u64) {
+        (**self).write_u64(i)
+    }
+
+    fn write_u128(&mut self, i: u128) {
+        (**self).write_u128(i)
+    }
+
+    fn write_usize(&mut self, i: usize) {
+        (**self).write_usize(i)
+    }
+
+    fn write_i8(&mut self, i: i8) {
+        (**self).write_i8(i)
+    }
+
+    fn write_i16(&mut self, i: i16) {
+        (**self).write_i16(i)
+    }
+
+    fn write_i32(&mut self, i: i32) {
+        (**self).write_i32(i)
+    }
+
+    fn write_i64(&mut self, i: i64) {
+        (**self).write_i64(i)
+    }
+
+    fn write_i128(&mut self, i: i128) {
+        (**self).write_i128(i)
+    }
+
+    fn write_isize(&mut self, i: isize) {
+        (**self).write_isize(i)
+    }
+
+    fn write_length_prefix(&mut self, len: usize) {
+        (**self).write_length_prefix(len)
+    }
+}
+
+macro_rules! impl_hash {
+    ($($t:ty => $method:ident,)*) => {$(
+        impl Hash for $t {
+            #[inline]
+            fn hash<H: Hasher>(&self, state: &mut H) {
+                state.$method(*self)
+            }
+        }
+    )*};
+}
+
+impl_hash! {
+    u8 => write_u8,
+    u16 => write_u16,
+    u32 => write_u32,
+    u64 => write_u64,
+    u128 => write_u128,
+    usize => write_usize,
+    i8 => write_i8,
+    i16 => write_i16,
+    i32 => write_i32,
+    i64 => write_i64,
+    i128 => write_i128,
+    isize => write_isize,
+}
+
+impl Hash for bool {
+    #[inline]
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        state.write_u8(*self as u8)
+    }
+}
+
+impl Hash for char {
+    #[inline]
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        state.write_u32(*self as u32)
+    }
+}
+
+impl Hash for () {
+    #[inline]
+    fn hash<H: Hasher>(&self, _state: &mut H) {}
+}
+
+impl Hash for ! {
+    #[inline]
+    fn hash<H: Hasher>(&self, _: &mut H) {
+        *self
+    }
+}
+
+impl<T: Hash> Hash for [T] {
+    #[inline]
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        state.write_length_prefix(self.len());
+        Hash::hash_slice(self, state)
+    }
+}
+
+impl<T: ?Sized + Hash> Hash for &T {
+    #[inline]
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        (**self).hash(state);
+    }
+}
+
+impl<T: ?Sized + Hash> Hash for &mut T {
+    #[inline]
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        (**self).hash(state);
+    }
+}
@@ -8,6 +8,7 @@
     issue = "none"
 )]
 
+use crate::marker::Sized;
 use crate::unreachable;
 
 /// The size of a type in bytes.
@@ -36,22 +37,128 @@ pub fn abort() -> ! {
     unreachable!()
 }
 
-// extern "rust-intrinsic" {
-//     // TODO: rustc_const_stable
-//     // #[rustc_const_stable(feature = "const_size_of", since = "1.0.0")]
-//     #[rustc_safe_intrinsic]
-//     #[rustc_nounwind]
-//     pub fn size_of<T>() -> usize;
+/// Informs the optimizer that this point in the code is not reachable,
+/// enabling further optimizations.
+///
+/// N.B., this is very different from the `unreachable!()` macro: Unlike the
+/// macro, which panics when it is executed, it is *undefined behavior* to
+/// reach code marked with this function.
+#[rustc_nounwind]
+#[rustc_intrinsic]
+#[rustc_intrinsic_must_be_overridden]
+pub const unsafe fn unreachable() -> ! {
+    unreachable!()
+}
+
+/// Reinterprets the bits of a value of one type as another type.
+///
+/// Both types must have the same size. Compilation will fail if this is not
+/// guaranteed. Neither the original, nor the result, may be an invalid value.
+#[rustc_nounwind]
+#[rustc_intrinsic]
+#[rustc_intrinsic_must_be_overridden]
+pub const unsafe fn transmute<Src, Dst>(_src: Src) -> Dst {
+    unreachable!()
+}
+
+/// Like [`transmute`], but even less checked at compile-time: rather than
+/// giving an error for `size_of::<Src>() != size_of::<Dst>()`, it's
+/// **Undefined Behavior** at runtime.
+#[rustc_nounwind]
+#[rustc_intrinsic]
+#[rustc_intrinsic_must_be_overridden]
+pub const unsafe fn transmute_unchecked<Src, Dst>(_src: Src) -> Dst {
+    unreachable!()
+}
+
+/// This is an implementation detail of [`crate::ptr::read`] and should
+/// not be used anywhere else.
+///
+/// This intrinsic can *only* be called where the pointer is a local without
+/// projections (`read_via_copy(ptr)`, not `read_via_copy(*ptr)`) so that it
+/// trivially obeys runtime-MIR rules about derefs in operands.
+#[rustc_nounwind]
+#[rustc_intrinsic]
+#[rustc_intrinsic_must_be_overridden]
+pub const unsafe fn read_via_copy<T>(_ptr: *const T) -> T {
+    unreachable!()
+}
+
+/// This is an implementation detail of [`crate::ptr::write`] and should
+/// not be used anywhere else.
+///
+/// This intrinsic can *only* be called where the pointer is a local without
+/// projections (`write_via_move(ptr, x)`, not `write_via_move(*ptr, x)`) so
+/// that it trivially obeys runtime-MIR rules about derefs in operands.
+#[rustc_nounwind]
+#[rustc_intrinsic]
+#[rustc_intrinsic_must_be_overridden]
+pub const unsafe fn write_via_move<T>(_ptr: *mut T, _value: T) {
+    unreachable!()
+}
 
-//     #[rustc_nounwind]
-//     pub fn transmute<Src, Dst>(src: Src) -> Dst;
+/// Lowers in MIR to `Rvalue::RawPtr` with `PtrMetadata`: extracts the
+/// metadata (like the length of a slice) of a possibly-wide pointer.
+///
+/// The stabilized version of this intrinsic is `core::ptr::metadata`.
+#[rustc_nounwind]
+#[rustc_intrinsic]
+#[rustc_intrinsic_must_be_overridden]
+pub const fn ptr_metadata<P: ?Sized, M>(_ptr: *const P) -> M {
+    // To implement a fallback we'd have to assume the layout of the pointer,
+    // but the whole point of this intrinsic is that we shouldn't do that.
+    unreachable!()
+}
 
-//     #[rustc_nounwind]
-//     pub fn transmute_unchecked<Src, Dst>(src: Src) -> Dst;
+/// Lowers in MIR to `Rvalue::Aggregate` with `AggregateKind::RawPtr`: builds
+/// a (possibly-wide) pointer `P` from its data pointer `D` and metadata `M`.
+///
+/// This is used to implement functions like `slice::from_raw_parts` without
+/// assuming the layout of wide pointers.
+#[rustc_nounwind]
+#[rustc_intrinsic]
+#[rustc_intrinsic_must_be_overridden]
+pub const fn aggregate_raw_ptr<P, D, M>(_data: D, _meta: M) -> P {
+    // To implement a fallback we'd have to assume the layout of the pointer,
+    // but the whole point of this intrinsic is that we shouldn't do that.
+    unreachable!()
+}
 
-//     #[rustc_nounwind]
-//     pub fn read_via_copy<T>(ptr: *const T) -> T;
+/// Calculates the offset from a pointer.
+///
+/// This is implemented as an intrinsic to avoid converting to and from an
+/// integer, since the conversion would throw away aliasing information.
+///
+/// # Safety
+///
+/// If the computed offset is non-zero, then both the starting and resulting
+/// pointer must be either in bounds or at the end of an allocated object.
+#[must_use = "returns a new pointer rather than modifying its argument"]
+#[rustc_nounwind]
+#[rustc_intrinsic]
+#[rustc_intrinsic_must_be_overridden]
+pub const unsafe fn offset<Ptr, Delta>(_dst: Ptr, _offset: Delta) -> Ptr {
+    unreachable!()
+}
+
+/// Gets a static string slice containing the name of a type.
+///
+/// The stabilized version of this intrinsic is [`core::any::type_name`].
+#[rustc_nounwind]
+#[rustc_intrinsic]
+#[rustc_intrinsic_must_be_overridden]
+pub const fn type_name<T: ?Sized>() -> &'static str {
+    unreachable!()
+}
 
-//     #[rustc_nounwind]
-//     pub fn write_via_move<T>(ptr: *mut T, value: T);
-// }
+/// Gets an identifier which is globally unique to the specified type. This
+/// function will return the same value for a type regardless of whichever
+/// crate it is invoked in.
+///
+/// The stabilized version of this intrinsic is [`core::any::TypeId::of`].
+#[rustc_nounwind]
+#[rustc_intrinsic]
+#[rustc_intrinsic_must_be_overridden]
+pub const fn type_id<T: ?Sized + 'static>() -> u128 {
+    unreachable!()
+}
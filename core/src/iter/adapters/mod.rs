@@ -0,0 +1,64 @@
+use crate::iter::Iterator;
+use crate::ops::{ChangeOutputType, ControlFlow, FnOnce, FromResidual, Residual, Try};
+use crate::option::Option::{self, None, Some};
+
+/// An iterator adapter that produces output as long as the underlying
+/// iterator produces values where `Try::branch` says to `ControlFlow::Continue`.
+///
+/// If a `ControlFlow::Break` is encountered, the iterator stops and the
+/// residual is stored.
+pub(crate) struct GenericShunt<'a, I, R> {
+    iter: I,
+    residual: &'a mut Option<R>,
+}
+
+/// Process the given iterator as if it yielded the item's `Try::Output`
+/// type instead. Any `Try::Residual`s encountered will stop the inner iterator
+/// and be propagated back to the overall result.
+pub(crate) fn try_process<I, T, R, F, U>(iter: I, f: F) -> ChangeOutputType<I::Item, U>
+where
+    I: Iterator<Item: Try<Output = T, Residual = R>>,
+    for<'a> F: FnOnce(GenericShunt<'a, I, R>) -> U,
+    R: Residual<U>,
+{
+    let mut residual = None;
+    let shunt = GenericShunt {
+        iter,
+        residual: &mut residual,
+    };
+    let value = f(shunt);
+    match residual {
+        Some(r) => FromResidual::from_residual(r),
+        None => Try::from_output(value),
+    }
+}
+
+impl<I, R> Iterator for GenericShunt<'_, I, R>
+where
+    I: Iterator<Item: Try<Residual = R>>,
+{
+    type Item = <I::Item as Try>::Output;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.residual.is_some() {
+            return None;
+        }
+
+        match Try::branch(self.iter.next()?) {
+            ControlFlow::Continue(output) => Some(output),
+            ControlFlow::Break(residual) => {
+                *self.residual = Some(residual);
+                None
+            }
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.residual.is_some() {
+            (0, Some(0))
+        } else {
+            let (_, upper) = self.iter.size_hint();
+            (0, upper)
+        }
+    }
+}
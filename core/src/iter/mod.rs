@@ -0,0 +1,12 @@
+//! Composable external iteration.
+//!
+//! Only the traits and the adapters needed by the rest of the core are
+//! available for now.
+
+mod adapters;
+mod sources;
+mod traits;
+
+pub(crate) use adapters::try_process;
+pub use sources::{FromCoroutine, from_coroutine};
+pub use traits::{FromIterator, IntoIterator, Iterator, Product, Sum};
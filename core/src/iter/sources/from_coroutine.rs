@@ -0,0 +1,58 @@
+use crate::clone::Clone;
+use crate::fmt::{self, Debug, Formatter};
+use crate::iter::Iterator;
+use crate::marker::Unpin;
+use crate::ops::{Coroutine, CoroutineState};
+use crate::option::Option::{self, None, Some};
+use crate::pin::Pin;
+
+/// Creates a new iterator where each iteration calls the provided coroutine.
+///
+/// The iterator yields the values yielded by the coroutine and ends once the
+/// coroutine completes.
+///
+/// ```ignore
+/// let mut it = iter::from_coroutine(#[coroutine] || {
+///     yield 1;
+///     yield 2;
+///     yield 3;
+/// });
+/// assert_eq!(it.next(), Some(1));
+/// assert_eq!(it.next(), Some(2));
+/// assert_eq!(it.next(), Some(3));
+/// assert_eq!(it.next(), None);
+/// ```
+#[inline]
+pub fn from_coroutine<G: Coroutine<Return = ()> + Unpin>(coroutine: G) -> FromCoroutine<G> {
+    FromCoroutine(coroutine)
+}
+
+/// An iterator over the values yielded by an underlying coroutine.
+///
+/// This `struct` is created by the [`from_coroutine()`] function. See its
+/// documentation for more.
+pub struct FromCoroutine<G>(G);
+
+impl<G: Coroutine<Return = ()> + Unpin> Iterator for FromCoroutine<G> {
+    type Item = G::Yield;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match Pin::new(&mut self.0).resume(()) {
+            CoroutineState::Yielded(n) => Some(n),
+            CoroutineState::Complete(()) => None,
+        }
+    }
+}
+
+impl<G: Clone> Clone for FromCoroutine<G> {
+    #[inline]
+    fn clone(&self) -> Self {
+        FromCoroutine(self.0.clone())
+    }
+}
+
+impl<G> Debug for FromCoroutine<G> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("FromCoroutine").finish()
+    }
+}
@@ -0,0 +1,3 @@
+mod from_coroutine;
+
+pub use from_coroutine::{from_coroutine, FromCoroutine};
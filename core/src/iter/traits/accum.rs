@@ -0,0 +1,134 @@
+use crate::iter::Iterator;
+use crate::marker::Sized;
+use crate::ops::{Add, Mul};
+use crate::option::Option::Some;
+
+/// Trait to represent types that can be created by summing up an iterator.
+///
+/// This trait is used to implement [`Iterator::sum()`]. Types which implement
+/// this trait can be generated by using the [`sum()`] method on an iterator.
+///
+/// [`sum()`]: Sum::sum
+#[rustc_on_unimplemented(
+    message = "a value of type `{Self}` cannot be made by summing an iterator over elements of type `{A}`",
+    label = "value of type `{Self}` cannot be made by summing a `core::iter::Iterator<Item={A}>`"
+)]
+pub trait Sum<A = Self>: Sized {
+    /// Takes an iterator and generates `Self` from the elements by "summing
+    /// up" the items.
+    fn sum<I: Iterator<Item = A>>(iter: I) -> Self;
+}
+
+/// Trait to represent types that can be created by multiplying elements of an
+/// iterator.
+///
+/// This trait is used to implement [`Iterator::product()`]. Types which
+/// implement this trait can be generated by using the [`product()`] method on
+/// an iterator.
+///
+/// [`product()`]: Product::product
+#[rustc_on_unimplemented(
+    message = "a value of type `{Self}` cannot be made by multiplying all elements of type `{A}` from an iterator",
+    label = "value of type `{Self}` cannot be made by multiplying all elements from a `core::iter::Iterator<Item={A}>`"
+)]
+pub trait Product<A = Self>: Sized {
+    /// Takes an iterator and generates `Self` from the elements by multiplying
+    /// the items.
+    fn product<I: Iterator<Item = A>>(iter: I) -> Self;
+}
+
+macro_rules! integer_sum_product {
+    (@impls $zero:expr, $one:expr, $($a:ty)*) => ($(
+        impl Sum for $a {
+            fn sum<I: Iterator<Item = Self>>(mut iter: I) -> Self {
+                let mut acc = $zero;
+                while let Some(x) = iter.next() {
+                    acc = Add::add(acc, x);
+                }
+                acc
+            }
+        }
+
+        impl Product for $a {
+            fn product<I: Iterator<Item = Self>>(mut iter: I) -> Self {
+                let mut acc = $one;
+                while let Some(x) = iter.next() {
+                    acc = Mul::mul(acc, x);
+                }
+                acc
+            }
+        }
+
+        impl<'a> Sum<&'a $a> for $a {
+            fn sum<I: Iterator<Item = &'a Self>>(mut iter: I) -> Self {
+                let mut acc = $zero;
+                while let Some(x) = iter.next() {
+                    acc = Add::add(acc, *x);
+                }
+                acc
+            }
+        }
+
+        impl<'a> Product<&'a $a> for $a {
+            fn product<I: Iterator<Item = &'a Self>>(mut iter: I) -> Self {
+                let mut acc = $one;
+                while let Some(x) = iter.next() {
+                    acc = Mul::mul(acc, *x);
+                }
+                acc
+            }
+        }
+    )*);
+    ($($a:ty)*) => (
+        integer_sum_product!(@impls 0, 1, $($a)*);
+    );
+}
+
+macro_rules! float_sum_product {
+    ($($a:ident)*) => ($(
+        impl Sum for $a {
+            fn sum<I: Iterator<Item = Self>>(mut iter: I) -> Self {
+                let mut acc = -0.0;
+                while let Some(x) = iter.next() {
+                    acc += x;
+                }
+                acc
+            }
+        }
+
+        impl Product for $a {
+            fn product<I: Iterator<Item = Self>>(mut iter: I) -> Self {
+                let mut acc = 1.0;
+                while let Some(x) = iter.next() {
+                    acc *= x;
+                }
+                acc
+            }
+        }
+
+        impl<'a> Sum<&'a $a> for $a {
+            fn sum<I: Iterator<Item = &'a Self>>(mut iter: I) -> Self {
+                let mut acc = -0.0;
+                while let Some(x) = iter.next() {
+                    acc += *x;
+                }
+                acc
+            }
+        }
+
+        impl<'a> Product<&'a $a> for $a {
+            fn product<I: Iterator<Item = &'a Self>>(mut iter: I) -> Self {
+                let mut acc = 1.0;
+                while let Some(x) = iter.next() {
+                    acc *= *x;
+                }
+                acc
+            }
+        }
+    )*)
+}
+
+// TODO: the impls for `Wrapping`, `Saturating`, `Option` and `Result` once
+// they exist.
+integer_sum_product! { i8 i16 i32 i64 i128 isize u8 u16 u32 u64 u128 usize }
+float_sum_product! { f16 f32 f64 f128 }
@@ -0,0 +1,45 @@
+use crate::iter::Iterator;
+use crate::marker::Sized;
+
+/// Conversion from an [`Iterator`].
+///
+/// By implementing `FromIterator` for a type, you define how it will be
+/// created from an iterator, this is what [`Iterator::collect`] relies on.
+#[rustc_on_unimplemented(
+    message = "a value of type `{Self}` cannot be built from an iterator \
+               over elements of type `{A}`",
+    label = "value of type `{Self}` cannot be built from `core::iter::Iterator<Item={A}>`"
+)]
+#[rustc_diagnostic_item = "FromIterator"]
+pub trait FromIterator<A>: Sized {
+    /// Creates a value from an iterator.
+    #[rustc_diagnostic_item = "from_iter_fn"]
+    fn from_iter<T: IntoIterator<Item = A>>(iter: T) -> Self;
+}
+
+/// Conversion into an [`Iterator`].
+///
+/// Implementing `IntoIterator` for a type defines how it will be converted to
+/// an iterator, this is what a `for` loop relies on.
+#[rustc_diagnostic_item = "IntoIterator"]
+pub trait IntoIterator {
+    /// The type of the elements being iterated over.
+    type Item;
+
+    /// Which kind of iterator are we turning this into?
+    type IntoIter: Iterator<Item = Self::Item>;
+
+    /// Creates an iterator from a value.
+    #[lang = "into_iter"]
+    fn into_iter(self) -> Self::IntoIter;
+}
+
+impl<I: Iterator> IntoIterator for I {
+    type IntoIter = I;
+    type Item = I::Item;
+
+    #[inline]
+    fn into_iter(self) -> I {
+        self
+    }
+}
@@ -0,0 +1,90 @@
+use crate::iter::{FromIterator, Product, Sum};
+use crate::marker::Sized;
+use crate::option::Option::{self, None};
+
+/// A trait for dealing with iterators.
+///
+/// This is the main iterator trait, an iterator only has to implement
+/// [`next`](Iterator::next) to be usable by a `for` loop.
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+#[lang = "iterator"]
+#[rustc_diagnostic_item = "Iterator"]
+pub trait Iterator {
+    /// The type of the elements being iterated over.
+    #[rustc_diagnostic_item = "IteratorItem"]
+    type Item;
+
+    /// Advances the iterator and returns the next value.
+    ///
+    /// Returns [`None`] when iteration is finished.
+    #[lang = "next"]
+    fn next(&mut self) -> Option<Self::Item>;
+
+    /// Returns the bounds on the remaining length of the iterator.
+    ///
+    /// The default implementation returns `(0, None)` which is correct for any
+    /// iterator.
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, None)
+    }
+
+    /// Transforms an iterator into a collection.
+    #[inline]
+    #[must_use]
+    #[rustc_diagnostic_item = "iterator_collect_fn"]
+    fn collect<B: FromIterator<Self::Item>>(self) -> B
+    where
+        Self: Sized,
+    {
+        FromIterator::from_iter(self)
+    }
+
+    /// Sums the elements of an iterator.
+    ///
+    /// Takes each element, adds them together, and returns the result. An
+    /// empty iterator returns the zero value of the type.
+    #[inline]
+    fn sum<S>(self) -> S
+    where
+        Self: Sized,
+        S: Sum<Self::Item>,
+    {
+        Sum::sum(self)
+    }
+
+    /// Iterates over the entire iterator, multiplying all the elements.
+    ///
+    /// An empty iterator returns the one value of the type.
+    #[inline]
+    fn product<P>(self) -> P
+    where
+        Self: Sized,
+        P: Product<Self::Item>,
+    {
+        Product::product(self)
+    }
+
+    /// Borrows an iterator, rather than consuming it.
+    #[inline]
+    fn by_ref(&mut self) -> &mut Self
+    where
+        Self: Sized,
+    {
+        self
+    }
+}
+
+impl<I: Iterator + ?Sized> Iterator for &mut I {
+    type Item = I::Item;
+
+    #[inline]
+    fn next(&mut self) -> Option<I::Item> {
+        (**self).next()
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (**self).size_hint()
+    }
+}
@@ -0,0 +1,7 @@
+mod accum;
+mod collect;
+mod iterator;
+
+pub use accum::{Product, Sum};
+pub use collect::{FromIterator, IntoIterator};
+pub use iterator::Iterator;
@@ -22,6 +22,8 @@
 #![feature(prelude_import)]
 // to allow to use macro as `macro macro_name { ... }`
 #![feature(decl_macro)]
+// to allow to declare the `Fn*` traits with the "rust-call" ABI
+#![feature(unboxed_closures)]
 
 // temporary features (only used during the construction of the core)
 #![feature(core_panicking_macro)]
@@ -37,8 +39,19 @@ use prelude::rust_2021::*;
 /* The core prelude, not as all-encompassing as the std prelude */
 pub mod prelude;
 
+// pub mod any;
+// pub mod array;
+// pub mod borrow;
+// pub mod char;
 // pub mod clone;
+// pub mod cmp;
+// pub mod convert;
 // pub mod default;
+// pub mod error;
+// pub mod fmt;
+// pub mod future;
+// pub mod hash;
+// pub mod iter;
 pub mod panic;
 pub mod intrinsics;
 #[macro_use]
@@ -47,5 +60,13 @@ mod macros;
 // pub mod mem;
 // pub mod ops;
 // pub mod option;
+// pub mod panicking;
+// pub mod pin;
 // pub mod ptr;
 // pub mod result;
+// pub mod slice;
+// pub mod str;
+// pub mod task;
+// pub mod time;
+// mod tuple;
+// mod unicode;
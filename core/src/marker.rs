@@ -1,11 +1,29 @@
 use super::clone::Clone;
+use crate::any::type_name;
+use crate::cmp::{Eq, Ord, Ordering, PartialEq, PartialOrd};
+use crate::default::Default;
+use crate::fmt::{self, Debug, Formatter};
+use crate::hash::{Hash, Hasher};
+use crate::option::Option::{self, Some};
 
-// #[lang = "send"]
+// `send` is not a lang item of the pinned rustc anymore (E0522)
+#[rustc_diagnostic_item = "Send"]
 pub unsafe auto trait Send {}
 
+impl<T: ?Sized> !Send for *const T {}
+impl<T: ?Sized> !Send for *mut T {}
+
+// Most instances arise automatically, but a shared reference can only be
+// sent to another thread if its pointee can be shared between threads.
+unsafe impl<T: Sync + ?Sized> Send for &T {}
+unsafe impl<T: Send + ?Sized> Send for &mut T {}
+
 #[lang = "sync"]
 pub unsafe auto trait Sync {}
 
+impl<T: ?Sized> !Sync for *const T {}
+impl<T: ?Sized> !Sync for *mut T {}
+
 #[rustc_diagnostic_item = "Unpin"]
 #[lang = "unpin"]
 pub auto trait Unpin {}
@@ -13,11 +31,61 @@ pub auto trait Unpin {}
 #[lang = "sized"]
 pub trait Sized {}
 
+/// Types that can be "unsized" to a dynamically sized type.
+///
+/// For example, the sized array type `[i8; 2]` implements `Unsize<[i8]>` and
+/// `Unsize<dyn fmt::Debug>`. All implementations of `Unsize` are provided
+/// automatically by the compiler.
+#[lang = "unsize"]
+#[rustc_deny_explicit_impl]
+#[rustc_do_not_implement_via_object]
+pub trait Unsize<T: ?Sized> {
+    // Empty.
+}
+
+/// Required trait for constants used in pattern matches.
+///
+/// Any type that derives `PartialEq` automatically implements this trait,
+/// regardless of whether its type-parameters implement `PartialEq`. It tells
+/// the compiler that the equality of the type is the structural one.
+#[lang = "structural_peq"]
+#[diagnostic::on_unimplemented(message = "the type `{Self}` does not `#[derive(PartialEq)]`")]
+pub trait StructuralPartialEq {
+    // Empty.
+}
+
 #[rustc_unsafe_specialization_marker]
 #[rustc_diagnostic_item = "Copy"]
 #[lang = "copy"]
 pub trait Copy: Clone {}
 
+/// Derive macro generating an impl of the trait `Copy`.
+#[rustc_builtin_macro]
+#[allow_internal_unstable(core_intrinsics, derive_clone_copy)]
+pub macro Copy($item:item) {
+    // compiler built-in
+}
+
+// Tuples, closures and function pointers are `Copy` through builtin impls of
+// the compiler (when all their components are `Copy`), and arrays implement
+// it in `crate::array`.
+macro_rules! impl_copy {
+    ($($t:ty),*) => {$(
+        impl Copy for $t {}
+    )*};
+}
+
+impl_copy! {
+    usize, u8, u16, u32, u64, u128,
+    isize, i8, i16, i32, i64, i128,
+    f32, f64,
+    bool, char, !
+}
+
+impl<T: ?Sized> Copy for *const T {}
+impl<T: ?Sized> Copy for *mut T {}
+impl<T: ?Sized> Copy for &T {}
+
 #[lang = "phantom_data"]
 pub struct PhantomData<T: ?Sized>;
 
@@ -29,4 +97,156 @@ impl<T: ?Sized> Clone for PhantomData<T> {
 
 impl<T: ?Sized> Copy for PhantomData<T> {}
 
+impl<T: ?Sized> Default for PhantomData<T> {
+    fn default() -> Self {
+        Self
+    }
+}
+
+impl<T: ?Sized> PartialEq for PhantomData<T> {
+    fn eq(&self, _other: &PhantomData<T>) -> bool {
+        true
+    }
+}
+
+impl<T: ?Sized> Eq for PhantomData<T> {}
+
+impl<T: ?Sized> PartialOrd for PhantomData<T> {
+    fn partial_cmp(&self, _other: &PhantomData<T>) -> Option<Ordering> {
+        Some(Ordering::Equal)
+    }
+}
+
+impl<T: ?Sized> Ord for PhantomData<T> {
+    fn cmp(&self, _other: &PhantomData<T>) -> Ordering {
+        Ordering::Equal
+    }
+}
+
+impl<T: ?Sized> Hash for PhantomData<T> {
+    #[inline]
+    fn hash<H: Hasher>(&self, _: &mut H) {}
+}
+
+impl<T: ?Sized> Debug for PhantomData<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_str("PhantomData<")?;
+        f.write_str(type_name::<T>())?;
+        f.write_str(">")
+    }
+}
+
+impl<T: ?Sized> StructuralPartialEq for PhantomData<T> {}
+
+/// A marker type which does not implement `Unpin`.
+///
+/// If a type contains a `PhantomPinned`, it will not implement `Unpin` by
+/// default, so its values cannot be moved once pinned.
 pub struct PhantomPinned;
+
+impl !Unpin for PhantomPinned {}
+
+impl Default for PhantomPinned {
+    fn default() -> Self {
+        Self
+    }
+}
+
+/// Compiler-internal trait used to determine whether a type contains any
+/// `UnsafeCell` internally, but not through an indirection.
+///
+/// This affects, for example, whether a `static` of that type is placed in
+/// read-only static memory or writable static memory.
+#[lang = "freeze"]
+pub unsafe auto trait Freeze {}
+
+// TODO: add `impl<T: ?Sized> !Freeze for UnsafeCell<T> {}` once `core::cell`
+// exists, every type is `Freeze` until then.
+unsafe impl<T: ?Sized> Freeze for PhantomData<T> {}
+unsafe impl<T: ?Sized> Freeze for *const T {}
+unsafe impl<T: ?Sized> Freeze for *mut T {}
+unsafe impl<T: ?Sized> Freeze for &T {}
+unsafe impl<T: ?Sized> Freeze for &mut T {}
+
+/// A marker for tuple types.
+///
+/// The implementation of this trait is built-in and cannot be implemented
+/// for any user type.
+#[lang = "tuple_trait"]
+#[diagnostic::on_unimplemented(message = "`{Self}` is not a tuple")]
+#[rustc_deny_explicit_impl]
+#[rustc_do_not_implement_via_object]
+pub trait Tuple {}
+
+/// A marker for pointer-like types.
+///
+/// This trait can only be implemented for types that are certain to have the
+/// same size and alignment as a `usize` or `*const ()`.
+#[lang = "pointer_like"]
+#[diagnostic::on_unimplemented(
+    message = "`{Self}` needs to have the same ABI as a pointer",
+    label = "`{Self}` needs to be a pointer-like type"
+)]
+#[rustc_do_not_implement_via_object]
+pub trait PointerLike {}
+
+impl PointerLike for isize {}
+impl PointerLike for usize {}
+impl<T: ?Sized> PointerLike for &T {}
+impl<T: ?Sized> PointerLike for &mut T {}
+impl<T: ?Sized> PointerLike for *const T {}
+impl<T: ?Sized> PointerLike for *mut T {}
+impl<T: PointerLike> PointerLike for crate::pin::Pin<T> {}
+
+/// A marker for types which can be used as types of `const` generic
+/// parameters.
+///
+/// These types must have a proper equivalence relation (`Eq`) and it must be
+/// automatically derived (`StructuralPartialEq`).
+#[lang = "const_param_ty"]
+#[diagnostic::on_unimplemented(message = "`{Self}` can't be used as a const parameter type")]
+pub trait ConstParamTy: UnsizedConstParamTy + StructuralPartialEq + Eq {}
+
+/// Like [`ConstParamTy`], but also allows the unsized types `str` and `[T]`
+/// behind references in `const` generic parameters.
+#[lang = "unsized_const_param_ty"]
+#[diagnostic::on_unimplemented(message = "`{Self}` can't be used as a const parameter type")]
+pub trait UnsizedConstParamTy: StructuralPartialEq + Eq {}
+
+macro_rules! const_param_ty_impls {
+    ($($t:ty),*) => {$(
+        impl StructuralPartialEq for $t {}
+        impl UnsizedConstParamTy for $t {}
+        impl ConstParamTy for $t {}
+    )*};
+}
+
+const_param_ty_impls! {
+    usize, u8, u16, u32, u64, u128,
+    isize, i8, i16, i32, i64, i128,
+    bool, char, ()
+}
+
+// TODO: implement the const parameter traits for slices and `str` once they
+// implement `Eq`.
+impl<T: StructuralPartialEq, const N: usize> StructuralPartialEq for [T; N] {}
+impl<T: UnsizedConstParamTy, const N: usize> UnsizedConstParamTy for [T; N] {}
+impl<T: ConstParamTy, const N: usize> ConstParamTy for [T; N] {}
+impl<T: StructuralPartialEq> StructuralPartialEq for [T] {}
+impl StructuralPartialEq for str {}
+
+impl<T: UnsizedConstParamTy + ?Sized> UnsizedConstParamTy for &T {}
+impl<T: StructuralPartialEq + ?Sized> StructuralPartialEq for &T {}
+
+/// A common trait implemented by all function pointers.
+///
+/// The implementation of this trait is built-in and cannot be implemented
+/// for any user type.
+#[lang = "fn_ptr_trait"]
+#[rustc_deny_explicit_impl]
+#[rustc_do_not_implement_via_object]
+pub trait FnPtr: Copy + Clone {
+    /// Returns the address of the function pointer.
+    #[lang = "fn_ptr_addr"]
+    fn addr(self) -> *const ();
+}
@@ -1,4 +1,5 @@
 use crate::core::clone::Clone;
+use crate::core::default::Default;
 use crate::core::marker::Copy;
 use crate::core::ops::{Deref, DerefMut};
 use crate::core::ptr::{drop_in_place, read};
@@ -51,3 +52,9 @@ impl<T: Clone> Clone for ManuallyDrop<T> {
 }
 
 impl<T: Copy> Copy for ManuallyDrop<T> {}
+
+impl<T: Default> Default for ManuallyDrop<T> {
+    fn default() -> Self {
+        ManuallyDrop::new(Default::default())
+    }
+}
@@ -83,6 +83,12 @@ impl<T, const N: usize> MaybeUninit<[T; N]> {
     }
 }
 
+impl<T, const N: usize> [MaybeUninit<T>; N] {
+    pub const fn transpose(self) -> MaybeUninit<[T; N]> {
+        unsafe { transmute_unchecked(self) }
+    }
+}
+
 impl<T: Copy> Clone for MaybeUninit<T> {
     fn clone(&self) -> Self {
         *self
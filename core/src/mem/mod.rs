@@ -1,4 +1,47 @@
+pub use manually_drop::ManuallyDrop;
 pub use maybe_uninit::MaybeUninit;
 
+use crate::default::Default;
+use crate::ptr;
+
 mod manually_drop;
 mod maybe_uninit;
+
+/// Takes ownership and "forgets" about the value **without running its
+/// destructor**.
+#[inline]
+pub const fn forget<T>(value: T) {
+    let _ = ManuallyDrop::new(value);
+}
+
+/// Swaps the values at two mutable locations, without deinitializing either
+/// one.
+#[inline]
+pub const fn swap<T>(x: &mut T, y: &mut T) {
+    // SAFETY: `&mut` guarantees these are typed readable and writable as well
+    // as non-overlapping.
+    unsafe { ptr::swap(x, y) }
+}
+
+/// Replaces `dest` with the default value of `T`, returning the previous
+/// `dest` value.
+#[inline]
+pub fn take<T: Default>(dest: &mut T) -> T {
+    replace(dest, T::default())
+}
+
+/// Moves `src` into the referenced `dest`, returning the previous `dest`
+/// value.
+///
+/// Neither value is dropped.
+#[inline]
+pub const fn replace<T>(dest: &mut T, src: T) -> T {
+    // SAFETY: We read from `dest` but directly write `src` into it afterwards,
+    // such that the old value is not duplicated. Nothing is dropped and
+    // nothing here can panic.
+    unsafe {
+        let result = ptr::read(dest);
+        ptr::write(dest, src);
+        result
+    }
+}
@@ -0,0 +1,140 @@
+use crate::future::Future;
+
+/// An async-aware version of the [`Fn`](crate::ops::Fn) trait.
+///
+/// All `async fn` and functions returning futures implement this trait.
+#[rustc_paren_sugar]
+#[fundamental]
+#[must_use = "async closures are lazy and do nothing unless called"]
+#[lang = "async_fn"]
+pub trait AsyncFn<Args>: AsyncFnMut<Args> {
+    /// Call the [`AsyncFn`], returning a future which may borrow from the
+    /// called closure.
+    fn async_call(&self, args: Args) -> Self::CallRefFuture<'_>;
+}
+
+/// An async-aware version of the [`FnMut`](crate::ops::FnMut) trait.
+///
+/// All `async fn` and functions returning futures implement this trait.
+#[rustc_paren_sugar]
+#[fundamental]
+#[must_use = "async closures are lazy and do nothing unless called"]
+#[lang = "async_fn_mut"]
+pub trait AsyncFnMut<Args>: AsyncFnOnce<Args> {
+    /// Future returned by [`AsyncFnMut::async_call_mut`] and
+    /// [`AsyncFn::async_call`].
+    #[lang = "call_ref_future"]
+    type CallRefFuture<'a>: Future<Output = Self::Output>
+    where
+        Self: 'a;
+
+    /// Call the [`AsyncFnMut`], returning a future which may borrow from the
+    /// called closure.
+    fn async_call_mut(&mut self, args: Args) -> Self::CallRefFuture<'_>;
+}
+
+/// An async-aware version of the [`FnOnce`](crate::ops::FnOnce) trait.
+///
+/// All `async fn` and functions returning futures implement this trait.
+#[rustc_paren_sugar]
+#[fundamental]
+#[must_use = "async closures are lazy and do nothing unless called"]
+#[lang = "async_fn_once"]
+pub trait AsyncFnOnce<Args> {
+    /// Future returned by [`AsyncFnOnce::async_call_once`].
+    #[lang = "call_once_future"]
+    type CallOnceFuture: Future<Output = Self::Output>;
+
+    /// Output type of the called closure's future.
+    #[lang = "async_fn_once_output"]
+    type Output;
+
+    /// Call the [`AsyncFnOnce`], returning a future which may move out of the
+    /// called closure.
+    fn async_call_once(self, args: Args) -> Self::CallOnceFuture;
+}
+
+mod impls {
+    use super::{AsyncFn, AsyncFnMut, AsyncFnOnce};
+    use crate::marker::Sized;
+
+    impl<A, F: ?Sized> AsyncFn<A> for &F
+    where
+        F: AsyncFn<A>,
+    {
+        fn async_call(&self, args: A) -> Self::CallRefFuture<'_> {
+            F::async_call(*self, args)
+        }
+    }
+
+    impl<A, F: ?Sized> AsyncFnMut<A> for &F
+    where
+        F: AsyncFn<A>,
+    {
+        type CallRefFuture<'a>
+            = F::CallRefFuture<'a>
+        where
+            Self: 'a;
+
+        fn async_call_mut(&mut self, args: A) -> Self::CallRefFuture<'_> {
+            F::async_call(*self, args)
+        }
+    }
+
+    impl<'a, A, F: ?Sized> AsyncFnOnce<A> for &'a F
+    where
+        F: AsyncFn<A>,
+    {
+        type CallOnceFuture = F::CallRefFuture<'a>;
+        type Output = F::Output;
+
+        fn async_call_once(self, args: A) -> Self::CallOnceFuture {
+            F::async_call(self, args)
+        }
+    }
+
+    impl<A, F: ?Sized> AsyncFnMut<A> for &mut F
+    where
+        F: AsyncFnMut<A>,
+    {
+        type CallRefFuture<'a>
+            = F::CallRefFuture<'a>
+        where
+            Self: 'a;
+
+        fn async_call_mut(&mut self, args: A) -> Self::CallRefFuture<'_> {
+            F::async_call_mut(*self, args)
+        }
+    }
+
+    impl<'a, A, F: ?Sized> AsyncFnOnce<A> for &'a mut F
+    where
+        F: AsyncFnMut<A>,
+    {
+        type CallOnceFuture = F::CallRefFuture<'a>;
+        type Output = F::Output;
+
+        fn async_call_once(self, args: A) -> Self::CallOnceFuture {
+            F::async_call_mut(self, args)
+        }
+    }
+}
+
+mod internal_implementation_detail {
+    /// A helper trait that is used to enforce that the `ClosureKind` of a
+    /// goal is within the capabilities of a `CoroutineClosure`, and which
+    /// allows the compiler to delay the projection of the tupled upvar types
+    /// until after upvar analysis is complete.
+    ///
+    /// The `Self` type is expected to be the `kind_ty` of the
+    /// coroutine-closure, and the `GoalKind` is the kind of the trait that the
+    /// closure is being called with.
+    #[lang = "async_fn_kind_helper"]
+    trait AsyncFnKindHelper<GoalKind> {
+        // Projects a set of closure inputs (arguments), a region, and a set of
+        // upvars (by move and by ref) to the upvars that the coroutine is
+        // expected to have according to the `GoalKind` parameter above.
+        #[lang = "async_fn_kind_upvars"]
+        type Upvars<'closure_env, Inputs, Upvars, BorrowedUpvarsAsFnPtr>;
+    }
+}
@@ -0,0 +1,142 @@
+use crate::clone::Clone;
+use crate::convert::Infallible;
+use crate::marker::Copy;
+use crate::ops::{FnOnce, FromResidual, Residual, Try};
+use crate::option::Option::{self, None, Some};
+
+/// Used to tell an operation whether it should exit early or go on as usual.
+///
+/// This is the type the `?` operator works with under the hood: every [`Try`]
+/// type is split by [`Try::branch`] into a `ControlFlow`, where `Continue`
+/// carries the value the expression evaluates to and `Break` carries the
+/// residual that is propagated to the caller.
+#[rustc_diagnostic_item = "ControlFlow"]
+pub enum ControlFlow<B, C = ()> {
+    /// Move on to the next phase of the operation as normal.
+    #[lang = "Continue"]
+    Continue(C),
+    /// Exit the operation without running subsequent phases.
+    #[lang = "Break"]
+    Break(B),
+    // The order of the variants doesn't match the type parameters on purpose,
+    // so that `ControlFlow<A, B>` <-> `Result<B, A>` is a no-op conversion in
+    // the `Try` implementation.
+}
+
+impl<B, C> ControlFlow<B, C> {
+    #[inline]
+    pub fn is_break(&self) -> bool {
+        match self {
+            ControlFlow::Break(_) => true,
+            ControlFlow::Continue(_) => false,
+        }
+    }
+
+    #[inline]
+    pub fn is_continue(&self) -> bool {
+        !self.is_break()
+    }
+
+    #[inline]
+    pub fn break_value(self) -> Option<B> {
+        match self {
+            ControlFlow::Continue(_) => None,
+            ControlFlow::Break(value) => Some(value),
+        }
+    }
+
+    #[inline]
+    pub fn map_break<T, F>(self, f: F) -> ControlFlow<T, C>
+    where
+        F: FnOnce(B) -> T,
+    {
+        match self {
+            ControlFlow::Continue(value) => ControlFlow::Continue(value),
+            ControlFlow::Break(value) => ControlFlow::Break(f(value)),
+        }
+    }
+
+    #[inline]
+    pub fn continue_value(self) -> Option<C> {
+        match self {
+            ControlFlow::Continue(value) => Some(value),
+            ControlFlow::Break(_) => None,
+        }
+    }
+
+    #[inline]
+    pub fn map_continue<T, F>(self, f: F) -> ControlFlow<B, T>
+    where
+        F: FnOnce(C) -> T,
+    {
+        match self {
+            ControlFlow::Continue(value) => ControlFlow::Continue(f(value)),
+            ControlFlow::Break(value) => ControlFlow::Break(value),
+        }
+    }
+}
+
+impl<R: Try> ControlFlow<R, R::Output> {
+    /// Create a `ControlFlow` from any type implementing [`Try`].
+    #[inline]
+    pub(crate) fn from_try(r: R) -> Self {
+        match R::branch(r) {
+            ControlFlow::Continue(value) => ControlFlow::Continue(value),
+            ControlFlow::Break(residual) => ControlFlow::Break(R::from_residual(residual)),
+        }
+    }
+
+    /// Convert a `ControlFlow` into any type implementing [`Try`].
+    #[inline]
+    pub(crate) fn into_try(self) -> R {
+        match self {
+            ControlFlow::Continue(value) => R::from_output(value),
+            ControlFlow::Break(value) => value,
+        }
+    }
+}
+
+impl<B, C> Try for ControlFlow<B, C> {
+    type Output = C;
+    type Residual = ControlFlow<B, Infallible>;
+
+    #[inline]
+    fn from_output(output: Self::Output) -> Self {
+        ControlFlow::Continue(output)
+    }
+
+    #[inline]
+    fn branch(self) -> ControlFlow<Self::Residual, Self::Output> {
+        match self {
+            ControlFlow::Continue(value) => ControlFlow::Continue(value),
+            ControlFlow::Break(value) => ControlFlow::Break(ControlFlow::Break(value)),
+        }
+    }
+}
+
+// The residual type is specified manually instead of using the default to
+// work around https://github.com/rust-lang/rust/issues/99940
+impl<B, C> FromResidual<ControlFlow<B, Infallible>> for ControlFlow<B, C> {
+    #[inline]
+    fn from_residual(residual: ControlFlow<B, Infallible>) -> Self {
+        match residual {
+            ControlFlow::Break(value) => ControlFlow::Break(value),
+        }
+    }
+}
+
+impl<B, C> Residual<C> for ControlFlow<B, Infallible> {
+    type TryType = ControlFlow<B, C>;
+}
+
+impl<B: Clone, C: Clone> Clone for ControlFlow<B, C> {
+    #[inline]
+    fn clone(&self) -> Self {
+        match self {
+            ControlFlow::Continue(value) => ControlFlow::Continue(value.clone()),
+            ControlFlow::Break(value) => ControlFlow::Break(value.clone()),
+        }
+    }
+}
+
+impl<B: Copy, C: Copy> Copy for ControlFlow<B, C> {}
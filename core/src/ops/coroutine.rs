@@ -0,0 +1,183 @@
+use crate::clone::Clone;
+use crate::cmp::{Eq, Ord, Ordering, PartialEq, PartialOrd};
+use crate::fmt::{self, Debug, Formatter};
+use crate::hash::{Hash, Hasher};
+use crate::marker::{Copy, Sized, Unpin};
+use crate::option::Option::{self, Some};
+use crate::pin::Pin;
+
+/// The result of a coroutine resumption.
+///
+/// This enum is returned from the `Coroutine::resume` method and indicates
+/// the possible return values of a coroutine. Currently this corresponds to
+/// either a suspension point (`Yielded`) or a termination point (`Complete`).
+#[lang = "coroutine_state"]
+pub enum CoroutineState<Y, R> {
+    /// The coroutine suspended with a value.
+    ///
+    /// This state indicates that a coroutine has been suspended, and
+    /// typically corresponds to a `yield` statement. The value provided in
+    /// this variant corresponds to the expression passed to `yield` and
+    /// allows coroutines to provide a value each time they yield.
+    Yielded(Y),
+
+    /// The coroutine completed with a return value.
+    ///
+    /// This state indicates that a coroutine has finished execution with the
+    /// provided value. Once a coroutine has returned `Complete` it is
+    /// considered a programmer error to call `resume` again.
+    Complete(R),
+}
+
+impl<Y: Clone, R: Clone> Clone for CoroutineState<Y, R> {
+    #[inline]
+    fn clone(&self) -> Self {
+        match self {
+            CoroutineState::Yielded(y) => CoroutineState::Yielded(y.clone()),
+            CoroutineState::Complete(r) => CoroutineState::Complete(r.clone()),
+        }
+    }
+}
+
+impl<Y: Copy, R: Copy> Copy for CoroutineState<Y, R> {}
+
+impl<Y: PartialEq, R: PartialEq> PartialEq for CoroutineState<Y, R> {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (CoroutineState::Yielded(a), CoroutineState::Yielded(b)) => *a == *b,
+            (CoroutineState::Complete(a), CoroutineState::Complete(b)) => *a == *b,
+            _ => false,
+        }
+    }
+}
+
+impl<Y: Eq, R: Eq> Eq for CoroutineState<Y, R> {}
+
+// `Yielded` is always less than `Complete`, like a derive would do with the
+// variants declared in this order.
+impl<Y: PartialOrd, R: PartialOrd> PartialOrd for CoroutineState<Y, R> {
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        match (self, other) {
+            (CoroutineState::Yielded(a), CoroutineState::Yielded(b)) => a.partial_cmp(b),
+            (CoroutineState::Complete(a), CoroutineState::Complete(b)) => a.partial_cmp(b),
+            (CoroutineState::Yielded(_), CoroutineState::Complete(_)) => Some(Ordering::Less),
+            (CoroutineState::Complete(_), CoroutineState::Yielded(_)) => Some(Ordering::Greater),
+        }
+    }
+}
+
+impl<Y: Ord, R: Ord> Ord for CoroutineState<Y, R> {
+    #[inline]
+    fn cmp(&self, other: &Self) -> Ordering {
+        match (self, other) {
+            (CoroutineState::Yielded(a), CoroutineState::Yielded(b)) => a.cmp(b),
+            (CoroutineState::Complete(a), CoroutineState::Complete(b)) => a.cmp(b),
+            (CoroutineState::Yielded(_), CoroutineState::Complete(_)) => Ordering::Less,
+            (CoroutineState::Complete(_), CoroutineState::Yielded(_)) => Ordering::Greater,
+        }
+    }
+}
+
+impl<Y: Hash, R: Hash> Hash for CoroutineState<Y, R> {
+    #[inline]
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        match self {
+            CoroutineState::Yielded(y) => {
+                state.write_isize(0);
+                y.hash(state);
+            }
+            CoroutineState::Complete(r) => {
+                state.write_isize(1);
+                r.hash(state);
+            }
+        }
+    }
+}
+
+impl<Y: Debug, R: Debug> Debug for CoroutineState<Y, R> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            CoroutineState::Yielded(y) => f.debug_tuple("Yielded").field(y).finish(),
+            CoroutineState::Complete(r) => f.debug_tuple("Complete").field(r).finish(),
+        }
+    }
+}
+
+/// The trait implemented by builtin coroutine types.
+///
+/// Coroutines are currently an experimental language feature in Rust.
+/// Coroutines are defined with a closure-like syntax, marked with
+/// `#[coroutine]`, and `yield` statements:
+///
+/// ```ignore
+/// let mut coroutine = #[coroutine] || {
+///     yield 1;
+///     "foo"
+/// };
+///
+/// match Pin::new(&mut coroutine).resume(()) {
+///     CoroutineState::Yielded(1) => {}
+///     _ => panic!("unexpected return from resume"),
+/// }
+/// match Pin::new(&mut coroutine).resume(()) {
+///     CoroutineState::Complete("foo") => {}
+///     _ => panic!("unexpected return from resume"),
+/// }
+/// ```
+///
+/// The `R` type parameter is the type of the value passed to `resume`, it is
+/// what the `yield` expressions evaluate to inside the coroutine.
+#[lang = "coroutine"]
+#[fundamental]
+#[must_use = "coroutines are lazy and do nothing unless resumed"]
+pub trait Coroutine<R = ()> {
+    /// The type of value this coroutine yields.
+    ///
+    /// This associated type corresponds to the `yield` expression and the
+    /// values which are allowed to be returned each time a coroutine yields.
+    #[lang = "coroutine_yield"]
+    type Yield;
+
+    /// The type of value this coroutine returns.
+    ///
+    /// This corresponds to the type returned from a coroutine either with a
+    /// `return` statement or implicitly as the last expression of a coroutine
+    /// literal.
+    #[lang = "coroutine_return"]
+    type Return;
+
+    /// Resumes the execution of this coroutine.
+    ///
+    /// This function will resume execution of the coroutine or start
+    /// execution if it hasn't already. This call will return back into the
+    /// coroutine's last suspension point, resuming execution from the latest
+    /// `yield`. The coroutine will continue executing until it either yields
+    /// or returns, at which point this function will return.
+    ///
+    /// # Panics
+    ///
+    /// This function may panic if it is called after the `Complete` variant
+    /// has been returned previously.
+    #[lang = "coroutine_resume"]
+    fn resume(self: Pin<&mut Self>, arg: R) -> CoroutineState<Self::Yield, Self::Return>;
+}
+
+impl<G: ?Sized + Coroutine<R>, R> Coroutine<R> for Pin<&mut G> {
+    type Return = G::Return;
+    type Yield = G::Yield;
+
+    fn resume(mut self: Pin<&mut Self>, arg: R) -> CoroutineState<Self::Yield, Self::Return> {
+        G::resume((*self).as_mut(), arg)
+    }
+}
+
+impl<G: ?Sized + Coroutine<R> + Unpin, R> Coroutine<R> for &mut G {
+    type Return = G::Return;
+    type Yield = G::Yield;
+
+    fn resume(mut self: Pin<&mut Self>, arg: R) -> CoroutineState<Self::Yield, Self::Return> {
+        G::resume(Pin::new(&mut *self), arg)
+    }
+}
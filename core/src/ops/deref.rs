@@ -63,3 +63,13 @@ impl<T: ?Sized> DerefMut for &mut T {
 #[lang = "receiver"]
 #[doc(hidden)]
 pub trait Receiver {}
+
+/// Indicates that a struct can be used as a method receiver without the
+/// `arbitrary_self_types` feature, like `self: Pin<&mut Self>`.
+#[lang = "legacy_receiver"]
+#[doc(hidden)]
+pub trait LegacyReceiver {}
+
+impl<T: ?Sized> LegacyReceiver for &T {}
+
+impl<T: ?Sized> LegacyReceiver for &mut T {}
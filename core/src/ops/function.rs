@@ -1,13 +1,13 @@
 #[rustc_paren_sugar]
 #[lang = "fn_mut"]
-pub trait FnMut<Args>: Fn<Args> {
-    fn call_mut(&mut self, args: Args) -> Self::Output;
+pub trait FnMut<Args>: FnOnce<Args> {
+    extern "rust-call" fn call_mut(&mut self, args: Args) -> Self::Output;
 }
 
 #[rustc_paren_sugar]
 #[lang = "fn"]
-pub trait Fn<Args>: FnOnce<Args> {
-    fn call(&self, args: Args) -> Self::Output;
+pub trait Fn<Args>: FnMut<Args> {
+    extern "rust-call" fn call(&self, args: Args) -> Self::Output;
 }
 
 #[rustc_paren_sugar]
@@ -15,5 +15,5 @@ pub trait Fn<Args>: FnOnce<Args> {
 pub trait FnOnce<Args> {
     type Output;
 
-    fn call_once(self, args: Args) -> Self::Output;
+    extern "rust-call" fn call_once(self, args: Args) -> Self::Output;
 }
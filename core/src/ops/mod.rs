@@ -1,13 +1,25 @@
 mod arith;
+mod async_function;
 mod bit;
+mod control_flow;
+mod coroutine;
 mod deref;
 mod drop;
 mod function;
 mod index;
+mod range;
+mod try_trait;
+mod unsize;
 
 pub use arith::*;
+pub use async_function::*;
 pub use bit::*;
+pub use control_flow::*;
+pub use coroutine::*;
 pub use deref::*;
 pub use drop::*;
 pub use function::*;
 pub use index::*;
+pub use range::*;
+pub use try_trait::*;
+pub use unsize::*;
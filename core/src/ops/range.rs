@@ -0,0 +1,445 @@
+use crate::clone::Clone;
+use crate::cmp::{Eq, PartialEq, PartialOrd};
+use crate::default::Default;
+use crate::fmt::{self, Debug, Formatter};
+use crate::hash::{Hash, Hasher};
+use crate::marker::{Copy, Sized};
+use crate::result::Result::Ok;
+
+// TODO: the `Bound` enum, the `RangeBounds` trait and the `Iterator` impls of
+// the ranges over integers.
+
+/// An unbounded range (`..`).
+///
+/// `RangeFull` is primarily used as a [slicing index], its shorthand is `..`.
+/// It cannot serve as an [`Iterator`] because it doesn't have a starting point.
+///
+/// [slicing index]: crate::slice::SliceIndex
+/// [`Iterator`]: crate::iter::Iterator
+#[lang = "RangeFull"]
+#[doc(alias = "..")]
+pub struct RangeFull;
+
+impl Clone for RangeFull {
+    #[inline]
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl Copy for RangeFull {}
+
+impl Default for RangeFull {
+    #[inline]
+    fn default() -> Self {
+        RangeFull
+    }
+}
+
+impl PartialEq for RangeFull {
+    #[inline]
+    fn eq(&self, _: &RangeFull) -> bool {
+        true
+    }
+}
+
+impl Eq for RangeFull {}
+
+impl Hash for RangeFull {
+    #[inline]
+    fn hash<H: Hasher>(&self, _: &mut H) {}
+}
+
+impl Debug for RangeFull {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_str("..")
+    }
+}
+
+/// A (half-open) range bounded inclusively below and exclusively above
+/// (`start..end`).
+///
+/// The range `start..end` contains all values with `start <= x < end`.
+/// It is empty if `start >= end`.
+#[lang = "Range"]
+#[doc(alias = "..")]
+pub struct Range<Idx> {
+    /// The lower bound of the range (inclusive).
+    pub start: Idx,
+    /// The upper bound of the range (exclusive).
+    pub end: Idx,
+}
+
+// not Copy, see rust-lang/rust#27186
+impl<Idx: Clone> Clone for Range<Idx> {
+    #[inline]
+    fn clone(&self) -> Self {
+        Range {
+            start: self.start.clone(),
+            end: self.end.clone(),
+        }
+    }
+}
+
+impl<Idx: Default> Default for Range<Idx> {
+    #[inline]
+    fn default() -> Self {
+        Range {
+            start: Idx::default(),
+            end: Idx::default(),
+        }
+    }
+}
+
+impl<Idx: PartialEq> PartialEq for Range<Idx> {
+    #[inline]
+    fn eq(&self, other: &Range<Idx>) -> bool {
+        self.start == other.start && self.end == other.end
+    }
+}
+
+impl<Idx: Eq> Eq for Range<Idx> {}
+
+impl<Idx: Hash> Hash for Range<Idx> {
+    #[inline]
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.start.hash(state);
+        self.end.hash(state);
+    }
+}
+
+impl<Idx: Debug> Debug for Range<Idx> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        self.start.fmt(f)?;
+        f.write_str("..")?;
+        self.end.fmt(f)
+    }
+}
+
+impl<Idx: PartialOrd<Idx>> Range<Idx> {
+    /// Returns `true` if `item` is contained in the range.
+    #[inline]
+    pub fn contains<U>(&self, item: &U) -> bool
+    where
+        Idx: PartialOrd<U>,
+        U: ?Sized + PartialOrd<Idx>,
+    {
+        *item >= self.start && *item < self.end
+    }
+
+    /// Returns `true` if the range contains no items.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        !(self.start < self.end)
+    }
+}
+
+/// A range only bounded inclusively below (`start..`).
+///
+/// The `RangeFrom` `start..` contains all values with `x >= start`.
+#[lang = "RangeFrom"]
+#[doc(alias = "..")]
+pub struct RangeFrom<Idx> {
+    /// The lower bound of the range (inclusive).
+    pub start: Idx,
+}
+
+// not Copy, see rust-lang/rust#27186
+impl<Idx: Clone> Clone for RangeFrom<Idx> {
+    #[inline]
+    fn clone(&self) -> Self {
+        RangeFrom {
+            start: self.start.clone(),
+        }
+    }
+}
+
+impl<Idx: PartialEq> PartialEq for RangeFrom<Idx> {
+    #[inline]
+    fn eq(&self, other: &RangeFrom<Idx>) -> bool {
+        self.start == other.start
+    }
+}
+
+impl<Idx: Eq> Eq for RangeFrom<Idx> {}
+
+impl<Idx: Hash> Hash for RangeFrom<Idx> {
+    #[inline]
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.start.hash(state);
+    }
+}
+
+impl<Idx: Debug> Debug for RangeFrom<Idx> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        self.start.fmt(f)?;
+        f.write_str("..")
+    }
+}
+
+impl<Idx: PartialOrd<Idx>> RangeFrom<Idx> {
+    /// Returns `true` if `item` is contained in the range.
+    #[inline]
+    pub fn contains<U>(&self, item: &U) -> bool
+    where
+        Idx: PartialOrd<U>,
+        U: ?Sized + PartialOrd<Idx>,
+    {
+        *item >= self.start
+    }
+}
+
+/// A range only bounded exclusively above (`..end`).
+///
+/// The `RangeTo` `..end` contains all values with `x < end`.
+/// It cannot serve as an [`Iterator`] because it doesn't have a starting point.
+///
+/// [`Iterator`]: crate::iter::Iterator
+#[lang = "RangeTo"]
+#[doc(alias = "..")]
+pub struct RangeTo<Idx> {
+    /// The upper bound of the range (exclusive).
+    pub end: Idx,
+}
+
+impl<Idx: Clone> Clone for RangeTo<Idx> {
+    #[inline]
+    fn clone(&self) -> Self {
+        RangeTo {
+            end: self.end.clone(),
+        }
+    }
+}
+
+impl<Idx: Copy> Copy for RangeTo<Idx> {}
+
+impl<Idx: PartialEq> PartialEq for RangeTo<Idx> {
+    #[inline]
+    fn eq(&self, other: &RangeTo<Idx>) -> bool {
+        self.end == other.end
+    }
+}
+
+impl<Idx: Eq> Eq for RangeTo<Idx> {}
+
+impl<Idx: Hash> Hash for RangeTo<Idx> {
+    #[inline]
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.end.hash(state);
+    }
+}
+
+impl<Idx: Debug> Debug for RangeTo<Idx> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_str("..")?;
+        self.end.fmt(f)
+    }
+}
+
+impl<Idx: PartialOrd<Idx>> RangeTo<Idx> {
+    /// Returns `true` if `item` is contained in the range.
+    #[inline]
+    pub fn contains<U>(&self, item: &U) -> bool
+    where
+        Idx: PartialOrd<U>,
+        U: ?Sized + PartialOrd<Idx>,
+    {
+        *item < self.end
+    }
+}
+
+/// A range bounded inclusively below and above (`start..=end`).
+///
+/// The `RangeInclusive` `start..=end` contains all values with `x >= start`
+/// and `x <= end`. It is empty unless `start <= end`.
+#[lang = "RangeInclusive"]
+#[doc(alias = "..=")]
+pub struct RangeInclusive<Idx> {
+    // Note that the fields here are not public to allow changing the
+    // representation in the future; in particular, while we could plausibly
+    // expose start/end, modifying them without changing (future/current)
+    // private fields may lead to incorrect behavior, so we don't want to
+    // support that mode.
+    pub(crate) start: Idx,
+    pub(crate) end: Idx,
+
+    // This field is:
+    //  - `false` upon construction
+    //  - `false` when iteration has yielded an element and the iterator is not exhausted
+    //  - `true` when iteration has been used to exhaust the iterator
+    //
+    // This is required to support PartialEq and Hash without a PartialOrd bound or specialization.
+    pub(crate) exhausted: bool,
+}
+
+impl<Idx> RangeInclusive<Idx> {
+    /// Creates a new inclusive range. Equivalent to writing `start..=end`.
+    #[lang = "range_inclusive_new"]
+    #[inline]
+    pub const fn new(start: Idx, end: Idx) -> Self {
+        Self {
+            start,
+            end,
+            exhausted: false,
+        }
+    }
+
+    /// Returns the lower bound of the range (inclusive).
+    #[inline]
+    pub const fn start(&self) -> &Idx {
+        &self.start
+    }
+
+    /// Returns the upper bound of the range (inclusive).
+    #[inline]
+    pub const fn end(&self) -> &Idx {
+        &self.end
+    }
+
+    /// Destructures the `RangeInclusive` into (lower bound, upper (inclusive)
+    /// bound).
+    #[inline]
+    pub fn into_inner(self) -> (Idx, Idx) {
+        (self.start, self.end)
+    }
+}
+
+impl RangeInclusive<usize> {
+    /// Converts to an exclusive `Range` for `SliceIndex` implementations.
+    /// The caller is responsible for dealing with `end == usize::MAX` (`!0`).
+    #[inline]
+    pub(crate) const fn into_slice_range(self) -> Range<usize> {
+        // If we're not exhausted, we want to simply slice `start..end + 1`.
+        // If we are exhausted, then slicing with `end + 1..end + 1` gives us an
+        // empty range that is still subject to bounds-checks for that endpoint.
+        let exclusive_end = self.end + 1;
+        let start = if self.exhausted {
+            exclusive_end
+        } else {
+            self.start
+        };
+        Range {
+            start,
+            end: exclusive_end,
+        }
+    }
+}
+
+// not Copy, see rust-lang/rust#27186
+impl<Idx: Clone> Clone for RangeInclusive<Idx> {
+    #[inline]
+    fn clone(&self) -> Self {
+        RangeInclusive {
+            start: self.start.clone(),
+            end: self.end.clone(),
+            exhausted: self.exhausted,
+        }
+    }
+}
+
+impl<Idx: PartialEq> PartialEq for RangeInclusive<Idx> {
+    #[inline]
+    fn eq(&self, other: &RangeInclusive<Idx>) -> bool {
+        self.start == other.start && self.end == other.end && self.exhausted == other.exhausted
+    }
+}
+
+impl<Idx: Eq> Eq for RangeInclusive<Idx> {}
+
+impl<Idx: Hash> Hash for RangeInclusive<Idx> {
+    #[inline]
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.start.hash(state);
+        self.end.hash(state);
+        self.exhausted.hash(state);
+    }
+}
+
+impl<Idx: Debug> Debug for RangeInclusive<Idx> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        self.start.fmt(f)?;
+        f.write_str("..=")?;
+        self.end.fmt(f)?;
+        if self.exhausted {
+            f.write_str(" (exhausted)")?;
+        }
+        Ok(())
+    }
+}
+
+impl<Idx: PartialOrd<Idx>> RangeInclusive<Idx> {
+    /// Returns `true` if `item` is contained in the range.
+    #[inline]
+    pub fn contains<U>(&self, item: &U) -> bool
+    where
+        Idx: PartialOrd<U>,
+        U: ?Sized + PartialOrd<Idx>,
+    {
+        !self.exhausted && *item >= self.start && *item <= self.end
+    }
+
+    /// Returns `true` if the range contains no items.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.exhausted || !(self.start <= self.end)
+    }
+}
+
+/// A range only bounded inclusively above (`..=end`).
+///
+/// The `RangeToInclusive` `..=end` contains all values with `x <= end`.
+/// It cannot serve as an [`Iterator`] because it doesn't have a starting point.
+///
+/// [`Iterator`]: crate::iter::Iterator
+#[lang = "RangeToInclusive"]
+#[doc(alias = "..=")]
+pub struct RangeToInclusive<Idx> {
+    /// The upper bound of the range (inclusive)
+    pub end: Idx,
+}
+
+impl<Idx: Clone> Clone for RangeToInclusive<Idx> {
+    #[inline]
+    fn clone(&self) -> Self {
+        RangeToInclusive {
+            end: self.end.clone(),
+        }
+    }
+}
+
+impl<Idx: Copy> Copy for RangeToInclusive<Idx> {}
+
+impl<Idx: PartialEq> PartialEq for RangeToInclusive<Idx> {
+    #[inline]
+    fn eq(&self, other: &RangeToInclusive<Idx>) -> bool {
+        self.end == other.end
+    }
+}
+
+impl<Idx: Eq> Eq for RangeToInclusive<Idx> {}
+
+impl<Idx: Hash> Hash for RangeToInclusive<Idx> {
+    #[inline]
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.end.hash(state);
+    }
+}
+
+impl<Idx: Debug> Debug for RangeToInclusive<Idx> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_str("..=")?;
+        self.end.fmt(f)
+    }
+}
+
+impl<Idx: PartialOrd<Idx>> RangeToInclusive<Idx> {
+    /// Returns `true` if `item` is contained in the range.
+    #[inline]
+    pub fn contains<U>(&self, item: &U) -> bool
+    where
+        Idx: PartialOrd<U>,
+        U: ?Sized + PartialOrd<Idx>,
+    {
+        *item <= self.end
+    }
+}
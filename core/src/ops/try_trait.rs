@@ -0,0 +1,117 @@
+use crate::ops::ControlFlow;
+
+/// The `?` operator and `try {}` blocks.
+///
+/// `try_*` methods typically involve a type implementing this trait. For
+/// example, the closures passed to `Iterator::try_fold` and
+/// `Iterator::try_for_each` must return such a type.
+///
+/// The `?` operator is desugared by the compiler into a call to
+/// [`Try::branch`]: if it returns [`ControlFlow::Continue`], the expression
+/// evaluates to the contained value, otherwise the residual held by
+/// [`ControlFlow::Break`] is converted with [`FromResidual::from_residual`]
+/// into the return type of the enclosing function and returned early:
+///
+/// ```ignore
+/// match Try::branch(expr) {
+///     ControlFlow::Continue(value) => value,
+///     ControlFlow::Break(residual) => return FromResidual::from_residual(residual),
+/// }
+/// ```
+#[doc(alias = "?")]
+#[lang = "Try"]
+pub trait Try: FromResidual {
+    /// The type of the value produced by `?` when *not* short-circuiting.
+    type Output;
+
+    /// The type of the value passed to [`FromResidual::from_residual`] as part
+    /// of `?` when short-circuiting.
+    ///
+    /// This represents the possible values of the `Self` type which are *not*
+    /// represented by the `Output` type (like `Option<Infallible>` for
+    /// `Option<T>`).
+    type Residual;
+
+    /// Constructs the type from its `Output` type.
+    #[lang = "from_output"]
+    fn from_output(output: Self::Output) -> Self;
+
+    /// Used in `?` to decide whether the operator should produce a value
+    /// (because this returned [`ControlFlow::Continue`]) or propagate a value
+    /// back to the caller (because this returned [`ControlFlow::Break`]).
+    #[lang = "branch"]
+    fn branch(self) -> ControlFlow<Self::Residual, Self::Output>;
+}
+
+/// Used to specify which residuals can be converted into which [`Try`] types.
+///
+/// Every `Try` type needs to be recreatable from its own associated `Residual`
+/// type, but can also have additional `FromResidual` implementations to
+/// support interconversion with other `Try` types.
+#[rustc_diagnostic_item = "FromResidual"]
+pub trait FromResidual<R = <Self as Try>::Residual> {
+    /// Constructs the type from a compatible `Residual` type.
+    #[lang = "from_residual"]
+    fn from_residual(residual: R) -> Self;
+}
+
+/// Allows retrieving the canonical type implementing [`Try`] that has this
+/// type as its residual and allows it to hold an `O` as its output.
+pub trait Residual<O> {
+    /// The "return" type of this meta-function.
+    type TryType: Try<Output = O, Residual = Self>;
+}
+
+/// Used to desugar `do yeet expr` into a call to [`FromResidual::from_residual`].
+#[inline]
+#[track_caller]
+#[lang = "from_yeet"]
+pub fn from_yeet<T, Y>(yeeted: Y) -> T
+where
+    T: FromResidual<Yeet<Y>>,
+{
+    FromResidual::from_residual(Yeet(yeeted))
+}
+
+/// Implement `FromResidual<Yeet<T>>` on your type to enable `do yeet expr`
+/// syntax in functions returning your type.
+pub struct Yeet<T>(pub T);
+
+/// Allows the `?` operator to be used on a type wrapping a value that can
+/// never short-circuit (used by the infallible versions of `try_*` methods).
+#[repr(transparent)]
+pub(crate) struct NeverShortCircuit<T>(pub T);
+
+pub(crate) enum NeverShortCircuitResidual {}
+
+impl<T> Try for NeverShortCircuit<T> {
+    type Output = T;
+    type Residual = NeverShortCircuitResidual;
+
+    #[inline]
+    fn from_output(output: T) -> Self {
+        NeverShortCircuit(output)
+    }
+
+    #[inline]
+    fn branch(self) -> ControlFlow<NeverShortCircuitResidual, T> {
+        ControlFlow::Continue(self.0)
+    }
+}
+
+impl<T> FromResidual for NeverShortCircuit<T> {
+    #[inline]
+    fn from_residual(never: NeverShortCircuitResidual) -> Self {
+        match never {}
+    }
+}
+
+impl<T> Residual<T> for NeverShortCircuitResidual {
+    type TryType = NeverShortCircuit<T>;
+}
+
+/// Shorthand to get the `Try` type with the same residual as `T` but an
+/// output of `V`.
+#[allow(type_alias_bounds)]
+pub(crate) type ChangeOutputType<T: Try<Residual: Residual<V>>, V> =
+    <T::Residual as Residual<V>>::TryType;
@@ -0,0 +1,60 @@
+use crate::marker::{Sized, Unsize};
+
+/// Trait that indicates that this is a pointer or a wrapper for one, where
+/// unsizing can be performed on the pointee.
+///
+/// For builtin pointer types, pointers to `T` will coerce to pointers to `U`
+/// if `T: Unsize<U>` by converting from a thin pointer to a fat pointer, like
+/// `&[i32; 2]` to `&[i32]` or `&MyStruct` to `&dyn MyTrait`.
+///
+/// For custom types, the coercion here works by coercing `Foo<T>` to `Foo<U>`
+/// provided an impl of `CoerceUnsized<Foo<U>> for Foo<T>` exists. Such an
+/// impl can only be written if `Foo<T>` has only a single non-phantomdata
+/// field involving `T`.
+#[lang = "coerce_unsized"]
+pub trait CoerceUnsized<T: ?Sized> {
+    // Empty.
+}
+
+// &mut T -> &mut U
+impl<'a, T: ?Sized + Unsize<U>, U: ?Sized> CoerceUnsized<&'a mut U> for &'a mut T {}
+// &mut T -> &U
+impl<'a, 'b: 'a, T: ?Sized + Unsize<U>, U: ?Sized> CoerceUnsized<&'a U> for &'b mut T {}
+// &mut T -> *mut U
+impl<'a, T: ?Sized + Unsize<U>, U: ?Sized> CoerceUnsized<*mut U> for &'a mut T {}
+// &mut T -> *const U
+impl<'a, T: ?Sized + Unsize<U>, U: ?Sized> CoerceUnsized<*const U> for &'a mut T {}
+
+// &T -> &U
+impl<'a, 'b: 'a, T: ?Sized + Unsize<U>, U: ?Sized> CoerceUnsized<&'a U> for &'b T {}
+// &T -> *const U
+impl<'a, T: ?Sized + Unsize<U>, U: ?Sized> CoerceUnsized<*const U> for &'a T {}
+
+// *mut T -> *mut U
+impl<T: ?Sized + Unsize<U>, U: ?Sized> CoerceUnsized<*mut U> for *mut T {}
+// *mut T -> *const U
+impl<T: ?Sized + Unsize<U>, U: ?Sized> CoerceUnsized<*const U> for *mut T {}
+
+// *const T -> *const U
+impl<T: ?Sized + Unsize<U>, U: ?Sized> CoerceUnsized<*const U> for *const T {}
+
+/// `DispatchFromDyn` is used in the implementation of dyn-compatibility
+/// checks (specifically allowing arbitrary self types), to guarantee that a
+/// method's receiver type can be dispatched on.
+///
+/// Unlike [`CoerceUnsized`], it requires the pointer to have the same layout
+/// once unsized, which is what allows `self: Pin<&mut Self>` methods to be
+/// called on a `Pin<&mut dyn Trait>`.
+#[lang = "dispatch_from_dyn"]
+pub trait DispatchFromDyn<T> {
+    // Empty.
+}
+
+// &T -> &U
+impl<'a, T: ?Sized + Unsize<U>, U: ?Sized> DispatchFromDyn<&'a U> for &'a T {}
+// &mut T -> &mut U
+impl<'a, T: ?Sized + Unsize<U>, U: ?Sized> DispatchFromDyn<&'a mut U> for &'a mut T {}
+// *const T -> *const U
+impl<T: ?Sized + Unsize<U>, U: ?Sized> DispatchFromDyn<*const U> for *const T {}
+// *mut T -> *mut U
+impl<T: ?Sized + Unsize<U>, U: ?Sized> DispatchFromDyn<*mut U> for *mut T {}
@@ -1,12 +1,24 @@
 use self::Option::*;
 use super::clone::Clone;
+use super::cmp::{Eq, Ord, Ordering, PartialEq, PartialOrd};
+use super::convert::{From, Infallible};
 use super::default::Default;
+use super::fmt::{self, Debug, Formatter};
+use super::hash::{Hash, Hasher};
 use super::marker::Copy;
-use super::ops::{Deref, FnOnce};
-use crate::matches;
+use super::ops::{ControlFlow, Deref, DerefMut, FnOnce, FromResidual, Residual, Try, Yeet};
+use super::pin::Pin;
+use super::result::Result::{self, Err, Ok};
+use crate::intrinsics::unreachable;
+use crate::panicking::{panic, panic_str};
+use crate::{matches, mem, slice};
 
+#[rustc_diagnostic_item = "Option"]
+#[lang = "Option"]
 pub enum Option<T> {
+    #[lang = "Some"]
     Some(T),
+    #[lang = "None"]
     None,
 }
 
@@ -68,6 +80,60 @@ impl<T> Option<T> {
         }
     }
 
+    #[inline]
+    pub fn inspect<F>(self, f: F) -> Self
+    where
+        F: FnOnce(&T),
+    {
+        if let Some(ref value) = self {
+            f(value);
+        }
+
+        self
+    }
+
+    #[inline]
+    pub fn map_or<U, F>(self, default: U, f: F) -> U
+    where
+        F: FnOnce(T) -> U,
+    {
+        match self {
+            Some(value) => f(value),
+            None => default,
+        }
+    }
+
+    #[inline]
+    pub fn map_or_else<U, D, F>(self, default: D, f: F) -> U
+    where
+        D: FnOnce() -> U,
+        F: FnOnce(T) -> U,
+    {
+        match self {
+            Some(value) => f(value),
+            None => default(),
+        }
+    }
+
+    #[inline]
+    pub fn ok_or<E>(self, err: E) -> Result<T, E> {
+        match self {
+            Some(value) => Ok(value),
+            None => Err(err),
+        }
+    }
+
+    #[inline]
+    pub fn ok_or_else<E, F>(self, err: F) -> Result<T, E>
+    where
+        F: FnOnce() -> E,
+    {
+        match self {
+            Some(value) => Ok(value),
+            None => Err(err()),
+        }
+    }
+
     #[inline]
     pub fn filter<F>(self, f: F) -> Self
     where
@@ -109,18 +175,101 @@ impl<T> Option<T> {
         }
     }
 
-    // TODO
-    // #[inline]
-    // pub unsafe fn unwrap_unchecked(self) -> T {}
+    /// Converts from [`Pin`]`<&Option<T>>` to `Option<`[`Pin`]`<&T>>`.
+    #[inline]
+    #[must_use]
+    pub const fn as_pin_ref(self: Pin<&Self>) -> Option<Pin<&T>> {
+        match Pin::get_ref(self).as_ref() {
+            // SAFETY: `value` is guaranteed to be pinned because it comes from
+            // `self` which is pinned.
+            Some(value) => unsafe { Some(Pin::new_unchecked(value)) },
+            None => None,
+        }
+    }
 
-    // const need compiled time Drop, not the case for now
-    // #[inline]
-    // pub fn unwrap(self) -> T {
-    //     match self {
-    //         Some(value) => value,
-    //         None => panic!("no value for `Option::None`"),
-    //     }
-    // }
+    /// Converts from [`Pin`]`<&mut Option<T>>` to `Option<`[`Pin`]`<&mut T>>`.
+    #[inline]
+    #[must_use]
+    pub const fn as_pin_mut(self: Pin<&mut Self>) -> Option<Pin<&mut T>> {
+        // SAFETY: `get_unchecked_mut` is never used to move the `Option`
+        // inside `self`. `value` is guaranteed to be pinned because it comes
+        // from `self` which is pinned.
+        unsafe {
+            match Pin::get_unchecked_mut(self).as_mut() {
+                Some(value) => Some(Pin::new_unchecked(value)),
+                None => None,
+            }
+        }
+    }
+
+    #[inline]
+    pub fn as_deref_mut(&mut self) -> Option<&mut T::Target>
+    where
+        T: DerefMut,
+    {
+        match self {
+            Some(value) => Some(value.deref_mut()),
+            None => None,
+        }
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn as_slice(&self) -> &[T] {
+        match self {
+            Some(value) => slice::from_ref(value),
+            // SAFETY: an empty slice only needs an aligned and non-null
+            // pointer, and `Option<T>` is at least as aligned as `T`.
+            None => unsafe { slice::from_raw_parts(self as *const Self as *const T, 0) },
+        }
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn as_mut_slice(&mut self) -> &mut [T] {
+        match self {
+            Some(value) => slice::from_mut(value),
+            // SAFETY: an empty slice only needs an aligned and non-null
+            // pointer, and `Option<T>` is at least as aligned as `T`.
+            None => unsafe { slice::from_raw_parts_mut(self as *mut Self as *mut T, 0) },
+        }
+    }
+
+    #[inline]
+    #[track_caller]
+    #[rustc_diagnostic_item = "option_expect"]
+    pub fn expect(self, msg: &str) -> T {
+        match self {
+            Some(value) => value,
+            None => expect_failed(msg),
+        }
+    }
+
+    #[inline(always)]
+    #[track_caller]
+    #[rustc_diagnostic_item = "option_unwrap"]
+    pub fn unwrap(self) -> T {
+        match self {
+            Some(value) => value,
+            None => unwrap_failed(),
+        }
+    }
+
+    /// Returns the contained `Some` value, consuming the `self` value,
+    /// without checking that the value is not `None`.
+    ///
+    /// # Safety
+    ///
+    /// Calling this method on `None` is *undefined behavior*.
+    #[inline]
+    #[track_caller]
+    pub const unsafe fn unwrap_unchecked(self) -> T {
+        match self {
+            Some(value) => value,
+            // SAFETY: the safety contract must be upheld by the caller.
+            None => unsafe { unreachable() },
+        }
+    }
 
     #[inline]
     pub fn unwrap_or(self, otherwise: T) -> T {
@@ -131,7 +280,7 @@ impl<T> Option<T> {
     }
 
     #[inline]
-    pub fn unwrap_or_default<F>(self) -> T
+    pub fn unwrap_or_default(self) -> T
     where
         T: Default,
     {
@@ -173,13 +322,126 @@ impl<T> Option<T> {
         !self.is_some()
     }
 
-    // TODO: mem::take
-    // #[inline]
-    // pub fn take(&mut self) -> Option<T> {}
+    #[inline]
+    pub fn is_none_or<F>(self, f: F) -> bool
+    where
+        F: FnOnce(T) -> bool,
+    {
+        match self {
+            Some(value) => f(value),
+            None => true,
+        }
+    }
+
+    #[inline]
+    pub fn insert(&mut self, value: T) -> &mut T {
+        *self = Some(value);
+
+        // SAFETY: the code above just filled the option
+        unsafe { self.as_mut().unwrap_unchecked() }
+    }
+
+    #[inline]
+    pub fn get_or_insert(&mut self, value: T) -> &mut T {
+        self.get_or_insert_with(|| value)
+    }
 
-    // TODO: mem::replace
-    // #[inline]
-    // pub fn replace(&mut self, new_value: T) -> Option<T> {}
+    #[inline]
+    pub fn get_or_insert_default(&mut self) -> &mut T
+    where
+        T: Default,
+    {
+        self.get_or_insert_with(T::default)
+    }
+
+    #[inline]
+    pub fn get_or_insert_with<F>(&mut self, f: F) -> &mut T
+    where
+        F: FnOnce() -> T,
+    {
+        if let None = self {
+            *self = Some(f());
+        }
+
+        // SAFETY: a `None` variant for `self` would have been replaced by a
+        // `Some` variant in the code above.
+        unsafe { self.as_mut().unwrap_unchecked() }
+    }
+
+    #[inline]
+    pub const fn take(&mut self) -> Option<T> {
+        mem::replace(self, None)
+    }
+
+    #[inline]
+    pub fn take_if<P>(&mut self, predicate: P) -> Option<T>
+    where
+        P: FnOnce(&mut T) -> bool,
+    {
+        match self.as_mut().map_or(false, predicate) {
+            true => self.take(),
+            false => None,
+        }
+    }
+
+    #[inline]
+    pub const fn replace(&mut self, value: T) -> Option<T> {
+        mem::replace(self, Some(value))
+    }
+
+    #[inline]
+    pub fn zip<U>(self, other: Option<U>) -> Option<(T, U)> {
+        match (self, other) {
+            (Some(a), Some(b)) => Some((a, b)),
+            _ => None,
+        }
+    }
+
+    #[inline]
+    pub fn zip_with<U, F, R>(self, other: Option<U>, f: F) -> Option<R>
+    where
+        F: FnOnce(T, U) -> R,
+    {
+        match (self, other) {
+            (Some(a), Some(b)) => Some(f(a, b)),
+            _ => None,
+        }
+    }
+}
+
+impl<T, U> Option<(T, U)> {
+    #[inline]
+    pub fn unzip(self) -> (Option<T>, Option<U>) {
+        match self {
+            Some((a, b)) => (Some(a), Some(b)),
+            None => (None, None),
+        }
+    }
+}
+
+impl<T, E> Option<Result<T, E>> {
+    #[inline]
+    pub fn transpose(self) -> Result<Option<T>, E> {
+        match self {
+            Some(Ok(value)) => Ok(Some(value)),
+            Some(Err(err)) => Err(err),
+            None => Ok(None),
+        }
+    }
+}
+
+#[cold]
+#[inline(never)]
+#[track_caller]
+fn unwrap_failed() -> ! {
+    panic("called `Option::unwrap()` on a `None` value")
+}
+
+#[cold]
+#[inline(never)]
+#[track_caller]
+fn expect_failed(msg: &str) -> ! {
+    panic_str(msg)
 }
 
 impl<T: Clone> Clone for Option<T> {
@@ -204,6 +466,97 @@ impl<T: Clone> Option<&T> {
 
 impl<T: Copy> Copy for Option<T> {}
 
+impl<T> Default for Option<T> {
+    /// Returns [`None`][Option::None].
+    #[inline]
+    fn default() -> Option<T> {
+        None
+    }
+}
+
+impl<T> From<T> for Option<T> {
+    /// Moves `value` into a new [`Some`].
+    #[inline]
+    fn from(value: T) -> Option<T> {
+        Some(value)
+    }
+}
+
+impl<'a, T> From<&'a Option<T>> for Option<&'a T> {
+    #[inline]
+    fn from(option: &'a Option<T>) -> Option<&'a T> {
+        option.as_ref()
+    }
+}
+
+impl<'a, T> From<&'a mut Option<T>> for Option<&'a mut T> {
+    #[inline]
+    fn from(option: &'a mut Option<T>) -> Option<&'a mut T> {
+        option.as_mut()
+    }
+}
+
+impl<T: PartialEq> PartialEq for Option<T> {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Some(a), Some(b)) => *a == *b,
+            (None, None) => true,
+            _ => false,
+        }
+    }
+}
+
+impl<T: Eq> Eq for Option<T> {}
+
+// `None` is always less than any `Some`, like a derive would do with the
+// variants declared in the order `None`, `Some`.
+impl<T: PartialOrd> PartialOrd for Option<T> {
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        match (self, other) {
+            (Some(a), Some(b)) => a.partial_cmp(b),
+            (Some(_), None) => Some(Ordering::Greater),
+            (None, Some(_)) => Some(Ordering::Less),
+            (None, None) => Some(Ordering::Equal),
+        }
+    }
+}
+
+impl<T: Ord> Ord for Option<T> {
+    #[inline]
+    fn cmp(&self, other: &Self) -> Ordering {
+        match (self, other) {
+            (Some(a), Some(b)) => a.cmp(b),
+            (Some(_), None) => Ordering::Greater,
+            (None, Some(_)) => Ordering::Less,
+            (None, None) => Ordering::Equal,
+        }
+    }
+}
+
+impl<T: Hash> Hash for Option<T> {
+    #[inline]
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        match self {
+            None => state.write_isize(0),
+            Some(value) => {
+                state.write_isize(1);
+                value.hash(state);
+            }
+        }
+    }
+}
+
+impl<T: Debug> Debug for Option<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Some(value) => f.debug_tuple("Some").field(value).finish(),
+            None => f.write_str("None"),
+        }
+    }
+}
+
 impl<T: Copy> Option<&T> {
     #[inline]
     pub fn copied(self) -> Option<T> {
@@ -220,3 +573,43 @@ impl<T> Option<Option<T>> {
         self.and_then(|x| x)
     }
 }
+
+impl<T> Try for Option<T> {
+    type Output = T;
+    type Residual = Option<Infallible>;
+
+    #[inline]
+    fn from_output(output: Self::Output) -> Self {
+        Some(output)
+    }
+
+    #[inline]
+    fn branch(self) -> ControlFlow<Self::Residual, Self::Output> {
+        match self {
+            Some(value) => ControlFlow::Continue(value),
+            None => ControlFlow::Break(None),
+        }
+    }
+}
+
+// The residual type is specified manually instead of using the default to
+// work around https://github.com/rust-lang/rust/issues/99940
+impl<T> FromResidual<Option<Infallible>> for Option<T> {
+    #[inline]
+    fn from_residual(residual: Option<Infallible>) -> Self {
+        match residual {
+            None => None,
+        }
+    }
+}
+
+impl<T> FromResidual<Yeet<()>> for Option<T> {
+    #[inline]
+    fn from_residual(Yeet(()): Yeet<()>) -> Self {
+        None
+    }
+}
+
+impl<T> Residual<T> for Option<Infallible> {
+    type TryType = Option<T>;
+}
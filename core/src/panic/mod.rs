@@ -2,6 +2,10 @@
 
 #![stable(feature = "core_panic", since = "1.0.0")]
 
+mod panic_info;
+#[stable(feature = "panic_hooks", since = "1.10.0")]
+pub use self::panic_info::PanicInfo;
+
 #[doc(hidden)]
 #[unstable(feature = "edition_panic", issue = "none", reason = "use unreachable!() instead")]
 #[allow_internal_unstable(panic_internals)]
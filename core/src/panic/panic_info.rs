@@ -0,0 +1,34 @@
+use crate::fmt::{self, Display, Formatter};
+
+/// Information about a panic, given to the `#[panic_handler]` function.
+///
+/// The location of the panic is not tracked yet.
+#[lang = "panic_info"]
+#[stable(feature = "panic_hooks", since = "1.10.0")]
+pub struct PanicInfo<'a> {
+    message: &'a dyn Display,
+}
+
+impl<'a> PanicInfo<'a> {
+    #[doc(hidden)]
+    #[unstable(feature = "panic_internals", issue = "none")]
+    #[inline]
+    pub fn new(message: &'a dyn Display) -> Self {
+        PanicInfo { message }
+    }
+
+    /// The message of the panic, formatted when it is displayed.
+    #[stable(feature = "panic_info_message", since = "1.81.0")]
+    #[inline]
+    pub fn message(&self) -> &dyn Display {
+        self.message
+    }
+}
+
+#[stable(feature = "panic_hook_display", since = "1.26.0")]
+impl Display for PanicInfo<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_str("panicked:\n")?;
+        self.message.fmt(f)
+    }
+}
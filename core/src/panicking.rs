@@ -0,0 +1,43 @@
+//! Panic support for core.
+//!
+//! The functions of this module are the entry points used by the panicking
+//! code of the core (like `Option::unwrap`). Until `format_args!` is
+//! supported, the message is handed to the `#[panic_handler]` function as a
+//! [`Display`] value, formatted by the handler.
+
+use crate::fmt::Display;
+use crate::marker::Sized;
+use crate::panic::PanicInfo;
+
+extern "Rust" {
+    /// The `#[panic_handler]` function of the final binary.
+    #[lang = "panic_impl"]
+    fn panic_impl(info: &PanicInfo<'_>) -> !;
+}
+
+/// The underlying implementation of core's `panic!` macro when no formatting
+/// is used.
+#[cold]
+#[inline(never)]
+#[track_caller]
+pub fn panic(expr: &'static str) -> ! {
+    panic_display(&expr)
+}
+
+/// Like [`panic`], but for a message that is not `'static`.
+#[cold]
+#[inline(never)]
+#[track_caller]
+pub fn panic_str(expr: &str) -> ! {
+    panic_display(&expr)
+}
+
+/// Panics with the [`Display`] representation of `x` as message.
+#[cold]
+#[inline(never)]
+#[track_caller]
+pub fn panic_display<T: Display + ?Sized>(x: &T) -> ! {
+    let info = PanicInfo::new(&x);
+    // SAFETY: `panic_impl` is defined by the `#[panic_handler]` of the binary
+    unsafe { panic_impl(&info) }
+}
@@ -0,0 +1,387 @@
+//! Types that pin data to a location in memory.
+//!
+//! A [`Pin<Ptr>`] wraps a pointer and guarantees that the pointee will not
+//! be moved again (unless it implements [`Unpin`]) until it is dropped. This
+//! is what allows self-referential types, like the state machines generated
+//! for `async` blocks, to be polled through a [`Future`][crate::future::Future].
+//!
+//! A value can be pinned on the heap by a smart pointer or on the stack with
+//! the [`pin!`] macro:
+//!
+//! ```ignore
+//! let fut = pin!(async { 42 });
+//! let _: Pin<&mut dyn Future<Output = i32>> = fut;
+//! ```
+
+use crate::cmp::{Eq, Ord, Ordering, PartialEq, PartialOrd};
+use crate::fmt::{self, Debug, Display, Formatter};
+use crate::hash::{Hash, Hasher};
+use crate::marker::{Sized, Unpin};
+use crate::ops::{CoerceUnsized, Deref, DerefMut, DispatchFromDyn, FnOnce, LegacyReceiver};
+use crate::option::Option;
+
+/// A pointer which pins its pointee in place.
+///
+/// The pointee of a `Pin<Ptr>` can only be accessed mutably through `unsafe`
+/// code if it does not implement [`Unpin`].
+#[lang = "pin"]
+#[fundamental]
+#[repr(transparent)]
+pub struct Pin<Ptr> {
+    // FIXME: the field is public because the `pin!` macro needs to construct
+    // a `Pin` in const contexts, it must not be used directly.
+    #[doc(hidden)]
+    pub __pointer: Ptr,
+}
+
+// The following implementations aren't derived in order to avoid soundness
+// issues: they compare the pointees, not the pointers.
+
+impl<Ptr: Deref, Q: Deref> PartialEq<Pin<Q>> for Pin<Ptr>
+where
+    Ptr::Target: PartialEq<Q::Target>,
+{
+    fn eq(&self, other: &Pin<Q>) -> bool {
+        Ptr::Target::eq(&**self, &**other)
+    }
+
+    fn ne(&self, other: &Pin<Q>) -> bool {
+        Ptr::Target::ne(&**self, &**other)
+    }
+}
+
+impl<Ptr: Deref<Target: Eq>> Eq for Pin<Ptr> {}
+
+impl<Ptr: Deref, Q: Deref> PartialOrd<Pin<Q>> for Pin<Ptr>
+where
+    Ptr::Target: PartialOrd<Q::Target>,
+{
+    fn partial_cmp(&self, other: &Pin<Q>) -> Option<Ordering> {
+        Ptr::Target::partial_cmp(&**self, &**other)
+    }
+
+    fn lt(&self, other: &Pin<Q>) -> bool {
+        Ptr::Target::lt(&**self, &**other)
+    }
+
+    fn le(&self, other: &Pin<Q>) -> bool {
+        Ptr::Target::le(&**self, &**other)
+    }
+
+    fn gt(&self, other: &Pin<Q>) -> bool {
+        Ptr::Target::gt(&**self, &**other)
+    }
+
+    fn ge(&self, other: &Pin<Q>) -> bool {
+        Ptr::Target::ge(&**self, &**other)
+    }
+}
+
+impl<Ptr: Deref<Target: Ord>> Ord for Pin<Ptr> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        Ptr::Target::cmp(&**self, &**other)
+    }
+}
+
+impl<Ptr: Deref<Target: Hash>> Hash for Pin<Ptr> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        Ptr::Target::hash(&**self, state);
+    }
+}
+
+impl<Ptr: Deref<Target: Unpin>> Pin<Ptr> {
+    /// Constructs a new `Pin<Ptr>` around a pointer to some data of a type
+    /// that implements [`Unpin`].
+    ///
+    /// Unlike [`Pin::new_unchecked`], this method is safe because the pointer
+    /// `Ptr` dereferences to an [`Unpin`] type, which cancels the pinning
+    /// guarantees.
+    #[inline(always)]
+    pub const fn new(pointer: Ptr) -> Pin<Ptr> {
+        // SAFETY: the value pointed to is `Unpin`, and so has no requirements
+        // around pinning.
+        unsafe { Pin::new_unchecked(pointer) }
+    }
+
+    /// Unwraps this `Pin<Ptr>`, returning the underlying pointer.
+    ///
+    /// This requires that the data inside this `Pin` implements [`Unpin`] so
+    /// that we can ignore the pinning invariants when unwrapping it.
+    #[inline(always)]
+    pub const fn into_inner(pin: Pin<Ptr>) -> Ptr {
+        pin.__pointer
+    }
+}
+
+impl<Ptr: Deref> Pin<Ptr> {
+    /// Constructs a new `Pin<Ptr>` around a reference to some data of a type
+    /// that may or may not implement [`Unpin`].
+    ///
+    /// # Safety
+    ///
+    /// The caller must guarantee that the data pointed to by `pointer` is
+    /// pinned: it is never moved again nor invalidated until it gets dropped,
+    /// even once this `Pin` is gone.
+    #[lang = "new_unchecked"]
+    #[inline(always)]
+    pub const unsafe fn new_unchecked(pointer: Ptr) -> Pin<Ptr> {
+        Pin { __pointer: pointer }
+    }
+
+    /// Gets a shared reference to the pinned value this [`Pin`] points to.
+    #[inline(always)]
+    pub fn as_ref(&self) -> Pin<&Ptr::Target> {
+        // SAFETY: see documentation on this function
+        unsafe { Pin::new_unchecked(&*self.__pointer) }
+    }
+
+    /// Unwraps this `Pin<Ptr>`, returning the underlying `Ptr`.
+    ///
+    /// # Safety
+    ///
+    /// The caller must keep treating the pointee as pinned once the `Pin` is
+    /// gone, as if [`Pin::new_unchecked`] had never been called.
+    #[inline(always)]
+    pub const unsafe fn into_inner_unchecked(pin: Pin<Ptr>) -> Ptr {
+        pin.__pointer
+    }
+}
+
+impl<Ptr: DerefMut> Pin<Ptr> {
+    /// Gets a mutable reference to the pinned value this `Pin<Ptr>` points
+    /// to.
+    #[inline(always)]
+    pub fn as_mut(&mut self) -> Pin<&mut Ptr::Target> {
+        // SAFETY: see documentation on this function
+        unsafe { Pin::new_unchecked(&mut *self.__pointer) }
+    }
+
+    /// Gets a mutable reference to the pinned value behind a pinned `Pin`,
+    /// which is what the `Future` impl of `Pin<Ptr>` polls.
+    #[inline(always)]
+    pub fn as_deref_mut(self: Pin<&mut Pin<Ptr>>) -> Pin<&mut Ptr::Target> {
+        // SAFETY: `Pin` never moves its pointer, projecting to the pointee
+        // keeps it pinned.
+        unsafe { self.get_unchecked_mut() }.as_mut()
+    }
+
+    /// Assigns a new value to the memory location pointed to by the
+    /// `Pin<Ptr>`.
+    ///
+    /// This overwrites pinned data, but that is okay: the destructor of the
+    /// old value runs before it is overwritten and the new value is also a
+    /// valid value of the type.
+    #[inline(always)]
+    pub fn set(&mut self, value: Ptr::Target)
+    where
+        Ptr::Target: Sized,
+    {
+        *(self.__pointer) = value;
+    }
+}
+
+impl<'a, T: ?Sized> Pin<&'a T> {
+    /// Constructs a new pin by mapping the interior value.
+    ///
+    /// # Safety
+    ///
+    /// The caller must guarantee that the data returned by `func` does not
+    /// move as long as the argument value does not move.
+    #[must_use = "`self` will be dropped if the result is not used"]
+    pub unsafe fn map_unchecked<U, F>(self, func: F) -> Pin<&'a U>
+    where
+        U: ?Sized,
+        F: FnOnce(&T) -> &U,
+    {
+        let pointer = &*self.__pointer;
+        let new_pointer = func(pointer);
+
+        // SAFETY: the safety contract for `new_unchecked` must be upheld by
+        // the caller.
+        unsafe { Pin::new_unchecked(new_pointer) }
+    }
+
+    /// Gets a shared reference out of a pin.
+    ///
+    /// This is safe because it is not possible to move out of a shared
+    /// reference.
+    #[inline(always)]
+    #[must_use]
+    pub const fn get_ref(self) -> &'a T {
+        self.__pointer
+    }
+}
+
+impl<'a, T: ?Sized> Pin<&'a mut T> {
+    /// Converts this `Pin<&mut T>` into a `Pin<&T>` with the same lifetime.
+    #[inline(always)]
+    #[must_use = "`self` will be dropped if the result is not used"]
+    pub const fn into_ref(self) -> Pin<&'a T> {
+        Pin {
+            __pointer: self.__pointer,
+        }
+    }
+
+    /// Gets a mutable reference to the data inside of this `Pin`.
+    ///
+    /// This requires that the data inside this `Pin` is `Unpin`.
+    #[inline(always)]
+    #[must_use = "`self` will be dropped if the result is not used"]
+    pub const fn get_mut(self) -> &'a mut T
+    where
+        T: Unpin,
+    {
+        self.__pointer
+    }
+
+    /// Gets a mutable reference to the data inside of this `Pin`.
+    ///
+    /// # Safety
+    ///
+    /// The caller must never move the data out of the returned reference, so
+    /// that the invariants on the `Pin` type can be upheld.
+    #[inline(always)]
+    #[must_use = "`self` will be dropped if the result is not used"]
+    pub const unsafe fn get_unchecked_mut(self) -> &'a mut T {
+        self.__pointer
+    }
+
+    /// Constructs a new pin by mapping the interior value.
+    ///
+    /// # Safety
+    ///
+    /// The caller must guarantee that the data returned by `func` does not
+    /// move as long as the argument value does not move.
+    #[must_use = "`self` will be dropped if the result is not used"]
+    pub unsafe fn map_unchecked_mut<U, F>(self, func: F) -> Pin<&'a mut U>
+    where
+        U: ?Sized,
+        F: FnOnce(&mut T) -> &mut U,
+    {
+        // SAFETY: the caller is responsible for not moving the value out of
+        // this reference.
+        let pointer = unsafe { Pin::get_unchecked_mut(self) };
+        let new_pointer = func(pointer);
+        // SAFETY: as the value of `this` is guaranteed to not have been moved
+        // out, this call to `new_unchecked` is safe.
+        unsafe { Pin::new_unchecked(new_pointer) }
+    }
+}
+
+impl<T: ?Sized> Pin<&'static T> {
+    /// Gets a pinning reference from a `&'static` reference.
+    ///
+    /// This is safe because `T` is borrowed immutably for the `'static`
+    /// lifetime, which never ends.
+    pub const fn static_ref(r: &'static T) -> Pin<&'static T> {
+        // SAFETY: The 'static borrow guarantees the data will not be
+        // moved/invalidated until it gets dropped (which is never).
+        unsafe { Pin::new_unchecked(r) }
+    }
+}
+
+impl<T: ?Sized> Pin<&'static mut T> {
+    /// Gets a pinning mutable reference from a static mutable reference.
+    ///
+    /// This is safe because `T` is borrowed for the `'static` lifetime, which
+    /// never ends.
+    pub const fn static_mut(r: &'static mut T) -> Pin<&'static mut T> {
+        // SAFETY: The 'static borrow guarantees the data will not be
+        // moved/invalidated until it gets dropped (which is never).
+        unsafe { Pin::new_unchecked(r) }
+    }
+}
+
+impl<Ptr: Deref> Deref for Pin<Ptr> {
+    type Target = Ptr::Target;
+
+    fn deref(&self) -> &Ptr::Target {
+        Pin::get_ref(Pin::as_ref(self))
+    }
+}
+
+impl<Ptr: DerefMut<Target: Unpin>> DerefMut for Pin<Ptr> {
+    fn deref_mut(&mut self) -> &mut Ptr::Target {
+        Pin::get_mut(Pin::as_mut(self))
+    }
+}
+
+impl<Ptr: LegacyReceiver> LegacyReceiver for Pin<Ptr> {}
+
+impl<Ptr: Debug> Debug for Pin<Ptr> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        Debug::fmt(&self.__pointer, f)
+    }
+}
+
+impl<Ptr: Display> Display for Pin<Ptr> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        Display::fmt(&self.__pointer, f)
+    }
+}
+
+// Note: this means that any impl of `CoerceUnsized` that allows coercing from
+// a type that impls `Deref<Target=impl !Unpin>` to a type that impls
+// `Deref<Target=Unpin>` is unsound. `PinCoerceUnsized` restricts the coercion
+// to the pointers known not to do that.
+impl<Ptr, U> CoerceUnsized<Pin<U>> for Pin<Ptr>
+where
+    Ptr: CoerceUnsized<U> + PinCoerceUnsized,
+    U: PinCoerceUnsized,
+{
+}
+
+impl<Ptr, U> DispatchFromDyn<Pin<U>> for Pin<Ptr>
+where
+    Ptr: DispatchFromDyn<U> + PinCoerceUnsized,
+    U: PinCoerceUnsized,
+{
+}
+
+/// Trait that indicates that this is a pointer or a wrapper for one, where
+/// unsizing can be performed on the pointee when it is pinned.
+///
+/// # Safety
+///
+/// Given a pointer of this type, the concrete type returned by its `deref`
+/// method and (if it implements `DerefMut`) its `deref_mut` method must be
+/// the same type and must not change without a modification. The following
+/// operations are not considered modifications: moving the pointer, unsizing
+/// the pointer, transmuting the pointer or reborrowing it.
+#[doc(hidden)]
+pub unsafe trait PinCoerceUnsized {}
+
+unsafe impl<'a, T: ?Sized> PinCoerceUnsized for &'a T {}
+
+unsafe impl<'a, T: ?Sized> PinCoerceUnsized for &'a mut T {}
+
+unsafe impl<T: PinCoerceUnsized> PinCoerceUnsized for Pin<T> {}
+
+unsafe impl<T: ?Sized> PinCoerceUnsized for *const T {}
+
+unsafe impl<T: ?Sized> PinCoerceUnsized for *mut T {}
+
+/// Constructs a <code>[Pin]<[&mut] T></code>, by pinning a `value: T`
+/// locally.
+///
+/// The value is moved into a temporary that lives as long as the enclosing
+/// statement (or block, through temporary lifetime extension), and is never
+/// accessible again except through the returned `Pin`, so it cannot be moved
+/// out after being pinned.
+///
+/// ```ignore
+/// let mut fut = pin!(async { 42 });
+/// let _ = fut.as_mut().poll(cx);
+/// ```
+///
+/// [&mut]: reference
+#[rustc_macro_transparency = "semitransparent"]
+pub macro pin($value:expr $(,)?) {
+    // Building the `Pin` directly (instead of calling `Pin::new_unchecked`
+    // on a `&mut $value`) keeps the temporary lifetime extension of `{ $value }`
+    // and does not need an `unsafe` block, which would let `$value` run unsafe
+    // code unchecked.
+    $crate::pin::Pin::<&mut _> {
+        __pointer: &mut { $value },
+    }
+}
@@ -4,13 +4,16 @@ use crate::core::mem::MaybeUninit;
 use crate::core::option::Option;
 
 #[repr(transparent)]
+#[compiler::valid_range(start = 1)]
+#[compiler::niche_guaranteed]
 pub struct NonNull<T: ?Sized>(*mut T);
 
 // TODO: implement all method for *mut T and *const T
 impl<T: ?Sized> NonNull<T> {
     #[inline]
     pub const unsafe fn new_unchecked(ptr: *mut T) -> NonNull<T> {
-        NonNull(ptr)
+        // SAFETY: the caller guarantees that `ptr` is non-null
+        unsafe { NonNull(ptr) }
     }
 
     #[inline]
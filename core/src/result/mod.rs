@@ -1,12 +1,22 @@
 use self::Result::*;
 use super::clone::Clone;
+use super::convert::{From, Infallible};
 use super::default::Default;
+use super::fmt::{self, Debug, Display, Formatter};
+use super::iter::{self, FromIterator, IntoIterator, Iterator};
 use super::marker::Copy;
-use super::ops::{Deref, FnOnce};
+use super::ops::{ControlFlow, Deref, FnOnce, FromResidual, Residual, Try, Yeet};
+use super::option::Option::{self, None, Some};
+use crate::intrinsics::unreachable;
 use crate::matches;
+use crate::panicking::panic_display;
 
+#[must_use = "this `Result` may be an `Err` variant, which should be handled"]
+#[rustc_diagnostic_item = "Result"]
 pub enum Result<T, E> {
+    #[lang = "Ok"]
     Ok(T),
+    #[lang = "Err"]
     Err(E),
 }
 
@@ -71,6 +81,69 @@ impl<T, E> Result<T, E> {
         }
     }
 
+    #[inline]
+    pub fn map_or<U, F>(self, default: U, f: F) -> U
+    where
+        F: FnOnce(T) -> U,
+    {
+        match self {
+            Ok(value) => f(value),
+            Err(_) => default,
+        }
+    }
+
+    #[inline]
+    pub fn map_or_else<U, D, F>(self, default: D, f: F) -> U
+    where
+        D: FnOnce(E) -> U,
+        F: FnOnce(T) -> U,
+    {
+        match self {
+            Ok(value) => f(value),
+            Err(err) => default(err),
+        }
+    }
+
+    #[inline]
+    pub fn inspect<F>(self, f: F) -> Self
+    where
+        F: FnOnce(&T),
+    {
+        if let Ok(ref value) = self {
+            f(value);
+        }
+
+        self
+    }
+
+    #[inline]
+    pub fn inspect_err<F>(self, f: F) -> Self
+    where
+        F: FnOnce(&E),
+    {
+        if let Err(ref err) = self {
+            f(err);
+        }
+
+        self
+    }
+
+    #[inline]
+    pub fn ok(self) -> Option<T> {
+        match self {
+            Ok(value) => Some(value),
+            Err(_) => None,
+        }
+    }
+
+    #[inline]
+    pub fn err(self) -> Option<E> {
+        match self {
+            Ok(_) => None,
+            Err(err) => Some(err),
+        }
+    }
+
     #[inline]
     pub const fn as_deref(&self) -> Result<&T, &E>
     where
@@ -99,20 +172,59 @@ impl<T, E> Result<T, E> {
         }
     }
 
-    // TODO
-    // #[inline]
-    // pub unsafe fn unwrap_unchecked(self) -> T {}
+    #[inline]
+    pub fn iter(&self) -> Iter<'_, T> {
+        Iter {
+            inner: self.as_ref().ok(),
+        }
+    }
+
+    #[inline]
+    pub fn iter_mut(&mut self) -> IterMut<'_, T> {
+        IterMut {
+            inner: self.as_mut().ok(),
+        }
+    }
+
+    #[inline]
+    #[track_caller]
+    pub fn expect(self, msg: &str) -> T
+    where
+        E: Debug,
+    {
+        match self {
+            Ok(value) => value,
+            Err(err) => unwrap_failed(msg, &err),
+        }
+    }
+
+    #[inline(always)]
+    #[track_caller]
+    pub fn unwrap(self) -> T
+    where
+        E: Debug,
+    {
+        match self {
+            Ok(value) => value,
+            Err(err) => unwrap_failed("called `Result::unwrap()` on an `Err` value", &err),
+        }
+    }
 
-    // #[inline]
-    // pub fn unwrap(self) -> T
-    // where
-    //     E: Debug,
-    // {
-    //     match self {
-    //         Ok(value) => value,
-    //         Err(err) => panic!("no value for `Result::Err({:?})`", err),
-    //     }
-    // }
+    /// Returns the contained [`Ok`] value, consuming the `self` value,
+    /// without checking that the value is not an [`Err`].
+    ///
+    /// # Safety
+    ///
+    /// Calling this method on an [`Err`] is *undefined behavior*.
+    #[inline]
+    #[track_caller]
+    pub unsafe fn unwrap_unchecked(self) -> T {
+        match self {
+            Ok(value) => value,
+            // SAFETY: the safety contract must be upheld by the caller.
+            Err(_) => unsafe { unreachable() },
+        }
+    }
 
     #[inline]
     pub fn unwrap_or(self, otherwise: T) -> T {
@@ -123,7 +235,7 @@ impl<T, E> Result<T, E> {
     }
 
     #[inline]
-    pub fn unwrap_or_default<F>(self) -> T
+    pub fn unwrap_or_default(self) -> T
     where
         T: Default,
     {
@@ -134,15 +246,47 @@ impl<T, E> Result<T, E> {
     }
 
     #[inline]
-    pub fn unwrap_err(self, otherwise: E) -> E {
+    #[track_caller]
+    pub fn expect_err(self, msg: &str) -> E
+    where
+        T: Debug,
+    {
+        match self {
+            Ok(value) => unwrap_failed(msg, &value),
+            Err(err) => err,
+        }
+    }
+
+    #[inline]
+    #[track_caller]
+    pub fn unwrap_err(self) -> E
+    where
+        T: Debug,
+    {
+        match self {
+            Ok(value) => unwrap_failed("called `Result::unwrap_err()` on an `Ok` value", &value),
+            Err(err) => err,
+        }
+    }
+
+    /// Returns the contained [`Err`] value, consuming the `self` value,
+    /// without checking that the value is not an [`Ok`].
+    ///
+    /// # Safety
+    ///
+    /// Calling this method on an [`Ok`] is *undefined behavior*.
+    #[inline]
+    #[track_caller]
+    pub unsafe fn unwrap_err_unchecked(self) -> E {
         match self {
-            Ok(_) => otherwise,
+            // SAFETY: the safety contract must be upheld by the caller.
+            Ok(_) => unsafe { unreachable() },
             Err(err) => err,
         }
     }
 
     #[inline]
-    pub fn unwrap_err_default<F>(self) -> E
+    pub fn unwrap_err_default(self) -> E
     where
         E: Default,
     {
@@ -194,14 +338,59 @@ impl<T, E> Result<T, E> {
             Err(err) => f(err),
         }
     }
+}
+
+impl<T> Result<T, Infallible> {
+    #[inline]
+    pub fn into_ok(self) -> T {
+        match self {
+            Ok(value) => value,
+            Err(never) => match never {},
+        }
+    }
+}
 
-    // TODO: mem::take
-    // #[inline]
-    // pub fn take(&mut self) -> Result<T, E> {}
+impl<E> Result<Infallible, E> {
+    #[inline]
+    pub fn into_err(self) -> E {
+        match self {
+            Ok(never) => match never {},
+            Err(err) => err,
+        }
+    }
+}
 
-    // TODO: mem::replace
-    // #[inline]
-    // pub fn replace(&mut self, new_value: T) -> Result<T, E> {}
+impl<T, E> Result<Option<T>, E> {
+    #[inline]
+    pub fn transpose(self) -> Option<Result<T, E>> {
+        match self {
+            Ok(Some(value)) => Some(Ok(value)),
+            Ok(None) => None,
+            Err(err) => Some(Err(err)),
+        }
+    }
+}
+
+#[cold]
+#[inline(never)]
+#[track_caller]
+fn unwrap_failed(msg: &str, error: &dyn Debug) -> ! {
+    panic_display(&UnwrapFailed { msg, error })
+}
+
+/// The message of a failed `unwrap`, formatted as `{msg}: {error:?}` (until
+/// `format_args!` is supported).
+struct UnwrapFailed<'a> {
+    msg: &'a str,
+    error: &'a dyn Debug,
+}
+
+impl Display for UnwrapFailed<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_str(self.msg)?;
+        f.write_str(": ")?;
+        self.error.fmt(f)
+    }
 }
 
 impl<T: Clone, E: Clone> Clone for Result<T, E> {
@@ -214,25 +403,33 @@ impl<T: Clone, E: Clone> Clone for Result<T, E> {
     }
 }
 
-impl<T: Clone, E: Clone> Result<&T, &E> {
+impl<T: Clone, E> Result<&T, E> {
     #[inline]
     pub fn cloned(self) -> Result<T, E> {
-        match self {
-            Ok(value) => Ok(value.clone()),
-            Err(err) => Err(err.clone()),
-        }
+        self.map(|value| value.clone())
+    }
+}
+
+impl<T: Clone, E> Result<&mut T, E> {
+    #[inline]
+    pub fn cloned(self) -> Result<T, E> {
+        self.map(|value| value.clone())
     }
 }
 
 impl<T: Copy, E: Copy> Copy for Result<T, E> {}
 
-impl<T: Copy, E: Copy> Result<&T, &E> {
+impl<T: Copy, E> Result<&T, E> {
     #[inline]
     pub fn copied(self) -> Result<T, E> {
-        match self {
-            Ok(value) => Ok(*value),
-            Err(err) => Err(*err),
-        }
+        self.map(|&value| value)
+    }
+}
+
+impl<T: Copy, E> Result<&mut T, E> {
+    #[inline]
+    pub fn copied(self) -> Result<T, E> {
+        self.map(|&mut value| value)
     }
 }
 
@@ -242,3 +439,169 @@ impl<T, E> Result<Result<T, E>, E> {
         self.and_then(|x| x)
     }
 }
+
+impl<T, E> IntoIterator for Result<T, E> {
+    type IntoIter = IntoIter<T>;
+    type Item = T;
+
+    #[inline]
+    fn into_iter(self) -> IntoIter<T> {
+        IntoIter { inner: self.ok() }
+    }
+}
+
+impl<'a, T, E> IntoIterator for &'a Result<T, E> {
+    type IntoIter = Iter<'a, T>;
+    type Item = &'a T;
+
+    #[inline]
+    fn into_iter(self) -> Iter<'a, T> {
+        self.iter()
+    }
+}
+
+impl<'a, T, E> IntoIterator for &'a mut Result<T, E> {
+    type IntoIter = IterMut<'a, T>;
+    type Item = &'a mut T;
+
+    #[inline]
+    fn into_iter(self) -> IterMut<'a, T> {
+        self.iter_mut()
+    }
+}
+
+/// An iterator over a reference to the [`Ok`] variant of a [`Result`].
+///
+/// The iterator yields one value if the result is [`Ok`], otherwise none.
+///
+/// Created by [`Result::iter`].
+pub struct Iter<'a, T: 'a> {
+    inner: Option<&'a T>,
+}
+
+impl<'a, T> Iterator for Iter<'a, T> {
+    type Item = &'a T;
+
+    #[inline]
+    fn next(&mut self) -> Option<&'a T> {
+        self.inner.take()
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let n = if self.inner.is_some() { 1 } else { 0 };
+        (n, Some(n))
+    }
+}
+
+impl<T> Clone for Iter<'_, T> {
+    #[inline]
+    fn clone(&self) -> Self {
+        Iter { inner: self.inner }
+    }
+}
+
+/// An iterator over a mutable reference to the [`Ok`] variant of a [`Result`].
+///
+/// Created by [`Result::iter_mut`].
+pub struct IterMut<'a, T: 'a> {
+    inner: Option<&'a mut T>,
+}
+
+impl<'a, T> Iterator for IterMut<'a, T> {
+    type Item = &'a mut T;
+
+    #[inline]
+    fn next(&mut self) -> Option<&'a mut T> {
+        self.inner.take()
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let n = if self.inner.is_some() { 1 } else { 0 };
+        (n, Some(n))
+    }
+}
+
+/// An iterator over the value in a [`Ok`] variant of a [`Result`].
+///
+/// Created by the [`into_iter`](IntoIterator::into_iter) method on
+/// [`Result`] (provided by the [`IntoIterator`] trait).
+pub struct IntoIter<T> {
+    inner: Option<T>,
+}
+
+impl<T> Iterator for IntoIter<T> {
+    type Item = T;
+
+    #[inline]
+    fn next(&mut self) -> Option<T> {
+        self.inner.take()
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let n = if self.inner.is_some() { 1 } else { 0 };
+        (n, Some(n))
+    }
+}
+
+impl<T: Clone> Clone for IntoIter<T> {
+    #[inline]
+    fn clone(&self) -> Self {
+        IntoIter {
+            inner: self.inner.clone(),
+        }
+    }
+}
+
+impl<A, E, V: FromIterator<A>> FromIterator<Result<A, E>> for Result<V, E> {
+    /// Takes each element in the iterator: if it is an `Err`, no further
+    /// elements are taken, and the `Err` is returned. Should no `Err` occur, a
+    /// container with the values of each `Result` is returned.
+    #[inline]
+    fn from_iter<I: IntoIterator<Item = Result<A, E>>>(iter: I) -> Result<V, E> {
+        iter::try_process(iter.into_iter(), |i| i.collect())
+    }
+}
+
+impl<T, E> Try for Result<T, E> {
+    type Output = T;
+    type Residual = Result<Infallible, E>;
+
+    #[inline]
+    fn from_output(output: Self::Output) -> Self {
+        Ok(output)
+    }
+
+    #[inline]
+    fn branch(self) -> ControlFlow<Self::Residual, Self::Output> {
+        match self {
+            Ok(value) => ControlFlow::Continue(value),
+            Err(err) => ControlFlow::Break(Err(err)),
+        }
+    }
+}
+
+// The error of the residual goes through `From` so that `?` can convert
+// between error types.
+impl<T, E, F: From<E>> FromResidual<Result<Infallible, E>> for Result<T, F> {
+    #[inline]
+    #[track_caller]
+    fn from_residual(residual: Result<Infallible, E>) -> Self {
+        match residual {
+            Err(err) => Err(From::from(err)),
+        }
+    }
+}
+
+impl<T, E, F: From<E>> FromResidual<Yeet<E>> for Result<T, F> {
+    #[inline]
+    fn from_residual(Yeet(err): Yeet<E>) -> Self {
+        Err(From::from(err))
+    }
+}
+
+impl<T, E> Residual<T> for Result<Infallible, E> {
+    type TryType = Result<T, E>;
+}
@@ -0,0 +1,353 @@
+//! Indexing implementations for `[T]`.
+
+use crate::marker::Sized;
+use crate::ops::{
+    Index, IndexMut, Range, RangeFrom, RangeFull, RangeInclusive, RangeTo, RangeToInclusive,
+};
+use crate::option::Option::{self, None, Some};
+use crate::panicking::panic;
+use crate::slice::{from_raw_parts, from_raw_parts_mut};
+
+impl<T, I> Index<I> for [T]
+where
+    I: SliceIndex<[T]>,
+{
+    type Output = I::Output;
+
+    #[inline(always)]
+    fn index(&self, index: I) -> &I::Output {
+        index.index(self)
+    }
+}
+
+impl<T, I> IndexMut<I> for [T]
+where
+    I: SliceIndex<[T]>,
+{
+    #[inline(always)]
+    fn index_mut(&mut self, index: I) -> &mut I::Output {
+        index.index_mut(self)
+    }
+}
+
+// TODO: put the indexes and the length in the messages once the panics can be
+// formatted.
+// TODO: `usize::MAX` instead of `!0` once the integers have their inherent
+// constants.
+
+#[inline(never)]
+#[cold]
+#[track_caller]
+fn slice_index_len_fail() -> ! {
+    panic("index out of bounds for slice")
+}
+
+#[inline(never)]
+#[cold]
+#[track_caller]
+fn slice_start_index_len_fail() -> ! {
+    panic("range start index out of range for slice")
+}
+
+#[inline(never)]
+#[cold]
+#[track_caller]
+fn slice_end_index_len_fail() -> ! {
+    panic("range end index out of range for slice")
+}
+
+#[inline(never)]
+#[cold]
+#[track_caller]
+fn slice_index_order_fail() -> ! {
+    panic("slice index starts after its end")
+}
+
+#[inline(never)]
+#[cold]
+#[track_caller]
+fn slice_end_index_overflow_fail() -> ! {
+    panic("attempted to index slice up to maximum usize")
+}
+
+mod private_slice_index {
+    use crate::ops::{Range, RangeFrom, RangeFull, RangeInclusive, RangeTo, RangeToInclusive};
+
+    pub trait Sealed {}
+
+    impl Sealed for usize {}
+    impl Sealed for Range<usize> {}
+    impl Sealed for RangeTo<usize> {}
+    impl Sealed for RangeFrom<usize> {}
+    impl Sealed for RangeFull {}
+    impl Sealed for RangeInclusive<usize> {}
+    impl Sealed for RangeToInclusive<usize> {}
+}
+
+/// A helper trait used for indexing operations.
+///
+/// It is implemented by `usize` and the ranges of `usize` to index slices.
+// TODO: `get_unchecked(_mut)`
+#[rustc_on_unimplemented(
+    on(T = "str", label = "string indices are ranges of `usize`",),
+    message = "the type `{T}` cannot be indexed by `{Self}`",
+    label = "slice indices are of type `usize` or ranges of `usize`"
+)]
+pub unsafe trait SliceIndex<T: ?Sized>: private_slice_index::Sealed {
+    /// The output type returned by methods.
+    type Output: ?Sized;
+
+    /// Returns a shared reference to the output at this location, if in
+    /// bounds.
+    fn get(self, slice: &T) -> Option<&Self::Output>;
+
+    /// Returns a mutable reference to the output at this location, if in
+    /// bounds.
+    fn get_mut(self, slice: &mut T) -> Option<&mut Self::Output>;
+
+    /// Returns a shared reference to the output at this location, panicking
+    /// if out of bounds.
+    #[track_caller]
+    fn index(self, slice: &T) -> &Self::Output;
+
+    /// Returns a mutable reference to the output at this location, panicking
+    /// if out of bounds.
+    #[track_caller]
+    fn index_mut(self, slice: &mut T) -> &mut Self::Output;
+}
+
+unsafe impl<T> SliceIndex<[T]> for usize {
+    type Output = T;
+
+    #[inline]
+    fn get(self, slice: &[T]) -> Option<&T> {
+        if self < slice.len() {
+            // SAFETY: `self` is checked to be in bounds.
+            unsafe { Some(&*slice.as_ptr().add(self)) }
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    fn get_mut(self, slice: &mut [T]) -> Option<&mut T> {
+        if self < slice.len() {
+            // SAFETY: `self` is checked to be in bounds.
+            unsafe { Some(&mut *slice.as_mut_ptr().add(self)) }
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    fn index(self, slice: &[T]) -> &T {
+        match self.get(slice) {
+            Some(value) => value,
+            None => slice_index_len_fail(),
+        }
+    }
+
+    #[inline]
+    fn index_mut(self, slice: &mut [T]) -> &mut T {
+        match self.get_mut(slice) {
+            Some(value) => value,
+            None => slice_index_len_fail(),
+        }
+    }
+}
+
+unsafe impl<T> SliceIndex<[T]> for Range<usize> {
+    type Output = [T];
+
+    #[inline]
+    fn get(self, slice: &[T]) -> Option<&[T]> {
+        if self.start > self.end || self.end > slice.len() {
+            None
+        } else {
+            // SAFETY: `self` is checked to be valid and in bounds.
+            unsafe {
+                Some(from_raw_parts(
+                    slice.as_ptr().add(self.start),
+                    self.end - self.start,
+                ))
+            }
+        }
+    }
+
+    #[inline]
+    fn get_mut(self, slice: &mut [T]) -> Option<&mut [T]> {
+        if self.start > self.end || self.end > slice.len() {
+            None
+        } else {
+            // SAFETY: `self` is checked to be valid and in bounds.
+            unsafe {
+                Some(from_raw_parts_mut(
+                    slice.as_mut_ptr().add(self.start),
+                    self.end - self.start,
+                ))
+            }
+        }
+    }
+
+    #[inline]
+    fn index(self, slice: &[T]) -> &[T] {
+        if self.start > self.end {
+            slice_index_order_fail()
+        } else if self.end > slice.len() {
+            slice_end_index_len_fail()
+        }
+        // SAFETY: `self` is checked to be valid and in bounds.
+        unsafe { from_raw_parts(slice.as_ptr().add(self.start), self.end - self.start) }
+    }
+
+    #[inline]
+    fn index_mut(self, slice: &mut [T]) -> &mut [T] {
+        if self.start > self.end {
+            slice_index_order_fail()
+        } else if self.end > slice.len() {
+            slice_end_index_len_fail()
+        }
+        // SAFETY: `self` is checked to be valid and in bounds.
+        unsafe { from_raw_parts_mut(slice.as_mut_ptr().add(self.start), self.end - self.start) }
+    }
+}
+
+unsafe impl<T> SliceIndex<[T]> for RangeTo<usize> {
+    type Output = [T];
+
+    #[inline]
+    fn get(self, slice: &[T]) -> Option<&[T]> {
+        (0..self.end).get(slice)
+    }
+
+    #[inline]
+    fn get_mut(self, slice: &mut [T]) -> Option<&mut [T]> {
+        (0..self.end).get_mut(slice)
+    }
+
+    #[inline]
+    fn index(self, slice: &[T]) -> &[T] {
+        (0..self.end).index(slice)
+    }
+
+    #[inline]
+    fn index_mut(self, slice: &mut [T]) -> &mut [T] {
+        (0..self.end).index_mut(slice)
+    }
+}
+
+unsafe impl<T> SliceIndex<[T]> for RangeFrom<usize> {
+    type Output = [T];
+
+    #[inline]
+    fn get(self, slice: &[T]) -> Option<&[T]> {
+        (self.start..slice.len()).get(slice)
+    }
+
+    #[inline]
+    fn get_mut(self, slice: &mut [T]) -> Option<&mut [T]> {
+        (self.start..slice.len()).get_mut(slice)
+    }
+
+    #[inline]
+    fn index(self, slice: &[T]) -> &[T] {
+        if self.start > slice.len() {
+            slice_start_index_len_fail()
+        }
+        (self.start..slice.len()).index(slice)
+    }
+
+    #[inline]
+    fn index_mut(self, slice: &mut [T]) -> &mut [T] {
+        if self.start > slice.len() {
+            slice_start_index_len_fail()
+        }
+        (self.start..slice.len()).index_mut(slice)
+    }
+}
+
+unsafe impl<T> SliceIndex<[T]> for RangeFull {
+    type Output = [T];
+
+    #[inline]
+    fn get(self, slice: &[T]) -> Option<&[T]> {
+        Some(slice)
+    }
+
+    #[inline]
+    fn get_mut(self, slice: &mut [T]) -> Option<&mut [T]> {
+        Some(slice)
+    }
+
+    #[inline]
+    fn index(self, slice: &[T]) -> &[T] {
+        slice
+    }
+
+    #[inline]
+    fn index_mut(self, slice: &mut [T]) -> &mut [T] {
+        slice
+    }
+}
+
+unsafe impl<T> SliceIndex<[T]> for RangeInclusive<usize> {
+    type Output = [T];
+
+    #[inline]
+    fn get(self, slice: &[T]) -> Option<&[T]> {
+        if self.end == !0 {
+            None
+        } else {
+            self.into_slice_range().get(slice)
+        }
+    }
+
+    #[inline]
+    fn get_mut(self, slice: &mut [T]) -> Option<&mut [T]> {
+        if self.end == !0 {
+            None
+        } else {
+            self.into_slice_range().get_mut(slice)
+        }
+    }
+
+    #[inline]
+    fn index(self, slice: &[T]) -> &[T] {
+        if self.end == !0 {
+            slice_end_index_overflow_fail();
+        }
+        self.into_slice_range().index(slice)
+    }
+
+    #[inline]
+    fn index_mut(self, slice: &mut [T]) -> &mut [T] {
+        if self.end == !0 {
+            slice_end_index_overflow_fail();
+        }
+        self.into_slice_range().index_mut(slice)
+    }
+}
+
+unsafe impl<T> SliceIndex<[T]> for RangeToInclusive<usize> {
+    type Output = [T];
+
+    #[inline]
+    fn get(self, slice: &[T]) -> Option<&[T]> {
+        (0..=self.end).get(slice)
+    }
+
+    #[inline]
+    fn get_mut(self, slice: &mut [T]) -> Option<&mut [T]> {
+        (0..=self.end).get_mut(slice)
+    }
+
+    #[inline]
+    fn index(self, slice: &[T]) -> &[T] {
+        (0..=self.end).index(slice)
+    }
+
+    #[inline]
+    fn index_mut(self, slice: &mut [T]) -> &mut [T] {
+        (0..=self.end).index_mut(slice)
+    }
+}
@@ -0,0 +1,165 @@
+//! Slice management and manipulation.
+
+use crate::default::Default;
+use crate::intrinsics::{aggregate_raw_ptr, ptr_metadata};
+use crate::option::Option;
+use crate::panicking::panic;
+
+mod index;
+
+pub use index::SliceIndex;
+
+impl<T> [T] {
+    /// Returns the number of elements in the slice.
+    #[lang = "slice_len_fn"]
+    #[inline]
+    #[must_use]
+    pub const fn len(&self) -> usize {
+        ptr_metadata(self)
+    }
+
+    /// Returns `true` if the slice has a length of 0.
+    #[inline]
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns a raw pointer to the slice's buffer.
+    #[inline]
+    #[must_use]
+    pub const fn as_ptr(&self) -> *const T {
+        self as *const [T] as *const T
+    }
+
+    /// Returns an unsafe mutable pointer to the slice's buffer.
+    #[inline]
+    #[must_use]
+    pub const fn as_mut_ptr(&mut self) -> *mut T {
+        self as *mut [T] as *mut T
+    }
+
+    /// Returns a reference to an element or subslice depending on the type of
+    /// index, or `None` if the index is out of bounds.
+    #[inline]
+    #[must_use]
+    pub fn get<I: SliceIndex<Self>>(&self, index: I) -> Option<&I::Output> {
+        index.get(self)
+    }
+
+    /// Returns a mutable reference to an element or subslice depending on the
+    /// type of index, or `None` if the index is out of bounds.
+    #[inline]
+    #[must_use]
+    pub fn get_mut<I: SliceIndex<Self>>(&mut self, index: I) -> Option<&mut I::Output> {
+        index.get_mut(self)
+    }
+
+    /// Divides one slice into two at an index.
+    ///
+    /// The first will contain all indices from `[0, mid)` (excluding the
+    /// index `mid` itself) and the second will contain all indices from
+    /// `[mid, len)` (excluding the index `len` itself).
+    ///
+    /// # Panics
+    ///
+    /// Panics if `mid > len`.
+    #[inline]
+    #[track_caller]
+    #[must_use]
+    pub fn split_at(&self, mid: usize) -> (&[T], &[T]) {
+        if mid > self.len() {
+            panic("mid > len")
+        }
+        let ptr = self.as_ptr();
+        // SAFETY: `[ptr; mid]` and `[mid; len]` are inside `self`.
+        unsafe {
+            (
+                from_raw_parts(ptr, mid),
+                from_raw_parts(ptr.add(mid), self.len() - mid),
+            )
+        }
+    }
+
+    /// Divides one mutable slice into two at an index.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `mid > len`.
+    #[inline]
+    #[track_caller]
+    #[must_use]
+    pub fn split_at_mut(&mut self, mid: usize) -> (&mut [T], &mut [T]) {
+        let len = self.len();
+        if mid > len {
+            panic("mid > len")
+        }
+        let ptr = self.as_mut_ptr();
+        // SAFETY: `[ptr; mid]` and `[mid; len]` are inside `self` and do not
+        // overlap.
+        unsafe {
+            (
+                from_raw_parts_mut(ptr, mid),
+                from_raw_parts_mut(ptr.add(mid), len - mid),
+            )
+        }
+    }
+}
+
+/// Forms a slice from a pointer and a length.
+///
+/// # Safety
+///
+/// `data` must be non-null, aligned and valid for reads for `len` consecutive
+/// values of type `T`, which must not be mutated for the lifetime `'a`.
+#[inline]
+#[must_use]
+pub const unsafe fn from_raw_parts<'a, T>(data: *const T, len: usize) -> &'a [T] {
+    // SAFETY: the caller must uphold the safety contract for `from_raw_parts`.
+    unsafe { &*aggregate_raw_ptr::<*const [T], *const T, usize>(data, len) }
+}
+
+/// Performs the same functionality as [`from_raw_parts`], except that a
+/// mutable slice is returned.
+///
+/// # Safety
+///
+/// Same as [`from_raw_parts`], with the memory being also valid for writes
+/// and not accessed through any other pointer for the lifetime `'a`.
+#[inline]
+#[must_use]
+pub const unsafe fn from_raw_parts_mut<'a, T>(data: *mut T, len: usize) -> &'a mut [T] {
+    // SAFETY: the caller must uphold the safety contract for `from_raw_parts_mut`.
+    unsafe { &mut *aggregate_raw_ptr::<*mut [T], *mut T, usize>(data, len) }
+}
+
+/// Converts a reference to `T` into a slice of length 1 (without copying).
+#[inline]
+#[must_use]
+pub const fn from_ref<T>(s: &T) -> &[T] {
+    // SAFETY: a reference is guaranteed to be valid for reads.
+    unsafe { from_raw_parts(s, 1) }
+}
+
+/// Converts a mutable reference to `T` into a slice of length 1 (without
+/// copying).
+#[inline]
+#[must_use]
+pub const fn from_mut<T>(s: &mut T) -> &mut [T] {
+    // SAFETY: a mutable reference is guaranteed to be valid for writes.
+    unsafe { from_raw_parts_mut(s, 1) }
+}
+
+impl<T> Default for &[T] {
+    /// Creates an empty slice.
+    fn default() -> Self {
+        &[]
+    }
+}
+
+impl<T> Default for &mut [T] {
+    /// Creates a mutable empty slice.
+    fn default() -> Self {
+        &mut []
+    }
+}
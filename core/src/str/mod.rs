@@ -0,0 +1,80 @@
+//! String manipulation.
+
+use crate::default::Default;
+use crate::intrinsics::transmute;
+
+impl str {
+    /// Returns the length of `self`, in bytes (not in `char`s).
+    #[inline]
+    #[must_use]
+    pub const fn len(&self) -> usize {
+        self.as_bytes().len()
+    }
+
+    /// Returns `true` if `self` has a length of zero bytes.
+    #[inline]
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Converts a string slice to a byte slice.
+    #[inline(always)]
+    #[must_use]
+    pub const fn as_bytes(&self) -> &[u8] {
+        // SAFETY: `str` and `[u8]` have the same layout.
+        unsafe { transmute(self) }
+    }
+
+    /// Converts a string slice to a raw pointer.
+    #[inline(always)]
+    #[must_use]
+    pub const fn as_ptr(&self) -> *const u8 {
+        self as *const str as *const u8
+    }
+}
+
+/// Converts a slice of bytes to a string slice without checking that it
+/// contains valid UTF-8.
+///
+/// # Safety
+///
+/// The bytes passed in must be valid UTF-8.
+#[inline]
+#[must_use]
+pub const unsafe fn from_utf8_unchecked(v: &[u8]) -> &str {
+    // SAFETY: the caller must guarantee that the bytes `v` are valid UTF-8.
+    // Also relies on `&str` and `&[u8]` having the same layout.
+    unsafe { transmute(v) }
+}
+
+/// Converts a mutable slice of bytes to a mutable string slice without
+/// checking that it contains valid UTF-8.
+///
+/// # Safety
+///
+/// The bytes passed in must be valid UTF-8.
+#[inline]
+#[must_use]
+pub const unsafe fn from_utf8_unchecked_mut(v: &mut [u8]) -> &mut str {
+    // SAFETY: the caller must guarantee that the bytes `v` are valid UTF-8.
+    // Also relies on `&mut str` and `&mut [u8]` having the same layout.
+    unsafe { transmute(v) }
+}
+
+impl Default for &str {
+    /// Creates an empty str
+    #[inline]
+    fn default() -> Self {
+        ""
+    }
+}
+
+impl Default for &mut str {
+    /// Creates an empty mutable str
+    #[inline]
+    fn default() -> Self {
+        // SAFETY: The empty string is valid UTF-8.
+        unsafe { from_utf8_unchecked_mut(&mut []) }
+    }
+}
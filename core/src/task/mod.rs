@@ -0,0 +1,9 @@
+//! Types and Traits for working with asynchronous tasks.
+
+mod poll;
+mod ready;
+mod wake;
+
+pub use poll::Poll;
+pub use ready::ready;
+pub use wake::{Context, RawWaker, RawWakerVTable, Waker};
@@ -0,0 +1,261 @@
+use crate::clone::Clone;
+use crate::cmp::{Eq, Ord, Ordering, PartialEq, PartialOrd};
+use crate::convert::{From, Infallible};
+use crate::fmt::{self, Debug, Formatter};
+use crate::hash::{Hash, Hasher};
+use crate::marker::Copy;
+use crate::ops::{ControlFlow, FnOnce, FromResidual, Try};
+use crate::option::Option::{self, None, Some};
+use crate::result::Result::{self, Err, Ok};
+
+/// Indicates whether a value is available or if the current task has been
+/// scheduled to receive a wakeup instead.
+#[must_use = "this `Poll` may be a `Pending` variant, which should be handled"]
+#[lang = "Poll"]
+pub enum Poll<T> {
+    /// Represents that a value is immediately ready.
+    #[lang = "Ready"]
+    Ready(T),
+
+    /// Represents that a value is not ready yet.
+    ///
+    /// When a function returns `Pending`, the function *must* also ensure
+    /// that the current task is scheduled to be awoken when progress can be
+    /// made.
+    #[lang = "Pending"]
+    Pending,
+}
+
+impl<T> Poll<T> {
+    /// Maps a `Poll<T>` to `Poll<U>` by applying a function to a contained
+    /// value.
+    #[inline]
+    pub fn map<U, F>(self, f: F) -> Poll<U>
+    where
+        F: FnOnce(T) -> U,
+    {
+        match self {
+            Poll::Ready(t) => Poll::Ready(f(t)),
+            Poll::Pending => Poll::Pending,
+        }
+    }
+
+    /// Returns `true` if the poll is a [`Poll::Ready`] value.
+    #[inline]
+    pub const fn is_ready(&self) -> bool {
+        match self {
+            Poll::Ready(_) => true,
+            Poll::Pending => false,
+        }
+    }
+
+    /// Returns `true` if the poll is a [`Pending`](Poll::Pending) value.
+    #[inline]
+    pub const fn is_pending(&self) -> bool {
+        !self.is_ready()
+    }
+}
+
+impl<T, E> Poll<Result<T, E>> {
+    /// Maps a `Poll<Result<T, E>>` to `Poll<Result<U, E>>` by applying a
+    /// function to a contained `Poll::Ready(Ok)` value, leaving all other
+    /// variants untouched.
+    #[inline]
+    pub fn map_ok<U, F>(self, f: F) -> Poll<Result<U, E>>
+    where
+        F: FnOnce(T) -> U,
+    {
+        match self {
+            Poll::Ready(Ok(t)) => Poll::Ready(Ok(f(t))),
+            Poll::Ready(Err(e)) => Poll::Ready(Err(e)),
+            Poll::Pending => Poll::Pending,
+        }
+    }
+
+    /// Maps a `Poll::Ready<Result<T, E>>` to `Poll::Ready<Result<T, F>>` by
+    /// applying a function to a contained `Poll::Ready(Err)` value, leaving
+    /// all other variants untouched.
+    #[inline]
+    pub fn map_err<U, F>(self, f: F) -> Poll<Result<T, U>>
+    where
+        F: FnOnce(E) -> U,
+    {
+        match self {
+            Poll::Ready(Ok(t)) => Poll::Ready(Ok(t)),
+            Poll::Ready(Err(e)) => Poll::Ready(Err(f(e))),
+            Poll::Pending => Poll::Pending,
+        }
+    }
+}
+
+impl<T, E> Poll<Option<Result<T, E>>> {
+    /// Maps a `Poll<Option<Result<T, E>>>` to `Poll<Option<Result<U, E>>>` by
+    /// applying a function to a contained `Poll::Ready(Some(Ok))` value,
+    /// leaving all other variants untouched.
+    #[inline]
+    pub fn map_ok<U, F>(self, f: F) -> Poll<Option<Result<U, E>>>
+    where
+        F: FnOnce(T) -> U,
+    {
+        match self {
+            Poll::Ready(Some(Ok(t))) => Poll::Ready(Some(Ok(f(t)))),
+            Poll::Ready(Some(Err(e))) => Poll::Ready(Some(Err(e))),
+            Poll::Ready(None) => Poll::Ready(None),
+            Poll::Pending => Poll::Pending,
+        }
+    }
+
+    /// Maps a `Poll::Ready<Option<Result<T, E>>>` to
+    /// `Poll::Ready<Option<Result<T, F>>>` by applying a function to a
+    /// contained `Poll::Ready(Some(Err))` value, leaving all other variants
+    /// untouched.
+    #[inline]
+    pub fn map_err<U, F>(self, f: F) -> Poll<Option<Result<T, U>>>
+    where
+        F: FnOnce(E) -> U,
+    {
+        match self {
+            Poll::Ready(Some(Ok(t))) => Poll::Ready(Some(Ok(t))),
+            Poll::Ready(Some(Err(e))) => Poll::Ready(Some(Err(f(e)))),
+            Poll::Ready(None) => Poll::Ready(None),
+            Poll::Pending => Poll::Pending,
+        }
+    }
+}
+
+impl<T> From<T> for Poll<T> {
+    /// Moves the value into a [`Poll::Ready`] to make a `Poll<T>`.
+    fn from(t: T) -> Poll<T> {
+        Poll::Ready(t)
+    }
+}
+
+impl<T: Clone> Clone for Poll<T> {
+    #[inline]
+    fn clone(&self) -> Self {
+        match self {
+            Poll::Ready(t) => Poll::Ready(t.clone()),
+            Poll::Pending => Poll::Pending,
+        }
+    }
+}
+
+impl<T: Copy> Copy for Poll<T> {}
+
+impl<T: PartialEq> PartialEq for Poll<T> {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Poll::Ready(a), Poll::Ready(b)) => *a == *b,
+            (Poll::Pending, Poll::Pending) => true,
+            _ => false,
+        }
+    }
+}
+
+impl<T: Eq> Eq for Poll<T> {}
+
+// `Ready` is always less than `Pending`, like a derive would do with the
+// variants declared in this order.
+impl<T: PartialOrd> PartialOrd for Poll<T> {
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        match (self, other) {
+            (Poll::Ready(a), Poll::Ready(b)) => a.partial_cmp(b),
+            (Poll::Ready(_), Poll::Pending) => Some(Ordering::Less),
+            (Poll::Pending, Poll::Ready(_)) => Some(Ordering::Greater),
+            (Poll::Pending, Poll::Pending) => Some(Ordering::Equal),
+        }
+    }
+}
+
+impl<T: Ord> Ord for Poll<T> {
+    #[inline]
+    fn cmp(&self, other: &Self) -> Ordering {
+        match (self, other) {
+            (Poll::Ready(a), Poll::Ready(b)) => a.cmp(b),
+            (Poll::Ready(_), Poll::Pending) => Ordering::Less,
+            (Poll::Pending, Poll::Ready(_)) => Ordering::Greater,
+            (Poll::Pending, Poll::Pending) => Ordering::Equal,
+        }
+    }
+}
+
+impl<T: Hash> Hash for Poll<T> {
+    #[inline]
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        match self {
+            Poll::Ready(t) => {
+                state.write_isize(0);
+                t.hash(state);
+            }
+            Poll::Pending => state.write_isize(1),
+        }
+    }
+}
+
+impl<T: Debug> Debug for Poll<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Poll::Ready(t) => f.debug_tuple("Ready").field(t).finish(),
+            Poll::Pending => f.write_str("Pending"),
+        }
+    }
+}
+
+impl<T, E> Try for Poll<Result<T, E>> {
+    type Output = Poll<T>;
+    type Residual = Result<Infallible, E>;
+
+    #[inline]
+    fn from_output(c: Self::Output) -> Self {
+        c.map(Ok)
+    }
+
+    #[inline]
+    fn branch(self) -> ControlFlow<Self::Residual, Self::Output> {
+        match self {
+            Poll::Ready(Ok(x)) => ControlFlow::Continue(Poll::Ready(x)),
+            Poll::Ready(Err(e)) => ControlFlow::Break(Err(e)),
+            Poll::Pending => ControlFlow::Continue(Poll::Pending),
+        }
+    }
+}
+
+impl<T, E, F: From<E>> FromResidual<Result<Infallible, E>> for Poll<Result<T, F>> {
+    #[inline]
+    fn from_residual(x: Result<Infallible, E>) -> Self {
+        match x {
+            Err(e) => Poll::Ready(Err(From::from(e))),
+        }
+    }
+}
+
+impl<T, E> Try for Poll<Option<Result<T, E>>> {
+    type Output = Poll<Option<T>>;
+    type Residual = Result<Infallible, E>;
+
+    #[inline]
+    fn from_output(c: Self::Output) -> Self {
+        c.map(|x| x.map(Ok))
+    }
+
+    #[inline]
+    fn branch(self) -> ControlFlow<Self::Residual, Self::Output> {
+        match self {
+            Poll::Ready(Some(Ok(x))) => ControlFlow::Continue(Poll::Ready(Some(x))),
+            Poll::Ready(Some(Err(e))) => ControlFlow::Break(Err(e)),
+            Poll::Ready(None) => ControlFlow::Continue(Poll::Ready(None)),
+            Poll::Pending => ControlFlow::Continue(Poll::Pending),
+        }
+    }
+}
+
+impl<T, E, F: From<E>> FromResidual<Result<Infallible, E>> for Poll<Option<Result<T, F>>> {
+    #[inline]
+    fn from_residual(x: Result<Infallible, E>) -> Self {
+        match x {
+            Err(e) => Poll::Ready(Some(Err(From::from(e)))),
+        }
+    }
+}
@@ -0,0 +1,283 @@
+use crate::clone::Clone;
+use crate::fmt::{self, Debug, Formatter};
+use crate::marker::{Copy, PhantomData, Send, Sync, Unpin};
+use crate::mem::ManuallyDrop;
+use crate::ops::Drop;
+use crate::ptr;
+
+/// A `RawWaker` allows the implementor of a task executor to create a
+/// [`Waker`] or a `LocalWaker` which provides customized wakeup behavior.
+///
+/// It consists of a data pointer and a [virtual function pointer table
+/// (vtable)][vtable] that customizes the behavior of the `RawWaker`.
+///
+/// [vtable]: https://en.wikipedia.org/wiki/Virtual_method_table
+pub struct RawWaker {
+    /// A data pointer, which can be used to store arbitrary data as required
+    /// by the executor. This could be e.g. a type-erased pointer to an `Arc`
+    /// that is associated with the task.
+    data: *const (),
+    /// Virtual function pointer table that customizes the behavior of this
+    /// waker.
+    vtable: &'static RawWakerVTable,
+}
+
+impl RawWaker {
+    const NOOP: RawWaker = {
+        const VTABLE: RawWakerVTable = RawWakerVTable::new(
+            // Cloning just returns a new no-op raw waker
+            |_| RawWaker::NOOP,
+            // `wake` does nothing
+            |_| {},
+            // `wake_by_ref` does nothing
+            |_| {},
+            // Dropping does nothing as we don't allocate anything
+            |_| {},
+        );
+        RawWaker::new(ptr::null(), &VTABLE)
+    };
+
+    /// Creates a new `RawWaker` from the provided `data` pointer and
+    /// `vtable`.
+    #[inline]
+    #[must_use]
+    pub const fn new(data: *const (), vtable: &'static RawWakerVTable) -> RawWaker {
+        RawWaker { data, vtable }
+    }
+}
+
+/// A virtual function pointer table (vtable) that specifies the behavior of
+/// a [`RawWaker`].
+///
+/// The pointer passed to all functions inside the vtable is the `data`
+/// pointer from the enclosing [`RawWaker`] object.
+pub struct RawWakerVTable {
+    /// This function will be called when the [`RawWaker`] gets cloned, e.g.
+    /// when the [`Waker`] in which the [`RawWaker`] is stored gets cloned.
+    clone: unsafe fn(*const ()) -> RawWaker,
+
+    /// This function will be called when `wake` is called on the [`Waker`].
+    /// It must wake up the task associated with this [`RawWaker`].
+    wake: unsafe fn(*const ()),
+
+    /// This function will be called when `wake_by_ref` is called on the
+    /// [`Waker`]. It must wake up the task associated with this
+    /// [`RawWaker`], without consuming the data pointer.
+    wake_by_ref: unsafe fn(*const ()),
+
+    /// This function will be called when a [`Waker`] gets dropped.
+    drop: unsafe fn(*const ()),
+}
+
+impl RawWakerVTable {
+    /// Creates a new `RawWakerVTable` from the provided `clone`, `wake`,
+    /// `wake_by_ref`, and `drop` functions.
+    pub const fn new(
+        clone: unsafe fn(*const ()) -> RawWaker,
+        wake: unsafe fn(*const ()),
+        wake_by_ref: unsafe fn(*const ()),
+        drop: unsafe fn(*const ()),
+    ) -> Self {
+        Self {
+            clone,
+            wake,
+            wake_by_ref,
+            drop,
+        }
+    }
+}
+
+impl Clone for RawWakerVTable {
+    #[inline]
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl Copy for RawWakerVTable {}
+
+/// The context of an asynchronous task.
+///
+/// Currently, `Context` only serves to provide access to a [`&Waker`](Waker)
+/// which can be used to wake the current task.
+#[lang = "Context"]
+pub struct Context<'a> {
+    waker: &'a Waker,
+    // Ensure we future-proof against variance changes by forcing the lifetime
+    // to be invariant (argument-position lifetimes are contravariant while
+    // return-position lifetimes are covariant).
+    _marker: PhantomData<fn(&'a ()) -> &'a ()>,
+    // Ensure `Context` is `!Send` and `!Sync` in order to allow for future
+    // `!Send` and / or `!Sync` fields.
+    _marker2: PhantomData<*mut ()>,
+}
+
+impl<'a> Context<'a> {
+    /// Creates a new `Context` from a [`&Waker`](Waker).
+    #[must_use]
+    #[inline]
+    pub const fn from_waker(waker: &'a Waker) -> Self {
+        Context {
+            waker,
+            _marker: PhantomData,
+            _marker2: PhantomData,
+        }
+    }
+
+    /// Returns a reference to the [`Waker`] for the current task.
+    #[inline]
+    #[must_use]
+    pub const fn waker(&self) -> &'a Waker {
+        self.waker
+    }
+}
+
+impl Debug for Context<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Context")
+            .field("waker", &self.waker)
+            .finish()
+    }
+}
+
+/// A `Waker` is a handle for waking up a task by notifying its executor that
+/// it is ready to be run.
+///
+/// This handle encapsulates a [`RawWaker`] instance, which defines the
+/// executor-specific wakeup behavior.
+#[repr(transparent)]
+#[rustc_diagnostic_item = "Waker"]
+pub struct Waker {
+    waker: RawWaker,
+}
+
+impl Unpin for Waker {}
+unsafe impl Send for Waker {}
+unsafe impl Sync for Waker {}
+
+impl Waker {
+    /// Wakes up the task associated with this `Waker`.
+    #[inline]
+    pub fn wake(self) {
+        // The actual wakeup call is delegated through a virtual function call
+        // to the implementation which is defined by the executor.
+
+        // Don't call `drop` -- the waker will be consumed by `wake`.
+        let this = ManuallyDrop::new(self);
+
+        // SAFETY: This is safe because `Waker::from_raw` is the only way to
+        // initialize `wake` and `data` requiring the user to acknowledge that
+        // the contract of `RawWaker` is upheld.
+        unsafe { (this.waker.vtable.wake)(this.waker.data) };
+    }
+
+    /// Wakes up the task associated with this `Waker` without consuming the
+    /// `Waker`.
+    #[inline]
+    pub fn wake_by_ref(&self) {
+        // SAFETY: see `wake`
+        unsafe { (self.waker.vtable.wake_by_ref)(self.waker.data) }
+    }
+
+    /// Returns `true` if this `Waker` and another `Waker` would awake the
+    /// same task.
+    ///
+    /// This function works on a best-effort basis, and may return false even
+    /// when the `Waker`s would awaken the same task.
+    #[inline]
+    #[must_use]
+    pub fn will_wake(&self, other: &Waker) -> bool {
+        // We optimize this by comparing vtable addresses instead of vtable
+        // contents. This is permitted since the function is documented as
+        // best-effort.
+        self.waker.data == other.waker.data && ptr::eq(self.waker.vtable, other.waker.vtable)
+    }
+
+    /// Creates a new `Waker` from a provided `data` pointer and `vtable`.
+    ///
+    /// # Safety
+    ///
+    /// The behavior of the returned `Waker` is undefined if the contract
+    /// defined in [`RawWakerVTable`]'s documentation is not upheld.
+    #[inline]
+    #[must_use]
+    pub const unsafe fn new(data: *const (), vtable: &'static RawWakerVTable) -> Self {
+        Waker {
+            waker: RawWaker { data, vtable },
+        }
+    }
+
+    /// Creates a new `Waker` from [`RawWaker`].
+    ///
+    /// # Safety
+    ///
+    /// The behavior of the returned `Waker` is undefined if the contract
+    /// defined in [`RawWaker`]'s and [`RawWakerVTable`]'s documentation is not
+    /// upheld.
+    #[inline]
+    #[must_use]
+    pub const unsafe fn from_raw(waker: RawWaker) -> Waker {
+        Waker { waker }
+    }
+
+    /// Returns a reference to a `Waker` that does nothing when used.
+    #[inline]
+    #[must_use]
+    pub const fn noop() -> &'static Waker {
+        const WAKER: &Waker = &Waker {
+            waker: RawWaker::NOOP,
+        };
+        WAKER
+    }
+
+    /// Gets the `data` pointer used to create this `Waker`.
+    #[inline]
+    #[must_use]
+    pub fn data(&self) -> *const () {
+        self.waker.data
+    }
+
+    /// Gets the `vtable` pointer used to create this `Waker`.
+    #[inline]
+    #[must_use]
+    pub fn vtable(&self) -> &'static RawWakerVTable {
+        self.waker.vtable
+    }
+}
+
+impl Clone for Waker {
+    #[inline]
+    fn clone(&self) -> Self {
+        Waker {
+            // SAFETY: This is safe because `Waker::from_raw` is the only way
+            // to initialize `clone` and `data` requiring the user to
+            // acknowledge that the contract of [`RawWaker`] is upheld.
+            waker: unsafe { (self.waker.vtable.clone)(self.waker.data) },
+        }
+    }
+
+    #[inline]
+    fn clone_from(&mut self, source: &Self) {
+        if !self.will_wake(source) {
+            *self = source.clone();
+        }
+    }
+}
+
+impl Drop for Waker {
+    #[inline]
+    fn drop(&mut self) {
+        // SAFETY: This is safe because `Waker::from_raw` is the only way to
+        // initialize `drop` and `data` requiring the user to acknowledge that
+        // the contract of `RawWaker` is upheld.
+        unsafe { (self.waker.vtable.drop)(self.waker.data) }
+    }
+}
+
+impl Debug for Waker {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        // TODO: show the `data` and `vtable` pointers once `fmt::Pointer`
+        // exists.
+        f.debug_struct("Waker").finish_non_exhaustive()
+    }
+}
@@ -0,0 +1,1141 @@
+//! Temporal quantification.
+//!
+//! There are multiple ways to create a new [`Duration`]:
+//!
+//! ```ignore (core has no test harness)
+//! let five_seconds = Duration::from_secs(5);
+//! assert_eq!(five_seconds, Duration::from_millis(5_000));
+//! assert_eq!(five_seconds, Duration::from_micros(5_000_000));
+//! assert_eq!(five_seconds, Duration::from_nanos(5_000_000_000));
+//!
+//! let ten_seconds = Duration::from_secs(10);
+//! let seven_nanos = Duration::from_nanos(7);
+//! let total = ten_seconds + seven_nanos;
+//! assert_eq!(total, Duration::new(10, 7));
+//! ```
+
+use crate::clone::Clone;
+use crate::cmp::{self, Eq, Ord, Ordering, PartialEq, PartialOrd};
+use crate::default::Default;
+use crate::fmt::{self, Debug, Display, Formatter};
+use crate::hash::{Hash, Hasher};
+use crate::intrinsics::transmute;
+use crate::iter::{Iterator, Sum};
+use crate::marker::Copy;
+use crate::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Sub, SubAssign};
+use crate::option::Option::{self, None, Some};
+use crate::panicking::panic;
+use crate::result::Result::{self, Err, Ok};
+use crate::{slice, str};
+
+const NANOS_PER_SEC: u32 = 1_000_000_000;
+const NANOS_PER_MILLI: u32 = 1_000_000;
+const NANOS_PER_MICRO: u32 = 1_000;
+const MILLIS_PER_SEC: u64 = 1_000;
+const MICROS_PER_SEC: u64 = 1_000_000;
+
+// TODO: use `u64::MAX` and the `checked_*` methods of `u64` once the integers
+// have their inherent constants and methods.
+const U64_MAX: u64 = !0;
+
+const fn checked_add(lhs: u64, rhs: u64) -> Option<u64> {
+    if rhs > U64_MAX - lhs {
+        None
+    } else {
+        Some(lhs + rhs)
+    }
+}
+
+const fn checked_sub(lhs: u64, rhs: u64) -> Option<u64> {
+    if rhs > lhs {
+        None
+    } else {
+        Some(lhs - rhs)
+    }
+}
+
+const fn checked_mul(lhs: u64, rhs: u64) -> Option<u64> {
+    let product = lhs as u128 * rhs as u128;
+    if product > U64_MAX as u128 {
+        None
+    } else {
+        Some(product as u64)
+    }
+}
+
+/// A `Duration` type to represent a span of time, typically used for system
+/// timeouts.
+///
+/// Each `Duration` is composed of a whole number of seconds and a fractional
+/// part represented in nanoseconds. If the underlying system does not support
+/// nanosecond-level precision, APIs binding a system timeout will typically
+/// round up the number of nanoseconds.
+///
+/// [`Duration`]s implement many common traits, including [`Add`], [`Sub`],
+/// and other [`ops`] traits. It implements [`Default`] by returning a
+/// zero-length `Duration`.
+///
+/// [`ops`]: crate::ops
+///
+/// # Formatting `Duration` values
+///
+/// `Duration` intentionally does not have a `Display` impl, as there are a
+/// variety of ways to format spans of time for human readability. `Duration`
+/// provides a `Debug` impl that shows the full precision of the value, like
+/// `1.5s` or `300µs`.
+#[rustc_diagnostic_item = "Duration"]
+pub struct Duration {
+    secs: u64,
+    // TODO: a niche type for the range below, like upstream `Nanoseconds`.
+    nanos: u32, // Always 0 <= nanos < NANOS_PER_SEC
+}
+
+impl Clone for Duration {
+    #[inline]
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl Copy for Duration {}
+
+impl PartialEq for Duration {
+    #[inline]
+    fn eq(&self, other: &Duration) -> bool {
+        self.secs == other.secs && self.nanos == other.nanos
+    }
+}
+
+impl Eq for Duration {}
+
+impl PartialOrd for Duration {
+    #[inline]
+    fn partial_cmp(&self, other: &Duration) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Duration {
+    #[inline]
+    fn cmp(&self, other: &Duration) -> Ordering {
+        self.secs
+            .cmp(&other.secs)
+            .then(self.nanos.cmp(&other.nanos))
+    }
+}
+
+impl Hash for Duration {
+    #[inline]
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.secs.hash(state);
+        self.nanos.hash(state);
+    }
+}
+
+impl Default for Duration {
+    #[inline]
+    fn default() -> Self {
+        Duration::ZERO
+    }
+}
+
+impl Duration {
+    /// The maximum duration.
+    ///
+    /// May vary by platform as necessary. Must be able to contain the
+    /// difference between two instances of `Instant` or two instances of
+    /// `SystemTime`. This constraint gives it a value of about 584,942,417,355
+    /// years in practice, which is currently used on all platforms.
+    pub const MAX: Duration = Duration {
+        secs: U64_MAX,
+        nanos: NANOS_PER_SEC - 1,
+    };
+    /// The duration of one microsecond.
+    pub const MICROSECOND: Duration = Duration::from_micros(1);
+    /// The duration of one millisecond.
+    pub const MILLISECOND: Duration = Duration::from_millis(1);
+    /// The duration of one nanosecond.
+    pub const NANOSECOND: Duration = Duration::from_nanos(1);
+    /// The duration of one second.
+    pub const SECOND: Duration = Duration::from_secs(1);
+    /// A duration of zero time.
+    pub const ZERO: Duration = Duration::from_nanos(0);
+
+    /// Creates a new `Duration` from the specified number of whole seconds
+    /// and additional nanoseconds.
+    ///
+    /// If the number of nanoseconds is greater than 1 billion (the number of
+    /// nanoseconds in a second), then it will carry over into the seconds
+    /// provided.
+    ///
+    /// # Panics
+    ///
+    /// This constructor will panic if the carry from the nanoseconds overflows
+    /// the seconds counter.
+    // TODO: `const` once the panics can be used in constants.
+    #[inline]
+    #[must_use]
+    pub fn new(secs: u64, nanos: u32) -> Duration {
+        if nanos < NANOS_PER_SEC {
+            Duration { secs, nanos }
+        } else {
+            let secs = match checked_add(secs, (nanos / NANOS_PER_SEC) as u64) {
+                Some(secs) => secs,
+                None => panic("overflow in Duration::new"),
+            };
+            let nanos = nanos % NANOS_PER_SEC;
+            Duration { secs, nanos }
+        }
+    }
+
+    /// Creates a new `Duration` from the specified number of whole seconds.
+    #[inline]
+    #[must_use]
+    pub const fn from_secs(secs: u64) -> Duration {
+        Duration { secs, nanos: 0 }
+    }
+
+    /// Creates a new `Duration` from the specified number of milliseconds.
+    #[inline]
+    #[must_use]
+    pub const fn from_millis(millis: u64) -> Duration {
+        let secs = millis / MILLIS_PER_SEC;
+        let subsec_millis = (millis % MILLIS_PER_SEC) as u32;
+        Duration {
+            secs,
+            nanos: subsec_millis * NANOS_PER_MILLI,
+        }
+    }
+
+    /// Creates a new `Duration` from the specified number of microseconds.
+    #[inline]
+    #[must_use]
+    pub const fn from_micros(micros: u64) -> Duration {
+        let secs = micros / MICROS_PER_SEC;
+        let subsec_micros = (micros % MICROS_PER_SEC) as u32;
+        Duration {
+            secs,
+            nanos: subsec_micros * NANOS_PER_MICRO,
+        }
+    }
+
+    /// Creates a new `Duration` from the specified number of nanoseconds.
+    ///
+    /// Note: Using this on the return value of `as_nanos()` might cause
+    /// unexpected behavior: `as_nanos()` returns a u128, and can return values
+    /// that do not fit in u64, e.g. 585 years.
+    #[inline]
+    #[must_use]
+    pub const fn from_nanos(nanos: u64) -> Duration {
+        const NANOS_PER_SEC: u64 = self::NANOS_PER_SEC as u64;
+        Duration {
+            secs: nanos / NANOS_PER_SEC,
+            nanos: (nanos % NANOS_PER_SEC) as u32,
+        }
+    }
+
+    /// Returns true if this `Duration` spans no time.
+    #[inline]
+    #[must_use]
+    pub const fn is_zero(&self) -> bool {
+        self.secs == 0 && self.nanos == 0
+    }
+
+    /// Returns the number of _whole_ seconds contained by this `Duration`.
+    ///
+    /// The returned value does not include the fractional (nanosecond) part
+    /// of the duration, which can be obtained using [`subsec_nanos`].
+    ///
+    /// [`subsec_nanos`]: Duration::subsec_nanos
+    #[inline]
+    #[must_use]
+    pub const fn as_secs(&self) -> u64 {
+        self.secs
+    }
+
+    /// Returns the fractional part of this `Duration`, in whole milliseconds.
+    #[inline]
+    #[must_use]
+    pub const fn subsec_millis(&self) -> u32 {
+        self.nanos / NANOS_PER_MILLI
+    }
+
+    /// Returns the fractional part of this `Duration`, in whole microseconds.
+    #[inline]
+    #[must_use]
+    pub const fn subsec_micros(&self) -> u32 {
+        self.nanos / NANOS_PER_MICRO
+    }
+
+    /// Returns the fractional part of this `Duration`, in nanoseconds.
+    ///
+    /// The returned number always represents a fractional portion of a second
+    /// (i.e., it is less than one billion).
+    #[inline]
+    #[must_use]
+    pub const fn subsec_nanos(&self) -> u32 {
+        self.nanos
+    }
+
+    /// Returns the total number of whole milliseconds contained by this
+    /// `Duration`.
+    #[inline]
+    #[must_use]
+    pub const fn as_millis(&self) -> u128 {
+        self.secs as u128 * MILLIS_PER_SEC as u128 + (self.nanos / NANOS_PER_MILLI) as u128
+    }
+
+    /// Returns the total number of whole microseconds contained by this
+    /// `Duration`.
+    #[inline]
+    #[must_use]
+    pub const fn as_micros(&self) -> u128 {
+        self.secs as u128 * MICROS_PER_SEC as u128 + (self.nanos / NANOS_PER_MICRO) as u128
+    }
+
+    /// Returns the total number of nanoseconds contained by this `Duration`.
+    #[inline]
+    #[must_use]
+    pub const fn as_nanos(&self) -> u128 {
+        self.secs as u128 * NANOS_PER_SEC as u128 + self.nanos as u128
+    }
+
+    /// Computes the absolute difference between `self` and `other`.
+    #[inline]
+    #[must_use = "this returns the result of the operation, \
+                  without modifying the original"]
+    pub fn abs_diff(self, other: Duration) -> Duration {
+        if let Some(res) = self.checked_sub(other) {
+            res
+        } else {
+            other.checked_sub(self).unwrap()
+        }
+    }
+
+    /// Checked `Duration` addition. Computes `self + other`, returning
+    /// [`None`] if overflow occurred.
+    #[inline]
+    #[must_use = "this returns the result of the operation, \
+                  without modifying the original"]
+    pub const fn checked_add(self, rhs: Duration) -> Option<Duration> {
+        if let Some(mut secs) = checked_add(self.secs, rhs.secs) {
+            let mut nanos = self.nanos + rhs.nanos;
+            if nanos >= NANOS_PER_SEC {
+                nanos -= NANOS_PER_SEC;
+                if let Some(new_secs) = checked_add(secs, 1) {
+                    secs = new_secs;
+                } else {
+                    return None;
+                }
+            }
+            Some(Duration { secs, nanos })
+        } else {
+            None
+        }
+    }
+
+    /// Saturating `Duration` addition. Computes `self + other`, returning
+    /// [`Duration::MAX`] if overflow occurred.
+    #[inline]
+    #[must_use = "this returns the result of the operation, \
+                  without modifying the original"]
+    pub const fn saturating_add(self, rhs: Duration) -> Duration {
+        match self.checked_add(rhs) {
+            Some(res) => res,
+            None => Duration::MAX,
+        }
+    }
+
+    /// Checked `Duration` subtraction. Computes `self - other`, returning
+    /// [`None`] if the result would be negative or if overflow occurred.
+    #[inline]
+    #[must_use = "this returns the result of the operation, \
+                  without modifying the original"]
+    pub const fn checked_sub(self, rhs: Duration) -> Option<Duration> {
+        if let Some(mut secs) = checked_sub(self.secs, rhs.secs) {
+            let nanos = if self.nanos >= rhs.nanos {
+                self.nanos - rhs.nanos
+            } else if let Some(sub_secs) = checked_sub(secs, 1) {
+                secs = sub_secs;
+                self.nanos + NANOS_PER_SEC - rhs.nanos
+            } else {
+                return None;
+            };
+            Some(Duration { secs, nanos })
+        } else {
+            None
+        }
+    }
+
+    /// Saturating `Duration` subtraction. Computes `self - other`, returning
+    /// [`Duration::ZERO`] if the result would be negative or if overflow
+    /// occurred.
+    #[inline]
+    #[must_use = "this returns the result of the operation, \
+                  without modifying the original"]
+    pub const fn saturating_sub(self, rhs: Duration) -> Duration {
+        match self.checked_sub(rhs) {
+            Some(res) => res,
+            None => Duration::ZERO,
+        }
+    }
+
+    /// Checked `Duration` multiplication. Computes `self * other`, returning
+    /// [`None`] if overflow occurred.
+    #[inline]
+    #[must_use = "this returns the result of the operation, \
+                  without modifying the original"]
+    pub const fn checked_mul(self, rhs: u32) -> Option<Duration> {
+        // Multiply nanoseconds as u64, because it cannot overflow that way.
+        let total_nanos = self.nanos as u64 * rhs as u64;
+        let extra_secs = total_nanos / (NANOS_PER_SEC as u64);
+        let nanos = (total_nanos % (NANOS_PER_SEC as u64)) as u32;
+        if let Some(s) = checked_mul(self.secs, rhs as u64) {
+            if let Some(secs) = checked_add(s, extra_secs) {
+                return Some(Duration { secs, nanos });
+            }
+        }
+        None
+    }
+
+    /// Saturating `Duration` multiplication. Computes `self * other`,
+    /// returning [`Duration::MAX`] if overflow occurred.
+    #[inline]
+    #[must_use = "this returns the result of the operation, \
+                  without modifying the original"]
+    pub const fn saturating_mul(self, rhs: u32) -> Duration {
+        match self.checked_mul(rhs) {
+            Some(res) => res,
+            None => Duration::MAX,
+        }
+    }
+
+    /// Checked `Duration` division. Computes `self / other`, returning
+    /// [`None`] if `other == 0`.
+    #[inline]
+    #[must_use = "this returns the result of the operation, \
+                  without modifying the original"]
+    pub const fn checked_div(self, rhs: u32) -> Option<Duration> {
+        if rhs != 0 {
+            let (secs, extra_secs) = (self.secs / (rhs as u64), self.secs % (rhs as u64));
+            let (mut nanos, extra_nanos) = (self.nanos / rhs, self.nanos % rhs);
+            nanos +=
+                ((extra_secs * (NANOS_PER_SEC as u64) + extra_nanos as u64) / (rhs as u64)) as u32;
+            Some(Duration { secs, nanos })
+        } else {
+            None
+        }
+    }
+
+    /// Returns the number of seconds contained by this `Duration` as `f64`.
+    ///
+    /// The returned value includes the fractional (nanosecond) part of the
+    /// duration.
+    #[inline]
+    #[must_use]
+    pub const fn as_secs_f64(&self) -> f64 {
+        (self.secs as f64) + (self.nanos as f64) / (NANOS_PER_SEC as f64)
+    }
+
+    /// Returns the number of seconds contained by this `Duration` as `f32`.
+    ///
+    /// The returned value includes the fractional (nanosecond) part of the
+    /// duration.
+    #[inline]
+    #[must_use]
+    pub const fn as_secs_f32(&self) -> f32 {
+        (self.secs as f32) + (self.nanos as f32) / (NANOS_PER_SEC as f32)
+    }
+
+    /// Returns the number of milliseconds contained by this `Duration` as
+    /// `f64`.
+    #[inline]
+    #[must_use]
+    pub const fn as_millis_f64(&self) -> f64 {
+        (self.secs as f64) * (MILLIS_PER_SEC as f64)
+            + (self.nanos as f64) / (NANOS_PER_MILLI as f64)
+    }
+
+    /// Returns the number of milliseconds contained by this `Duration` as
+    /// `f32`.
+    #[inline]
+    #[must_use]
+    pub const fn as_millis_f32(&self) -> f32 {
+        (self.secs as f32) * (MILLIS_PER_SEC as f32)
+            + (self.nanos as f32) / (NANOS_PER_MILLI as f32)
+    }
+
+    /// Creates a new `Duration` from the specified number of seconds
+    /// represented as `f64`.
+    ///
+    /// # Panics
+    ///
+    /// This constructor will panic if `secs` is negative, overflows
+    /// `Duration` or not finite.
+    #[inline]
+    #[must_use]
+    pub fn from_secs_f64(secs: f64) -> Duration {
+        match Duration::try_from_secs_f64(secs) {
+            Ok(v) => v,
+            Err(e) => panic(e.description()),
+        }
+    }
+
+    /// Creates a new `Duration` from the specified number of seconds
+    /// represented as `f32`.
+    ///
+    /// # Panics
+    ///
+    /// This constructor will panic if `secs` is negative, overflows
+    /// `Duration` or not finite.
+    #[inline]
+    #[must_use]
+    pub fn from_secs_f32(secs: f32) -> Duration {
+        match Duration::try_from_secs_f32(secs) {
+            Ok(v) => v,
+            Err(e) => panic(e.description()),
+        }
+    }
+
+    /// Multiplies `Duration` by `f64`.
+    ///
+    /// # Panics
+    ///
+    /// This method will panic if result is negative, overflows `Duration` or
+    /// not finite.
+    #[inline]
+    #[must_use = "this returns the result of the operation, \
+                  without modifying the original"]
+    pub fn mul_f64(self, rhs: f64) -> Duration {
+        Duration::from_secs_f64(rhs * self.as_secs_f64())
+    }
+
+    /// Multiplies `Duration` by `f32`.
+    ///
+    /// # Panics
+    ///
+    /// This method will panic if result is negative, overflows `Duration` or
+    /// not finite.
+    #[inline]
+    #[must_use = "this returns the result of the operation, \
+                  without modifying the original"]
+    pub fn mul_f32(self, rhs: f32) -> Duration {
+        Duration::from_secs_f32(rhs * self.as_secs_f32())
+    }
+
+    /// Divides `Duration` by `f64`.
+    ///
+    /// # Panics
+    ///
+    /// This method will panic if result is negative, overflows `Duration` or
+    /// not finite.
+    #[inline]
+    #[must_use = "this returns the result of the operation, \
+                  without modifying the original"]
+    pub fn div_f64(self, rhs: f64) -> Duration {
+        Duration::from_secs_f64(self.as_secs_f64() / rhs)
+    }
+
+    /// Divides `Duration` by `f32`.
+    ///
+    /// # Panics
+    ///
+    /// This method will panic if result is negative, overflows `Duration` or
+    /// not finite.
+    #[inline]
+    #[must_use = "this returns the result of the operation, \
+                  without modifying the original"]
+    pub fn div_f32(self, rhs: f32) -> Duration {
+        Duration::from_secs_f32(self.as_secs_f32() / rhs)
+    }
+
+    /// Divides `Duration` by `Duration` and returns `f64`.
+    #[inline]
+    #[must_use = "this returns the result of the operation, \
+                  without modifying the original"]
+    pub const fn div_duration_f64(self, rhs: Duration) -> f64 {
+        let self_nanos = (self.secs as f64) * (NANOS_PER_SEC as f64) + (self.nanos as f64);
+        let rhs_nanos = (rhs.secs as f64) * (NANOS_PER_SEC as f64) + (rhs.nanos as f64);
+        self_nanos / rhs_nanos
+    }
+
+    /// Divides `Duration` by `Duration` and returns `f32`.
+    #[inline]
+    #[must_use = "this returns the result of the operation, \
+                  without modifying the original"]
+    pub const fn div_duration_f32(self, rhs: Duration) -> f32 {
+        let self_nanos = (self.secs as f32) * (NANOS_PER_SEC as f32) + (self.nanos as f32);
+        let rhs_nanos = (rhs.secs as f32) * (NANOS_PER_SEC as f32) + (rhs.nanos as f32);
+        self_nanos / rhs_nanos
+    }
+}
+
+impl Add for Duration {
+    type Output = Duration;
+
+    #[inline]
+    fn add(self, rhs: Duration) -> Duration {
+        self.checked_add(rhs)
+            .expect("overflow when adding durations")
+    }
+}
+
+impl AddAssign for Duration {
+    #[inline]
+    fn add_assign(&mut self, rhs: Duration) {
+        *self = *self + rhs;
+    }
+}
+
+impl Sub for Duration {
+    type Output = Duration;
+
+    #[inline]
+    fn sub(self, rhs: Duration) -> Duration {
+        self.checked_sub(rhs)
+            .expect("overflow when subtracting durations")
+    }
+}
+
+impl SubAssign for Duration {
+    #[inline]
+    fn sub_assign(&mut self, rhs: Duration) {
+        *self = *self - rhs;
+    }
+}
+
+impl Mul<u32> for Duration {
+    type Output = Duration;
+
+    #[inline]
+    fn mul(self, rhs: u32) -> Duration {
+        self.checked_mul(rhs)
+            .expect("overflow when multiplying duration by scalar")
+    }
+}
+
+impl Mul<Duration> for u32 {
+    type Output = Duration;
+
+    #[inline]
+    fn mul(self, rhs: Duration) -> Duration {
+        rhs * self
+    }
+}
+
+impl MulAssign<u32> for Duration {
+    #[inline]
+    fn mul_assign(&mut self, rhs: u32) {
+        *self = *self * rhs;
+    }
+}
+
+impl Div<u32> for Duration {
+    type Output = Duration;
+
+    #[inline]
+    #[track_caller]
+    fn div(self, rhs: u32) -> Duration {
+        self.checked_div(rhs)
+            .expect("divide by zero error when dividing duration by scalar")
+    }
+}
+
+impl DivAssign<u32> for Duration {
+    #[inline]
+    #[track_caller]
+    fn div_assign(&mut self, rhs: u32) {
+        *self = *self / rhs;
+    }
+}
+
+macro_rules! sum_durations {
+    ($iter:expr) => {{
+        let mut iter = $iter;
+        let mut total_secs: u64 = 0;
+        let mut total_nanos: u64 = 0;
+
+        while let Some(entry) = iter.next() {
+            total_secs =
+                checked_add(total_secs, entry.secs).expect("overflow in iter::sum over durations");
+            total_nanos = match checked_add(total_nanos, entry.nanos as u64) {
+                Some(n) => n,
+                None => {
+                    total_secs = checked_add(total_secs, total_nanos / NANOS_PER_SEC as u64)
+                        .expect("overflow in iter::sum over durations");
+                    (total_nanos % NANOS_PER_SEC as u64) + entry.nanos as u64
+                }
+            };
+        }
+        total_secs = checked_add(total_secs, total_nanos / NANOS_PER_SEC as u64)
+            .expect("overflow in iter::sum over durations");
+        total_nanos = total_nanos % NANOS_PER_SEC as u64;
+        Duration::new(total_secs, total_nanos as u32)
+    }};
+}
+
+impl Sum for Duration {
+    fn sum<I: Iterator<Item = Duration>>(iter: I) -> Duration {
+        sum_durations!(iter)
+    }
+}
+
+impl<'a> Sum<&'a Duration> for Duration {
+    fn sum<I: Iterator<Item = &'a Duration>>(iter: I) -> Duration {
+        sum_durations!(iter)
+    }
+}
+
+/// Writes the decimal digits of `n`, without the padding that the `Display`
+/// impl of `u64` would apply.
+fn write_u64(f: &mut Formatter<'_>, mut n: u64) -> fmt::Result {
+    let mut buf = [0u8; 20];
+    let mut start = buf.len();
+    loop {
+        start -= 1;
+        buf[start] = b'0' + (n % 10) as u8;
+        n /= 10;
+        if n == 0 {
+            break;
+        }
+    }
+    // SAFETY: `buf[start..]` is initialized with ASCII digits only.
+    f.write_str(unsafe { str::from_utf8_unchecked(&buf[start..]) })
+}
+
+/// The number of `char`s of `s`.
+fn char_count(s: &str) -> usize {
+    let bytes = s.as_bytes();
+    let mut count = 0;
+    let mut i = 0;
+    while i < bytes.len() {
+        // every `char` has exactly one byte which is not a continuation byte
+        if bytes[i] & 0xC0 != 0x80 {
+            count += 1;
+        }
+        i += 1;
+    }
+    count
+}
+
+impl Debug for Duration {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        /// Formats a floating point number in decimal notation.
+        ///
+        /// The number is given as the `integer_part` and a fractional part.
+        /// The value of the fractional part is `fractional_part / divisor`. So
+        /// `integer_part` = 3, `fractional_part` = 12 and `divisor` = 100
+        /// represents the number `3.012`. Trailing zeros are omitted.
+        ///
+        /// `divisor` must not be above 100_000_000. It also should be a power
+        /// of 10, everything else doesn't make sense. `fractional_part` has
+        /// to be less than `10 * divisor`!
+        ///
+        /// A prefix and postfix may be added. The whole thing is padded
+        /// to the formatter's `width`, if specified.
+        fn fmt_decimal(
+            f: &mut Formatter<'_>,
+            integer_part: u64,
+            mut fractional_part: u32,
+            mut divisor: u32,
+            prefix: &str,
+            postfix: &str,
+        ) -> fmt::Result {
+            // Encode the fractional part into a temporary buffer. The buffer
+            // only need to hold 9 elements, because `fractional_part` has to
+            // be smaller than 10^9. The buffer is prefilled with '0' digits
+            // to simplify the code below.
+            let mut buf = [b'0'; 9];
+
+            // The next digit is written at this position
+            let mut pos = 0;
+
+            // We keep writing digits into the buffer while there are non-zero
+            // digits left and we haven't written enough digits yet.
+            while fractional_part > 0 && pos < f.precision().unwrap_or(9) {
+                // Write new digit into the buffer
+                buf[pos] = b'0' + (fractional_part / divisor) as u8;
+
+                fractional_part %= divisor;
+                divisor /= 10;
+                pos += 1;
+            }
+
+            // If a precision < 9 was specified, there may be some non-zero
+            // digits left that weren't written into the buffer. In that case we
+            // need to perform rounding to match the semantics of printing
+            // normal floating point numbers. However, we only need to do work
+            // when rounding up. This happens if the first digit of the
+            // remaining ones is >= 5.
+            let integer_part = if fractional_part > 0 && fractional_part >= divisor * 5 {
+                // Round up the number contained in the buffer. We go through
+                // the buffer backwards and keep track of the carry.
+                let mut rev_pos = pos;
+                let mut carry = true;
+                while carry && rev_pos > 0 {
+                    rev_pos -= 1;
+
+                    // If the digit in the buffer is not '9', we just need to
+                    // increment it and can stop then (since we don't have a
+                    // carry anymore). Otherwise, we set it to '0' (overflow)
+                    // and continue.
+                    if buf[rev_pos] < b'9' {
+                        buf[rev_pos] += 1;
+                        carry = false;
+                    } else {
+                        buf[rev_pos] = b'0';
+                    }
+                }
+
+                // If we still have the carry bit set, that means that we set
+                // the whole buffer to '0's and need to increment the integer
+                // part.
+                if carry {
+                    // If `integer_part == u64::MAX` and precision < 9, any
+                    // carry of the overflow during rounding of the
+                    // `fractional_part` into the `integer_part` will cause the
+                    // `integer_part` itself to overflow. Avoid this by using an
+                    // `Option<u64>`, with `None` representing `u64::MAX + 1`.
+                    checked_add(integer_part, 1)
+                } else {
+                    Some(integer_part)
+                }
+            } else {
+                Some(integer_part)
+            };
+
+            // Determine the end of the buffer: if precision is set, we just
+            // use as many digits from the buffer (capped to 9). If it isn't
+            // set, we only use all digits up to the last non-zero one.
+            let end = f.precision().map(|p| cmp::min(p, 9)).unwrap_or(pos);
+
+            // This closure emits the formatted duration without emitting any
+            // padding (padding is calculated below).
+            let emit_without_padding = |f: &mut Formatter<'_>| {
+                f.write_str(prefix)?;
+                if let Some(integer_part) = integer_part {
+                    write_u64(f, integer_part)?;
+                } else {
+                    // u64::MAX + 1 == 18446744073709551616
+                    f.write_str("18446744073709551616")?;
+                }
+
+                // Write the decimal point and the fractional part (if any).
+                if end > 0 {
+                    // SAFETY: We are only writing ASCII digits into the buffer
+                    // and it was initialized with '0's, so it contains valid
+                    // UTF8.
+                    let s = unsafe {
+                        str::from_utf8_unchecked(slice::from_raw_parts(buf.as_ptr(), end))
+                    };
+                    f.write_str(".")?;
+                    f.write_str(s)?;
+
+                    // If the user request a precision > 9, we pad '0's at the
+                    // end.
+                    let mut w = f.precision().unwrap_or(pos);
+                    while w > end {
+                        f.write_str("0")?;
+                        w -= 1;
+                    }
+                }
+
+                f.write_str(postfix)
+            };
+
+            match f.width() {
+                None => {
+                    // No `width` specified. There's no need to calculate the
+                    // length of the output in this case, just emit it.
+                    emit_without_padding(f)
+                }
+                Some(requested_w) => {
+                    // A `width` was specified. Calculate the actual width of
+                    // the output in order to calculate the required padding.
+                    // It consists of 4 parts:
+                    // 1. The prefix: is either "+" or "", so we can just use len().
+                    // 2. The postfix: can be "µs" so we have to count UTF8 characters.
+                    let mut actual_w = prefix.len() + char_count(postfix);
+                    // 3. The integer part:
+                    if let Some(mut integer_part) = integer_part {
+                        actual_w += 1;
+                        while integer_part >= 10 {
+                            integer_part /= 10;
+                            actual_w += 1;
+                        }
+                    } else {
+                        // integer_part is u64::MAX + 1, so has length 20
+                        actual_w += 20;
+                    }
+                    // 4. The fractional part (if any):
+                    if end > 0 {
+                        let frac_part_w = f.precision().unwrap_or(pos);
+                        actual_w += 1 + frac_part_w;
+                    }
+
+                    // TODO: honor the fill character and the alignment once
+                    // the `Formatter` has them, the output is left-aligned
+                    // and padded with spaces for now.
+                    emit_without_padding(f)?;
+                    while actual_w < requested_w {
+                        f.write_str(" ")?;
+                        actual_w += 1;
+                    }
+                    Ok(())
+                }
+            }
+        }
+
+        // Print leading '+' sign if requested
+        let prefix = if f.sign_plus() { "+" } else { "" };
+
+        if self.secs > 0 {
+            fmt_decimal(f, self.secs, self.nanos, NANOS_PER_SEC / 10, prefix, "s")
+        } else if self.nanos >= NANOS_PER_MILLI {
+            fmt_decimal(
+                f,
+                (self.nanos / NANOS_PER_MILLI) as u64,
+                self.nanos % NANOS_PER_MILLI,
+                NANOS_PER_MILLI / 10,
+                prefix,
+                "ms",
+            )
+        } else if self.nanos >= NANOS_PER_MICRO {
+            fmt_decimal(
+                f,
+                (self.nanos / NANOS_PER_MICRO) as u64,
+                self.nanos % NANOS_PER_MICRO,
+                NANOS_PER_MICRO / 10,
+                prefix,
+                "µs",
+            )
+        } else {
+            fmt_decimal(f, self.nanos as u64, 0, 1, prefix, "ns")
+        }
+    }
+}
+
+/// An error which can be returned when converting a floating-point value of
+/// seconds into a [`Duration`].
+///
+/// This error is used as the error type for [`Duration::try_from_secs_f32`]
+/// and [`Duration::try_from_secs_f64`].
+pub struct TryFromFloatSecsError {
+    kind: TryFromFloatSecsErrorKind,
+}
+
+impl TryFromFloatSecsError {
+    pub(crate) const fn description(&self) -> &'static str {
+        match self.kind {
+            TryFromFloatSecsErrorKind::Negative => {
+                "cannot convert float seconds to Duration: value is negative"
+            }
+            TryFromFloatSecsErrorKind::OverflowOrNan => {
+                "cannot convert float seconds to Duration: value is either too big or NaN"
+            }
+        }
+    }
+}
+
+impl Clone for TryFromFloatSecsError {
+    #[inline]
+    fn clone(&self) -> Self {
+        TryFromFloatSecsError {
+            kind: self.kind.clone(),
+        }
+    }
+}
+
+impl PartialEq for TryFromFloatSecsError {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.kind == other.kind
+    }
+}
+
+impl Eq for TryFromFloatSecsError {}
+
+impl Debug for TryFromFloatSecsError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("TryFromFloatSecsError")
+            .field("kind", &self.kind)
+            .finish()
+    }
+}
+
+impl Display for TryFromFloatSecsError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        Display::fmt(self.description(), f)
+    }
+}
+
+enum TryFromFloatSecsErrorKind {
+    // Value is negative.
+    Negative,
+    // Value is either too big to be represented as `Duration` or `NaN`.
+    OverflowOrNan,
+}
+
+impl Clone for TryFromFloatSecsErrorKind {
+    #[inline]
+    fn clone(&self) -> Self {
+        match self {
+            TryFromFloatSecsErrorKind::Negative => TryFromFloatSecsErrorKind::Negative,
+            TryFromFloatSecsErrorKind::OverflowOrNan => TryFromFloatSecsErrorKind::OverflowOrNan,
+        }
+    }
+}
+
+impl PartialEq for TryFromFloatSecsErrorKind {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (TryFromFloatSecsErrorKind::Negative, TryFromFloatSecsErrorKind::Negative) => true,
+            (
+                TryFromFloatSecsErrorKind::OverflowOrNan,
+                TryFromFloatSecsErrorKind::OverflowOrNan,
+            ) => true,
+            _ => false,
+        }
+    }
+}
+
+impl Eq for TryFromFloatSecsErrorKind {}
+
+impl Debug for TryFromFloatSecsErrorKind {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            TryFromFloatSecsErrorKind::Negative => "Negative",
+            TryFromFloatSecsErrorKind::OverflowOrNan => "OverflowOrNan",
+        })
+    }
+}
+
+macro_rules! try_from_secs {
+    (
+        secs = $secs: expr,
+        mantissa_bits = $mant_bits: literal,
+        exponent_bits = $exp_bits: literal,
+        offset = $offset: literal,
+        bits_ty = $bits_ty:ty,
+        double_ty = $double_ty:ty,
+    ) => {{
+        const MIN_EXP: i16 = 1 - (1i16 << $exp_bits) / 2;
+        const MANT_MASK: $bits_ty = (1 << $mant_bits) - 1;
+        const EXP_MASK: $bits_ty = (1 << $exp_bits) - 1;
+
+        if $secs < 0.0 {
+            return Err(TryFromFloatSecsError {
+                kind: TryFromFloatSecsErrorKind::Negative,
+            });
+        }
+
+        // TODO: `to_bits` once the floats have their inherent methods.
+        // SAFETY: a float has the same size as its bits type, and every bit
+        // pattern is a valid integer.
+        let bits: $bits_ty = unsafe { transmute($secs) };
+        let mant = (bits & MANT_MASK) | (MANT_MASK + 1);
+        let exp = ((bits >> $mant_bits) & EXP_MASK) as i16 + MIN_EXP;
+
+        let (secs, nanos) = if exp < -31 {
+            // the input represents less than 1ns and can not be rounded to it
+            (0u64, 0u32)
+        } else if exp < 0 {
+            // the input is less than 1 second
+            let t = (mant as $double_ty) << ($offset + exp);
+            let nanos_offset = $mant_bits + $offset;
+            let nanos_tmp = NANOS_PER_SEC as u128 * t as u128;
+            let nanos = (nanos_tmp >> nanos_offset) as u32;
+
+            let rem_mask = (1 << nanos_offset) - 1;
+            let rem_msb_mask = 1 << (nanos_offset - 1);
+            let rem = nanos_tmp & rem_mask;
+            let is_tie = rem == rem_msb_mask;
+            let is_even = (nanos & 1) == 0;
+            let rem_msb = nanos_tmp & rem_msb_mask == 0;
+            let add_ns = !(rem_msb || (is_even && is_tie));
+
+            // f32 does not have enough precision to trigger the second branch
+            // since it can not represent numbers between 0.999_999_940_395 and 1.0.
+            let nanos = nanos + add_ns as u32;
+            if ($mant_bits == 23) || (nanos != NANOS_PER_SEC) {
+                (0, nanos)
+            } else {
+                (1, 0)
+            }
+        } else if exp < $mant_bits {
+            let secs = (mant >> ($mant_bits - exp)) as u64;
+            let t = ((mant << exp) & MANT_MASK) as $double_ty;
+            let nanos_offset = $mant_bits;
+            let nanos_tmp = NANOS_PER_SEC as $double_ty * t;
+            let nanos = (nanos_tmp >> nanos_offset) as u32;
+
+            let rem_mask = (1 << nanos_offset) - 1;
+            let rem_msb_mask = 1 << (nanos_offset - 1);
+            let rem = nanos_tmp & rem_mask;
+            let is_tie = rem == rem_msb_mask;
+            let is_even = (nanos & 1) == 0;
+            let rem_msb = nanos_tmp & rem_msb_mask == 0;
+            let add_ns = !(rem_msb || (is_even && is_tie));
+
+            // f32 does not have enough precision to trigger the second branch.
+            // For example, it can not represent numbers between 1.999_999_880...
+            // and 2.0. Bigger values result in even smaller precision of the
+            // fractional part.
+            let nanos = nanos + add_ns as u32;
+            if ($mant_bits == 23) || (nanos != NANOS_PER_SEC) {
+                (secs, nanos)
+            } else {
+                (secs + 1, 0)
+            }
+        } else if exp < 64 {
+            // the input has no fractional part
+            let secs = (mant as u64) << (exp - $mant_bits);
+            (secs, 0)
+        } else {
+            return Err(TryFromFloatSecsError {
+                kind: TryFromFloatSecsErrorKind::OverflowOrNan,
+            });
+        };
+
+        Ok(Duration::new(secs, nanos))
+    }};
+}
+
+impl Duration {
+    /// The checked version of [`from_secs_f32`].
+    ///
+    /// [`from_secs_f32`]: Duration::from_secs_f32
+    ///
+    /// This constructor will return an `Err` if `secs` is negative, overflows
+    /// `Duration` or not finite.
+    #[inline]
+    pub fn try_from_secs_f32(secs: f32) -> Result<Duration, TryFromFloatSecsError> {
+        try_from_secs!(
+            secs = secs,
+            mantissa_bits = 23,
+            exponent_bits = 8,
+            offset = 41,
+            bits_ty = u32,
+            double_ty = u64,
+        )
+    }
+
+    /// The checked version of [`from_secs_f64`].
+    ///
+    /// [`from_secs_f64`]: Duration::from_secs_f64
+    ///
+    /// This constructor will return an `Err` if `secs` is negative, overflows
+    /// `Duration` or not finite.
+    #[inline]
+    pub fn try_from_secs_f64(secs: f64) -> Result<Duration, TryFromFloatSecsError> {
+        try_from_secs!(
+            secs = secs,
+            mantissa_bits = 52,
+            exponent_bits = 11,
+            offset = 44,
+            bits_ty = u64,
+            double_ty = u128,
+        )
+    }
+}
@@ -0,0 +1,31 @@
+//! Trait implementations for tuples.
+//!
+//! `()` gets its impls next to the ones of the other primitive types, the
+//! impls here cover the tuples from 1 to 12 elements.
+
+use crate::default::Default;
+
+// Recursive macro calling `tuple_impls!(@impl ..)` for every tuple type
+// from the longest one to the 1-ary tuple.
+macro_rules! tuple_impls {
+    // Stopping criteria (1-ary tuple)
+    ($T:ident) => {
+        tuple_impls!(@impl $T);
+    };
+    // Running criteria (n-ary tuple, with n >= 2)
+    ($T:ident $( $U:ident )+) => {
+        tuple_impls!($( $U )+);
+        tuple_impls!(@impl $T $( $U )+);
+    };
+    // "Private" internal implementation
+    (@impl $( $T:ident )+) => {
+        impl<$($T: Default),+> Default for ($($T,)+) {
+            #[inline]
+            fn default() -> ($($T,)+) {
+                ($($T::default(),)+)
+            }
+        }
+    };
+}
+
+tuple_impls!(E D C B A Z Y X W V U T);
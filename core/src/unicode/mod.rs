@@ -0,0 +1,84 @@
+//! Lookup of the Unicode properties used by `char`.
+//!
+//! The tables of [`unicode_data`] are generated by
+//! `tools/unicode-table-generator`, see its documentation for how the
+//! properties are derived from the Unicode Character Database.
+
+mod unicode_data;
+
+pub(crate) use unicode_data::alphabetic::lookup as Alphabetic;
+pub(crate) use unicode_data::conversions;
+pub(crate) use unicode_data::grapheme_extend::lookup as Grapheme_Extend;
+pub(crate) use unicode_data::lowercase::lookup as Lowercase;
+pub(crate) use unicode_data::n::lookup as N;
+pub(crate) use unicode_data::printable::lookup as Printable;
+pub(crate) use unicode_data::uppercase::lookup as Uppercase;
+pub(crate) use unicode_data::white_space::lookup as White_Space;
+
+/// The version of [Unicode](https://www.unicode.org/) that the Unicode parts
+/// of `char` are based on.
+pub const UNICODE_VERSION: (u8, u8, u8) = unicode_data::UNICODE_VERSION;
+
+/// Whether `needle` has the property of the skip list made of
+/// `short_offset_runs` and `offsets`, see the generator for the format.
+fn skip_search(needle: u32, short_offset_runs: &[u32], offsets: &[u8]) -> bool {
+    let prefix_sum = |header: u32| header & ((1 << 21) - 1);
+    let start = |header: u32| (header >> 21) as usize;
+
+    // the first run ending after `needle`, the last one ends after
+    // `char::MAX` so there is always one
+    let mut low = 0;
+    let mut high = short_offset_runs.len();
+    while low < high {
+        let mid = low + (high - low) / 2;
+        if prefix_sum(short_offset_runs[mid]) <= needle {
+            low = mid + 1;
+        } else {
+            high = mid;
+        }
+    }
+    let run = low;
+
+    let mut offset_idx = start(short_offset_runs[run]);
+    let end = if run + 1 < short_offset_runs.len() {
+        start(short_offset_runs[run + 1])
+    } else {
+        offsets.len()
+    };
+    let previous = if run > 0 {
+        prefix_sum(short_offset_runs[run - 1])
+    } else {
+        0
+    };
+
+    // the number of changes of the property up to `needle` within the run,
+    // the last offset of the run being the one which did not fit in a byte
+    let total = needle - previous;
+    let mut sum = 0;
+    while offset_idx + 1 < end {
+        sum += offsets[offset_idx] as u32;
+        if sum > total {
+            break;
+        }
+        offset_idx += 1;
+    }
+    offset_idx % 2 == 1
+}
+
+/// Searches the mapping of `c` in a table of pairs sorted by their first
+/// element.
+fn case_search<T: Copy>(c: char, table: &[(char, T)]) -> Option<T> {
+    let mut low = 0;
+    let mut high = table.len();
+    while low < high {
+        let mid = low + (high - low) / 2;
+        if c < table[mid].0 {
+            high = mid;
+        } else if c > table[mid].0 {
+            low = mid + 1;
+        } else {
+            return Some(table[mid].1);
+        }
+    }
+    None
+}
@@ -0,0 +1,120 @@
+//! Extracts the `Default` impls of restd from `core/src`, see the crate
+//! documentation.
+
+use std::path::PathBuf;
+use std::{env, fs};
+
+/// The files of `core/src` defining `Default` impls, with the module they
+/// are compiled into.
+const SOURCES: &[(&str, &str)] = &[
+    ("primitives", "default.rs"),
+    ("tuple", "tuple.rs"),
+    ("array", "array/mod.rs"),
+    ("slice", "slice/mod.rs"),
+    ("str", "str/mod.rs"),
+];
+
+fn main() {
+    let manifest_dir = PathBuf::from(env::var_os("CARGO_MANIFEST_DIR").unwrap());
+    let core_dir = manifest_dir.join("../../core/src");
+    let out_dir = PathBuf::from(env::var_os("OUT_DIR").unwrap());
+
+    let mut out = String::from("// Extracted from `core/src` by `build.rs`.\n");
+    for (module, source) in SOURCES {
+        let path = core_dir.join(source);
+        println!("cargo::rerun-if-changed={}", path.display());
+        let content = fs::read_to_string(&path)
+            .unwrap_or_else(|e| panic!("cannot read `{}`: {e}", path.display()));
+
+        out.push_str(&format!(
+            "\n/// The `Default` impls of `core/src/{source}`.\n\
+             #[allow(unused_imports)]\n\
+             pub mod {module} {{\n\
+             use crate::Default;\n\
+             use crate::shim::*;\n\n"
+        ));
+        for item in items(&content) {
+            out.push_str(item);
+            out.push('\n');
+        }
+        out.push_str("}\n");
+    }
+
+    fs::write(out_dir.join("impls.rs"), out).unwrap();
+}
+
+/// Returns the top-level items of `content` taking part in the `Default`
+/// impls: the `impl ... Default for ...` blocks, the local `macro_rules!`
+/// and their invocations. The attributes of the impls are dropped, they are
+/// the ones of the core (stability, lang items...).
+fn items(content: &str) -> Vec<&str> {
+    let mut macros = Vec::new();
+    let mut items = Vec::new();
+    let mut offset = 0;
+    while offset < content.len() {
+        let line = &content[offset..];
+        let line = &line[..line.find('\n').map_or(line.len(), |end| end + 1)];
+
+        let item = if let Some(name) = line.strip_prefix("macro_rules! ") {
+            macros.push(name[..name.find(' ').unwrap()].to_owned());
+            true
+        } else if line.starts_with("impl") {
+            let header = &content[offset..offset + content[offset..].find('{').unwrap()];
+            header.contains(" Default for ")
+        } else {
+            macros
+                .iter()
+                .any(|name| line.starts_with(&format!("{name}!")))
+        };
+
+        if item {
+            let end = offset + item_len(&content[offset..]);
+            items.push(&content[offset..end]);
+            offset = end;
+        } else {
+            offset += line.len();
+        }
+    }
+    items
+}
+
+/// Returns the length of the item starting `content`, up to its closing
+/// brace or parenthesis (and the following `;`).
+fn item_len(content: &str) -> usize {
+    let start = content.find(['{', '(']).unwrap();
+    let mut depth = 0;
+    let mut chars = content.char_indices().skip(start);
+    while let Some((i, c)) = chars.next() {
+        match c {
+            '{' | '(' | '[' => depth += 1,
+            '}' | ')' | ']' => {
+                depth -= 1;
+                if depth == 0 {
+                    let end = i + 1;
+                    return end + usize::from(content[end..].starts_with(';'));
+                }
+            }
+            // the comments and strings of the impls may contain brackets
+            '/' if content[i..].starts_with("//") => {
+                for (_, c) in chars.by_ref() {
+                    if c == '\n' {
+                        break;
+                    }
+                }
+            }
+            '"' => {
+                while let Some((_, c)) = chars.next() {
+                    match c {
+                        '\\' => {
+                            chars.next();
+                        }
+                        '"' => break,
+                        _ => {}
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+    panic!("unterminated item:\n{content}")
+}
@@ -0,0 +1,27 @@
+//! Runs the `Default` impls of restd until the core builds on its own.
+//!
+//! ```text
+//! cargo test -p default-impls
+//! ```
+//!
+//! The build script extracts the `impl ... Default for ...` blocks, the
+//! local `macro_rules!` generating them and their invocations from
+//! `core/src/default.rs`, `tuple.rs`, `array/mod.rs`, `slice/mod.rs` and
+//! `str/mod.rs`. They are compiled here, unchanged, for the [`Default`]
+//! trait below, with `std` standing in for the rest of the core (see
+//! `shim`). The tests of `tests/default.rs` check the values they build.
+
+#![feature(maybe_uninit_uninit_array, maybe_uninit_uninit_array_transpose)]
+
+/// The `Default` trait of `core/src/default.rs`, without its attributes.
+pub trait Default: Sized {
+    fn default() -> Self;
+}
+
+/// The items of the core used by the extracted impls, taken from `std`.
+mod shim {
+    pub use std::mem::MaybeUninit;
+    pub use std::str::from_utf8_unchecked_mut;
+}
+
+include!(concat!(env!("OUT_DIR"), "/impls.rs"));
@@ -0,0 +1,99 @@
+use std::cell::Cell;
+
+use default_impls::Default;
+
+thread_local! {
+    static BUILT: Cell<u32> = const { Cell::new(0) };
+}
+
+/// Counts the values built by its `Default` impl, in the order they are
+/// built.
+#[derive(Debug, PartialEq)]
+struct Counted(u32);
+
+impl Default for Counted {
+    fn default() -> Self {
+        let n = BUILT.get();
+        BUILT.set(n + 1);
+        Counted(n)
+    }
+}
+
+/// The value of the `Default` impl of restd for `T`, the prelude of `std`
+/// brings its own `Default` impls in scope.
+fn default<T: Default>() -> T {
+    T::default()
+}
+
+#[test]
+fn primitives() {
+    let () = default::<()>();
+    assert!(!default::<bool>());
+    assert_eq!(default::<char>(), '\0');
+    assert_eq!(default::<u8>(), 0);
+    assert_eq!(default::<i128>(), 0);
+    assert_eq!(default::<usize>(), 0);
+    assert_eq!(default::<f32>(), 0.0);
+    assert_eq!(default::<f64>(), 0.0);
+}
+
+#[test]
+fn tuples() {
+    assert_eq!(default::<(u8,)>(), (0,));
+    assert_eq!(default::<(bool, char)>(), (false, '\0'));
+    assert_eq!(
+        default::<(
+            u8,
+            u16,
+            u32,
+            u64,
+            u128,
+            usize,
+            i8,
+            i16,
+            i32,
+            i64,
+            i128,
+            isize
+        )>(),
+        (0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0)
+    );
+    assert_eq!(default::<((), (u8, (bool,)))>(), ((), (0, (false,))));
+}
+
+#[test]
+fn arrays() {
+    assert_eq!(default::<[u8; 0]>(), []);
+    assert_eq!(default::<[u32; 4]>(), [0; 4]);
+    assert_eq!(default::<[(bool, char); 2]>(), [(false, '\0'); 2]);
+    assert_eq!(default::<[[i8; 2]; 3]>(), [[0; 2]; 3]);
+    assert_eq!(default::<[&str; 2]>(), [""; 2]);
+}
+
+#[test]
+fn arrays_build_in_order() {
+    BUILT.set(0);
+    assert_eq!(
+        default::<[Counted; 3]>(),
+        [Counted(0), Counted(1), Counted(2)]
+    );
+    assert_eq!(BUILT.get(), 3);
+}
+
+#[test]
+fn slices() {
+    let slice: &[u8] = default();
+    assert!(slice.is_empty());
+    let slice: &[Counted] = default();
+    assert!(slice.is_empty());
+    let slice: &mut [u8] = default();
+    assert!(slice.is_empty());
+}
+
+#[test]
+fn strs() {
+    let str: &str = default();
+    assert_eq!(str, "");
+    let str: &mut str = default();
+    assert_eq!(str, "");
+}
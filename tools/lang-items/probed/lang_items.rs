@@ -0,0 +1,208 @@
+// The lang items of the pinned toolchain, probed with rustc and written in
+// the format of the `language_item_table!` of rustc's
+// `compiler/rustc_hir/src/lang_items.rs`. This is NOT a snapshot of the
+// upstream file: `tools/lang-items` only falls back to it while
+// `rustc_hir/lang_items.rs` is not checked in.
+//
+// Toolchain: nightly-2025-02-05 (rustc 1.86.0-nightly bef3c3b01 2025-02-04)
+//
+// The name, target and generic requirement of every lang item were probed
+// with the rustc of the `Toolchain:` line (`E0522` for an unknown name,
+// `E0718` for the expected target and generics, the count of generics of an
+// associated item including the ones of its parent). The variant and getter
+// columns were guessed from the names and never checked, the tool does not
+// read them. The rows are sorted by variant name.
+
+language_item_table! {
+//  Variant name, Name, Getter method name, Target, Generic requirements;
+    Add,                               sym::add,                                    add_trait,                                 Target::Trait,                                     GenericRequirement::Exact(1);
+    AddAssign,                         sym::add_assign,                             add_assign_trait,                          Target::Trait,                                     GenericRequirement::Exact(1);
+    AllocLayout,                       sym::alloc_layout,                           alloc_layout,                              Target::Struct,                                    GenericRequirement::None;
+    AsyncDestruct,                     sym::async_destruct,                         async_destruct_trait,                      Target::Trait,                                     GenericRequirement::Exact(0);
+    AsyncDrop,                         sym::async_drop,                             async_drop_trait,                          Target::Trait,                                     GenericRequirement::Exact(0);
+    AsyncDropChain,                    sym::async_drop_chain,                       async_drop_chain_fn,                       Target::Fn,                                        GenericRequirement::Exact(2);
+    AsyncDropDefer,                    sym::async_drop_defer,                       async_drop_defer_fn,                       Target::Fn,                                        GenericRequirement::Exact(1);
+    AsyncDropDeferredDropInPlace,      sym::async_drop_deferred_drop_in_place,      async_drop_deferred_drop_in_place_fn,      Target::Fn,                                        GenericRequirement::Exact(1);
+    AsyncDropEither,                   sym::async_drop_either,                      async_drop_either_fn,                      Target::Fn,                                        GenericRequirement::Exact(3);
+    AsyncDropFuse,                     sym::async_drop_fuse,                        async_drop_fuse_fn,                        Target::Fn,                                        GenericRequirement::Exact(1);
+    AsyncDropInPlace,                  sym::async_drop_in_place,                    async_drop_in_place_fn,                    Target::Fn,                                        GenericRequirement::Exact(1);
+    AsyncDropNoop,                     sym::async_drop_noop,                        async_drop_noop_fn,                        Target::Fn,                                        GenericRequirement::Exact(0);
+    AsyncDropSlice,                    sym::async_drop_slice,                       async_drop_slice_fn,                       Target::Fn,                                        GenericRequirement::Exact(1);
+    AsyncDropSurfaceDropInPlace,       sym::async_drop_surface_drop_in_place,       async_drop_surface_drop_in_place_fn,       Target::Fn,                                        GenericRequirement::Exact(1);
+    AsyncFn,                           sym::async_fn,                               async_fn_trait,                            Target::Trait,                                     GenericRequirement::Exact(1);
+    AsyncFnKindHelper,                 sym::async_fn_kind_helper,                   async_fn_kind_helper_trait,                Target::Trait,                                     GenericRequirement::Exact(1);
+    AsyncFnKindUpvars,                 sym::async_fn_kind_upvars,                   async_fn_kind_upvars,                      Target::AssocTy,                                   GenericRequirement::Exact(5);
+    AsyncFnMut,                        sym::async_fn_mut,                           async_fn_mut_trait,                        Target::Trait,                                     GenericRequirement::Exact(1);
+    AsyncFnOnce,                       sym::async_fn_once,                          async_fn_once_trait,                       Target::Trait,                                     GenericRequirement::Exact(1);
+    AsyncFnOnceOutput,                 sym::async_fn_once_output,                   async_fn_once_output,                      Target::AssocTy,                                   GenericRequirement::Exact(1);
+    AsyncIterator,                     sym::async_iterator,                         async_iterator_trait,                      Target::Trait,                                     GenericRequirement::Exact(0);
+    AsyncIteratorPollNext,             sym::async_iterator_poll_next,               async_iterator_poll_next_fn,               Target::Method(MethodKind::Trait { body: false }), GenericRequirement::Exact(0);
+    AsyncGenFinished,                  sym::AsyncGenFinished,                       async_gen_finished,                        Target::AssocConst,                                GenericRequirement::None;
+    AsyncGenPending,                   sym::AsyncGenPending,                        async_gen_pending,                         Target::AssocConst,                                GenericRequirement::None;
+    AsyncGenReady,                     sym::AsyncGenReady,                          async_gen_ready_fn,                        Target::Method(MethodKind::Inherent),              GenericRequirement::Exact(1);
+    BeginPanic,                        sym::begin_panic,                            begin_panic_fn,                            Target::Fn,                                        GenericRequirement::None;
+    Bitand,                            sym::bitand,                                 bitand_trait,                              Target::Trait,                                     GenericRequirement::Exact(1);
+    BitandAssign,                      sym::bitand_assign,                          bitand_assign_trait,                       Target::Trait,                                     GenericRequirement::Exact(1);
+    Bitor,                             sym::bitor,                                  bitor_trait,                               Target::Trait,                                     GenericRequirement::Exact(1);
+    BitorAssign,                       sym::bitor_assign,                           bitor_assign_trait,                        Target::Trait,                                     GenericRequirement::Exact(1);
+    Bitxor,                            sym::bitxor,                                 bitxor_trait,                              Target::Trait,                                     GenericRequirement::Exact(1);
+    BitxorAssign,                      sym::bitxor_assign,                          bitxor_assign_trait,                       Target::Trait,                                     GenericRequirement::Exact(1);
+    Branch,                            sym::branch,                                 branch_fn,                                 Target::Method(MethodKind::Trait { body: false }), GenericRequirement::None;
+    Break,                             sym::Break,                                  break,                                     Target::Variant,                                   GenericRequirement::None;
+    CVoid,                             sym::c_void,                                 c_void,                                    Target::Enum,                                      GenericRequirement::None;
+    CallOnceFuture,                    sym::call_once_future,                       call_once_future,                          Target::AssocTy,                                   GenericRequirement::Exact(1);
+    CallRefFuture,                     sym::call_ref_future,                        call_ref_future,                           Target::AssocTy,                                   GenericRequirement::Exact(2);
+    Clone,                             sym::clone,                                  clone_trait,                               Target::Trait,                                     GenericRequirement::None;
+    CloneFn,                           sym::clone_fn,                               clone_fn_fn,                               Target::Method(MethodKind::Trait { body: false }), GenericRequirement::None;
+    CoerceUnsized,                     sym::coerce_unsized,                         coerce_unsized_trait,                      Target::Trait,                                     GenericRequirement::Minimum(1);
+    ConstPanicFmt,                     sym::const_panic_fmt,                        const_panic_fmt_fn,                        Target::Fn,                                        GenericRequirement::None;
+    ConstParamTy,                      sym::const_param_ty,                         const_param_ty_trait,                      Target::Trait,                                     GenericRequirement::Exact(0);
+    Context,                           sym::Context,                                context,                                   Target::Struct,                                    GenericRequirement::None;
+    Continue,                          sym::Continue,                               continue,                                  Target::Variant,                                   GenericRequirement::None;
+    Copy,                              sym::copy,                                   copy_trait,                                Target::Trait,                                     GenericRequirement::Exact(0);
+    Coroutine,                         sym::coroutine,                              coroutine_trait,                           Target::Trait,                                     GenericRequirement::Exact(1);
+    CoroutineResume,                   sym::coroutine_resume,                       coroutine_resume_fn,                       Target::Method(MethodKind::Trait { body: false }), GenericRequirement::None;
+    CoroutineReturn,                   sym::coroutine_return,                       coroutine_return,                          Target::AssocTy,                                   GenericRequirement::Exact(1);
+    CoroutineState,                    sym::coroutine_state,                        coroutine_state,                           Target::Enum,                                      GenericRequirement::None;
+    CoroutineYield,                    sym::coroutine_yield,                        coroutine_yield,                           Target::AssocTy,                                   GenericRequirement::Exact(1);
+    CStr,                              sym::CStr,                                   c_str,                                     Target::Struct,                                    GenericRequirement::None;
+    Deref,                             sym::deref,                                  deref_trait,                               Target::Trait,                                     GenericRequirement::Exact(0);
+    DerefMut,                          sym::deref_mut,                              deref_mut_trait,                           Target::Trait,                                     GenericRequirement::Exact(0);
+    DerefPure,                         sym::deref_pure,                             deref_pure_trait,                          Target::Trait,                                     GenericRequirement::Exact(0);
+    DerefTarget,                       sym::deref_target,                           deref_target,                              Target::AssocTy,                                   GenericRequirement::None;
+    Destruct,                          sym::destruct,                               destruct_trait,                            Target::Trait,                                     GenericRequirement::None;
+    DiscriminantKind,                  sym::discriminant_kind,                      discriminant_kind_trait,                   Target::Trait,                                     GenericRequirement::None;
+    DiscriminantType,                  sym::discriminant_type,                      discriminant_type,                         Target::AssocTy,                                   GenericRequirement::None;
+    DispatchFromDyn,                   sym::dispatch_from_dyn,                      dispatch_from_dyn_trait,                   Target::Trait,                                     GenericRequirement::Minimum(1);
+    Div,                               sym::div,                                    div_trait,                                 Target::Trait,                                     GenericRequirement::Exact(1);
+    DivAssign,                         sym::div_assign,                             div_assign_trait,                          Target::Trait,                                     GenericRequirement::Exact(1);
+    Drop,                              sym::drop,                                   drop_trait,                                Target::Trait,                                     GenericRequirement::None;
+    DropInPlace,                       sym::drop_in_place,                          drop_in_place_fn,                          Target::Fn,                                        GenericRequirement::Minimum(1);
+    DynMetadata,                       sym::dyn_metadata,                           dyn_metadata,                              Target::Struct,                                    GenericRequirement::None;
+    EhCatchTypeinfo,                   sym::eh_catch_typeinfo,                      eh_catch_typeinfo,                         Target::Static,                                    GenericRequirement::None;
+    EhPersonality,                     sym::eh_personality,                         eh_personality_fn,                         Target::Fn,                                        GenericRequirement::None;
+    Eq,                                sym::eq,                                     eq_trait,                                  Target::Trait,                                     GenericRequirement::Exact(1);
+    Err,                               sym::Err,                                    err,                                       Target::Variant,                                   GenericRequirement::None;
+    ExchangeMalloc,                    sym::exchange_malloc,                        exchange_malloc_fn,                        Target::Fn,                                        GenericRequirement::None;
+    FallbackSurfaceDrop,               sym::fallback_surface_drop,                  fallback_surface_drop_fn,                  Target::Fn,                                        GenericRequirement::None;
+    Fn,                                sym::fn,                                     fn_trait,                                  Target::Trait,                                     GenericRequirement::Exact(1);
+    FnMut,                             sym::fn_mut,                                 fn_mut_trait,                              Target::Trait,                                     GenericRequirement::Exact(1);
+    FnOnce,                            sym::fn_once,                                fn_once_trait,                             Target::Trait,                                     GenericRequirement::Exact(1);
+    FnOnceOutput,                      sym::fn_once_output,                         fn_once_output,                            Target::AssocTy,                                   GenericRequirement::None;
+    FnPtrAddr,                         sym::fn_ptr_addr,                            fn_ptr_addr_fn,                            Target::Method(MethodKind::Trait { body: false }), GenericRequirement::None;
+    FnPtrTrait,                        sym::fn_ptr_trait,                           fn_ptr_trait_trait,                        Target::Trait,                                     GenericRequirement::Exact(0);
+    FormatAlignment,                   sym::format_alignment,                       format_alignment,                          Target::Enum,                                      GenericRequirement::None;
+    FormatArgument,                    sym::format_argument,                        format_argument,                           Target::Struct,                                    GenericRequirement::None;
+    FormatArguments,                   sym::format_arguments,                       format_arguments,                          Target::Struct,                                    GenericRequirement::None;
+    FormatCount,                       sym::format_count,                           format_count,                              Target::Enum,                                      GenericRequirement::None;
+    FormatPlaceholder,                 sym::format_placeholder,                     format_placeholder,                        Target::Struct,                                    GenericRequirement::None;
+    FormatUnsafeArg,                   sym::format_unsafe_arg,                      format_unsafe_arg,                         Target::Struct,                                    GenericRequirement::None;
+    Freeze,                            sym::freeze,                                 freeze_trait,                              Target::Trait,                                     GenericRequirement::Exact(0);
+    FromOutput,                        sym::from_output,                            from_output_fn,                            Target::Method(MethodKind::Trait { body: false }), GenericRequirement::None;
+    FromResidual,                      sym::from_residual,                          from_residual_fn,                          Target::Method(MethodKind::Trait { body: false }), GenericRequirement::None;
+    FromYeet,                          sym::from_yeet,                              from_yeet_fn,                              Target::Fn,                                        GenericRequirement::None;
+    FusedIterator,                     sym::fused_iterator,                         fused_iterator_trait,                      Target::Trait,                                     GenericRequirement::Exact(0);
+    FutureOutput,                      sym::future_output,                          future_output,                             Target::AssocTy,                                   GenericRequirement::Exact(0);
+    FutureTrait,                       sym::future_trait,                           future_trait_trait,                        Target::Trait,                                     GenericRequirement::Exact(0);
+    GetContext,                        sym::get_context,                            get_context_fn,                            Target::Fn,                                        GenericRequirement::None;
+    GlobalAllocTy,                     sym::global_alloc_ty,                        global_alloc_ty,                           Target::Struct,                                    GenericRequirement::None;
+    Index,                             sym::index,                                  index_trait,                               Target::Trait,                                     GenericRequirement::Exact(1);
+    IndexMut,                          sym::index_mut,                              index_mut_trait,                           Target::Trait,                                     GenericRequirement::Exact(1);
+    IntoAsyncIterIntoIter,             sym::into_async_iter_into_iter,              into_async_iter_into_iter_fn,              Target::Method(MethodKind::Trait { body: false }), GenericRequirement::Exact(0);
+    IntoFuture,                        sym::into_future,                            into_future_fn,                            Target::Method(MethodKind::Trait { body: false }), GenericRequirement::None;
+    IntoIter,                          sym::into_iter,                              into_iter_fn,                              Target::Method(MethodKind::Trait { body: false }), GenericRequirement::None;
+    Iterator,                          sym::iterator,                               iterator_trait,                            Target::Trait,                                     GenericRequirement::Exact(0);
+    LegacyReceiver,                    sym::legacy_receiver,                        legacy_receiver_trait,                     Target::Trait,                                     GenericRequirement::None;
+    ManuallyDrop,                      sym::manually_drop,                          manually_drop,                             Target::Struct,                                    GenericRequirement::None;
+    MaybeUninit,                       sym::maybe_uninit,                           maybe_uninit,                              Target::Union,                                     GenericRequirement::None;
+    MetadataType,                      sym::metadata_type,                          metadata_type,                             Target::AssocTy,                                   GenericRequirement::None;
+    Mul,                               sym::mul,                                    mul_trait,                                 Target::Trait,                                     GenericRequirement::Exact(1);
+    MulAssign,                         sym::mul_assign,                             mul_assign_trait,                          Target::Trait,                                     GenericRequirement::Exact(1);
+    Neg,                               sym::neg,                                    neg_trait,                                 Target::Trait,                                     GenericRequirement::Exact(0);
+    NewUnchecked,                      sym::new_unchecked,                          new_unchecked_fn,                          Target::Method(MethodKind::Inherent),              GenericRequirement::None;
+    Next,                              sym::next,                                   next_fn,                                   Target::Method(MethodKind::Trait { body: false }), GenericRequirement::None;
+    None,                              sym::None,                                   none,                                      Target::Variant,                                   GenericRequirement::None;
+    Not,                               sym::not,                                    not_trait,                                 Target::Trait,                                     GenericRequirement::Exact(0);
+    Ok,                                sym::Ok,                                     ok,                                        Target::Variant,                                   GenericRequirement::None;
+    Option,                            sym::Option,                                 option,                                    Target::Enum,                                      GenericRequirement::None;
+    Ordering,                          sym::Ordering,                               ordering,                                  Target::Enum,                                      GenericRequirement::Exact(0);
+    OwnedBox,                          sym::owned_box,                              owned_box,                                 Target::Struct,                                    GenericRequirement::Minimum(1);
+    Panic,                             sym::panic,                                  panic_fn,                                  Target::Fn,                                        GenericRequirement::Exact(0);
+    PanicBoundsCheck,                  sym::panic_bounds_check,                     panic_bounds_check_fn,                     Target::Fn,                                        GenericRequirement::Exact(0);
+    PanicCannotUnwind,                 sym::panic_cannot_unwind,                    panic_cannot_unwind_fn,                    Target::Fn,                                        GenericRequirement::Exact(0);
+    PanicConstAddOverflow,             sym::panic_const_add_overflow,               panic_const_add_overflow_fn,               Target::Fn,                                        GenericRequirement::None;
+    PanicConstAsyncFnResumed,          sym::panic_const_async_fn_resumed,           panic_const_async_fn_resumed_fn,           Target::Fn,                                        GenericRequirement::None;
+    PanicConstAsyncFnResumedPanic,     sym::panic_const_async_fn_resumed_panic,     panic_const_async_fn_resumed_panic_fn,     Target::Fn,                                        GenericRequirement::None;
+    PanicConstAsyncGenFnResumed,       sym::panic_const_async_gen_fn_resumed,       panic_const_async_gen_fn_resumed_fn,       Target::Fn,                                        GenericRequirement::None;
+    PanicConstAsyncGenFnResumedPanic,  sym::panic_const_async_gen_fn_resumed_panic, panic_const_async_gen_fn_resumed_panic_fn, Target::Fn,                                        GenericRequirement::None;
+    PanicConstCoroutineResumed,        sym::panic_const_coroutine_resumed,          panic_const_coroutine_resumed_fn,          Target::Fn,                                        GenericRequirement::None;
+    PanicConstCoroutineResumedPanic,   sym::panic_const_coroutine_resumed_panic,    panic_const_coroutine_resumed_panic_fn,    Target::Fn,                                        GenericRequirement::None;
+    PanicConstDivByZero,               sym::panic_const_div_by_zero,                panic_const_div_by_zero_fn,                Target::Fn,                                        GenericRequirement::None;
+    PanicConstDivOverflow,             sym::panic_const_div_overflow,               panic_const_div_overflow_fn,               Target::Fn,                                        GenericRequirement::None;
+    PanicConstGenFnNone,               sym::panic_const_gen_fn_none,                panic_const_gen_fn_none_fn,                Target::Fn,                                        GenericRequirement::None;
+    PanicConstGenFnNonePanic,          sym::panic_const_gen_fn_none_panic,          panic_const_gen_fn_none_panic_fn,          Target::Fn,                                        GenericRequirement::None;
+    PanicConstMulOverflow,             sym::panic_const_mul_overflow,               panic_const_mul_overflow_fn,               Target::Fn,                                        GenericRequirement::None;
+    PanicConstNegOverflow,             sym::panic_const_neg_overflow,               panic_const_neg_overflow_fn,               Target::Fn,                                        GenericRequirement::None;
+    PanicConstRemByZero,               sym::panic_const_rem_by_zero,                panic_const_rem_by_zero_fn,                Target::Fn,                                        GenericRequirement::None;
+    PanicConstRemOverflow,             sym::panic_const_rem_overflow,               panic_const_rem_overflow_fn,               Target::Fn,                                        GenericRequirement::None;
+    PanicConstShlOverflow,             sym::panic_const_shl_overflow,               panic_const_shl_overflow_fn,               Target::Fn,                                        GenericRequirement::None;
+    PanicConstShrOverflow,             sym::panic_const_shr_overflow,               panic_const_shr_overflow_fn,               Target::Fn,                                        GenericRequirement::None;
+    PanicConstSubOverflow,             sym::panic_const_sub_overflow,               panic_const_sub_overflow_fn,               Target::Fn,                                        GenericRequirement::None;
+    PanicFmt,                          sym::panic_fmt,                              panic_fmt_fn,                              Target::Fn,                                        GenericRequirement::None;
+    PanicImpl,                         sym::panic_impl,                             panic_impl_fn,                             Target::Fn,                                        GenericRequirement::None;
+    PanicInCleanup,                    sym::panic_in_cleanup,                       panic_in_cleanup_fn,                       Target::Fn,                                        GenericRequirement::Exact(0);
+    PanicInfo,                         sym::panic_info,                             panic_info,                                Target::Struct,                                    GenericRequirement::None;
+    PanicLocation,                     sym::panic_location,                         panic_location,                            Target::Struct,                                    GenericRequirement::None;
+    PanicMisalignedPointerDereference, sym::panic_misaligned_pointer_dereference,   panic_misaligned_pointer_dereference_fn,   Target::Fn,                                        GenericRequirement::Exact(0);
+    PanicNounwind,                     sym::panic_nounwind,                         panic_nounwind_fn,                         Target::Fn,                                        GenericRequirement::Exact(0);
+    PanicNullPointerDereference,       sym::panic_null_pointer_dereference,         panic_null_pointer_dereference_fn,         Target::Fn,                                        GenericRequirement::None;
+    PartialOrd,                        sym::partial_ord,                            partial_ord_trait,                         Target::Trait,                                     GenericRequirement::Exact(1);
+    Pending,                           sym::Pending,                                pending,                                   Target::Variant,                                   GenericRequirement::None;
+    PhantomData,                       sym::phantom_data,                           phantom_data,                              Target::Struct,                                    GenericRequirement::Exact(1);
+    Pin,                               sym::pin,                                    pin,                                       Target::Struct,                                    GenericRequirement::None;
+    PointeeTrait,                      sym::pointee_trait,                          pointee_trait_trait,                       Target::Trait,                                     GenericRequirement::None;
+    PointerLike,                       sym::pointer_like,                           pointer_like_trait,                        Target::Trait,                                     GenericRequirement::Exact(0);
+    Poll,                              sym::Poll,                                   poll,                                      Target::Enum,                                      GenericRequirement::None;
+    Poll,                              sym::poll,                                   poll_fn,                                   Target::Method(MethodKind::Trait { body: false }), GenericRequirement::None;
+    PtrUnique,                         sym::ptr_unique,                             ptr_unique,                                Target::Struct,                                    GenericRequirement::Exact(1);
+    Range,                             sym::Range,                                  range,                                     Target::Struct,                                    GenericRequirement::None;
+    RangeInclusiveNew,                 sym::range_inclusive_new,                    range_inclusive_new_fn,                    Target::Method(MethodKind::Inherent),              GenericRequirement::None;
+    RangeCopy,                         sym::RangeCopy,                              range_copy,                                Target::Struct,                                    GenericRequirement::None;
+    RangeFrom,                         sym::RangeFrom,                              range_from,                                Target::Struct,                                    GenericRequirement::None;
+    RangeFromCopy,                     sym::RangeFromCopy,                          range_from_copy,                           Target::Struct,                                    GenericRequirement::None;
+    RangeFull,                         sym::RangeFull,                              range_full,                                Target::Struct,                                    GenericRequirement::None;
+    RangeInclusive,                    sym::RangeInclusive,                         range_inclusive,                           Target::Struct,                                    GenericRequirement::None;
+    RangeInclusiveCopy,                sym::RangeInclusiveCopy,                     range_inclusive_copy,                      Target::Struct,                                    GenericRequirement::None;
+    RangeTo,                           sym::RangeTo,                                range_to,                                  Target::Struct,                                    GenericRequirement::None;
+    RangeToInclusive,                  sym::RangeToInclusive,                       range_to_inclusive,                        Target::Struct,                                    GenericRequirement::None;
+    Ready,                             sym::Ready,                                  ready,                                     Target::Variant,                                   GenericRequirement::None;
+    Receiver,                          sym::receiver,                               receiver_trait,                            Target::Trait,                                     GenericRequirement::None;
+    ReceiverTarget,                    sym::receiver_target,                        receiver_target,                           Target::AssocTy,                                   GenericRequirement::None;
+    Rem,                               sym::rem,                                    rem_trait,                                 Target::Trait,                                     GenericRequirement::Exact(1);
+    RemAssign,                         sym::rem_assign,                             rem_assign_trait,                          Target::Trait,                                     GenericRequirement::Exact(1);
+    ResumeTy,                          sym::ResumeTy,                               resume_ty,                                 Target::Struct,                                    GenericRequirement::None;
+    Shl,                               sym::shl,                                    shl_trait,                                 Target::Trait,                                     GenericRequirement::Exact(1);
+    ShlAssign,                         sym::shl_assign,                             shl_assign_trait,                          Target::Trait,                                     GenericRequirement::Exact(1);
+    Shr,                               sym::shr,                                    shr_trait,                                 Target::Trait,                                     GenericRequirement::Exact(1);
+    ShrAssign,                         sym::shr_assign,                             shr_assign_trait,                          Target::Trait,                                     GenericRequirement::Exact(1);
+    Sized,                             sym::sized,                                  sized_trait,                               Target::Trait,                                     GenericRequirement::Exact(0);
+    SliceLenFn,                        sym::slice_len_fn,                           slice_len_fn_fn,                           Target::Method(MethodKind::Inherent),              GenericRequirement::None;
+    Some,                              sym::Some,                                   some,                                      Target::Variant,                                   GenericRequirement::None;
+    Start,                             sym::start,                                  start_fn,                                  Target::Fn,                                        GenericRequirement::Exact(1);
+    String,                            sym::String,                                 string,                                    Target::Struct,                                    GenericRequirement::None;
+    StructuralPeq,                     sym::structural_peq,                         structural_peq_trait,                      Target::Trait,                                     GenericRequirement::None;
+    Sub,                               sym::sub,                                    sub_trait,                                 Target::Trait,                                     GenericRequirement::Exact(1);
+    SubAssign,                         sym::sub_assign,                             sub_assign_trait,                          Target::Trait,                                     GenericRequirement::Exact(1);
+    SurfaceAsyncDropInPlace,           sym::surface_async_drop_in_place,            surface_async_drop_in_place_fn,            Target::Fn,                                        GenericRequirement::Exact(1);
+    Sync,                              sym::sync,                                   sync_trait,                                Target::Trait,                                     GenericRequirement::Exact(0);
+    Termination,                       sym::termination,                            termination_trait,                         Target::Trait,                                     GenericRequirement::None;
+    TransmuteOpts,                     sym::transmute_opts,                         transmute_opts,                            Target::Struct,                                    GenericRequirement::Exact(0);
+    TransmuteTrait,                    sym::transmute_trait,                        transmute_trait_trait,                     Target::Trait,                                     GenericRequirement::Exact(2);
+    Try,                               sym::Try,                                    try_trait,                                 Target::Trait,                                     GenericRequirement::None;
+    TupleTrait,                        sym::tuple_trait,                            tuple_trait_trait,                         Target::Trait,                                     GenericRequirement::Exact(0);
+    Unpin,                             sym::unpin,                                  unpin_trait,                               Target::Trait,                                     GenericRequirement::None;
+    UnsafeCell,                        sym::unsafe_cell,                            unsafe_cell,                               Target::Struct,                                    GenericRequirement::None;
+    Unsize,                            sym::unsize,                                 unsize_trait,                              Target::Trait,                                     GenericRequirement::Minimum(1);
+    UnsizedConstParamTy,               sym::unsized_const_param_ty,                 unsized_const_param_ty_trait,              Target::Trait,                                     GenericRequirement::Exact(0);
+    VaList,                            sym::va_list,                                va_list,                                   Target::Struct,                                    GenericRequirement::None;
+}
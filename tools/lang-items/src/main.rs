@@ -0,0 +1,276 @@
+//! Checks the lang items of restd against the core and against rustc.
+//!
+//! ```text
+//! cargo run -p lang-items [--deny-missing]
+//! cargo run -p lang-items rustc
+//! ```
+//!
+//! Without a command, the items are collected from the `#[lang = "..."]` and
+//! `#[compiler::item("...")]` attributes of the modules of the core, the
+//! ones rustc loads from `core/src/lib.rs` (see `modules`), and checked
+//! against the table of `compiler/src/lang_item.rs`:
+//! - a lang item defined more than once is an error,
+//! - a `#[lang = "..."]` unknown to the table is reported, it has to be added
+//!   to the table,
+//! - the lang items of the table which are not defined yet are listed, and
+//!   are an error with `--deny-missing`.
+//!
+//! `compiler::item` already rejects duplicates inside a crate through its
+//! hidden `__lang_item_*` markers, this tool also covers the raw
+//! `#[lang = "..."]` attributes and the missing items.
+//!
+//! With `rustc`, the table is compared to the lang items of the pinned
+//! toolchain, read from `rustc_hir/lang_items.rs`: rustc's
+//! `compiler/rustc_hir/src/lang_items.rs` at the commit of the toolchain,
+//! preceded by a `// Toolchain:` line like the one of
+//! `probed/lang_items.rs`. The renamed and removed lang items, and the ones
+//! with another target or count of generics, are errors. The lang items
+//! missing from the table (but the ones of `UPSTREAM_HINTS`) are listed as
+//! rows ready to be added. After a bump of `rust-toolchain.toml`, update
+//! `rustc_hir/lang_items.rs` first.
+//!
+//! The upstream file is not checked in yet: until it is, the tool falls back
+//! to `probed/lang_items.rs`, the lang items probed from the toolchain, and
+//! warns about it.
+//!
+//! In both modes, the rows of the table are the ones known by the pinned
+//! rustc, with the names this rustc gives them (see the `since`, `until` and
+//! `Renamed` columns of the table).
+
+mod modules;
+mod rustc;
+mod table;
+
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+use std::{env, fs, process};
+
+use modules::Cfg;
+use table::Row;
+
+/// rustc's `compiler/rustc_hir/src/lang_items.rs` at the commit of the
+/// toolchain, with a `// Toolchain:` line.
+const UPSTREAM: &str = "rustc_hir/lang_items.rs";
+
+/// The lang items probed from the toolchain, read while `UPSTREAM` is
+/// missing.
+const PROBED: &str = "probed/lang_items.rs";
+
+/// Where a lang item is defined.
+struct Definition {
+    path: PathBuf,
+    line: usize,
+}
+
+fn main() {
+    let manifest_dir = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+    let root = manifest_dir.join("../..");
+    let table_path = root.join("compiler/src/lang_item.rs");
+    let upstream_path = manifest_dir.join(UPSTREAM);
+    let probed = !upstream_path.exists();
+    let rustc_path = if probed {
+        manifest_dir.join(PROBED)
+    } else {
+        upstream_path
+    };
+    let rustc = read(&rustc_path);
+    let Some((channel, date)) = rustc::toolchain(&rustc) else {
+        eprintln!("`{}`: no `// Toolchain:` line", rustc_path.display());
+        process::exit(1);
+    };
+    let table = read(&table_path);
+    // the lang items known by the pinned rustc
+    let rows = table::rows(&table, date).unwrap_or_else(|e| {
+        eprintln!("`{}`: {e}", table_path.display());
+        process::exit(1);
+    });
+
+    let args: Vec<String> = env::args().skip(1).collect();
+    match args
+        .iter()
+        .map(String::as_str)
+        .collect::<Vec<_>>()
+        .as_slice()
+    {
+        [] => check_core(&root, &rows, false),
+        ["--deny-missing"] => check_core(&root, &rows, true),
+        ["rustc"] => {
+            let excluded = table::upstream_hints(&table).unwrap_or_else(|e| {
+                eprintln!("`{}`: {e}", table_path.display());
+                process::exit(1);
+            });
+            if probed {
+                println!(
+                    "warning: `tools/lang-items/{UPSTREAM}` is not checked in, the table is compared to \
+                     the names, targets and generics probed from the toolchain in `tools/lang-items/{PROBED}`"
+                );
+            }
+            check_rustc(&root, &rustc_path, &rustc, channel, &rows, &excluded)
+        }
+        _ => {
+            eprintln!("expected no argument, `--deny-missing` or `rustc`");
+            process::exit(1);
+        }
+    }
+}
+
+/// Compares the table to the lang items of rustc read from `rustc_path`.
+fn check_rustc(
+    root: &Path,
+    rustc_path: &Path,
+    rustc: &str,
+    toolchain: &str,
+    rows: &[Row],
+    excluded: &[String],
+) {
+    let lang_items = rustc::lang_items(rustc).unwrap_or_else(|e| {
+        eprintln!("`{}`: {e}", rustc_path.display());
+        process::exit(1);
+    });
+
+    let channel = read(&root.join("rust-toolchain.toml"))
+        .lines()
+        .find_map(|line| line.trim().strip_prefix("channel = "))
+        .map(|channel| channel.trim_matches('"').to_owned());
+    if channel.as_deref() != Some(toolchain) {
+        println!(
+            "warning: the lang items are the ones of `{toolchain}` but the toolchain is `{}`, update `{}`",
+            channel.as_deref().unwrap_or("?"),
+            rustc_path
+                .strip_prefix(root)
+                .unwrap_or(rustc_path)
+                .display(),
+        );
+    }
+
+    let diff = rustc::diff(&lang_items, rows, excluded);
+    print!("{}", rustc::report(&diff));
+    println!(
+        "{} lang items in rustc, {} in the table, {} added, {} removed, {} renamed, {} mismatched",
+        lang_items.len(),
+        rows.iter().filter(|row| row.rustc_name.is_some()).count(),
+        diff.added.len(),
+        diff.removed.len(),
+        diff.renamed.len(),
+        diff.mismatched.len(),
+    );
+    if diff.is_breaking() {
+        process::exit(1);
+    }
+}
+
+/// Checks the lang items defined by `core/src` against the table.
+fn check_core(root: &Path, rows: &[Row], deny_missing: bool) {
+    let cfg = Cfg::host(root).unwrap_or_else(|e| {
+        eprintln!("{e}");
+        process::exit(1);
+    });
+    let mut files = modules::files(&root.join("core/src/lib.rs"), &cfg).unwrap_or_else(|e| {
+        eprintln!("{e}");
+        process::exit(1);
+    });
+    files.sort();
+
+    let mut definitions: BTreeMap<&str, Vec<Definition>> = BTreeMap::new();
+    let mut unknown = Vec::new();
+    for path in files {
+        let content = read(&path);
+        for (line, attribute) in attributes(&content) {
+            let row = match &attribute {
+                Attribute::Lang(name) => rows
+                    .iter()
+                    .find(|row| row.rustc_name.as_ref() == Some(name)),
+                Attribute::Item(name) => rows.iter().find(|row| &row.name == name),
+            };
+            let definition = Definition {
+                path: path.strip_prefix(root).unwrap_or(&path).to_owned(),
+                line,
+            };
+            match row {
+                Some(row) => definitions.entry(&row.name).or_default().push(definition),
+                // an unknown `compiler::item` is already an error of the macro
+                None => unknown.push((attribute, definition)),
+            }
+        }
+    }
+
+    let mut failed = false;
+    for (name, definitions) in definitions.iter().filter(|(_, d)| d.len() > 1) {
+        failed = true;
+        println!(
+            "error: the lang item `{name}` is defined {} times",
+            definitions.len()
+        );
+        for Definition { path, line } in definitions {
+            println!("  --> {}:{line}", path.display());
+        }
+    }
+
+    for (attribute, Definition { path, line }) in &unknown {
+        if let Attribute::Lang(name) = attribute {
+            println!("warning: `#[lang = \"{name}\"]` is not in the table");
+            println!("  --> {}:{line}", path.display());
+        }
+    }
+
+    let missing: Vec<&Row> = rows
+        .iter()
+        .filter(|row| row.rustc_name.is_some() && !definitions.contains_key(row.name.as_str()))
+        .collect();
+    let required = rows.iter().filter(|row| row.rustc_name.is_some()).count();
+    println!(
+        "{} of the {required} lang items required by rustc are defined, {} are missing{}",
+        required - missing.len(),
+        missing.len(),
+        if missing.is_empty() { "" } else { ":" },
+    );
+    for row in &missing {
+        println!("  {} ({})", row.name, row.variant);
+    }
+
+    if failed || (deny_missing && !missing.is_empty()) {
+        process::exit(1);
+    }
+}
+
+/// A lang item attribute, with the name it is given.
+enum Attribute {
+    /// `#[lang = "..."]`, with the name known by rustc.
+    Lang(String),
+    /// `#[compiler::item("...")]`, with the name of the table.
+    Item(String),
+}
+
+/// Returns the lang item attributes of `content`, with their line number,
+/// the commented lines are skipped.
+fn attributes(content: &str) -> Vec<(usize, Attribute)> {
+    let mut attributes = Vec::new();
+    for (i, line) in content.lines().enumerate() {
+        let line = line.trim_start();
+        if line.starts_with("//") {
+            continue;
+        }
+        for (prefix, attribute) in [
+            ("#[lang = \"", Attribute::Lang as fn(String) -> Attribute),
+            ("#[compiler::item(\"", Attribute::Item),
+        ] {
+            let mut remaining = line;
+            while let Some(start) = remaining.find(prefix) {
+                let after = &remaining[start + prefix.len()..];
+                let Some(end) = after.find('"') else {
+                    break;
+                };
+                attributes.push((i + 1, attribute(after[..end].to_owned())));
+                remaining = &after[end..];
+            }
+        }
+    }
+    attributes
+}
+
+fn read(path: &Path) -> String {
+    fs::read_to_string(path).unwrap_or_else(|e| {
+        eprintln!("cannot read `{}`: {e}", path.display());
+        process::exit(1);
+    })
+}
@@ -0,0 +1,221 @@
+//! Walks the module tree of a crate from its root file, the way rustc loads
+//! it: the commented `mod` declarations are not seen, and the ones (or the
+//! files) gated by a `cfg` false for the host are skipped.
+
+use std::collections::BTreeSet;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+use crate::table::split;
+
+/// The `cfg` options of the host, as given by `rustc --print cfg`, like
+/// `unix` or `target_os="linux"`.
+pub struct Cfg(BTreeSet<String>);
+
+impl Cfg {
+    /// Asks the rustc of the toolchain of `root` for the options of the host.
+    pub fn host(root: &Path) -> Result<Cfg, String> {
+        let output = Command::new("rustc")
+            .args(["--print", "cfg"])
+            .current_dir(root)
+            .output()
+            .map_err(|e| format!("cannot run `rustc --print cfg`: {e}"))?;
+        if !output.status.success() {
+            return Err(format!(
+                "`rustc --print cfg` failed: {}",
+                String::from_utf8_lossy(&output.stderr).trim()
+            ));
+        }
+        let options = String::from_utf8_lossy(&output.stdout)
+            .lines()
+            .map(str::to_owned)
+            .collect();
+        Ok(Cfg(options))
+    }
+
+    /// Evaluates the predicate of a `cfg(...)`, like `any(unix, test)`.
+    fn eval(&self, predicate: &str) -> Result<bool, String> {
+        let predicate = predicate.trim();
+        let invalid = || format!("invalid `cfg` predicate `{predicate}`");
+        let Some(open) = predicate.find('(').filter(|&open| {
+            // not the parenthesis of a value, like `feature = "a(b)"`
+            predicate.find('=').is_none_or(|equal| open < equal)
+        }) else {
+            return Ok(match predicate.split_once('=') {
+                Some((name, value)) => {
+                    self.0
+                        .contains(&format!("{}={}", name.trim(), value.trim()))
+                }
+                None => self.0.contains(predicate),
+            });
+        };
+        let arguments = predicate[open + 1..]
+            .strip_suffix(')')
+            .ok_or_else(invalid)?;
+        let mut arguments = split(arguments, ',')
+            .into_iter()
+            .filter(|argument| !argument.trim().is_empty())
+            .map(|argument| self.eval(argument));
+        match predicate[..open].trim() {
+            "all" => arguments.try_fold(true, |all, value| Ok(all && value?)),
+            "any" => arguments.try_fold(false, |any, value| Ok(any || value?)),
+            "not" => match (arguments.next(), arguments.next()) {
+                (Some(value), None) => Ok(!value?),
+                _ => Err(invalid()),
+            },
+            _ => Err(invalid()),
+        }
+    }
+
+    /// Whether none of the `#[cfg(...)]` (or `#![cfg(...)]`) of `attributes`
+    /// is false.
+    fn enabled(&self, attributes: &[String]) -> Result<bool, String> {
+        for attribute in attributes {
+            let attribute = attribute.trim_start_matches("#!").trim_start_matches('#');
+            let Some(predicate) = attribute
+                .strip_prefix("[cfg(")
+                .and_then(|predicate| predicate.strip_suffix(")]"))
+            else {
+                continue;
+            };
+            if !self.eval(predicate)? {
+                return Ok(false);
+            }
+        }
+        Ok(true)
+    }
+}
+
+/// Returns the files of the modules of the crate rooted at `root_file`,
+/// starting with `root_file`.
+pub fn files(root_file: &Path, cfg: &Cfg) -> Result<Vec<PathBuf>, String> {
+    let mut files = Vec::new();
+    walk(root_file, true, cfg, &mut files)?;
+    Ok(files)
+}
+
+/// Adds `file` and the files of the modules it declares, `owns_directory`
+/// for the files whose modules live next to them (`lib.rs` and `mod.rs`).
+fn walk(
+    file: &Path,
+    owns_directory: bool,
+    cfg: &Cfg,
+    files: &mut Vec<PathBuf>,
+) -> Result<(), String> {
+    let content =
+        fs::read_to_string(file).map_err(|e| format!("cannot read `{}`: {e}", file.display()))?;
+    let error = |e: String| format!("`{}`: {e}", file.display());
+
+    let items = items(&content);
+    let inner: Vec<String> = items
+        .iter()
+        .flat_map(|(attributes, _)| attributes)
+        .filter(|attribute| attribute.starts_with("#!"))
+        .cloned()
+        .collect();
+    if !cfg.enabled(&inner).map_err(error)? {
+        return Ok(());
+    }
+    files.push(file.to_owned());
+
+    let directory = match (file.parent(), file.file_stem()) {
+        (Some(parent), _) if owns_directory => parent.to_owned(),
+        (Some(parent), Some(stem)) => parent.join(stem),
+        _ => return Err(error("no parent directory".to_owned())),
+    };
+    for (attributes, item) in &items {
+        let Some(name) = declaration(item) else {
+            continue;
+        };
+        if !cfg.enabled(attributes).map_err(error)? {
+            continue;
+        }
+        let flat = directory.join(format!("{name}.rs"));
+        let nested = directory.join(name).join("mod.rs");
+        match (flat.exists(), nested.exists()) {
+            (true, false) => walk(&flat, false, cfg, files)?,
+            (false, true) => walk(&nested, true, cfg, files)?,
+            (true, true) => {
+                return Err(error(format!("both `{name}.rs` and `{name}/mod.rs` exist")));
+            }
+            (false, false) => return Err(error(format!("no file for `mod {name};`"))),
+        }
+    }
+    Ok(())
+}
+
+/// Returns the name of the module declared by `item`, like `foo` for
+/// `pub(crate) mod foo;`, the inline modules have their items in the same
+/// file and are not returned.
+fn declaration(item: &str) -> Option<&str> {
+    let item = match item.strip_prefix("pub") {
+        Some(rest) if rest.starts_with('(') => rest[rest.find(')')? + 1..].trim_start(),
+        Some(rest) => rest.trim_start(),
+        None => item,
+    };
+    let name = item.strip_prefix("mod ")?.strip_suffix(';')?.trim();
+    name.chars()
+        .all(|c| c.is_alphanumeric() || c == '_')
+        .then_some(name)
+}
+
+/// Returns the lines of `content` which are not attributes nor comments,
+/// with the attributes written above them (or at their start). The
+/// attributes spanning several lines are joined.
+fn items(content: &str) -> Vec<(Vec<String>, String)> {
+    let mut items = Vec::new();
+    let mut attributes = Vec::new();
+    // the text not consumed yet, an unfinished attribute included
+    let mut rest = String::new();
+    for line in content.lines() {
+        let line = line.trim();
+        if rest.is_empty() && line.starts_with("//") {
+            continue;
+        }
+        rest.push_str(line);
+        while rest.starts_with("#[") || rest.starts_with("#![") {
+            let Some(end) = attribute_end(&rest) else {
+                break;
+            };
+            attributes.push(rest[..end].to_owned());
+            rest = rest[end..].trim_start().to_owned();
+        }
+        if !rest.is_empty() && !rest.starts_with('#') {
+            items.push((std::mem::take(&mut attributes), std::mem::take(&mut rest)));
+        }
+    }
+    items
+}
+
+/// Returns the end of the attribute starting `attribute`, after its closing
+/// bracket, if it is complete.
+fn attribute_end(attribute: &str) -> Option<usize> {
+    let mut depth = 0;
+    let mut chars = attribute.char_indices();
+    while let Some((i, c)) = chars.next() {
+        match c {
+            '[' | '(' => depth += 1,
+            ']' | ')' => {
+                depth -= 1;
+                if depth == 0 {
+                    return Some(i + 1);
+                }
+            }
+            // like `#[doc = "["]`
+            '"' => {
+                while let Some((_, c)) = chars.next() {
+                    match c {
+                        '\\' => {
+                            chars.next();
+                        }
+                        '"' => break,
+                        _ => {}
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+    None
+}
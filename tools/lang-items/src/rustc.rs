@@ -0,0 +1,292 @@
+//! Reads a `language_item_table!` in the format of rustc's
+//! `compiler/rustc_hir/src/lang_items.rs` (the upstream file or the probed
+//! lang items, see the crate documentation) and compares it to the table of
+//! `compiler/src/lang_item.rs`.
+
+use std::fmt::Write;
+
+use crate::table::{Row, associated, block, split, strip_comments};
+
+/// The `GenericRequirement` of a lang item.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Generics {
+    None,
+    Minimum(usize),
+    Exact(usize),
+}
+
+/// One lang item known by rustc.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LangItem {
+    /// The name of `#[lang = "..."]`.
+    pub name: String,
+    /// The rustc target, like `Trait` or `Method(MethodKind::Inherent)`.
+    pub target: String,
+    pub generics: Generics,
+}
+
+impl LangItem {
+    /// The variant of `Target` in the table, rustc targets without one (like
+    /// `Static`) cannot be lang items of the table.
+    pub fn table_target(&self) -> Option<&'static str> {
+        Some(match self.target.as_str() {
+            "Trait" => "Trait",
+            "Struct" => "Struct",
+            "Enum" => "Enum",
+            "Union" => "Union",
+            "Fn" => "Fn",
+            "AssocTy" => "AssocType",
+            "Variant" => "Variant",
+            target if target.starts_with("Method(") => "AssocFn",
+            _ => return None,
+        })
+    }
+
+    /// Writes the row of the table generated for this lang item.
+    fn row(&self) -> String {
+        let Some(target) = self.table_target() else {
+            return format!(
+                "// `{}`: no `Target` for the rustc target `{}`",
+                self.name, self.target
+            );
+        };
+        let name = snake_case(&self.name);
+        let variant = name
+            .split('_')
+            .map(|part| part[..1].to_uppercase() + &part[1..])
+            .collect::<String>();
+        let constraints = match self.generics {
+            Generics::Exact(n) | Generics::Minimum(n) if n > 0 && !associated(target) => {
+                format!("Generics({n})")
+            }
+            _ => String::new(),
+        };
+        let lang = if name == self.name {
+            "Inherited".to_owned()
+        } else {
+            format!("Named({:?})", self.name)
+        };
+        format!("{variant}, {name:?}, Target::{target}, [{constraints}], No, {lang};")
+    }
+}
+
+/// Returns the channel and the rustc commit date of the `// Toolchain:` line
+/// of the lang items of rustc, like `nightly-2025-02-05 (rustc 1.86.0-nightly bef3c3b01 2025-02-04)`.
+pub fn toolchain(content: &str) -> Option<(&str, &str)> {
+    let toolchain = content
+        .lines()
+        .find_map(|line| line.strip_prefix("// Toolchain: "))?;
+    let (channel, version) = toolchain.split_once(' ')?;
+    let date = version.trim_end_matches(')').rsplit(' ').next()?;
+    Some((channel, date))
+}
+
+/// Returns the lang items of the `language_item_table!` invocation written in
+/// `content`.
+pub fn lang_items(content: &str) -> Result<Vec<LangItem>, String> {
+    let start = content
+        .find("language_item_table! {")
+        .ok_or("no `language_item_table!` invocation")?;
+
+    let mut lang_items = Vec::new();
+    for row in split(&strip_comments(block(&content[start..])?), ';') {
+        let row = row.trim();
+        if row.is_empty() {
+            continue;
+        }
+        let columns = split(row, ',');
+        let [_variant, name, _getter, target, generics] = columns.as_slice() else {
+            return Err(format!("expected 5 columns in `{row}`"));
+        };
+        let name = name
+            .trim()
+            .strip_prefix("sym::")
+            .ok_or_else(|| format!("invalid name in `{row}`"))?;
+        let target = target
+            .trim()
+            .strip_prefix("Target::")
+            .ok_or_else(|| format!("invalid target in `{row}`"))?;
+        let generics = generics
+            .trim()
+            .strip_prefix("GenericRequirement::")
+            .and_then(|generics| {
+                let count = |prefix| {
+                    generics
+                        .strip_prefix(prefix)?
+                        .strip_suffix(')')?
+                        .parse()
+                        .ok()
+                };
+                match generics {
+                    "None" => Some(Generics::None),
+                    _ => count("Exact(")
+                        .map(Generics::Exact)
+                        .or_else(|| count("Minimum(").map(Generics::Minimum)),
+                }
+            })
+            .ok_or_else(|| format!("invalid generic requirement in `{row}`"))?;
+        lang_items.push(LangItem {
+            name: name.to_owned(),
+            target: target.to_owned(),
+            generics,
+        });
+    }
+    Ok(lang_items)
+}
+
+/// The differences between the lang items of rustc and the table.
+#[derive(Debug, Default)]
+pub struct Diff<'a> {
+    /// Known by rustc, not by the table.
+    pub added: Vec<&'a LangItem>,
+    /// In the table, unknown to rustc.
+    pub removed: Vec<&'a Row>,
+    /// In the table under an old name.
+    pub renamed: Vec<(&'a Row, &'a LangItem)>,
+    /// In both, with a different target or count of generics.
+    pub mismatched: Vec<(&'a Row, &'a LangItem, String)>,
+}
+
+impl Diff<'_> {
+    /// Whether the table would give rustc lang items it rejects.
+    pub fn is_breaking(&self) -> bool {
+        !(self.removed.is_empty() && self.renamed.is_empty() && self.mismatched.is_empty())
+    }
+}
+
+/// Compares the lang items of rustc to the rows of the table, the items of
+/// `excluded` are left out of the table on purpose.
+pub fn diff<'a>(lang_items: &'a [LangItem], rows: &'a [Row], excluded: &[String]) -> Diff<'a> {
+    let mut diff = Diff::default();
+    for lang_item in lang_items {
+        match rows
+            .iter()
+            .find(|row| row.rustc_name.as_ref() == Some(&lang_item.name))
+        {
+            Some(row) => {
+                if let Some(mismatch) = mismatch(row, lang_item) {
+                    diff.mismatched.push((row, lang_item, mismatch));
+                }
+            }
+            None if excluded.contains(&lang_item.name) => {}
+            None => diff.added.push(lang_item),
+        }
+    }
+    diff.removed = rows
+        .iter()
+        .filter(|row| {
+            row.rustc_name.as_ref().is_some_and(|rustc_name| {
+                !lang_items
+                    .iter()
+                    .any(|lang_item| &lang_item.name == rustc_name)
+            })
+        })
+        .collect();
+
+    // a removed row and an added lang item with the same target, one name
+    // containing the other, like `receiver` and `legacy_receiver`
+    diff.removed.retain(|row| {
+        let rustc_name = row.rustc_name.as_deref().unwrap_or_default();
+        let Some(position) = diff.added.iter().position(|lang_item| {
+            lang_item.table_target() == Some(row.target.as_str())
+                && (lang_item.name.contains(rustc_name) || rustc_name.contains(&lang_item.name))
+        }) else {
+            return true;
+        };
+        diff.renamed.push((row, diff.added.remove(position)));
+        false
+    });
+    diff
+}
+
+/// Describes how the row differs from the lang item of rustc, if it does.
+fn mismatch(row: &Row, lang_item: &LangItem) -> Option<String> {
+    let Some(target) = lang_item.table_target() else {
+        return Some(format!(
+            "rustc expects a `{}`, which has no `Target`",
+            lang_item.target
+        ));
+    };
+    if row.target != target {
+        return Some(format!(
+            "rustc expects `Target::{target}`, the table has `Target::{}`",
+            row.target
+        ));
+    }
+    // rustc counts the generics of the parent of an associated item too,
+    // without `Self`
+    let generics = row.generics + row.parent_generics;
+    let parent = match row.parent_generics {
+        0 => "",
+        _ => " with the ones of its parent",
+    };
+    match lang_item.generics {
+        Generics::Exact(n) if generics != n => Some(format!(
+            "rustc expects {n} generics, the table has {generics}{parent}"
+        )),
+        Generics::Minimum(n) if generics < n => Some(format!(
+            "rustc expects at least {n} generics, the table has {generics}{parent}"
+        )),
+        _ => None,
+    }
+}
+
+/// Writes the report of `diff`.
+pub fn report(diff: &Diff) -> String {
+    let mut out = String::new();
+    for (row, lang_item) in &diff.renamed {
+        writeln!(
+            out,
+            "renamed: `{}` ({}) is now `{}`",
+            row.rustc_name.as_deref().unwrap_or_default(),
+            row.variant,
+            lang_item.name
+        )
+        .unwrap();
+    }
+    for row in &diff.removed {
+        writeln!(
+            out,
+            "removed: `{}` ({}) is unknown to rustc",
+            row.rustc_name.as_deref().unwrap_or_default(),
+            row.variant
+        )
+        .unwrap();
+    }
+    for (row, _, mismatch) in &diff.mismatched {
+        writeln!(
+            out,
+            "mismatch: `{}` ({}): {mismatch}",
+            row.name, row.variant
+        )
+        .unwrap();
+    }
+    if !diff.added.is_empty() {
+        writeln!(
+            out,
+            "added: {} lang items are not in the table, generated rows:",
+            diff.added.len()
+        )
+        .unwrap();
+        for lang_item in &diff.added {
+            writeln!(out, "    {}", lang_item.row()).unwrap();
+        }
+    }
+    out
+}
+
+/// `RangeInclusive` to `range_inclusive`, snake case names are kept.
+fn snake_case(name: &str) -> String {
+    let mut snake = String::new();
+    for (i, c) in name.char_indices() {
+        if c.is_uppercase() {
+            if i > 0 {
+                snake.push('_');
+            }
+            snake.extend(c.to_lowercase());
+        } else {
+            snake.push(c);
+        }
+    }
+    snake
+}
@@ -0,0 +1,231 @@
+//! Reads the rows of the `lang_item_table!` invocation of
+//! `compiler/src/lang_item.rs`.
+//!
+//! Only the columns needed by the checks are kept, the rows gated by an
+//! attribute (the test items) are skipped. The text helpers are shared with
+//! the reader of the lang items of rustc.
+
+/// One row of the table.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Row {
+    pub variant: String,
+    /// The name used in `#[compiler::item("...")]`.
+    pub name: String,
+    /// The name given to rustc through `#[lang = "..."]`, if any.
+    pub rustc_name: Option<String>,
+    /// The variant of `Target`, like `Trait`.
+    pub target: String,
+    /// The count of the `Generics(n)` constraint, 0 without it.
+    pub generics: usize,
+    /// For an associated item, the count of generics of its parent, which is
+    /// the closest row above it that is not an associated item.
+    pub parent_generics: usize,
+}
+
+/// Returns the rows of the `lang_item_table!` invocation written in
+/// `content` known by the rustc of the commit date `date`, with the names
+/// this rustc gives them.
+pub fn rows(content: &str, date: &str) -> Result<Vec<Row>, String> {
+    let start = content
+        .find("\nlang_item_table! {")
+        .ok_or("no `lang_item_table!` invocation")?;
+    let body = block(&content[start..])?;
+
+    let mut rows = Vec::new();
+    // the generics of the last row which is not an associated item
+    let mut parent = 0;
+    'rows: for row in split(&strip_comments(body), ';') {
+        let row = row.trim();
+        if row.is_empty() || row.starts_with("#[") {
+            continue;
+        }
+        let columns = split(row, ',');
+        let [variant, name, target, constraints, _diag, lang, bounds @ ..] = columns.as_slice()
+        else {
+            return Err(format!("expected at least 6 columns in `{row}`"));
+        };
+        for bound in bounds {
+            let (kind, bound) = bound
+                .trim()
+                .split_once(' ')
+                .and_then(|(kind, bound)| Some((kind, string(bound)?)))
+                .ok_or_else(|| format!("invalid bound in `{row}`"))?;
+            let active = match kind {
+                "since" => bound.as_str() <= date,
+                "until" => date < bound.as_str(),
+                _ => return Err(format!("invalid bound in `{row}`")),
+            };
+            if !active {
+                continue 'rows;
+            }
+        }
+        let name = string(name).ok_or_else(|| format!("invalid name in `{row}`"))?;
+        let rustc_name = match lang.trim() {
+            "Inherited" => Some(name.clone()),
+            "No" => None,
+            lang => {
+                Some(named(lang, date).ok_or_else(|| format!("invalid lang column in `{row}`"))?)
+            }
+        };
+        let target = target
+            .trim()
+            .strip_prefix("Target::")
+            .ok_or_else(|| format!("invalid target in `{row}`"))?;
+        let constraints = constraints.trim();
+        let generics = split(&constraints[1..constraints.len() - 1], ',')
+            .into_iter()
+            .find_map(|constraint| {
+                // like `Generics(1) /* lifetime */`
+                let constraint = constraint.split("/*").next()?;
+                constraint
+                    .trim()
+                    .strip_prefix("Generics(")?
+                    .strip_suffix(')')?
+                    .parse()
+                    .ok()
+            })
+            .unwrap_or(0);
+        let parent_generics = match associated(target) {
+            true => parent,
+            false => {
+                parent = generics;
+                0
+            }
+        };
+        rows.push(Row {
+            variant: variant.trim().to_owned(),
+            name,
+            rustc_name,
+            target: target.to_owned(),
+            generics,
+            parent_generics,
+        });
+    }
+    Ok(rows)
+}
+
+/// Whether `target` is the one of an item of a trait or of an impl.
+pub fn associated(target: &str) -> bool {
+    matches!(target, "AssocType" | "AssocFn")
+}
+
+/// Returns the name of a `Named("...")` or `Renamed { .. }` column for the
+/// rustc of the commit date `date`.
+fn named(column: &str, date: &str) -> Option<String> {
+    if let Some(name) = column.strip_prefix("Named(") {
+        return string(name.strip_suffix(')')?);
+    }
+
+    let fields = column.strip_prefix("Renamed {")?.strip_suffix('}')?;
+    let field = |field: &str| {
+        split(fields, ',').into_iter().find_map(|part| {
+            let (name, value) = part.split_once(':')?;
+            (name.trim() == field).then(|| string(value)).flatten()
+        })
+    };
+    match field("since")?.as_str() <= date {
+        true => field("new"),
+        false => field("old"),
+    }
+}
+
+/// Returns the rustc names of `UPSTREAM_HINTS`, the lang items deliberately
+/// left out of the table.
+pub fn upstream_hints(content: &str) -> Result<Vec<String>, String> {
+    let start = content
+        .find("const UPSTREAM_HINTS")
+        .ok_or("no `UPSTREAM_HINTS` constant")?;
+    let list = &content[start..];
+    let list = &list[list.find("= &[").ok_or("invalid `UPSTREAM_HINTS`")? + 4..];
+    let end = list.find("\n];").ok_or("unterminated `UPSTREAM_HINTS`")?;
+    split(&strip_comments(&list[..end]), ',')
+        .into_iter()
+        .filter(|hint| !hint.trim().is_empty())
+        .map(|hint| {
+            let hint = hint.trim();
+            string(split(&hint[1..hint.len() - 1], ',')[0])
+                .ok_or_else(|| format!("invalid hint `{hint}`"))
+        })
+        .collect()
+}
+
+/// Returns the content of the block following the macro invocation at the
+/// start of `content`, without its braces.
+pub fn block(content: &str) -> Result<&str, String> {
+    let open = content.find('{').ok_or("no block")?;
+    let body = &content[open + 1..];
+    Ok(&body[..closing_brace(body)?])
+}
+
+/// Removes the `//` comments which are not in a string.
+pub fn strip_comments(content: &str) -> String {
+    content
+        .lines()
+        .map(|line| {
+            let mut in_string = false;
+            let mut previous = ' ';
+            for (i, c) in line.char_indices() {
+                match c {
+                    '"' if previous != '\\' => in_string = !in_string,
+                    '/' if !in_string && previous == '/' => return &line[..i - 1],
+                    _ => {}
+                }
+                previous = c;
+            }
+            line
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Returns the index of the brace closing the block `content` starts in.
+fn closing_brace(content: &str) -> Result<usize, String> {
+    let mut depth = 0;
+    let mut in_string = false;
+    let mut previous = ' ';
+    for (i, c) in content.char_indices() {
+        match c {
+            '"' if previous != '\\' => in_string = !in_string,
+            '{' if !in_string => depth += 1,
+            '}' if !in_string => {
+                if depth == 0 {
+                    return Ok(i);
+                }
+                depth -= 1;
+            }
+            _ => {}
+        }
+        previous = c;
+    }
+    Err("unterminated block".to_owned())
+}
+
+/// Splits `content` on `separator`, except inside strings and brackets.
+pub fn split(content: &str, separator: char) -> Vec<&str> {
+    let mut parts = Vec::new();
+    let mut depth = 0;
+    let mut in_string = false;
+    let mut previous = ' ';
+    let mut start = 0;
+    for (i, c) in content.char_indices() {
+        match c {
+            '"' if previous != '\\' => in_string = !in_string,
+            '(' | '[' | '{' if !in_string => depth += 1,
+            ')' | ']' | '}' if !in_string => depth -= 1,
+            c if c == separator && !in_string && depth == 0 => {
+                parts.push(&content[start..i]);
+                start = i + 1;
+            }
+            _ => {}
+        }
+        previous = c;
+    }
+    parts.push(&content[start..]);
+    parts
+}
+
+/// Returns the value of the string literal `literal`.
+pub fn string(literal: &str) -> Option<String> {
+    let literal = literal.trim().strip_prefix('"')?.strip_suffix('"')?;
+    Some(literal.replace("\\\"", "\""))
+}
@@ -0,0 +1,276 @@
+//! Generates `tools/ops-matrix/tests/matrix.rs`, the exhaustive test of the
+//! operator impls of restd between primitive types.
+//!
+//! ```text
+//! cargo run -p ops-matrix [output]
+//! cargo test -p ops-matrix
+//! ```
+//!
+//! The impls are collected from the macro invocations of
+//! `core/src/ops/arith.rs` and `core/src/ops/bit.rs`, expanded the way the
+//! helpers of `core/src/macros.rs` do it (`forward_ref_binop!`,
+//! `forward_ref_unop!` and `forward_ref_op_assign!` included). The generated
+//! test probes every operator trait of `std` (which re-exports the upstream
+//! `core` ones) for every pair of primitive types and checks that it finds
+//! exactly the impls of restd.
+
+mod parse;
+
+use std::fmt::Write;
+use std::path::PathBuf;
+use std::{env, fs, process};
+
+use parse::{Impl, Kind};
+
+/// The operator traits, with the shape of their impls.
+const TRAITS: &[(&str, Kind)] = &[
+    ("Add", Kind::Binary),
+    ("Sub", Kind::Binary),
+    ("Mul", Kind::Binary),
+    ("Div", Kind::Binary),
+    ("Rem", Kind::Binary),
+    ("Neg", Kind::Unary),
+    ("Not", Kind::Unary),
+    ("BitAnd", Kind::Binary),
+    ("BitOr", Kind::Binary),
+    ("BitXor", Kind::Binary),
+    ("Shl", Kind::Binary),
+    ("Shr", Kind::Binary),
+    ("AddAssign", Kind::Assign),
+    ("SubAssign", Kind::Assign),
+    ("MulAssign", Kind::Assign),
+    ("DivAssign", Kind::Assign),
+    ("RemAssign", Kind::Assign),
+    ("BitAndAssign", Kind::Assign),
+    ("BitOrAssign", Kind::Assign),
+    ("BitXorAssign", Kind::Assign),
+    ("ShlAssign", Kind::Assign),
+    ("ShrAssign", Kind::Assign),
+];
+
+/// The primitive types probed, their references are probed too.
+const PRIMITIVES: &[&str] = &[
+    "usize", "u8", "u16", "u32", "u64", "u128", "isize", "i8", "i16", "i32", "i64", "i128", "f16",
+    "f32", "f64", "f128", "bool", "char", "!",
+];
+
+/// The files of `core/src/ops` defining the operator impls.
+const SOURCES: &[&str] = &["arith.rs", "bit.rs"];
+
+fn main() {
+    let manifest_dir = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+    let ops_dir = manifest_dir.join("../../core/src/ops");
+    let output = match env::args_os().nth(1) {
+        Some(output) => PathBuf::from(output),
+        None => manifest_dir.join("tests/matrix.rs"),
+    };
+
+    let mut impls = Vec::new();
+    for source in SOURCES {
+        let path = ops_dir.join(source);
+        let content = fs::read_to_string(&path).unwrap_or_else(|e| {
+            eprintln!("cannot read `{}`: {e}", path.display());
+            process::exit(1);
+        });
+        let found = parse::impls(&content, TRAITS).unwrap_or_else(|e| {
+            eprintln!("`{}`: {e}", path.display());
+            process::exit(1);
+        });
+        impls.extend(found);
+    }
+
+    for Impl { lhs, rhs, .. } in &impls {
+        for ty in [lhs, rhs] {
+            if !PRIMITIVES.contains(&ty.trim_start_matches('&')) {
+                eprintln!("`{ty}` is not a probed primitive type, add it to `PRIMITIVES`");
+                process::exit(1);
+            }
+        }
+    }
+
+    fs::write(&output, generate(&impls)).unwrap_or_else(|e| {
+        eprintln!("cannot write `{}`: {e}", output.display());
+        process::exit(1);
+    });
+}
+
+fn generate(impls: &[Impl]) -> String {
+    let mut out = String::new();
+    out.push_str(
+        "//! Exhaustive test of the operator impls between primitive types generated\n\
+         //! by `tools/ops-matrix` from `core/src/ops`, do not edit by hand.\n\
+         //!\n\
+         //! Every operator trait of `std` is probed for every pair of primitive\n\
+         //! types (and references to them), the impls found must be exactly the ones\n\
+         //! of restd listed in `RESTD`.\n\n\
+         // No formatting: the lists are generated.\n\
+         #![cfg_attr(rustfmt, rustfmt::skip)]\n\
+         #![feature(f16, f128, never_type)]\n\n\
+         use std::collections::BTreeSet;\n\
+         use std::marker::PhantomData;\n\n",
+    );
+    out.push_str(PROBE_MACROS);
+
+    let mut names: Vec<String> = impls.iter().map(Impl::to_string).collect();
+    names.sort();
+    names.dedup();
+    writeln!(out, "\nstatic RESTD: [&str; {}] = [", names.len()).unwrap();
+    for name in &names {
+        writeln!(out, "    {name:?},").unwrap();
+    }
+    out.push_str("];\n");
+
+    let values = PRIMITIVES.join(", ");
+    let all = PRIMITIVES
+        .iter()
+        .flat_map(|ty| [ty.to_string(), format!("&{ty}")])
+        .collect::<Vec<_>>()
+        .join(", ");
+
+    out.push('\n');
+    for (name, kind) in TRAITS {
+        let macro_name = match kind {
+            Kind::Unary => "unary_probe",
+            Kind::Binary | Kind::Assign => "binary_probe",
+        };
+        writeln!(out, "{macro_name}!({}, {name});", module(name)).unwrap();
+    }
+
+    // one function per trait, a single one would need a huge stack frame in
+    // debug builds
+    for (name, kind) in TRAITS {
+        let module = module(name);
+        writeln!(out, "\nfn {module}(found: &mut Vec<String>) {{").unwrap();
+        match kind {
+            Kind::Unary => writeln!(
+                out,
+                "    probe_types!(found, {module}, \"{name} for {{0}}\", [{all}]);"
+            ),
+            Kind::Binary => writeln!(
+                out,
+                "    probe_pairs!(found, {module}, \"{name}<{{1}}> for {{0}}\", [{all}], [{all}]);"
+            ),
+            Kind::Assign => writeln!(
+                out,
+                "    probe_pairs!(found, {module}, \"{name}<{{1}}> for {{0}}\", [{values}], [{all}]);"
+            ),
+        }
+        .unwrap();
+        out.push_str("}\n");
+    }
+
+    out.push_str(
+        "\n#[test]\n\
+         fn operators_match_upstream() {\n    \
+         let mut found = Vec::new();\n",
+    );
+    for (name, _) in TRAITS {
+        writeln!(out, "    {}(&mut found);", module(name)).unwrap();
+    }
+    out.push_str(
+        "\n    \
+         // `stringify!` may space the `&` of the references differently\n    \
+         let normalize = |s: &str| s.replace(\"& \", \"&\");\n    \
+         let found: BTreeSet<String> = found.iter().map(|s| normalize(s)).collect();\n    \
+         let restd: BTreeSet<String> = RESTD.iter().map(|s| normalize(s)).collect();\n    \
+         let missing: Vec<_> = found.difference(&restd).collect();\n    \
+         let extra: Vec<_> = restd.difference(&found).collect();\n    \
+         assert!(\n        \
+         missing.is_empty() && extra.is_empty(),\n        \
+         \"missing in restd: {missing:#?}\\nnot in upstream core: {extra:#?}\"\n    \
+         );\n\
+         }\n",
+    );
+    out
+}
+
+/// The name of the module holding the probe of the trait `name`.
+fn module(name: &str) -> String {
+    let mut module = String::from("probe");
+    for c in name.chars() {
+        if c.is_uppercase() {
+            module.push('_');
+        }
+        module.push(c.to_ascii_lowercase());
+    }
+    module
+}
+
+/// The probes rely on the method resolution: the inherent `found` method of
+/// `Probe` only applies when the trait is implemented, otherwise the one of
+/// the `Missing` trait is picked.
+const PROBE_MACROS: &str = r#"macro_rules! binary_probe {
+    ($module:ident, $trait:ident) => {
+        mod $module {
+            use std::marker::PhantomData;
+            use std::ops::$trait;
+
+            pub struct Probe<L, R>(pub PhantomData<(L, R)>);
+
+            pub trait Missing {
+                fn found(&self) -> bool {
+                    false
+                }
+            }
+
+            impl<L, R> Missing for Probe<L, R> {}
+
+            impl<L: $trait<R>, R> Probe<L, R> {
+                pub fn found(&self) -> bool {
+                    true
+                }
+            }
+        }
+    };
+}
+
+macro_rules! unary_probe {
+    ($module:ident, $trait:ident) => {
+        mod $module {
+            use std::marker::PhantomData;
+            use std::ops::$trait;
+
+            pub struct Probe<T>(pub PhantomData<T>);
+
+            pub trait Missing {
+                fn found(&self) -> bool {
+                    false
+                }
+            }
+
+            impl<T> Missing for Probe<T> {}
+
+            impl<T: $trait> Probe<T> {
+                pub fn found(&self) -> bool {
+                    true
+                }
+            }
+        }
+    };
+}
+
+macro_rules! probe_pairs {
+    ($found:ident, $module:ident, $fmt:literal, [$($lhs:ty),*], $rhs:tt) => {
+        $(probe_pairs!(@lhs $found, $module, $fmt, $lhs, $rhs);)*
+    };
+    (@lhs $found:ident, $module:ident, $fmt:literal, $lhs:ty, [$($rhs:ty),*]) => {{
+        use $module::Missing as _;
+        $(
+            if $module::Probe::<$lhs, $rhs>(PhantomData).found() {
+                $found.push(format!($fmt, stringify!($lhs), stringify!($rhs)));
+            }
+        )*
+    }};
+}
+
+macro_rules! probe_types {
+    ($found:ident, $module:ident, $fmt:literal, [$($ty:ty),*]) => {{
+        use $module::Missing as _;
+        $(
+            if $module::Probe::<$ty>(PhantomData).found() {
+                $found.push(format!($fmt, stringify!($ty)));
+            }
+        )*
+    }};
+}
+"#;
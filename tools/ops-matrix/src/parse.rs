@@ -0,0 +1,276 @@
+//! Collects the operator impls written in a file of `core/src/ops`.
+//!
+//! Three forms are understood:
+//! - the invocations of the helpers of `core/src/macros.rs`, like
+//!   `internal_impl_binop_native! { impl Add, add (+) for u8, u16 }`, with an
+//!   optional leading `#[...]` attribute and an optional `<Rhs>` type;
+//! - the local `macro_rules!` wrapping these helpers for a fixed list of
+//!   right-hand types, like `impl_shifting!`, and their invocations;
+//! - the impls written by hand, like `impl Not for ! { ... }`.
+
+use std::fmt;
+
+/// The shape of the impls of an operator trait.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Kind {
+    /// `Trait for T`, like `Neg`.
+    Unary,
+    /// `Trait<Rhs> for T`, like `Add`.
+    Binary,
+    /// `Trait<Rhs> for T` taking `&mut self`, like `AddAssign`.
+    Assign,
+}
+
+/// One impl of an operator trait, `rhs` is the same as `lhs` for the unary
+/// traits.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Impl {
+    pub kind: Kind,
+    pub name: String,
+    pub lhs: String,
+    pub rhs: String,
+}
+
+impl fmt::Display for Impl {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.kind {
+            Kind::Unary => write!(f, "{} for {}", self.name, self.lhs),
+            Kind::Binary | Kind::Assign => {
+                write!(f, "{}<{}> for {}", self.name, self.rhs, self.lhs)
+            }
+        }
+    }
+}
+
+/// A macro generating impls: either a helper of `core/src/macros.rs` or a
+/// local `macro_rules!` calling one for each of its right-hand types.
+struct Generator {
+    name: String,
+    kind: Kind,
+    rhs: Option<Vec<String>>,
+}
+
+const HELPERS: &[(&str, Kind)] = &[
+    ("internal_impl_unop_native", Kind::Unary),
+    ("internal_impl_binop_native", Kind::Binary),
+    ("internal_impl_assign_binop_native", Kind::Assign),
+];
+
+/// Returns the impls of the traits of `traits` written in `content`,
+/// including the ones forwarded to references by the helpers.
+pub fn impls(content: &str, traits: &[(&str, Kind)]) -> Result<Vec<Impl>, String> {
+    let content = normalize(content);
+    let mut generators: Vec<Generator> = HELPERS
+        .iter()
+        .map(|&(name, kind)| Generator {
+            name: name.to_owned(),
+            kind,
+            rhs: None,
+        })
+        .collect();
+
+    // the local macros, their bodies are removed from the content so that
+    // the `impl $trait ...` they contain are not seen as invocations
+    let mut rest = String::new();
+    let mut remaining = content.as_str();
+    while let Some(start) = remaining.find("macro_rules! ") {
+        rest.push_str(&remaining[..start]);
+        let after = &remaining[start + "macro_rules! ".len()..];
+        let name = ident(after);
+        let open = after.find('{').ok_or("unterminated `macro_rules!`")?;
+        let end = matching_brace(after, open)?;
+        let body = &after[open + 1..end];
+        if let Some(generator) = local_generator(name, body, &generators) {
+            generators.push(generator);
+        }
+        remaining = &after[end + 1..];
+    }
+    rest.push_str(remaining);
+
+    let mut impls = Vec::new();
+    for generator in &generators {
+        let pattern = format!("{}! {{", generator.name);
+        let mut remaining = rest.as_str();
+        while let Some(start) = remaining.find(&pattern) {
+            let after = &remaining[start + pattern.len() - 1..];
+            let end = matching_brace(after, 0)?;
+            invocation(generator, &after[1..end], traits, &mut impls)?;
+            remaining = &after[end + 1..];
+        }
+    }
+
+    handwritten(&rest, traits, &mut impls);
+    Ok(impls)
+}
+
+/// Replaces the comments and the runs of whitespace by a single space.
+fn normalize(content: &str) -> String {
+    content
+        .lines()
+        .map(|line| match line.find("//") {
+            Some(comment) => &line[..comment],
+            None => line,
+        })
+        .flat_map(str::split_whitespace)
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// Returns the identifier at the start of `s`.
+fn ident(s: &str) -> &str {
+    let end = s
+        .find(|c: char| !(c.is_alphanumeric() || c == '_'))
+        .unwrap_or(s.len());
+    &s[..end]
+}
+
+/// Returns the index of the brace closing the one at `open` in `s`.
+fn matching_brace(s: &str, open: usize) -> Result<usize, String> {
+    let mut depth = 0;
+    for (i, c) in s[open..].char_indices() {
+        match c {
+            '{' => depth += 1,
+            '}' => {
+                depth -= 1;
+                if depth == 0 {
+                    return Ok(open + i);
+                }
+            }
+            _ => {}
+        }
+    }
+    Err(format!("unbalanced braces after `{}`", &s[..open]))
+}
+
+/// Recognizes a local macro whose body only calls one helper with a list of
+/// explicit right-hand types: `helper! { impl $trait<u8>, ... }`.
+fn local_generator(name: &str, body: &str, generators: &[Generator]) -> Option<Generator> {
+    let mut kind = None;
+    let mut rhs = Vec::new();
+    for generator in generators.iter().filter(|g| g.rhs.is_none()) {
+        let pattern = format!("{}! {{ impl $trait<", generator.name);
+        let mut remaining = body;
+        while let Some(start) = remaining.find(&pattern) {
+            let after = &remaining[start + pattern.len()..];
+            let end = after.find('>')?;
+            rhs.push(after[..end].trim().to_owned());
+            kind = Some(generator.kind);
+            remaining = &after[end..];
+        }
+    }
+    Some(Generator {
+        name: name.to_owned(),
+        kind: kind?,
+        rhs: Some(rhs),
+    })
+}
+
+/// Expands the invocation `impl Trait<Rhs>, method (symbol) for T1, T2`.
+fn invocation(
+    generator: &Generator,
+    args: &str,
+    traits: &[(&str, Kind)],
+    impls: &mut Vec<Impl>,
+) -> Result<(), String> {
+    let mut args = args.trim();
+    if args.starts_with("#[") {
+        let end = args.find(']').ok_or("unterminated attribute")?;
+        args = args[end + 1..].trim_start();
+    }
+    let args = args
+        .strip_prefix("impl ")
+        .ok_or_else(|| format!("`{}!` invocation without `impl`", generator.name))?;
+    let name = ident(args);
+    if !traits
+        .iter()
+        .any(|&(t, kind)| t == name && kind == generator.kind)
+    {
+        return Err(format!(
+            "`{name}` is not a {:?} operator trait",
+            generator.kind
+        ));
+    }
+
+    let explicit_rhs = match args[name.len()..].trim_start().strip_prefix('<') {
+        Some(rhs) => Some(rhs[..rhs.find('>').ok_or("unterminated `<`")?].trim()),
+        None => None,
+    };
+    let types = &args[args.find(" for ").ok_or("invocation without `for`")? + 5..];
+
+    for lhs in types.split(',').map(str::trim).filter(|t| !t.is_empty()) {
+        let rhs_list: Vec<&str> = match (&generator.rhs, explicit_rhs) {
+            (Some(rhs), _) => rhs.iter().map(String::as_str).collect(),
+            (None, Some(rhs)) => vec![rhs],
+            (None, None) => vec![lhs],
+        };
+        for rhs in rhs_list {
+            forwarded(generator.kind, name, lhs, rhs, impls);
+        }
+    }
+    Ok(())
+}
+
+/// Pushes the impl and the ones forwarding it to references, as done by
+/// `forward_ref_unop!`, `forward_ref_binop!` and `forward_ref_op_assign!`.
+fn forwarded(kind: Kind, name: &str, lhs: &str, rhs: &str, impls: &mut Vec<Impl>) {
+    let pairs = match kind {
+        Kind::Unary => vec![
+            (lhs.to_owned(), lhs.to_owned()),
+            (format!("&{lhs}"), format!("&{lhs}")),
+        ],
+        Kind::Binary => vec![
+            (lhs.to_owned(), rhs.to_owned()),
+            (format!("&{lhs}"), rhs.to_owned()),
+            (lhs.to_owned(), format!("&{rhs}")),
+            (format!("&{lhs}"), format!("&{rhs}")),
+        ],
+        Kind::Assign => vec![
+            (lhs.to_owned(), rhs.to_owned()),
+            (lhs.to_owned(), format!("&{rhs}")),
+        ],
+    };
+    for (lhs, rhs) in pairs {
+        impls.push(Impl {
+            kind,
+            name: name.to_owned(),
+            lhs,
+            rhs,
+        });
+    }
+}
+
+/// Collects the impls written by hand: `impl Trait for T {` and
+/// `impl Trait<Rhs> for T {`, they are not forwarded to references.
+fn handwritten(content: &str, traits: &[(&str, Kind)], impls: &mut Vec<Impl>) {
+    let mut remaining = content;
+    while let Some(start) = remaining.find("impl ") {
+        let after = &remaining[start + "impl ".len()..];
+        remaining = after;
+
+        let name = ident(after);
+        let Some(&(_, kind)) = traits.iter().find(|&&(t, _)| t == name) else {
+            continue;
+        };
+        let after = &after[name.len()..];
+        let (rhs, after) = match after.strip_prefix('<') {
+            Some(rhs) => match rhs.find('>') {
+                Some(end) => (Some(rhs[..end].trim()), &rhs[end + 1..]),
+                None => continue,
+            },
+            None => (None, after),
+        };
+        let Some(after) = after.strip_prefix(" for ") else {
+            continue;
+        };
+        let Some(end) = after.find(" {") else {
+            continue;
+        };
+        let lhs = after[..end].trim();
+        impls.push(Impl {
+            kind,
+            name: name.to_owned(),
+            lhs: lhs.to_owned(),
+            rhs: rhs.unwrap_or(lhs).to_owned(),
+        });
+    }
+}
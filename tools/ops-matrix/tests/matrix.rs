@@ -0,0 +1,2729 @@
+//! Exhaustive test of the operator impls between primitive types generated
+//! by `tools/ops-matrix` from `core/src/ops`, do not edit by hand.
+//!
+//! Every operator trait of `std` is probed for every pair of primitive
+//! types (and references to them), the impls found must be exactly the ones
+//! of restd listed in `RESTD`.
+
+// No formatting: the lists are generated.
+#![cfg_attr(rustfmt, rustfmt::skip)]
+#![feature(f16, f128, never_type)]
+
+use std::collections::BTreeSet;
+use std::marker::PhantomData;
+
+macro_rules! binary_probe {
+    ($module:ident, $trait:ident) => {
+        mod $module {
+            use std::marker::PhantomData;
+            use std::ops::$trait;
+
+            pub struct Probe<L, R>(pub PhantomData<(L, R)>);
+
+            pub trait Missing {
+                fn found(&self) -> bool {
+                    false
+                }
+            }
+
+            impl<L, R> Missing for Probe<L, R> {}
+
+            impl<L: $trait<R>, R> Probe<L, R> {
+                pub fn found(&self) -> bool {
+                    true
+                }
+            }
+        }
+    };
+}
+
+macro_rules! unary_probe {
+    ($module:ident, $trait:ident) => {
+        mod $module {
+            use std::marker::PhantomData;
+            use std::ops::$trait;
+
+            pub struct Probe<T>(pub PhantomData<T>);
+
+            pub trait Missing {
+                fn found(&self) -> bool {
+                    false
+                }
+            }
+
+            impl<T> Missing for Probe<T> {}
+
+            impl<T: $trait> Probe<T> {
+                pub fn found(&self) -> bool {
+                    true
+                }
+            }
+        }
+    };
+}
+
+macro_rules! probe_pairs {
+    ($found:ident, $module:ident, $fmt:literal, [$($lhs:ty),*], $rhs:tt) => {
+        $(probe_pairs!(@lhs $found, $module, $fmt, $lhs, $rhs);)*
+    };
+    (@lhs $found:ident, $module:ident, $fmt:literal, $lhs:ty, [$($rhs:ty),*]) => {{
+        use $module::Missing as _;
+        $(
+            if $module::Probe::<$lhs, $rhs>(PhantomData).found() {
+                $found.push(format!($fmt, stringify!($lhs), stringify!($rhs)));
+            }
+        )*
+    }};
+}
+
+macro_rules! probe_types {
+    ($found:ident, $module:ident, $fmt:literal, [$($ty:ty),*]) => {{
+        use $module::Missing as _;
+        $(
+            if $module::Probe::<$ty>(PhantomData).found() {
+                $found.push(format!($fmt, stringify!($ty)));
+            }
+        )*
+    }};
+}
+
+static RESTD: [&str; 2489] = [
+    "Add<&f128> for &f128",
+    "Add<&f128> for f128",
+    "Add<&f16> for &f16",
+    "Add<&f16> for f16",
+    "Add<&f32> for &f32",
+    "Add<&f32> for f32",
+    "Add<&f64> for &f64",
+    "Add<&f64> for f64",
+    "Add<&i128> for &i128",
+    "Add<&i128> for i128",
+    "Add<&i16> for &i16",
+    "Add<&i16> for i16",
+    "Add<&i32> for &i32",
+    "Add<&i32> for i32",
+    "Add<&i64> for &i64",
+    "Add<&i64> for i64",
+    "Add<&i8> for &i8",
+    "Add<&i8> for i8",
+    "Add<&isize> for &isize",
+    "Add<&isize> for isize",
+    "Add<&u128> for &u128",
+    "Add<&u128> for u128",
+    "Add<&u16> for &u16",
+    "Add<&u16> for u16",
+    "Add<&u32> for &u32",
+    "Add<&u32> for u32",
+    "Add<&u64> for &u64",
+    "Add<&u64> for u64",
+    "Add<&u8> for &u8",
+    "Add<&u8> for u8",
+    "Add<&usize> for &usize",
+    "Add<&usize> for usize",
+    "Add<f128> for &f128",
+    "Add<f128> for f128",
+    "Add<f16> for &f16",
+    "Add<f16> for f16",
+    "Add<f32> for &f32",
+    "Add<f32> for f32",
+    "Add<f64> for &f64",
+    "Add<f64> for f64",
+    "Add<i128> for &i128",
+    "Add<i128> for i128",
+    "Add<i16> for &i16",
+    "Add<i16> for i16",
+    "Add<i32> for &i32",
+    "Add<i32> for i32",
+    "Add<i64> for &i64",
+    "Add<i64> for i64",
+    "Add<i8> for &i8",
+    "Add<i8> for i8",
+    "Add<isize> for &isize",
+    "Add<isize> for isize",
+    "Add<u128> for &u128",
+    "Add<u128> for u128",
+    "Add<u16> for &u16",
+    "Add<u16> for u16",
+    "Add<u32> for &u32",
+    "Add<u32> for u32",
+    "Add<u64> for &u64",
+    "Add<u64> for u64",
+    "Add<u8> for &u8",
+    "Add<u8> for u8",
+    "Add<usize> for &usize",
+    "Add<usize> for usize",
+    "AddAssign<&f128> for f128",
+    "AddAssign<&f16> for f16",
+    "AddAssign<&f32> for f32",
+    "AddAssign<&f64> for f64",
+    "AddAssign<&i128> for i128",
+    "AddAssign<&i16> for i16",
+    "AddAssign<&i32> for i32",
+    "AddAssign<&i64> for i64",
+    "AddAssign<&i8> for i8",
+    "AddAssign<&isize> for isize",
+    "AddAssign<&u128> for u128",
+    "AddAssign<&u16> for u16",
+    "AddAssign<&u32> for u32",
+    "AddAssign<&u64> for u64",
+    "AddAssign<&u8> for u8",
+    "AddAssign<&usize> for usize",
+    "AddAssign<f128> for f128",
+    "AddAssign<f16> for f16",
+    "AddAssign<f32> for f32",
+    "AddAssign<f64> for f64",
+    "AddAssign<i128> for i128",
+    "AddAssign<i16> for i16",
+    "AddAssign<i32> for i32",
+    "AddAssign<i64> for i64",
+    "AddAssign<i8> for i8",
+    "AddAssign<isize> for isize",
+    "AddAssign<u128> for u128",
+    "AddAssign<u16> for u16",
+    "AddAssign<u32> for u32",
+    "AddAssign<u64> for u64",
+    "AddAssign<u8> for u8",
+    "AddAssign<usize> for usize",
+    "BitAnd<&bool> for &bool",
+    "BitAnd<&bool> for bool",
+    "BitAnd<&i128> for &i128",
+    "BitAnd<&i128> for i128",
+    "BitAnd<&i16> for &i16",
+    "BitAnd<&i16> for i16",
+    "BitAnd<&i32> for &i32",
+    "BitAnd<&i32> for i32",
+    "BitAnd<&i64> for &i64",
+    "BitAnd<&i64> for i64",
+    "BitAnd<&i8> for &i8",
+    "BitAnd<&i8> for i8",
+    "BitAnd<&isize> for &isize",
+    "BitAnd<&isize> for isize",
+    "BitAnd<&u128> for &u128",
+    "BitAnd<&u128> for u128",
+    "BitAnd<&u16> for &u16",
+    "BitAnd<&u16> for u16",
+    "BitAnd<&u32> for &u32",
+    "BitAnd<&u32> for u32",
+    "BitAnd<&u64> for &u64",
+    "BitAnd<&u64> for u64",
+    "BitAnd<&u8> for &u8",
+    "BitAnd<&u8> for u8",
+    "BitAnd<&usize> for &usize",
+    "BitAnd<&usize> for usize",
+    "BitAnd<bool> for &bool",
+    "BitAnd<bool> for bool",
+    "BitAnd<i128> for &i128",
+    "BitAnd<i128> for i128",
+    "BitAnd<i16> for &i16",
+    "BitAnd<i16> for i16",
+    "BitAnd<i32> for &i32",
+    "BitAnd<i32> for i32",
+    "BitAnd<i64> for &i64",
+    "BitAnd<i64> for i64",
+    "BitAnd<i8> for &i8",
+    "BitAnd<i8> for i8",
+    "BitAnd<isize> for &isize",
+    "BitAnd<isize> for isize",
+    "BitAnd<u128> for &u128",
+    "BitAnd<u128> for u128",
+    "BitAnd<u16> for &u16",
+    "BitAnd<u16> for u16",
+    "BitAnd<u32> for &u32",
+    "BitAnd<u32> for u32",
+    "BitAnd<u64> for &u64",
+    "BitAnd<u64> for u64",
+    "BitAnd<u8> for &u8",
+    "BitAnd<u8> for u8",
+    "BitAnd<usize> for &usize",
+    "BitAnd<usize> for usize",
+    "BitAndAssign<&bool> for bool",
+    "BitAndAssign<&i128> for i128",
+    "BitAndAssign<&i16> for i16",
+    "BitAndAssign<&i32> for i32",
+    "BitAndAssign<&i64> for i64",
+    "BitAndAssign<&i8> for i8",
+    "BitAndAssign<&isize> for isize",
+    "BitAndAssign<&u128> for u128",
+    "BitAndAssign<&u16> for u16",
+    "BitAndAssign<&u32> for u32",
+    "BitAndAssign<&u64> for u64",
+    "BitAndAssign<&u8> for u8",
+    "BitAndAssign<&usize> for usize",
+    "BitAndAssign<bool> for bool",
+    "BitAndAssign<i128> for i128",
+    "BitAndAssign<i16> for i16",
+    "BitAndAssign<i32> for i32",
+    "BitAndAssign<i64> for i64",
+    "BitAndAssign<i8> for i8",
+    "BitAndAssign<isize> for isize",
+    "BitAndAssign<u128> for u128",
+    "BitAndAssign<u16> for u16",
+    "BitAndAssign<u32> for u32",
+    "BitAndAssign<u64> for u64",
+    "BitAndAssign<u8> for u8",
+    "BitAndAssign<usize> for usize",
+    "BitOr<&bool> for &bool",
+    "BitOr<&bool> for bool",
+    "BitOr<&i128> for &i128",
+    "BitOr<&i128> for i128",
+    "BitOr<&i16> for &i16",
+    "BitOr<&i16> for i16",
+    "BitOr<&i32> for &i32",
+    "BitOr<&i32> for i32",
+    "BitOr<&i64> for &i64",
+    "BitOr<&i64> for i64",
+    "BitOr<&i8> for &i8",
+    "BitOr<&i8> for i8",
+    "BitOr<&isize> for &isize",
+    "BitOr<&isize> for isize",
+    "BitOr<&u128> for &u128",
+    "BitOr<&u128> for u128",
+    "BitOr<&u16> for &u16",
+    "BitOr<&u16> for u16",
+    "BitOr<&u32> for &u32",
+    "BitOr<&u32> for u32",
+    "BitOr<&u64> for &u64",
+    "BitOr<&u64> for u64",
+    "BitOr<&u8> for &u8",
+    "BitOr<&u8> for u8",
+    "BitOr<&usize> for &usize",
+    "BitOr<&usize> for usize",
+    "BitOr<bool> for &bool",
+    "BitOr<bool> for bool",
+    "BitOr<i128> for &i128",
+    "BitOr<i128> for i128",
+    "BitOr<i16> for &i16",
+    "BitOr<i16> for i16",
+    "BitOr<i32> for &i32",
+    "BitOr<i32> for i32",
+    "BitOr<i64> for &i64",
+    "BitOr<i64> for i64",
+    "BitOr<i8> for &i8",
+    "BitOr<i8> for i8",
+    "BitOr<isize> for &isize",
+    "BitOr<isize> for isize",
+    "BitOr<u128> for &u128",
+    "BitOr<u128> for u128",
+    "BitOr<u16> for &u16",
+    "BitOr<u16> for u16",
+    "BitOr<u32> for &u32",
+    "BitOr<u32> for u32",
+    "BitOr<u64> for &u64",
+    "BitOr<u64> for u64",
+    "BitOr<u8> for &u8",
+    "BitOr<u8> for u8",
+    "BitOr<usize> for &usize",
+    "BitOr<usize> for usize",
+    "BitOrAssign<&bool> for bool",
+    "BitOrAssign<&i128> for i128",
+    "BitOrAssign<&i16> for i16",
+    "BitOrAssign<&i32> for i32",
+    "BitOrAssign<&i64> for i64",
+    "BitOrAssign<&i8> for i8",
+    "BitOrAssign<&isize> for isize",
+    "BitOrAssign<&u128> for u128",
+    "BitOrAssign<&u16> for u16",
+    "BitOrAssign<&u32> for u32",
+    "BitOrAssign<&u64> for u64",
+    "BitOrAssign<&u8> for u8",
+    "BitOrAssign<&usize> for usize",
+    "BitOrAssign<bool> for bool",
+    "BitOrAssign<i128> for i128",
+    "BitOrAssign<i16> for i16",
+    "BitOrAssign<i32> for i32",
+    "BitOrAssign<i64> for i64",
+    "BitOrAssign<i8> for i8",
+    "BitOrAssign<isize> for isize",
+    "BitOrAssign<u128> for u128",
+    "BitOrAssign<u16> for u16",
+    "BitOrAssign<u32> for u32",
+    "BitOrAssign<u64> for u64",
+    "BitOrAssign<u8> for u8",
+    "BitOrAssign<usize> for usize",
+    "BitXor<&bool> for &bool",
+    "BitXor<&bool> for bool",
+    "BitXor<&i128> for &i128",
+    "BitXor<&i128> for i128",
+    "BitXor<&i16> for &i16",
+    "BitXor<&i16> for i16",
+    "BitXor<&i32> for &i32",
+    "BitXor<&i32> for i32",
+    "BitXor<&i64> for &i64",
+    "BitXor<&i64> for i64",
+    "BitXor<&i8> for &i8",
+    "BitXor<&i8> for i8",
+    "BitXor<&isize> for &isize",
+    "BitXor<&isize> for isize",
+    "BitXor<&u128> for &u128",
+    "BitXor<&u128> for u128",
+    "BitXor<&u16> for &u16",
+    "BitXor<&u16> for u16",
+    "BitXor<&u32> for &u32",
+    "BitXor<&u32> for u32",
+    "BitXor<&u64> for &u64",
+    "BitXor<&u64> for u64",
+    "BitXor<&u8> for &u8",
+    "BitXor<&u8> for u8",
+    "BitXor<&usize> for &usize",
+    "BitXor<&usize> for usize",
+    "BitXor<bool> for &bool",
+    "BitXor<bool> for bool",
+    "BitXor<i128> for &i128",
+    "BitXor<i128> for i128",
+    "BitXor<i16> for &i16",
+    "BitXor<i16> for i16",
+    "BitXor<i32> for &i32",
+    "BitXor<i32> for i32",
+    "BitXor<i64> for &i64",
+    "BitXor<i64> for i64",
+    "BitXor<i8> for &i8",
+    "BitXor<i8> for i8",
+    "BitXor<isize> for &isize",
+    "BitXor<isize> for isize",
+    "BitXor<u128> for &u128",
+    "BitXor<u128> for u128",
+    "BitXor<u16> for &u16",
+    "BitXor<u16> for u16",
+    "BitXor<u32> for &u32",
+    "BitXor<u32> for u32",
+    "BitXor<u64> for &u64",
+    "BitXor<u64> for u64",
+    "BitXor<u8> for &u8",
+    "BitXor<u8> for u8",
+    "BitXor<usize> for &usize",
+    "BitXor<usize> for usize",
+    "BitXorAssign<&bool> for bool",
+    "BitXorAssign<&i128> for i128",
+    "BitXorAssign<&i16> for i16",
+    "BitXorAssign<&i32> for i32",
+    "BitXorAssign<&i64> for i64",
+    "BitXorAssign<&i8> for i8",
+    "BitXorAssign<&isize> for isize",
+    "BitXorAssign<&u128> for u128",
+    "BitXorAssign<&u16> for u16",
+    "BitXorAssign<&u32> for u32",
+    "BitXorAssign<&u64> for u64",
+    "BitXorAssign<&u8> for u8",
+    "BitXorAssign<&usize> for usize",
+    "BitXorAssign<bool> for bool",
+    "BitXorAssign<i128> for i128",
+    "BitXorAssign<i16> for i16",
+    "BitXorAssign<i32> for i32",
+    "BitXorAssign<i64> for i64",
+    "BitXorAssign<i8> for i8",
+    "BitXorAssign<isize> for isize",
+    "BitXorAssign<u128> for u128",
+    "BitXorAssign<u16> for u16",
+    "BitXorAssign<u32> for u32",
+    "BitXorAssign<u64> for u64",
+    "BitXorAssign<u8> for u8",
+    "BitXorAssign<usize> for usize",
+    "Div<&f128> for &f128",
+    "Div<&f128> for f128",
+    "Div<&f16> for &f16",
+    "Div<&f16> for f16",
+    "Div<&f32> for &f32",
+    "Div<&f32> for f32",
+    "Div<&f64> for &f64",
+    "Div<&f64> for f64",
+    "Div<&i128> for &i128",
+    "Div<&i128> for i128",
+    "Div<&i16> for &i16",
+    "Div<&i16> for i16",
+    "Div<&i32> for &i32",
+    "Div<&i32> for i32",
+    "Div<&i64> for &i64",
+    "Div<&i64> for i64",
+    "Div<&i8> for &i8",
+    "Div<&i8> for i8",
+    "Div<&isize> for &isize",
+    "Div<&isize> for isize",
+    "Div<&u128> for &u128",
+    "Div<&u128> for u128",
+    "Div<&u16> for &u16",
+    "Div<&u16> for u16",
+    "Div<&u32> for &u32",
+    "Div<&u32> for u32",
+    "Div<&u64> for &u64",
+    "Div<&u64> for u64",
+    "Div<&u8> for &u8",
+    "Div<&u8> for u8",
+    "Div<&usize> for &usize",
+    "Div<&usize> for usize",
+    "Div<f128> for &f128",
+    "Div<f128> for f128",
+    "Div<f16> for &f16",
+    "Div<f16> for f16",
+    "Div<f32> for &f32",
+    "Div<f32> for f32",
+    "Div<f64> for &f64",
+    "Div<f64> for f64",
+    "Div<i128> for &i128",
+    "Div<i128> for i128",
+    "Div<i16> for &i16",
+    "Div<i16> for i16",
+    "Div<i32> for &i32",
+    "Div<i32> for i32",
+    "Div<i64> for &i64",
+    "Div<i64> for i64",
+    "Div<i8> for &i8",
+    "Div<i8> for i8",
+    "Div<isize> for &isize",
+    "Div<isize> for isize",
+    "Div<u128> for &u128",
+    "Div<u128> for u128",
+    "Div<u16> for &u16",
+    "Div<u16> for u16",
+    "Div<u32> for &u32",
+    "Div<u32> for u32",
+    "Div<u64> for &u64",
+    "Div<u64> for u64",
+    "Div<u8> for &u8",
+    "Div<u8> for u8",
+    "Div<usize> for &usize",
+    "Div<usize> for usize",
+    "DivAssign<&f128> for f128",
+    "DivAssign<&f16> for f16",
+    "DivAssign<&f32> for f32",
+    "DivAssign<&f64> for f64",
+    "DivAssign<&i128> for i128",
+    "DivAssign<&i16> for i16",
+    "DivAssign<&i32> for i32",
+    "DivAssign<&i64> for i64",
+    "DivAssign<&i8> for i8",
+    "DivAssign<&isize> for isize",
+    "DivAssign<&u128> for u128",
+    "DivAssign<&u16> for u16",
+    "DivAssign<&u32> for u32",
+    "DivAssign<&u64> for u64",
+    "DivAssign<&u8> for u8",
+    "DivAssign<&usize> for usize",
+    "DivAssign<f128> for f128",
+    "DivAssign<f16> for f16",
+    "DivAssign<f32> for f32",
+    "DivAssign<f64> for f64",
+    "DivAssign<i128> for i128",
+    "DivAssign<i16> for i16",
+    "DivAssign<i32> for i32",
+    "DivAssign<i64> for i64",
+    "DivAssign<i8> for i8",
+    "DivAssign<isize> for isize",
+    "DivAssign<u128> for u128",
+    "DivAssign<u16> for u16",
+    "DivAssign<u32> for u32",
+    "DivAssign<u64> for u64",
+    "DivAssign<u8> for u8",
+    "DivAssign<usize> for usize",
+    "Mul<&f128> for &f128",
+    "Mul<&f128> for f128",
+    "Mul<&f16> for &f16",
+    "Mul<&f16> for f16",
+    "Mul<&f32> for &f32",
+    "Mul<&f32> for f32",
+    "Mul<&f64> for &f64",
+    "Mul<&f64> for f64",
+    "Mul<&i128> for &i128",
+    "Mul<&i128> for i128",
+    "Mul<&i16> for &i16",
+    "Mul<&i16> for i16",
+    "Mul<&i32> for &i32",
+    "Mul<&i32> for i32",
+    "Mul<&i64> for &i64",
+    "Mul<&i64> for i64",
+    "Mul<&i8> for &i8",
+    "Mul<&i8> for i8",
+    "Mul<&isize> for &isize",
+    "Mul<&isize> for isize",
+    "Mul<&u128> for &u128",
+    "Mul<&u128> for u128",
+    "Mul<&u16> for &u16",
+    "Mul<&u16> for u16",
+    "Mul<&u32> for &u32",
+    "Mul<&u32> for u32",
+    "Mul<&u64> for &u64",
+    "Mul<&u64> for u64",
+    "Mul<&u8> for &u8",
+    "Mul<&u8> for u8",
+    "Mul<&usize> for &usize",
+    "Mul<&usize> for usize",
+    "Mul<f128> for &f128",
+    "Mul<f128> for f128",
+    "Mul<f16> for &f16",
+    "Mul<f16> for f16",
+    "Mul<f32> for &f32",
+    "Mul<f32> for f32",
+    "Mul<f64> for &f64",
+    "Mul<f64> for f64",
+    "Mul<i128> for &i128",
+    "Mul<i128> for i128",
+    "Mul<i16> for &i16",
+    "Mul<i16> for i16",
+    "Mul<i32> for &i32",
+    "Mul<i32> for i32",
+    "Mul<i64> for &i64",
+    "Mul<i64> for i64",
+    "Mul<i8> for &i8",
+    "Mul<i8> for i8",
+    "Mul<isize> for &isize",
+    "Mul<isize> for isize",
+    "Mul<u128> for &u128",
+    "Mul<u128> for u128",
+    "Mul<u16> for &u16",
+    "Mul<u16> for u16",
+    "Mul<u32> for &u32",
+    "Mul<u32> for u32",
+    "Mul<u64> for &u64",
+    "Mul<u64> for u64",
+    "Mul<u8> for &u8",
+    "Mul<u8> for u8",
+    "Mul<usize> for &usize",
+    "Mul<usize> for usize",
+    "MulAssign<&f128> for f128",
+    "MulAssign<&f16> for f16",
+    "MulAssign<&f32> for f32",
+    "MulAssign<&f64> for f64",
+    "MulAssign<&i128> for i128",
+    "MulAssign<&i16> for i16",
+    "MulAssign<&i32> for i32",
+    "MulAssign<&i64> for i64",
+    "MulAssign<&i8> for i8",
+    "MulAssign<&isize> for isize",
+    "MulAssign<&u128> for u128",
+    "MulAssign<&u16> for u16",
+    "MulAssign<&u32> for u32",
+    "MulAssign<&u64> for u64",
+    "MulAssign<&u8> for u8",
+    "MulAssign<&usize> for usize",
+    "MulAssign<f128> for f128",
+    "MulAssign<f16> for f16",
+    "MulAssign<f32> for f32",
+    "MulAssign<f64> for f64",
+    "MulAssign<i128> for i128",
+    "MulAssign<i16> for i16",
+    "MulAssign<i32> for i32",
+    "MulAssign<i64> for i64",
+    "MulAssign<i8> for i8",
+    "MulAssign<isize> for isize",
+    "MulAssign<u128> for u128",
+    "MulAssign<u16> for u16",
+    "MulAssign<u32> for u32",
+    "MulAssign<u64> for u64",
+    "MulAssign<u8> for u8",
+    "MulAssign<usize> for usize",
+    "Neg for &f128",
+    "Neg for &f16",
+    "Neg for &f32",
+    "Neg for &f64",
+    "Neg for &i128",
+    "Neg for &i16",
+    "Neg for &i32",
+    "Neg for &i64",
+    "Neg for &i8",
+    "Neg for &isize",
+    "Neg for f128",
+    "Neg for f16",
+    "Neg for f32",
+    "Neg for f64",
+    "Neg for i128",
+    "Neg for i16",
+    "Neg for i32",
+    "Neg for i64",
+    "Neg for i8",
+    "Neg for isize",
+    "Not for !",
+    "Not for &bool",
+    "Not for &i128",
+    "Not for &i16",
+    "Not for &i32",
+    "Not for &i64",
+    "Not for &i8",
+    "Not for &isize",
+    "Not for &u128",
+    "Not for &u16",
+    "Not for &u32",
+    "Not for &u64",
+    "Not for &u8",
+    "Not for &usize",
+    "Not for bool",
+    "Not for i128",
+    "Not for i16",
+    "Not for i32",
+    "Not for i64",
+    "Not for i8",
+    "Not for isize",
+    "Not for u128",
+    "Not for u16",
+    "Not for u32",
+    "Not for u64",
+    "Not for u8",
+    "Not for usize",
+    "Rem<&f128> for &f128",
+    "Rem<&f128> for f128",
+    "Rem<&f16> for &f16",
+    "Rem<&f16> for f16",
+    "Rem<&f32> for &f32",
+    "Rem<&f32> for f32",
+    "Rem<&f64> for &f64",
+    "Rem<&f64> for f64",
+    "Rem<&i128> for &i128",
+    "Rem<&i128> for i128",
+    "Rem<&i16> for &i16",
+    "Rem<&i16> for i16",
+    "Rem<&i32> for &i32",
+    "Rem<&i32> for i32",
+    "Rem<&i64> for &i64",
+    "Rem<&i64> for i64",
+    "Rem<&i8> for &i8",
+    "Rem<&i8> for i8",
+    "Rem<&isize> for &isize",
+    "Rem<&isize> for isize",
+    "Rem<&u128> for &u128",
+    "Rem<&u128> for u128",
+    "Rem<&u16> for &u16",
+    "Rem<&u16> for u16",
+    "Rem<&u32> for &u32",
+    "Rem<&u32> for u32",
+    "Rem<&u64> for &u64",
+    "Rem<&u64> for u64",
+    "Rem<&u8> for &u8",
+    "Rem<&u8> for u8",
+    "Rem<&usize> for &usize",
+    "Rem<&usize> for usize",
+    "Rem<f128> for &f128",
+    "Rem<f128> for f128",
+    "Rem<f16> for &f16",
+    "Rem<f16> for f16",
+    "Rem<f32> for &f32",
+    "Rem<f32> for f32",
+    "Rem<f64> for &f64",
+    "Rem<f64> for f64",
+    "Rem<i128> for &i128",
+    "Rem<i128> for i128",
+    "Rem<i16> for &i16",
+    "Rem<i16> for i16",
+    "Rem<i32> for &i32",
+    "Rem<i32> for i32",
+    "Rem<i64> for &i64",
+    "Rem<i64> for i64",
+    "Rem<i8> for &i8",
+    "Rem<i8> for i8",
+    "Rem<isize> for &isize",
+    "Rem<isize> for isize",
+    "Rem<u128> for &u128",
+    "Rem<u128> for u128",
+    "Rem<u16> for &u16",
+    "Rem<u16> for u16",
+    "Rem<u32> for &u32",
+    "Rem<u32> for u32",
+    "Rem<u64> for &u64",
+    "Rem<u64> for u64",
+    "Rem<u8> for &u8",
+    "Rem<u8> for u8",
+    "Rem<usize> for &usize",
+    "Rem<usize> for usize",
+    "RemAssign<&f128> for f128",
+    "RemAssign<&f16> for f16",
+    "RemAssign<&f32> for f32",
+    "RemAssign<&f64> for f64",
+    "RemAssign<&i128> for i128",
+    "RemAssign<&i16> for i16",
+    "RemAssign<&i32> for i32",
+    "RemAssign<&i64> for i64",
+    "RemAssign<&i8> for i8",
+    "RemAssign<&isize> for isize",
+    "RemAssign<&u128> for u128",
+    "RemAssign<&u16> for u16",
+    "RemAssign<&u32> for u32",
+    "RemAssign<&u64> for u64",
+    "RemAssign<&u8> for u8",
+    "RemAssign<&usize> for usize",
+    "RemAssign<f128> for f128",
+    "RemAssign<f16> for f16",
+    "RemAssign<f32> for f32",
+    "RemAssign<f64> for f64",
+    "RemAssign<i128> for i128",
+    "RemAssign<i16> for i16",
+    "RemAssign<i32> for i32",
+    "RemAssign<i64> for i64",
+    "RemAssign<i8> for i8",
+    "RemAssign<isize> for isize",
+    "RemAssign<u128> for u128",
+    "RemAssign<u16> for u16",
+    "RemAssign<u32> for u32",
+    "RemAssign<u64> for u64",
+    "RemAssign<u8> for u8",
+    "RemAssign<usize> for usize",
+    "Shl<&i128> for &i128",
+    "Shl<&i128> for &i16",
+    "Shl<&i128> for &i32",
+    "Shl<&i128> for &i64",
+    "Shl<&i128> for &i8",
+    "Shl<&i128> for &isize",
+    "Shl<&i128> for &u128",
+    "Shl<&i128> for &u16",
+    "Shl<&i128> for &u32",
+    "Shl<&i128> for &u64",
+    "Shl<&i128> for &u8",
+    "Shl<&i128> for &usize",
+    "Shl<&i128> for i128",
+    "Shl<&i128> for i16",
+    "Shl<&i128> for i32",
+    "Shl<&i128> for i64",
+    "Shl<&i128> for i8",
+    "Shl<&i128> for isize",
+    "Shl<&i128> for u128",
+    "Shl<&i128> for u16",
+    "Shl<&i128> for u32",
+    "Shl<&i128> for u64",
+    "Shl<&i128> for u8",
+    "Shl<&i128> for usize",
+    "Shl<&i16> for &i128",
+    "Shl<&i16> for &i16",
+    "Shl<&i16> for &i32",
+    "Shl<&i16> for &i64",
+    "Shl<&i16> for &i8",
+    "Shl<&i16> for &isize",
+    "Shl<&i16> for &u128",
+    "Shl<&i16> for &u16",
+    "Shl<&i16> for &u32",
+    "Shl<&i16> for &u64",
+    "Shl<&i16> for &u8",
+    "Shl<&i16> for &usize",
+    "Shl<&i16> for i128",
+    "Shl<&i16> for i16",
+    "Shl<&i16> for i32",
+    "Shl<&i16> for i64",
+    "Shl<&i16> for i8",
+    "Shl<&i16> for isize",
+    "Shl<&i16> for u128",
+    "Shl<&i16> for u16",
+    "Shl<&i16> for u32",
+    "Shl<&i16> for u64",
+    "Shl<&i16> for u8",
+    "Shl<&i16> for usize",
+    "Shl<&i32> for &i128",
+    "Shl<&i32> for &i16",
+    "Shl<&i32> for &i32",
+    "Shl<&i32> for &i64",
+    "Shl<&i32> for &i8",
+    "Shl<&i32> for &isize",
+    "Shl<&i32> for &u128",
+    "Shl<&i32> for &u16",
+    "Shl<&i32> for &u32",
+    "Shl<&i32> for &u64",
+    "Shl<&i32> for &u8",
+    "Shl<&i32> for &usize",
+    "Shl<&i32> for i128",
+    "Shl<&i32> for i16",
+    "Shl<&i32> for i32",
+    "Shl<&i32> for i64",
+    "Shl<&i32> for i8",
+    "Shl<&i32> for isize",
+    "Shl<&i32> for u128",
+    "Shl<&i32> for u16",
+    "Shl<&i32> for u32",
+    "Shl<&i32> for u64",
+    "Shl<&i32> for u8",
+    "Shl<&i32> for usize",
+    "Shl<&i64> for &i128",
+    "Shl<&i64> for &i16",
+    "Shl<&i64> for &i32",
+    "Shl<&i64> for &i64",
+    "Shl<&i64> for &i8",
+    "Shl<&i64> for &isize",
+    "Shl<&i64> for &u128",
+    "Shl<&i64> for &u16",
+    "Shl<&i64> for &u32",
+    "Shl<&i64> for &u64",
+    "Shl<&i64> for &u8",
+    "Shl<&i64> for &usize",
+    "Shl<&i64> for i128",
+    "Shl<&i64> for i16",
+    "Shl<&i64> for i32",
+    "Shl<&i64> for i64",
+    "Shl<&i64> for i8",
+    "Shl<&i64> for isize",
+    "Shl<&i64> for u128",
+    "Shl<&i64> for u16",
+    "Shl<&i64> for u32",
+    "Shl<&i64> for u64",
+    "Shl<&i64> for u8",
+    "Shl<&i64> for usize",
+    "Shl<&i8> for &i128",
+    "Shl<&i8> for &i16",
+    "Shl<&i8> for &i32",
+    "Shl<&i8> for &i64",
+    "Shl<&i8> for &i8",
+    "Shl<&i8> for &isize",
+    "Shl<&i8> for &u128",
+    "Shl<&i8> for &u16",
+    "Shl<&i8> for &u32",
+    "Shl<&i8> for &u64",
+    "Shl<&i8> for &u8",
+    "Shl<&i8> for &usize",
+    "Shl<&i8> for i128",
+    "Shl<&i8> for i16",
+    "Shl<&i8> for i32",
+    "Shl<&i8> for i64",
+    "Shl<&i8> for i8",
+    "Shl<&i8> for isize",
+    "Shl<&i8> for u128",
+    "Shl<&i8> for u16",
+    "Shl<&i8> for u32",
+    "Shl<&i8> for u64",
+    "Shl<&i8> for u8",
+    "Shl<&i8> for usize",
+    "Shl<&isize> for &i128",
+    "Shl<&isize> for &i16",
+    "Shl<&isize> for &i32",
+    "Shl<&isize> for &i64",
+    "Shl<&isize> for &i8",
+    "Shl<&isize> for &isize",
+    "Shl<&isize> for &u128",
+    "Shl<&isize> for &u16",
+    "Shl<&isize> for &u32",
+    "Shl<&isize> for &u64",
+    "Shl<&isize> for &u8",
+    "Shl<&isize> for &usize",
+    "Shl<&isize> for i128",
+    "Shl<&isize> for i16",
+    "Shl<&isize> for i32",
+    "Shl<&isize> for i64",
+    "Shl<&isize> for i8",
+    "Shl<&isize> for isize",
+    "Shl<&isize> for u128",
+    "Shl<&isize> for u16",
+    "Shl<&isize> for u32",
+    "Shl<&isize> for u64",
+    "Shl<&isize> for u8",
+    "Shl<&isize> for usize",
+    "Shl<&u128> for &i128",
+    "Shl<&u128> for &i16",
+    "Shl<&u128> for &i32",
+    "Shl<&u128> for &i64",
+    "Shl<&u128> for &i8",
+    "Shl<&u128> for &isize",
+    "Shl<&u128> for &u128",
+    "Shl<&u128> for &u16",
+    "Shl<&u128> for &u32",
+    "Shl<&u128> for &u64",
+    "Shl<&u128> for &u8",
+    "Shl<&u128> for &usize",
+    "Shl<&u128> for i128",
+    "Shl<&u128> for i16",
+    "Shl<&u128> for i32",
+    "Shl<&u128> for i64",
+    "Shl<&u128> for i8",
+    "Shl<&u128> for isize",
+    "Shl<&u128> for u128",
+    "Shl<&u128> for u16",
+    "Shl<&u128> for u32",
+    "Shl<&u128> for u64",
+    "Shl<&u128> for u8",
+    "Shl<&u128> for usize",
+    "Shl<&u16> for &i128",
+    "Shl<&u16> for &i16",
+    "Shl<&u16> for &i32",
+    "Shl<&u16> for &i64",
+    "Shl<&u16> for &i8",
+    "Shl<&u16> for &isize",
+    "Shl<&u16> for &u128",
+    "Shl<&u16> for &u16",
+    "Shl<&u16> for &u32",
+    "Shl<&u16> for &u64",
+    "Shl<&u16> for &u8",
+    "Shl<&u16> for &usize",
+    "Shl<&u16> for i128",
+    "Shl<&u16> for i16",
+    "Shl<&u16> for i32",
+    "Shl<&u16> for i64",
+    "Shl<&u16> for i8",
+    "Shl<&u16> for isize",
+    "Shl<&u16> for u128",
+    "Shl<&u16> for u16",
+    "Shl<&u16> for u32",
+    "Shl<&u16> for u64",
+    "Shl<&u16> for u8",
+    "Shl<&u16> for usize",
+    "Shl<&u32> for &i128",
+    "Shl<&u32> for &i16",
+    "Shl<&u32> for &i32",
+    "Shl<&u32> for &i64",
+    "Shl<&u32> for &i8",
+    "Shl<&u32> for &isize",
+    "Shl<&u32> for &u128",
+    "Shl<&u32> for &u16",
+    "Shl<&u32> for &u32",
+    "Shl<&u32> for &u64",
+    "Shl<&u32> for &u8",
+    "Shl<&u32> for &usize",
+    "Shl<&u32> for i128",
+    "Shl<&u32> for i16",
+    "Shl<&u32> for i32",
+    "Shl<&u32> for i64",
+    "Shl<&u32> for i8",
+    "Shl<&u32> for isize",
+    "Shl<&u32> for u128",
+    "Shl<&u32> for u16",
+    "Shl<&u32> for u32",
+    "Shl<&u32> for u64",
+    "Shl<&u32> for u8",
+    "Shl<&u32> for usize",
+    "Shl<&u64> for &i128",
+    "Shl<&u64> for &i16",
+    "Shl<&u64> for &i32",
+    "Shl<&u64> for &i64",
+    "Shl<&u64> for &i8",
+    "Shl<&u64> for &isize",
+    "Shl<&u64> for &u128",
+    "Shl<&u64> for &u16",
+    "Shl<&u64> for &u32",
+    "Shl<&u64> for &u64",
+    "Shl<&u64> for &u8",
+    "Shl<&u64> for &usize",
+    "Shl<&u64> for i128",
+    "Shl<&u64> for i16",
+    "Shl<&u64> for i32",
+    "Shl<&u64> for i64",
+    "Shl<&u64> for i8",
+    "Shl<&u64> for isize",
+    "Shl<&u64> for u128",
+    "Shl<&u64> for u16",
+    "Shl<&u64> for u32",
+    "Shl<&u64> for u64",
+    "Shl<&u64> for u8",
+    "Shl<&u64> for usize",
+    "Shl<&u8> for &i128",
+    "Shl<&u8> for &i16",
+    "Shl<&u8> for &i32",
+    "Shl<&u8> for &i64",
+    "Shl<&u8> for &i8",
+    "Shl<&u8> for &isize",
+    "Shl<&u8> for &u128",
+    "Shl<&u8> for &u16",
+    "Shl<&u8> for &u32",
+    "Shl<&u8> for &u64",
+    "Shl<&u8> for &u8",
+    "Shl<&u8> for &usize",
+    "Shl<&u8> for i128",
+    "Shl<&u8> for i16",
+    "Shl<&u8> for i32",
+    "Shl<&u8> for i64",
+    "Shl<&u8> for i8",
+    "Shl<&u8> for isize",
+    "Shl<&u8> for u128",
+    "Shl<&u8> for u16",
+    "Shl<&u8> for u32",
+    "Shl<&u8> for u64",
+    "Shl<&u8> for u8",
+    "Shl<&u8> for usize",
+    "Shl<&usize> for &i128",
+    "Shl<&usize> for &i16",
+    "Shl<&usize> for &i32",
+    "Shl<&usize> for &i64",
+    "Shl<&usize> for &i8",
+    "Shl<&usize> for &isize",
+    "Shl<&usize> for &u128",
+    "Shl<&usize> for &u16",
+    "Shl<&usize> for &u32",
+    "Shl<&usize> for &u64",
+    "Shl<&usize> for &u8",
+    "Shl<&usize> for &usize",
+    "Shl<&usize> for i128",
+    "Shl<&usize> for i16",
+    "Shl<&usize> for i32",
+    "Shl<&usize> for i64",
+    "Shl<&usize> for i8",
+    "Shl<&usize> for isize",
+    "Shl<&usize> for u128",
+    "Shl<&usize> for u16",
+    "Shl<&usize> for u32",
+    "Shl<&usize> for u64",
+    "Shl<&usize> for u8",
+    "Shl<&usize> for usize",
+    "Shl<i128> for &i128",
+    "Shl<i128> for &i16",
+    "Shl<i128> for &i32",
+    "Shl<i128> for &i64",
+    "Shl<i128> for &i8",
+    "Shl<i128> for &isize",
+    "Shl<i128> for &u128",
+    "Shl<i128> for &u16",
+    "Shl<i128> for &u32",
+    "Shl<i128> for &u64",
+    "Shl<i128> for &u8",
+    "Shl<i128> for &usize",
+    "Shl<i128> for i128",
+    "Shl<i128> for i16",
+    "Shl<i128> for i32",
+    "Shl<i128> for i64",
+    "Shl<i128> for i8",
+    "Shl<i128> for isize",
+    "Shl<i128> for u128",
+    "Shl<i128> for u16",
+    "Shl<i128> for u32",
+    "Shl<i128> for u64",
+    "Shl<i128> for u8",
+    "Shl<i128> for usize",
+    "Shl<i16> for &i128",
+    "Shl<i16> for &i16",
+    "Shl<i16> for &i32",
+    "Shl<i16> for &i64",
+    "Shl<i16> for &i8",
+    "Shl<i16> for &isize",
+    "Shl<i16> for &u128",
+    "Shl<i16> for &u16",
+    "Shl<i16> for &u32",
+    "Shl<i16> for &u64",
+    "Shl<i16> for &u8",
+    "Shl<i16> for &usize",
+    "Shl<i16> for i128",
+    "Shl<i16> for i16",
+    "Shl<i16> for i32",
+    "Shl<i16> for i64",
+    "Shl<i16> for i8",
+    "Shl<i16> for isize",
+    "Shl<i16> for u128",
+    "Shl<i16> for u16",
+    "Shl<i16> for u32",
+    "Shl<i16> for u64",
+    "Shl<i16> for u8",
+    "Shl<i16> for usize",
+    "Shl<i32> for &i128",
+    "Shl<i32> for &i16",
+    "Shl<i32> for &i32",
+    "Shl<i32> for &i64",
+    "Shl<i32> for &i8",
+    "Shl<i32> for &isize",
+    "Shl<i32> for &u128",
+    "Shl<i32> for &u16",
+    "Shl<i32> for &u32",
+    "Shl<i32> for &u64",
+    "Shl<i32> for &u8",
+    "Shl<i32> for &usize",
+    "Shl<i32> for i128",
+    "Shl<i32> for i16",
+    "Shl<i32> for i32",
+    "Shl<i32> for i64",
+    "Shl<i32> for i8",
+    "Shl<i32> for isize",
+    "Shl<i32> for u128",
+    "Shl<i32> for u16",
+    "Shl<i32> for u32",
+    "Shl<i32> for u64",
+    "Shl<i32> for u8",
+    "Shl<i32> for usize",
+    "Shl<i64> for &i128",
+    "Shl<i64> for &i16",
+    "Shl<i64> for &i32",
+    "Shl<i64> for &i64",
+    "Shl<i64> for &i8",
+    "Shl<i64> for &isize",
+    "Shl<i64> for &u128",
+    "Shl<i64> for &u16",
+    "Shl<i64> for &u32",
+    "Shl<i64> for &u64",
+    "Shl<i64> for &u8",
+    "Shl<i64> for &usize",
+    "Shl<i64> for i128",
+    "Shl<i64> for i16",
+    "Shl<i64> for i32",
+    "Shl<i64> for i64",
+    "Shl<i64> for i8",
+    "Shl<i64> for isize",
+    "Shl<i64> for u128",
+    "Shl<i64> for u16",
+    "Shl<i64> for u32",
+    "Shl<i64> for u64",
+    "Shl<i64> for u8",
+    "Shl<i64> for usize",
+    "Shl<i8> for &i128",
+    "Shl<i8> for &i16",
+    "Shl<i8> for &i32",
+    "Shl<i8> for &i64",
+    "Shl<i8> for &i8",
+    "Shl<i8> for &isize",
+    "Shl<i8> for &u128",
+    "Shl<i8> for &u16",
+    "Shl<i8> for &u32",
+    "Shl<i8> for &u64",
+    "Shl<i8> for &u8",
+    "Shl<i8> for &usize",
+    "Shl<i8> for i128",
+    "Shl<i8> for i16",
+    "Shl<i8> for i32",
+    "Shl<i8> for i64",
+    "Shl<i8> for i8",
+    "Shl<i8> for isize",
+    "Shl<i8> for u128",
+    "Shl<i8> for u16",
+    "Shl<i8> for u32",
+    "Shl<i8> for u64",
+    "Shl<i8> for u8",
+    "Shl<i8> for usize",
+    "Shl<isize> for &i128",
+    "Shl<isize> for &i16",
+    "Shl<isize> for &i32",
+    "Shl<isize> for &i64",
+    "Shl<isize> for &i8",
+    "Shl<isize> for &isize",
+    "Shl<isize> for &u128",
+    "Shl<isize> for &u16",
+    "Shl<isize> for &u32",
+    "Shl<isize> for &u64",
+    "Shl<isize> for &u8",
+    "Shl<isize> for &usize",
+    "Shl<isize> for i128",
+    "Shl<isize> for i16",
+    "Shl<isize> for i32",
+    "Shl<isize> for i64",
+    "Shl<isize> for i8",
+    "Shl<isize> for isize",
+    "Shl<isize> for u128",
+    "Shl<isize> for u16",
+    "Shl<isize> for u32",
+    "Shl<isize> for u64",
+    "Shl<isize> for u8",
+    "Shl<isize> for usize",
+    "Shl<u128> for &i128",
+    "Shl<u128> for &i16",
+    "Shl<u128> for &i32",
+    "Shl<u128> for &i64",
+    "Shl<u128> for &i8",
+    "Shl<u128> for &isize",
+    "Shl<u128> for &u128",
+    "Shl<u128> for &u16",
+    "Shl<u128> for &u32",
+    "Shl<u128> for &u64",
+    "Shl<u128> for &u8",
+    "Shl<u128> for &usize",
+    "Shl<u128> for i128",
+    "Shl<u128> for i16",
+    "Shl<u128> for i32",
+    "Shl<u128> for i64",
+    "Shl<u128> for i8",
+    "Shl<u128> for isize",
+    "Shl<u128> for u128",
+    "Shl<u128> for u16",
+    "Shl<u128> for u32",
+    "Shl<u128> for u64",
+    "Shl<u128> for u8",
+    "Shl<u128> for usize",
+    "Shl<u16> for &i128",
+    "Shl<u16> for &i16",
+    "Shl<u16> for &i32",
+    "Shl<u16> for &i64",
+    "Shl<u16> for &i8",
+    "Shl<u16> for &isize",
+    "Shl<u16> for &u128",
+    "Shl<u16> for &u16",
+    "Shl<u16> for &u32",
+    "Shl<u16> for &u64",
+    "Shl<u16> for &u8",
+    "Shl<u16> for &usize",
+    "Shl<u16> for i128",
+    "Shl<u16> for i16",
+    "Shl<u16> for i32",
+    "Shl<u16> for i64",
+    "Shl<u16> for i8",
+    "Shl<u16> for isize",
+    "Shl<u16> for u128",
+    "Shl<u16> for u16",
+    "Shl<u16> for u32",
+    "Shl<u16> for u64",
+    "Shl<u16> for u8",
+    "Shl<u16> for usize",
+    "Shl<u32> for &i128",
+    "Shl<u32> for &i16",
+    "Shl<u32> for &i32",
+    "Shl<u32> for &i64",
+    "Shl<u32> for &i8",
+    "Shl<u32> for &isize",
+    "Shl<u32> for &u128",
+    "Shl<u32> for &u16",
+    "Shl<u32> for &u32",
+    "Shl<u32> for &u64",
+    "Shl<u32> for &u8",
+    "Shl<u32> for &usize",
+    "Shl<u32> for i128",
+    "Shl<u32> for i16",
+    "Shl<u32> for i32",
+    "Shl<u32> for i64",
+    "Shl<u32> for i8",
+    "Shl<u32> for isize",
+    "Shl<u32> for u128",
+    "Shl<u32> for u16",
+    "Shl<u32> for u32",
+    "Shl<u32> for u64",
+    "Shl<u32> for u8",
+    "Shl<u32> for usize",
+    "Shl<u64> for &i128",
+    "Shl<u64> for &i16",
+    "Shl<u64> for &i32",
+    "Shl<u64> for &i64",
+    "Shl<u64> for &i8",
+    "Shl<u64> for &isize",
+    "Shl<u64> for &u128",
+    "Shl<u64> for &u16",
+    "Shl<u64> for &u32",
+    "Shl<u64> for &u64",
+    "Shl<u64> for &u8",
+    "Shl<u64> for &usize",
+    "Shl<u64> for i128",
+    "Shl<u64> for i16",
+    "Shl<u64> for i32",
+    "Shl<u64> for i64",
+    "Shl<u64> for i8",
+    "Shl<u64> for isize",
+    "Shl<u64> for u128",
+    "Shl<u64> for u16",
+    "Shl<u64> for u32",
+    "Shl<u64> for u64",
+    "Shl<u64> for u8",
+    "Shl<u64> for usize",
+    "Shl<u8> for &i128",
+    "Shl<u8> for &i16",
+    "Shl<u8> for &i32",
+    "Shl<u8> for &i64",
+    "Shl<u8> for &i8",
+    "Shl<u8> for &isize",
+    "Shl<u8> for &u128",
+    "Shl<u8> for &u16",
+    "Shl<u8> for &u32",
+    "Shl<u8> for &u64",
+    "Shl<u8> for &u8",
+    "Shl<u8> for &usize",
+    "Shl<u8> for i128",
+    "Shl<u8> for i16",
+    "Shl<u8> for i32",
+    "Shl<u8> for i64",
+    "Shl<u8> for i8",
+    "Shl<u8> for isize",
+    "Shl<u8> for u128",
+    "Shl<u8> for u16",
+    "Shl<u8> for u32",
+    "Shl<u8> for u64",
+    "Shl<u8> for u8",
+    "Shl<u8> for usize",
+    "Shl<usize> for &i128",
+    "Shl<usize> for &i16",
+    "Shl<usize> for &i32",
+    "Shl<usize> for &i64",
+    "Shl<usize> for &i8",
+    "Shl<usize> for &isize",
+    "Shl<usize> for &u128",
+    "Shl<usize> for &u16",
+    "Shl<usize> for &u32",
+    "Shl<usize> for &u64",
+    "Shl<usize> for &u8",
+    "Shl<usize> for &usize",
+    "Shl<usize> for i128",
+    "Shl<usize> for i16",
+    "Shl<usize> for i32",
+    "Shl<usize> for i64",
+    "Shl<usize> for i8",
+    "Shl<usize> for isize",
+    "Shl<usize> for u128",
+    "Shl<usize> for u16",
+    "Shl<usize> for u32",
+    "Shl<usize> for u64",
+    "Shl<usize> for u8",
+    "Shl<usize> for usize",
+    "ShlAssign<&i128> for i128",
+    "ShlAssign<&i128> for i16",
+    "ShlAssign<&i128> for i32",
+    "ShlAssign<&i128> for i64",
+    "ShlAssign<&i128> for i8",
+    "ShlAssign<&i128> for isize",
+    "ShlAssign<&i128> for u128",
+    "ShlAssign<&i128> for u16",
+    "ShlAssign<&i128> for u32",
+    "ShlAssign<&i128> for u64",
+    "ShlAssign<&i128> for u8",
+    "ShlAssign<&i128> for usize",
+    "ShlAssign<&i16> for i128",
+    "ShlAssign<&i16> for i16",
+    "ShlAssign<&i16> for i32",
+    "ShlAssign<&i16> for i64",
+    "ShlAssign<&i16> for i8",
+    "ShlAssign<&i16> for isize",
+    "ShlAssign<&i16> for u128",
+    "ShlAssign<&i16> for u16",
+    "ShlAssign<&i16> for u32",
+    "ShlAssign<&i16> for u64",
+    "ShlAssign<&i16> for u8",
+    "ShlAssign<&i16> for usize",
+    "ShlAssign<&i32> for i128",
+    "ShlAssign<&i32> for i16",
+    "ShlAssign<&i32> for i32",
+    "ShlAssign<&i32> for i64",
+    "ShlAssign<&i32> for i8",
+    "ShlAssign<&i32> for isize",
+    "ShlAssign<&i32> for u128",
+    "ShlAssign<&i32> for u16",
+    "ShlAssign<&i32> for u32",
+    "ShlAssign<&i32> for u64",
+    "ShlAssign<&i32> for u8",
+    "ShlAssign<&i32> for usize",
+    "ShlAssign<&i64> for i128",
+    "ShlAssign<&i64> for i16",
+    "ShlAssign<&i64> for i32",
+    "ShlAssign<&i64> for i64",
+    "ShlAssign<&i64> for i8",
+    "ShlAssign<&i64> for isize",
+    "ShlAssign<&i64> for u128",
+    "ShlAssign<&i64> for u16",
+    "ShlAssign<&i64> for u32",
+    "ShlAssign<&i64> for u64",
+    "ShlAssign<&i64> for u8",
+    "ShlAssign<&i64> for usize",
+    "ShlAssign<&i8> for i128",
+    "ShlAssign<&i8> for i16",
+    "ShlAssign<&i8> for i32",
+    "ShlAssign<&i8> for i64",
+    "ShlAssign<&i8> for i8",
+    "ShlAssign<&i8> for isize",
+    "ShlAssign<&i8> for u128",
+    "ShlAssign<&i8> for u16",
+    "ShlAssign<&i8> for u32",
+    "ShlAssign<&i8> for u64",
+    "ShlAssign<&i8> for u8",
+    "ShlAssign<&i8> for usize",
+    "ShlAssign<&isize> for i128",
+    "ShlAssign<&isize> for i16",
+    "ShlAssign<&isize> for i32",
+    "ShlAssign<&isize> for i64",
+    "ShlAssign<&isize> for i8",
+    "ShlAssign<&isize> for isize",
+    "ShlAssign<&isize> for u128",
+    "ShlAssign<&isize> for u16",
+    "ShlAssign<&isize> for u32",
+    "ShlAssign<&isize> for u64",
+    "ShlAssign<&isize> for u8",
+    "ShlAssign<&isize> for usize",
+    "ShlAssign<&u128> for i128",
+    "ShlAssign<&u128> for i16",
+    "ShlAssign<&u128> for i32",
+    "ShlAssign<&u128> for i64",
+    "ShlAssign<&u128> for i8",
+    "ShlAssign<&u128> for isize",
+    "ShlAssign<&u128> for u128",
+    "ShlAssign<&u128> for u16",
+    "ShlAssign<&u128> for u32",
+    "ShlAssign<&u128> for u64",
+    "ShlAssign<&u128> for u8",
+    "ShlAssign<&u128> for usize",
+    "ShlAssign<&u16> for i128",
+    "ShlAssign<&u16> for i16",
+    "ShlAssign<&u16> for i32",
+    "ShlAssign<&u16> for i64",
+    "ShlAssign<&u16> for i8",
+    "ShlAssign<&u16> for isize",
+    "ShlAssign<&u16> for u128",
+    "ShlAssign<&u16> for u16",
+    "ShlAssign<&u16> for u32",
+    "ShlAssign<&u16> for u64",
+    "ShlAssign<&u16> for u8",
+    "ShlAssign<&u16> for usize",
+    "ShlAssign<&u32> for i128",
+    "ShlAssign<&u32> for i16",
+    "ShlAssign<&u32> for i32",
+    "ShlAssign<&u32> for i64",
+    "ShlAssign<&u32> for i8",
+    "ShlAssign<&u32> for isize",
+    "ShlAssign<&u32> for u128",
+    "ShlAssign<&u32> for u16",
+    "ShlAssign<&u32> for u32",
+    "ShlAssign<&u32> for u64",
+    "ShlAssign<&u32> for u8",
+    "ShlAssign<&u32> for usize",
+    "ShlAssign<&u64> for i128",
+    "ShlAssign<&u64> for i16",
+    "ShlAssign<&u64> for i32",
+    "ShlAssign<&u64> for i64",
+    "ShlAssign<&u64> for i8",
+    "ShlAssign<&u64> for isize",
+    "ShlAssign<&u64> for u128",
+    "ShlAssign<&u64> for u16",
+    "ShlAssign<&u64> for u32",
+    "ShlAssign<&u64> for u64",
+    "ShlAssign<&u64> for u8",
+    "ShlAssign<&u64> for usize",
+    "ShlAssign<&u8> for i128",
+    "ShlAssign<&u8> for i16",
+    "ShlAssign<&u8> for i32",
+    "ShlAssign<&u8> for i64",
+    "ShlAssign<&u8> for i8",
+    "ShlAssign<&u8> for isize",
+    "ShlAssign<&u8> for u128",
+    "ShlAssign<&u8> for u16",
+    "ShlAssign<&u8> for u32",
+    "ShlAssign<&u8> for u64",
+    "ShlAssign<&u8> for u8",
+    "ShlAssign<&u8> for usize",
+    "ShlAssign<&usize> for i128",
+    "ShlAssign<&usize> for i16",
+    "ShlAssign<&usize> for i32",
+    "ShlAssign<&usize> for i64",
+    "ShlAssign<&usize> for i8",
+    "ShlAssign<&usize> for isize",
+    "ShlAssign<&usize> for u128",
+    "ShlAssign<&usize> for u16",
+    "ShlAssign<&usize> for u32",
+    "ShlAssign<&usize> for u64",
+    "ShlAssign<&usize> for u8",
+    "ShlAssign<&usize> for usize",
+    "ShlAssign<i128> for i128",
+    "ShlAssign<i128> for i16",
+    "ShlAssign<i128> for i32",
+    "ShlAssign<i128> for i64",
+    "ShlAssign<i128> for i8",
+    "ShlAssign<i128> for isize",
+    "ShlAssign<i128> for u128",
+    "ShlAssign<i128> for u16",
+    "ShlAssign<i128> for u32",
+    "ShlAssign<i128> for u64",
+    "ShlAssign<i128> for u8",
+    "ShlAssign<i128> for usize",
+    "ShlAssign<i16> for i128",
+    "ShlAssign<i16> for i16",
+    "ShlAssign<i16> for i32",
+    "ShlAssign<i16> for i64",
+    "ShlAssign<i16> for i8",
+    "ShlAssign<i16> for isize",
+    "ShlAssign<i16> for u128",
+    "ShlAssign<i16> for u16",
+    "ShlAssign<i16> for u32",
+    "ShlAssign<i16> for u64",
+    "ShlAssign<i16> for u8",
+    "ShlAssign<i16> for usize",
+    "ShlAssign<i32> for i128",
+    "ShlAssign<i32> for i16",
+    "ShlAssign<i32> for i32",
+    "ShlAssign<i32> for i64",
+    "ShlAssign<i32> for i8",
+    "ShlAssign<i32> for isize",
+    "ShlAssign<i32> for u128",
+    "ShlAssign<i32> for u16",
+    "ShlAssign<i32> for u32",
+    "ShlAssign<i32> for u64",
+    "ShlAssign<i32> for u8",
+    "ShlAssign<i32> for usize",
+    "ShlAssign<i64> for i128",
+    "ShlAssign<i64> for i16",
+    "ShlAssign<i64> for i32",
+    "ShlAssign<i64> for i64",
+    "ShlAssign<i64> for i8",
+    "ShlAssign<i64> for isize",
+    "ShlAssign<i64> for u128",
+    "ShlAssign<i64> for u16",
+    "ShlAssign<i64> for u32",
+    "ShlAssign<i64> for u64",
+    "ShlAssign<i64> for u8",
+    "ShlAssign<i64> for usize",
+    "ShlAssign<i8> for i128",
+    "ShlAssign<i8> for i16",
+    "ShlAssign<i8> for i32",
+    "ShlAssign<i8> for i64",
+    "ShlAssign<i8> for i8",
+    "ShlAssign<i8> for isize",
+    "ShlAssign<i8> for u128",
+    "ShlAssign<i8> for u16",
+    "ShlAssign<i8> for u32",
+    "ShlAssign<i8> for u64",
+    "ShlAssign<i8> for u8",
+    "ShlAssign<i8> for usize",
+    "ShlAssign<isize> for i128",
+    "ShlAssign<isize> for i16",
+    "ShlAssign<isize> for i32",
+    "ShlAssign<isize> for i64",
+    "ShlAssign<isize> for i8",
+    "ShlAssign<isize> for isize",
+    "ShlAssign<isize> for u128",
+    "ShlAssign<isize> for u16",
+    "ShlAssign<isize> for u32",
+    "ShlAssign<isize> for u64",
+    "ShlAssign<isize> for u8",
+    "ShlAssign<isize> for usize",
+    "ShlAssign<u128> for i128",
+    "ShlAssign<u128> for i16",
+    "ShlAssign<u128> for i32",
+    "ShlAssign<u128> for i64",
+    "ShlAssign<u128> for i8",
+    "ShlAssign<u128> for isize",
+    "ShlAssign<u128> for u128",
+    "ShlAssign<u128> for u16",
+    "ShlAssign<u128> for u32",
+    "ShlAssign<u128> for u64",
+    "ShlAssign<u128> for u8",
+    "ShlAssign<u128> for usize",
+    "ShlAssign<u16> for i128",
+    "ShlAssign<u16> for i16",
+    "ShlAssign<u16> for i32",
+    "ShlAssign<u16> for i64",
+    "ShlAssign<u16> for i8",
+    "ShlAssign<u16> for isize",
+    "ShlAssign<u16> for u128",
+    "ShlAssign<u16> for u16",
+    "ShlAssign<u16> for u32",
+    "ShlAssign<u16> for u64",
+    "ShlAssign<u16> for u8",
+    "ShlAssign<u16> for usize",
+    "ShlAssign<u32> for i128",
+    "ShlAssign<u32> for i16",
+    "ShlAssign<u32> for i32",
+    "ShlAssign<u32> for i64",
+    "ShlAssign<u32> for i8",
+    "ShlAssign<u32> for isize",
+    "ShlAssign<u32> for u128",
+    "ShlAssign<u32> for u16",
+    "ShlAssign<u32> for u32",
+    "ShlAssign<u32> for u64",
+    "ShlAssign<u32> for u8",
+    "ShlAssign<u32> for usize",
+    "ShlAssign<u64> for i128",
+    "ShlAssign<u64> for i16",
+    "ShlAssign<u64> for i32",
+    "ShlAssign<u64> for i64",
+    "ShlAssign<u64> for i8",
+    "ShlAssign<u64> for isize",
+    "ShlAssign<u64> for u128",
+    "ShlAssign<u64> for u16",
+    "ShlAssign<u64> for u32",
+    "ShlAssign<u64> for u64",
+    "ShlAssign<u64> for u8",
+    "ShlAssign<u64> for usize",
+    "ShlAssign<u8> for i128",
+    "ShlAssign<u8> for i16",
+    "ShlAssign<u8> for i32",
+    "ShlAssign<u8> for i64",
+    "ShlAssign<u8> for i8",
+    "ShlAssign<u8> for isize",
+    "ShlAssign<u8> for u128",
+    "ShlAssign<u8> for u16",
+    "ShlAssign<u8> for u32",
+    "ShlAssign<u8> for u64",
+    "ShlAssign<u8> for u8",
+    "ShlAssign<u8> for usize",
+    "ShlAssign<usize> for i128",
+    "ShlAssign<usize> for i16",
+    "ShlAssign<usize> for i32",
+    "ShlAssign<usize> for i64",
+    "ShlAssign<usize> for i8",
+    "ShlAssign<usize> for isize",
+    "ShlAssign<usize> for u128",
+    "ShlAssign<usize> for u16",
+    "ShlAssign<usize> for u32",
+    "ShlAssign<usize> for u64",
+    "ShlAssign<usize> for u8",
+    "ShlAssign<usize> for usize",
+    "Shr<&i128> for &i128",
+    "Shr<&i128> for &i16",
+    "Shr<&i128> for &i32",
+    "Shr<&i128> for &i64",
+    "Shr<&i128> for &i8",
+    "Shr<&i128> for &isize",
+    "Shr<&i128> for &u128",
+    "Shr<&i128> for &u16",
+    "Shr<&i128> for &u32",
+    "Shr<&i128> for &u64",
+    "Shr<&i128> for &u8",
+    "Shr<&i128> for &usize",
+    "Shr<&i128> for i128",
+    "Shr<&i128> for i16",
+    "Shr<&i128> for i32",
+    "Shr<&i128> for i64",
+    "Shr<&i128> for i8",
+    "Shr<&i128> for isize",
+    "Shr<&i128> for u128",
+    "Shr<&i128> for u16",
+    "Shr<&i128> for u32",
+    "Shr<&i128> for u64",
+    "Shr<&i128> for u8",
+    "Shr<&i128> for usize",
+    "Shr<&i16> for &i128",
+    "Shr<&i16> for &i16",
+    "Shr<&i16> for &i32",
+    "Shr<&i16> for &i64",
+    "Shr<&i16> for &i8",
+    "Shr<&i16> for &isize",
+    "Shr<&i16> for &u128",
+    "Shr<&i16> for &u16",
+    "Shr<&i16> for &u32",
+    "Shr<&i16> for &u64",
+    "Shr<&i16> for &u8",
+    "Shr<&i16> for &usize",
+    "Shr<&i16> for i128",
+    "Shr<&i16> for i16",
+    "Shr<&i16> for i32",
+    "Shr<&i16> for i64",
+    "Shr<&i16> for i8",
+    "Shr<&i16> for isize",
+    "Shr<&i16> for u128",
+    "Shr<&i16> for u16",
+    "Shr<&i16> for u32",
+    "Shr<&i16> for u64",
+    "Shr<&i16> for u8",
+    "Shr<&i16> for usize",
+    "Shr<&i32> for &i128",
+    "Shr<&i32> for &i16",
+    "Shr<&i32> for &i32",
+    "Shr<&i32> for &i64",
+    "Shr<&i32> for &i8",
+    "Shr<&i32> for &isize",
+    "Shr<&i32> for &u128",
+    "Shr<&i32> for &u16",
+    "Shr<&i32> for &u32",
+    "Shr<&i32> for &u64",
+    "Shr<&i32> for &u8",
+    "Shr<&i32> for &usize",
+    "Shr<&i32> for i128",
+    "Shr<&i32> for i16",
+    "Shr<&i32> for i32",
+    "Shr<&i32> for i64",
+    "Shr<&i32> for i8",
+    "Shr<&i32> for isize",
+    "Shr<&i32> for u128",
+    "Shr<&i32> for u16",
+    "Shr<&i32> for u32",
+    "Shr<&i32> for u64",
+    "Shr<&i32> for u8",
+    "Shr<&i32> for usize",
+    "Shr<&i64> for &i128",
+    "Shr<&i64> for &i16",
+    "Shr<&i64> for &i32",
+    "Shr<&i64> for &i64",
+    "Shr<&i64> for &i8",
+    "Shr<&i64> for &isize",
+    "Shr<&i64> for &u128",
+    "Shr<&i64> for &u16",
+    "Shr<&i64> for &u32",
+    "Shr<&i64> for &u64",
+    "Shr<&i64> for &u8",
+    "Shr<&i64> for &usize",
+    "Shr<&i64> for i128",
+    "Shr<&i64> for i16",
+    "Shr<&i64> for i32",
+    "Shr<&i64> for i64",
+    "Shr<&i64> for i8",
+    "Shr<&i64> for isize",
+    "Shr<&i64> for u128",
+    "Shr<&i64> for u16",
+    "Shr<&i64> for u32",
+    "Shr<&i64> for u64",
+    "Shr<&i64> for u8",
+    "Shr<&i64> for usize",
+    "Shr<&i8> for &i128",
+    "Shr<&i8> for &i16",
+    "Shr<&i8> for &i32",
+    "Shr<&i8> for &i64",
+    "Shr<&i8> for &i8",
+    "Shr<&i8> for &isize",
+    "Shr<&i8> for &u128",
+    "Shr<&i8> for &u16",
+    "Shr<&i8> for &u32",
+    "Shr<&i8> for &u64",
+    "Shr<&i8> for &u8",
+    "Shr<&i8> for &usize",
+    "Shr<&i8> for i128",
+    "Shr<&i8> for i16",
+    "Shr<&i8> for i32",
+    "Shr<&i8> for i64",
+    "Shr<&i8> for i8",
+    "Shr<&i8> for isize",
+    "Shr<&i8> for u128",
+    "Shr<&i8> for u16",
+    "Shr<&i8> for u32",
+    "Shr<&i8> for u64",
+    "Shr<&i8> for u8",
+    "Shr<&i8> for usize",
+    "Shr<&isize> for &i128",
+    "Shr<&isize> for &i16",
+    "Shr<&isize> for &i32",
+    "Shr<&isize> for &i64",
+    "Shr<&isize> for &i8",
+    "Shr<&isize> for &isize",
+    "Shr<&isize> for &u128",
+    "Shr<&isize> for &u16",
+    "Shr<&isize> for &u32",
+    "Shr<&isize> for &u64",
+    "Shr<&isize> for &u8",
+    "Shr<&isize> for &usize",
+    "Shr<&isize> for i128",
+    "Shr<&isize> for i16",
+    "Shr<&isize> for i32",
+    "Shr<&isize> for i64",
+    "Shr<&isize> for i8",
+    "Shr<&isize> for isize",
+    "Shr<&isize> for u128",
+    "Shr<&isize> for u16",
+    "Shr<&isize> for u32",
+    "Shr<&isize> for u64",
+    "Shr<&isize> for u8",
+    "Shr<&isize> for usize",
+    "Shr<&u128> for &i128",
+    "Shr<&u128> for &i16",
+    "Shr<&u128> for &i32",
+    "Shr<&u128> for &i64",
+    "Shr<&u128> for &i8",
+    "Shr<&u128> for &isize",
+    "Shr<&u128> for &u128",
+    "Shr<&u128> for &u16",
+    "Shr<&u128> for &u32",
+    "Shr<&u128> for &u64",
+    "Shr<&u128> for &u8",
+    "Shr<&u128> for &usize",
+    "Shr<&u128> for i128",
+    "Shr<&u128> for i16",
+    "Shr<&u128> for i32",
+    "Shr<&u128> for i64",
+    "Shr<&u128> for i8",
+    "Shr<&u128> for isize",
+    "Shr<&u128> for u128",
+    "Shr<&u128> for u16",
+    "Shr<&u128> for u32",
+    "Shr<&u128> for u64",
+    "Shr<&u128> for u8",
+    "Shr<&u128> for usize",
+    "Shr<&u16> for &i128",
+    "Shr<&u16> for &i16",
+    "Shr<&u16> for &i32",
+    "Shr<&u16> for &i64",
+    "Shr<&u16> for &i8",
+    "Shr<&u16> for &isize",
+    "Shr<&u16> for &u128",
+    "Shr<&u16> for &u16",
+    "Shr<&u16> for &u32",
+    "Shr<&u16> for &u64",
+    "Shr<&u16> for &u8",
+    "Shr<&u16> for &usize",
+    "Shr<&u16> for i128",
+    "Shr<&u16> for i16",
+    "Shr<&u16> for i32",
+    "Shr<&u16> for i64",
+    "Shr<&u16> for i8",
+    "Shr<&u16> for isize",
+    "Shr<&u16> for u128",
+    "Shr<&u16> for u16",
+    "Shr<&u16> for u32",
+    "Shr<&u16> for u64",
+    "Shr<&u16> for u8",
+    "Shr<&u16> for usize",
+    "Shr<&u32> for &i128",
+    "Shr<&u32> for &i16",
+    "Shr<&u32> for &i32",
+    "Shr<&u32> for &i64",
+    "Shr<&u32> for &i8",
+    "Shr<&u32> for &isize",
+    "Shr<&u32> for &u128",
+    "Shr<&u32> for &u16",
+    "Shr<&u32> for &u32",
+    "Shr<&u32> for &u64",
+    "Shr<&u32> for &u8",
+    "Shr<&u32> for &usize",
+    "Shr<&u32> for i128",
+    "Shr<&u32> for i16",
+    "Shr<&u32> for i32",
+    "Shr<&u32> for i64",
+    "Shr<&u32> for i8",
+    "Shr<&u32> for isize",
+    "Shr<&u32> for u128",
+    "Shr<&u32> for u16",
+    "Shr<&u32> for u32",
+    "Shr<&u32> for u64",
+    "Shr<&u32> for u8",
+    "Shr<&u32> for usize",
+    "Shr<&u64> for &i128",
+    "Shr<&u64> for &i16",
+    "Shr<&u64> for &i32",
+    "Shr<&u64> for &i64",
+    "Shr<&u64> for &i8",
+    "Shr<&u64> for &isize",
+    "Shr<&u64> for &u128",
+    "Shr<&u64> for &u16",
+    "Shr<&u64> for &u32",
+    "Shr<&u64> for &u64",
+    "Shr<&u64> for &u8",
+    "Shr<&u64> for &usize",
+    "Shr<&u64> for i128",
+    "Shr<&u64> for i16",
+    "Shr<&u64> for i32",
+    "Shr<&u64> for i64",
+    "Shr<&u64> for i8",
+    "Shr<&u64> for isize",
+    "Shr<&u64> for u128",
+    "Shr<&u64> for u16",
+    "Shr<&u64> for u32",
+    "Shr<&u64> for u64",
+    "Shr<&u64> for u8",
+    "Shr<&u64> for usize",
+    "Shr<&u8> for &i128",
+    "Shr<&u8> for &i16",
+    "Shr<&u8> for &i32",
+    "Shr<&u8> for &i64",
+    "Shr<&u8> for &i8",
+    "Shr<&u8> for &isize",
+    "Shr<&u8> for &u128",
+    "Shr<&u8> for &u16",
+    "Shr<&u8> for &u32",
+    "Shr<&u8> for &u64",
+    "Shr<&u8> for &u8",
+    "Shr<&u8> for &usize",
+    "Shr<&u8> for i128",
+    "Shr<&u8> for i16",
+    "Shr<&u8> for i32",
+    "Shr<&u8> for i64",
+    "Shr<&u8> for i8",
+    "Shr<&u8> for isize",
+    "Shr<&u8> for u128",
+    "Shr<&u8> for u16",
+    "Shr<&u8> for u32",
+    "Shr<&u8> for u64",
+    "Shr<&u8> for u8",
+    "Shr<&u8> for usize",
+    "Shr<&usize> for &i128",
+    "Shr<&usize> for &i16",
+    "Shr<&usize> for &i32",
+    "Shr<&usize> for &i64",
+    "Shr<&usize> for &i8",
+    "Shr<&usize> for &isize",
+    "Shr<&usize> for &u128",
+    "Shr<&usize> for &u16",
+    "Shr<&usize> for &u32",
+    "Shr<&usize> for &u64",
+    "Shr<&usize> for &u8",
+    "Shr<&usize> for &usize",
+    "Shr<&usize> for i128",
+    "Shr<&usize> for i16",
+    "Shr<&usize> for i32",
+    "Shr<&usize> for i64",
+    "Shr<&usize> for i8",
+    "Shr<&usize> for isize",
+    "Shr<&usize> for u128",
+    "Shr<&usize> for u16",
+    "Shr<&usize> for u32",
+    "Shr<&usize> for u64",
+    "Shr<&usize> for u8",
+    "Shr<&usize> for usize",
+    "Shr<i128> for &i128",
+    "Shr<i128> for &i16",
+    "Shr<i128> for &i32",
+    "Shr<i128> for &i64",
+    "Shr<i128> for &i8",
+    "Shr<i128> for &isize",
+    "Shr<i128> for &u128",
+    "Shr<i128> for &u16",
+    "Shr<i128> for &u32",
+    "Shr<i128> for &u64",
+    "Shr<i128> for &u8",
+    "Shr<i128> for &usize",
+    "Shr<i128> for i128",
+    "Shr<i128> for i16",
+    "Shr<i128> for i32",
+    "Shr<i128> for i64",
+    "Shr<i128> for i8",
+    "Shr<i128> for isize",
+    "Shr<i128> for u128",
+    "Shr<i128> for u16",
+    "Shr<i128> for u32",
+    "Shr<i128> for u64",
+    "Shr<i128> for u8",
+    "Shr<i128> for usize",
+    "Shr<i16> for &i128",
+    "Shr<i16> for &i16",
+    "Shr<i16> for &i32",
+    "Shr<i16> for &i64",
+    "Shr<i16> for &i8",
+    "Shr<i16> for &isize",
+    "Shr<i16> for &u128",
+    "Shr<i16> for &u16",
+    "Shr<i16> for &u32",
+    "Shr<i16> for &u64",
+    "Shr<i16> for &u8",
+    "Shr<i16> for &usize",
+    "Shr<i16> for i128",
+    "Shr<i16> for i16",
+    "Shr<i16> for i32",
+    "Shr<i16> for i64",
+    "Shr<i16> for i8",
+    "Shr<i16> for isize",
+    "Shr<i16> for u128",
+    "Shr<i16> for u16",
+    "Shr<i16> for u32",
+    "Shr<i16> for u64",
+    "Shr<i16> for u8",
+    "Shr<i16> for usize",
+    "Shr<i32> for &i128",
+    "Shr<i32> for &i16",
+    "Shr<i32> for &i32",
+    "Shr<i32> for &i64",
+    "Shr<i32> for &i8",
+    "Shr<i32> for &isize",
+    "Shr<i32> for &u128",
+    "Shr<i32> for &u16",
+    "Shr<i32> for &u32",
+    "Shr<i32> for &u64",
+    "Shr<i32> for &u8",
+    "Shr<i32> for &usize",
+    "Shr<i32> for i128",
+    "Shr<i32> for i16",
+    "Shr<i32> for i32",
+    "Shr<i32> for i64",
+    "Shr<i32> for i8",
+    "Shr<i32> for isize",
+    "Shr<i32> for u128",
+    "Shr<i32> for u16",
+    "Shr<i32> for u32",
+    "Shr<i32> for u64",
+    "Shr<i32> for u8",
+    "Shr<i32> for usize",
+    "Shr<i64> for &i128",
+    "Shr<i64> for &i16",
+    "Shr<i64> for &i32",
+    "Shr<i64> for &i64",
+    "Shr<i64> for &i8",
+    "Shr<i64> for &isize",
+    "Shr<i64> for &u128",
+    "Shr<i64> for &u16",
+    "Shr<i64> for &u32",
+    "Shr<i64> for &u64",
+    "Shr<i64> for &u8",
+    "Shr<i64> for &usize",
+    "Shr<i64> for i128",
+    "Shr<i64> for i16",
+    "Shr<i64> for i32",
+    "Shr<i64> for i64",
+    "Shr<i64> for i8",
+    "Shr<i64> for isize",
+    "Shr<i64> for u128",
+    "Shr<i64> for u16",
+    "Shr<i64> for u32",
+    "Shr<i64> for u64",
+    "Shr<i64> for u8",
+    "Shr<i64> for usize",
+    "Shr<i8> for &i128",
+    "Shr<i8> for &i16",
+    "Shr<i8> for &i32",
+    "Shr<i8> for &i64",
+    "Shr<i8> for &i8",
+    "Shr<i8> for &isize",
+    "Shr<i8> for &u128",
+    "Shr<i8> for &u16",
+    "Shr<i8> for &u32",
+    "Shr<i8> for &u64",
+    "Shr<i8> for &u8",
+    "Shr<i8> for &usize",
+    "Shr<i8> for i128",
+    "Shr<i8> for i16",
+    "Shr<i8> for i32",
+    "Shr<i8> for i64",
+    "Shr<i8> for i8",
+    "Shr<i8> for isize",
+    "Shr<i8> for u128",
+    "Shr<i8> for u16",
+    "Shr<i8> for u32",
+    "Shr<i8> for u64",
+    "Shr<i8> for u8",
+    "Shr<i8> for usize",
+    "Shr<isize> for &i128",
+    "Shr<isize> for &i16",
+    "Shr<isize> for &i32",
+    "Shr<isize> for &i64",
+    "Shr<isize> for &i8",
+    "Shr<isize> for &isize",
+    "Shr<isize> for &u128",
+    "Shr<isize> for &u16",
+    "Shr<isize> for &u32",
+    "Shr<isize> for &u64",
+    "Shr<isize> for &u8",
+    "Shr<isize> for &usize",
+    "Shr<isize> for i128",
+    "Shr<isize> for i16",
+    "Shr<isize> for i32",
+    "Shr<isize> for i64",
+    "Shr<isize> for i8",
+    "Shr<isize> for isize",
+    "Shr<isize> for u128",
+    "Shr<isize> for u16",
+    "Shr<isize> for u32",
+    "Shr<isize> for u64",
+    "Shr<isize> for u8",
+    "Shr<isize> for usize",
+    "Shr<u128> for &i128",
+    "Shr<u128> for &i16",
+    "Shr<u128> for &i32",
+    "Shr<u128> for &i64",
+    "Shr<u128> for &i8",
+    "Shr<u128> for &isize",
+    "Shr<u128> for &u128",
+    "Shr<u128> for &u16",
+    "Shr<u128> for &u32",
+    "Shr<u128> for &u64",
+    "Shr<u128> for &u8",
+    "Shr<u128> for &usize",
+    "Shr<u128> for i128",
+    "Shr<u128> for i16",
+    "Shr<u128> for i32",
+    "Shr<u128> for i64",
+    "Shr<u128> for i8",
+    "Shr<u128> for isize",
+    "Shr<u128> for u128",
+    "Shr<u128> for u16",
+    "Shr<u128> for u32",
+    "Shr<u128> for u64",
+    "Shr<u128> for u8",
+    "Shr<u128> for usize",
+    "Shr<u16> for &i128",
+    "Shr<u16> for &i16",
+    "Shr<u16> for &i32",
+    "Shr<u16> for &i64",
+    "Shr<u16> for &i8",
+    "Shr<u16> for &isize",
+    "Shr<u16> for &u128",
+    "Shr<u16> for &u16",
+    "Shr<u16> for &u32",
+    "Shr<u16> for &u64",
+    "Shr<u16> for &u8",
+    "Shr<u16> for &usize",
+    "Shr<u16> for i128",
+    "Shr<u16> for i16",
+    "Shr<u16> for i32",
+    "Shr<u16> for i64",
+    "Shr<u16> for i8",
+    "Shr<u16> for isize",
+    "Shr<u16> for u128",
+    "Shr<u16> for u16",
+    "Shr<u16> for u32",
+    "Shr<u16> for u64",
+    "Shr<u16> for u8",
+    "Shr<u16> for usize",
+    "Shr<u32> for &i128",
+    "Shr<u32> for &i16",
+    "Shr<u32> for &i32",
+    "Shr<u32> for &i64",
+    "Shr<u32> for &i8",
+    "Shr<u32> for &isize",
+    "Shr<u32> for &u128",
+    "Shr<u32> for &u16",
+    "Shr<u32> for &u32",
+    "Shr<u32> for &u64",
+    "Shr<u32> for &u8",
+    "Shr<u32> for &usize",
+    "Shr<u32> for i128",
+    "Shr<u32> for i16",
+    "Shr<u32> for i32",
+    "Shr<u32> for i64",
+    "Shr<u32> for i8",
+    "Shr<u32> for isize",
+    "Shr<u32> for u128",
+    "Shr<u32> for u16",
+    "Shr<u32> for u32",
+    "Shr<u32> for u64",
+    "Shr<u32> for u8",
+    "Shr<u32> for usize",
+    "Shr<u64> for &i128",
+    "Shr<u64> for &i16",
+    "Shr<u64> for &i32",
+    "Shr<u64> for &i64",
+    "Shr<u64> for &i8",
+    "Shr<u64> for &isize",
+    "Shr<u64> for &u128",
+    "Shr<u64> for &u16",
+    "Shr<u64> for &u32",
+    "Shr<u64> for &u64",
+    "Shr<u64> for &u8",
+    "Shr<u64> for &usize",
+    "Shr<u64> for i128",
+    "Shr<u64> for i16",
+    "Shr<u64> for i32",
+    "Shr<u64> for i64",
+    "Shr<u64> for i8",
+    "Shr<u64> for isize",
+    "Shr<u64> for u128",
+    "Shr<u64> for u16",
+    "Shr<u64> for u32",
+    "Shr<u64> for u64",
+    "Shr<u64> for u8",
+    "Shr<u64> for usize",
+    "Shr<u8> for &i128",
+    "Shr<u8> for &i16",
+    "Shr<u8> for &i32",
+    "Shr<u8> for &i64",
+    "Shr<u8> for &i8",
+    "Shr<u8> for &isize",
+    "Shr<u8> for &u128",
+    "Shr<u8> for &u16",
+    "Shr<u8> for &u32",
+    "Shr<u8> for &u64",
+    "Shr<u8> for &u8",
+    "Shr<u8> for &usize",
+    "Shr<u8> for i128",
+    "Shr<u8> for i16",
+    "Shr<u8> for i32",
+    "Shr<u8> for i64",
+    "Shr<u8> for i8",
+    "Shr<u8> for isize",
+    "Shr<u8> for u128",
+    "Shr<u8> for u16",
+    "Shr<u8> for u32",
+    "Shr<u8> for u64",
+    "Shr<u8> for u8",
+    "Shr<u8> for usize",
+    "Shr<usize> for &i128",
+    "Shr<usize> for &i16",
+    "Shr<usize> for &i32",
+    "Shr<usize> for &i64",
+    "Shr<usize> for &i8",
+    "Shr<usize> for &isize",
+    "Shr<usize> for &u128",
+    "Shr<usize> for &u16",
+    "Shr<usize> for &u32",
+    "Shr<usize> for &u64",
+    "Shr<usize> for &u8",
+    "Shr<usize> for &usize",
+    "Shr<usize> for i128",
+    "Shr<usize> for i16",
+    "Shr<usize> for i32",
+    "Shr<usize> for i64",
+    "Shr<usize> for i8",
+    "Shr<usize> for isize",
+    "Shr<usize> for u128",
+    "Shr<usize> for u16",
+    "Shr<usize> for u32",
+    "Shr<usize> for u64",
+    "Shr<usize> for u8",
+    "Shr<usize> for usize",
+    "ShrAssign<&i128> for i128",
+    "ShrAssign<&i128> for i16",
+    "ShrAssign<&i128> for i32",
+    "ShrAssign<&i128> for i64",
+    "ShrAssign<&i128> for i8",
+    "ShrAssign<&i128> for isize",
+    "ShrAssign<&i128> for u128",
+    "ShrAssign<&i128> for u16",
+    "ShrAssign<&i128> for u32",
+    "ShrAssign<&i128> for u64",
+    "ShrAssign<&i128> for u8",
+    "ShrAssign<&i128> for usize",
+    "ShrAssign<&i16> for i128",
+    "ShrAssign<&i16> for i16",
+    "ShrAssign<&i16> for i32",
+    "ShrAssign<&i16> for i64",
+    "ShrAssign<&i16> for i8",
+    "ShrAssign<&i16> for isize",
+    "ShrAssign<&i16> for u128",
+    "ShrAssign<&i16> for u16",
+    "ShrAssign<&i16> for u32",
+    "ShrAssign<&i16> for u64",
+    "ShrAssign<&i16> for u8",
+    "ShrAssign<&i16> for usize",
+    "ShrAssign<&i32> for i128",
+    "ShrAssign<&i32> for i16",
+    "ShrAssign<&i32> for i32",
+    "ShrAssign<&i32> for i64",
+    "ShrAssign<&i32> for i8",
+    "ShrAssign<&i32> for isize",
+    "ShrAssign<&i32> for u128",
+    "ShrAssign<&i32> for u16",
+    "ShrAssign<&i32> for u32",
+    "ShrAssign<&i32> for u64",
+    "ShrAssign<&i32> for u8",
+    "ShrAssign<&i32> for usize",
+    "ShrAssign<&i64> for i128",
+    "ShrAssign<&i64> for i16",
+    "ShrAssign<&i64> for i32",
+    "ShrAssign<&i64> for i64",
+    "ShrAssign<&i64> for i8",
+    "ShrAssign<&i64> for isize",
+    "ShrAssign<&i64> for u128",
+    "ShrAssign<&i64> for u16",
+    "ShrAssign<&i64> for u32",
+    "ShrAssign<&i64> for u64",
+    "ShrAssign<&i64> for u8",
+    "ShrAssign<&i64> for usize",
+    "ShrAssign<&i8> for i128",
+    "ShrAssign<&i8> for i16",
+    "ShrAssign<&i8> for i32",
+    "ShrAssign<&i8> for i64",
+    "ShrAssign<&i8> for i8",
+    "ShrAssign<&i8> for isize",
+    "ShrAssign<&i8> for u128",
+    "ShrAssign<&i8> for u16",
+    "ShrAssign<&i8> for u32",
+    "ShrAssign<&i8> for u64",
+    "ShrAssign<&i8> for u8",
+    "ShrAssign<&i8> for usize",
+    "ShrAssign<&isize> for i128",
+    "ShrAssign<&isize> for i16",
+    "ShrAssign<&isize> for i32",
+    "ShrAssign<&isize> for i64",
+    "ShrAssign<&isize> for i8",
+    "ShrAssign<&isize> for isize",
+    "ShrAssign<&isize> for u128",
+    "ShrAssign<&isize> for u16",
+    "ShrAssign<&isize> for u32",
+    "ShrAssign<&isize> for u64",
+    "ShrAssign<&isize> for u8",
+    "ShrAssign<&isize> for usize",
+    "ShrAssign<&u128> for i128",
+    "ShrAssign<&u128> for i16",
+    "ShrAssign<&u128> for i32",
+    "ShrAssign<&u128> for i64",
+    "ShrAssign<&u128> for i8",
+    "ShrAssign<&u128> for isize",
+    "ShrAssign<&u128> for u128",
+    "ShrAssign<&u128> for u16",
+    "ShrAssign<&u128> for u32",
+    "ShrAssign<&u128> for u64",
+    "ShrAssign<&u128> for u8",
+    "ShrAssign<&u128> for usize",
+    "ShrAssign<&u16> for i128",
+    "ShrAssign<&u16> for i16",
+    "ShrAssign<&u16> for i32",
+    "ShrAssign<&u16> for i64",
+    "ShrAssign<&u16> for i8",
+    "ShrAssign<&u16> for isize",
+    "ShrAssign<&u16> for u128",
+    "ShrAssign<&u16> for u16",
+    "ShrAssign<&u16> for u32",
+    "ShrAssign<&u16> for u64",
+    "ShrAssign<&u16> for u8",
+    "ShrAssign<&u16> for usize",
+    "ShrAssign<&u32> for i128",
+    "ShrAssign<&u32> for i16",
+    "ShrAssign<&u32> for i32",
+    "ShrAssign<&u32> for i64",
+    "ShrAssign<&u32> for i8",
+    "ShrAssign<&u32> for isize",
+    "ShrAssign<&u32> for u128",
+    "ShrAssign<&u32> for u16",
+    "ShrAssign<&u32> for u32",
+    "ShrAssign<&u32> for u64",
+    "ShrAssign<&u32> for u8",
+    "ShrAssign<&u32> for usize",
+    "ShrAssign<&u64> for i128",
+    "ShrAssign<&u64> for i16",
+    "ShrAssign<&u64> for i32",
+    "ShrAssign<&u64> for i64",
+    "ShrAssign<&u64> for i8",
+    "ShrAssign<&u64> for isize",
+    "ShrAssign<&u64> for u128",
+    "ShrAssign<&u64> for u16",
+    "ShrAssign<&u64> for u32",
+    "ShrAssign<&u64> for u64",
+    "ShrAssign<&u64> for u8",
+    "ShrAssign<&u64> for usize",
+    "ShrAssign<&u8> for i128",
+    "ShrAssign<&u8> for i16",
+    "ShrAssign<&u8> for i32",
+    "ShrAssign<&u8> for i64",
+    "ShrAssign<&u8> for i8",
+    "ShrAssign<&u8> for isize",
+    "ShrAssign<&u8> for u128",
+    "ShrAssign<&u8> for u16",
+    "ShrAssign<&u8> for u32",
+    "ShrAssign<&u8> for u64",
+    "ShrAssign<&u8> for u8",
+    "ShrAssign<&u8> for usize",
+    "ShrAssign<&usize> for i128",
+    "ShrAssign<&usize> for i16",
+    "ShrAssign<&usize> for i32",
+    "ShrAssign<&usize> for i64",
+    "ShrAssign<&usize> for i8",
+    "ShrAssign<&usize> for isize",
+    "ShrAssign<&usize> for u128",
+    "ShrAssign<&usize> for u16",
+    "ShrAssign<&usize> for u32",
+    "ShrAssign<&usize> for u64",
+    "ShrAssign<&usize> for u8",
+    "ShrAssign<&usize> for usize",
+    "ShrAssign<i128> for i128",
+    "ShrAssign<i128> for i16",
+    "ShrAssign<i128> for i32",
+    "ShrAssign<i128> for i64",
+    "ShrAssign<i128> for i8",
+    "ShrAssign<i128> for isize",
+    "ShrAssign<i128> for u128",
+    "ShrAssign<i128> for u16",
+    "ShrAssign<i128> for u32",
+    "ShrAssign<i128> for u64",
+    "ShrAssign<i128> for u8",
+    "ShrAssign<i128> for usize",
+    "ShrAssign<i16> for i128",
+    "ShrAssign<i16> for i16",
+    "ShrAssign<i16> for i32",
+    "ShrAssign<i16> for i64",
+    "ShrAssign<i16> for i8",
+    "ShrAssign<i16> for isize",
+    "ShrAssign<i16> for u128",
+    "ShrAssign<i16> for u16",
+    "ShrAssign<i16> for u32",
+    "ShrAssign<i16> for u64",
+    "ShrAssign<i16> for u8",
+    "ShrAssign<i16> for usize",
+    "ShrAssign<i32> for i128",
+    "ShrAssign<i32> for i16",
+    "ShrAssign<i32> for i32",
+    "ShrAssign<i32> for i64",
+    "ShrAssign<i32> for i8",
+    "ShrAssign<i32> for isize",
+    "ShrAssign<i32> for u128",
+    "ShrAssign<i32> for u16",
+    "ShrAssign<i32> for u32",
+    "ShrAssign<i32> for u64",
+    "ShrAssign<i32> for u8",
+    "ShrAssign<i32> for usize",
+    "ShrAssign<i64> for i128",
+    "ShrAssign<i64> for i16",
+    "ShrAssign<i64> for i32",
+    "ShrAssign<i64> for i64",
+    "ShrAssign<i64> for i8",
+    "ShrAssign<i64> for isize",
+    "ShrAssign<i64> for u128",
+    "ShrAssign<i64> for u16",
+    "ShrAssign<i64> for u32",
+    "ShrAssign<i64> for u64",
+    "ShrAssign<i64> for u8",
+    "ShrAssign<i64> for usize",
+    "ShrAssign<i8> for i128",
+    "ShrAssign<i8> for i16",
+    "ShrAssign<i8> for i32",
+    "ShrAssign<i8> for i64",
+    "ShrAssign<i8> for i8",
+    "ShrAssign<i8> for isize",
+    "ShrAssign<i8> for u128",
+    "ShrAssign<i8> for u16",
+    "ShrAssign<i8> for u32",
+    "ShrAssign<i8> for u64",
+    "ShrAssign<i8> for u8",
+    "ShrAssign<i8> for usize",
+    "ShrAssign<isize> for i128",
+    "ShrAssign<isize> for i16",
+    "ShrAssign<isize> for i32",
+    "ShrAssign<isize> for i64",
+    "ShrAssign<isize> for i8",
+    "ShrAssign<isize> for isize",
+    "ShrAssign<isize> for u128",
+    "ShrAssign<isize> for u16",
+    "ShrAssign<isize> for u32",
+    "ShrAssign<isize> for u64",
+    "ShrAssign<isize> for u8",
+    "ShrAssign<isize> for usize",
+    "ShrAssign<u128> for i128",
+    "ShrAssign<u128> for i16",
+    "ShrAssign<u128> for i32",
+    "ShrAssign<u128> for i64",
+    "ShrAssign<u128> for i8",
+    "ShrAssign<u128> for isize",
+    "ShrAssign<u128> for u128",
+    "ShrAssign<u128> for u16",
+    "ShrAssign<u128> for u32",
+    "ShrAssign<u128> for u64",
+    "ShrAssign<u128> for u8",
+    "ShrAssign<u128> for usize",
+    "ShrAssign<u16> for i128",
+    "ShrAssign<u16> for i16",
+    "ShrAssign<u16> for i32",
+    "ShrAssign<u16> for i64",
+    "ShrAssign<u16> for i8",
+    "ShrAssign<u16> for isize",
+    "ShrAssign<u16> for u128",
+    "ShrAssign<u16> for u16",
+    "ShrAssign<u16> for u32",
+    "ShrAssign<u16> for u64",
+    "ShrAssign<u16> for u8",
+    "ShrAssign<u16> for usize",
+    "ShrAssign<u32> for i128",
+    "ShrAssign<u32> for i16",
+    "ShrAssign<u32> for i32",
+    "ShrAssign<u32> for i64",
+    "ShrAssign<u32> for i8",
+    "ShrAssign<u32> for isize",
+    "ShrAssign<u32> for u128",
+    "ShrAssign<u32> for u16",
+    "ShrAssign<u32> for u32",
+    "ShrAssign<u32> for u64",
+    "ShrAssign<u32> for u8",
+    "ShrAssign<u32> for usize",
+    "ShrAssign<u64> for i128",
+    "ShrAssign<u64> for i16",
+    "ShrAssign<u64> for i32",
+    "ShrAssign<u64> for i64",
+    "ShrAssign<u64> for i8",
+    "ShrAssign<u64> for isize",
+    "ShrAssign<u64> for u128",
+    "ShrAssign<u64> for u16",
+    "ShrAssign<u64> for u32",
+    "ShrAssign<u64> for u64",
+    "ShrAssign<u64> for u8",
+    "ShrAssign<u64> for usize",
+    "ShrAssign<u8> for i128",
+    "ShrAssign<u8> for i16",
+    "ShrAssign<u8> for i32",
+    "ShrAssign<u8> for i64",
+    "ShrAssign<u8> for i8",
+    "ShrAssign<u8> for isize",
+    "ShrAssign<u8> for u128",
+    "ShrAssign<u8> for u16",
+    "ShrAssign<u8> for u32",
+    "ShrAssign<u8> for u64",
+    "ShrAssign<u8> for u8",
+    "ShrAssign<u8> for usize",
+    "ShrAssign<usize> for i128",
+    "ShrAssign<usize> for i16",
+    "ShrAssign<usize> for i32",
+    "ShrAssign<usize> for i64",
+    "ShrAssign<usize> for i8",
+    "ShrAssign<usize> for isize",
+    "ShrAssign<usize> for u128",
+    "ShrAssign<usize> for u16",
+    "ShrAssign<usize> for u32",
+    "ShrAssign<usize> for u64",
+    "ShrAssign<usize> for u8",
+    "ShrAssign<usize> for usize",
+    "Sub<&f128> for &f128",
+    "Sub<&f128> for f128",
+    "Sub<&f16> for &f16",
+    "Sub<&f16> for f16",
+    "Sub<&f32> for &f32",
+    "Sub<&f32> for f32",
+    "Sub<&f64> for &f64",
+    "Sub<&f64> for f64",
+    "Sub<&i128> for &i128",
+    "Sub<&i128> for i128",
+    "Sub<&i16> for &i16",
+    "Sub<&i16> for i16",
+    "Sub<&i32> for &i32",
+    "Sub<&i32> for i32",
+    "Sub<&i64> for &i64",
+    "Sub<&i64> for i64",
+    "Sub<&i8> for &i8",
+    "Sub<&i8> for i8",
+    "Sub<&isize> for &isize",
+    "Sub<&isize> for isize",
+    "Sub<&u128> for &u128",
+    "Sub<&u128> for u128",
+    "Sub<&u16> for &u16",
+    "Sub<&u16> for u16",
+    "Sub<&u32> for &u32",
+    "Sub<&u32> for u32",
+    "Sub<&u64> for &u64",
+    "Sub<&u64> for u64",
+    "Sub<&u8> for &u8",
+    "Sub<&u8> for u8",
+    "Sub<&usize> for &usize",
+    "Sub<&usize> for usize",
+    "Sub<f128> for &f128",
+    "Sub<f128> for f128",
+    "Sub<f16> for &f16",
+    "Sub<f16> for f16",
+    "Sub<f32> for &f32",
+    "Sub<f32> for f32",
+    "Sub<f64> for &f64",
+    "Sub<f64> for f64",
+    "Sub<i128> for &i128",
+    "Sub<i128> for i128",
+    "Sub<i16> for &i16",
+    "Sub<i16> for i16",
+    "Sub<i32> for &i32",
+    "Sub<i32> for i32",
+    "Sub<i64> for &i64",
+    "Sub<i64> for i64",
+    "Sub<i8> for &i8",
+    "Sub<i8> for i8",
+    "Sub<isize> for &isize",
+    "Sub<isize> for isize",
+    "Sub<u128> for &u128",
+    "Sub<u128> for u128",
+    "Sub<u16> for &u16",
+    "Sub<u16> for u16",
+    "Sub<u32> for &u32",
+    "Sub<u32> for u32",
+    "Sub<u64> for &u64",
+    "Sub<u64> for u64",
+    "Sub<u8> for &u8",
+    "Sub<u8> for u8",
+    "Sub<usize> for &usize",
+    "Sub<usize> for usize",
+    "SubAssign<&f128> for f128",
+    "SubAssign<&f16> for f16",
+    "SubAssign<&f32> for f32",
+    "SubAssign<&f64> for f64",
+    "SubAssign<&i128> for i128",
+    "SubAssign<&i16> for i16",
+    "SubAssign<&i32> for i32",
+    "SubAssign<&i64> for i64",
+    "SubAssign<&i8> for i8",
+    "SubAssign<&isize> for isize",
+    "SubAssign<&u128> for u128",
+    "SubAssign<&u16> for u16",
+    "SubAssign<&u32> for u32",
+    "SubAssign<&u64> for u64",
+    "SubAssign<&u8> for u8",
+    "SubAssign<&usize> for usize",
+    "SubAssign<f128> for f128",
+    "SubAssign<f16> for f16",
+    "SubAssign<f32> for f32",
+    "SubAssign<f64> for f64",
+    "SubAssign<i128> for i128",
+    "SubAssign<i16> for i16",
+    "SubAssign<i32> for i32",
+    "SubAssign<i64> for i64",
+    "SubAssign<i8> for i8",
+    "SubAssign<isize> for isize",
+    "SubAssign<u128> for u128",
+    "SubAssign<u16> for u16",
+    "SubAssign<u32> for u32",
+    "SubAssign<u64> for u64",
+    "SubAssign<u8> for u8",
+    "SubAssign<usize> for usize",
+];
+
+binary_probe!(probe_add, Add);
+binary_probe!(probe_sub, Sub);
+binary_probe!(probe_mul, Mul);
+binary_probe!(probe_div, Div);
+binary_probe!(probe_rem, Rem);
+unary_probe!(probe_neg, Neg);
+unary_probe!(probe_not, Not);
+binary_probe!(probe_bit_and, BitAnd);
+binary_probe!(probe_bit_or, BitOr);
+binary_probe!(probe_bit_xor, BitXor);
+binary_probe!(probe_shl, Shl);
+binary_probe!(probe_shr, Shr);
+binary_probe!(probe_add_assign, AddAssign);
+binary_probe!(probe_sub_assign, SubAssign);
+binary_probe!(probe_mul_assign, MulAssign);
+binary_probe!(probe_div_assign, DivAssign);
+binary_probe!(probe_rem_assign, RemAssign);
+binary_probe!(probe_bit_and_assign, BitAndAssign);
+binary_probe!(probe_bit_or_assign, BitOrAssign);
+binary_probe!(probe_bit_xor_assign, BitXorAssign);
+binary_probe!(probe_shl_assign, ShlAssign);
+binary_probe!(probe_shr_assign, ShrAssign);
+
+fn probe_add(found: &mut Vec<String>) {
+    probe_pairs!(found, probe_add, "Add<{1}> for {0}", [usize, &usize, u8, &u8, u16, &u16, u32, &u32, u64, &u64, u128, &u128, isize, &isize, i8, &i8, i16, &i16, i32, &i32, i64, &i64, i128, &i128, f16, &f16, f32, &f32, f64, &f64, f128, &f128, bool, &bool, char, &char, !, &!], [usize, &usize, u8, &u8, u16, &u16, u32, &u32, u64, &u64, u128, &u128, isize, &isize, i8, &i8, i16, &i16, i32, &i32, i64, &i64, i128, &i128, f16, &f16, f32, &f32, f64, &f64, f128, &f128, bool, &bool, char, &char, !, &!]);
+}
+
+fn probe_sub(found: &mut Vec<String>) {
+    probe_pairs!(found, probe_sub, "Sub<{1}> for {0}", [usize, &usize, u8, &u8, u16, &u16, u32, &u32, u64, &u64, u128, &u128, isize, &isize, i8, &i8, i16, &i16, i32, &i32, i64, &i64, i128, &i128, f16, &f16, f32, &f32, f64, &f64, f128, &f128, bool, &bool, char, &char, !, &!], [usize, &usize, u8, &u8, u16, &u16, u32, &u32, u64, &u64, u128, &u128, isize, &isize, i8, &i8, i16, &i16, i32, &i32, i64, &i64, i128, &i128, f16, &f16, f32, &f32, f64, &f64, f128, &f128, bool, &bool, char, &char, !, &!]);
+}
+
+fn probe_mul(found: &mut Vec<String>) {
+    probe_pairs!(found, probe_mul, "Mul<{1}> for {0}", [usize, &usize, u8, &u8, u16, &u16, u32, &u32, u64, &u64, u128, &u128, isize, &isize, i8, &i8, i16, &i16, i32, &i32, i64, &i64, i128, &i128, f16, &f16, f32, &f32, f64, &f64, f128, &f128, bool, &bool, char, &char, !, &!], [usize, &usize, u8, &u8, u16, &u16, u32, &u32, u64, &u64, u128, &u128, isize, &isize, i8, &i8, i16, &i16, i32, &i32, i64, &i64, i128, &i128, f16, &f16, f32, &f32, f64, &f64, f128, &f128, bool, &bool, char, &char, !, &!]);
+}
+
+fn probe_div(found: &mut Vec<String>) {
+    probe_pairs!(found, probe_div, "Div<{1}> for {0}", [usize, &usize, u8, &u8, u16, &u16, u32, &u32, u64, &u64, u128, &u128, isize, &isize, i8, &i8, i16, &i16, i32, &i32, i64, &i64, i128, &i128, f16, &f16, f32, &f32, f64, &f64, f128, &f128, bool, &bool, char, &char, !, &!], [usize, &usize, u8, &u8, u16, &u16, u32, &u32, u64, &u64, u128, &u128, isize, &isize, i8, &i8, i16, &i16, i32, &i32, i64, &i64, i128, &i128, f16, &f16, f32, &f32, f64, &f64, f128, &f128, bool, &bool, char, &char, !, &!]);
+}
+
+fn probe_rem(found: &mut Vec<String>) {
+    probe_pairs!(found, probe_rem, "Rem<{1}> for {0}", [usize, &usize, u8, &u8, u16, &u16, u32, &u32, u64, &u64, u128, &u128, isize, &isize, i8, &i8, i16, &i16, i32, &i32, i64, &i64, i128, &i128, f16, &f16, f32, &f32, f64, &f64, f128, &f128, bool, &bool, char, &char, !, &!], [usize, &usize, u8, &u8, u16, &u16, u32, &u32, u64, &u64, u128, &u128, isize, &isize, i8, &i8, i16, &i16, i32, &i32, i64, &i64, i128, &i128, f16, &f16, f32, &f32, f64, &f64, f128, &f128, bool, &bool, char, &char, !, &!]);
+}
+
+fn probe_neg(found: &mut Vec<String>) {
+    probe_types!(found, probe_neg, "Neg for {0}", [usize, &usize, u8, &u8, u16, &u16, u32, &u32, u64, &u64, u128, &u128, isize, &isize, i8, &i8, i16, &i16, i32, &i32, i64, &i64, i128, &i128, f16, &f16, f32, &f32, f64, &f64, f128, &f128, bool, &bool, char, &char, !, &!]);
+}
+
+fn probe_not(found: &mut Vec<String>) {
+    probe_types!(found, probe_not, "Not for {0}", [usize, &usize, u8, &u8, u16, &u16, u32, &u32, u64, &u64, u128, &u128, isize, &isize, i8, &i8, i16, &i16, i32, &i32, i64, &i64, i128, &i128, f16, &f16, f32, &f32, f64, &f64, f128, &f128, bool, &bool, char, &char, !, &!]);
+}
+
+fn probe_bit_and(found: &mut Vec<String>) {
+    probe_pairs!(found, probe_bit_and, "BitAnd<{1}> for {0}", [usize, &usize, u8, &u8, u16, &u16, u32, &u32, u64, &u64, u128, &u128, isize, &isize, i8, &i8, i16, &i16, i32, &i32, i64, &i64, i128, &i128, f16, &f16, f32, &f32, f64, &f64, f128, &f128, bool, &bool, char, &char, !, &!], [usize, &usize, u8, &u8, u16, &u16, u32, &u32, u64, &u64, u128, &u128, isize, &isize, i8, &i8, i16, &i16, i32, &i32, i64, &i64, i128, &i128, f16, &f16, f32, &f32, f64, &f64, f128, &f128, bool, &bool, char, &char, !, &!]);
+}
+
+fn probe_bit_or(found: &mut Vec<String>) {
+    probe_pairs!(found, probe_bit_or, "BitOr<{1}> for {0}", [usize, &usize, u8, &u8, u16, &u16, u32, &u32, u64, &u64, u128, &u128, isize, &isize, i8, &i8, i16, &i16, i32, &i32, i64, &i64, i128, &i128, f16, &f16, f32, &f32, f64, &f64, f128, &f128, bool, &bool, char, &char, !, &!], [usize, &usize, u8, &u8, u16, &u16, u32, &u32, u64, &u64, u128, &u128, isize, &isize, i8, &i8, i16, &i16, i32, &i32, i64, &i64, i128, &i128, f16, &f16, f32, &f32, f64, &f64, f128, &f128, bool, &bool, char, &char, !, &!]);
+}
+
+fn probe_bit_xor(found: &mut Vec<String>) {
+    probe_pairs!(found, probe_bit_xor, "BitXor<{1}> for {0}", [usize, &usize, u8, &u8, u16, &u16, u32, &u32, u64, &u64, u128, &u128, isize, &isize, i8, &i8, i16, &i16, i32, &i32, i64, &i64, i128, &i128, f16, &f16, f32, &f32, f64, &f64, f128, &f128, bool, &bool, char, &char, !, &!], [usize, &usize, u8, &u8, u16, &u16, u32, &u32, u64, &u64, u128, &u128, isize, &isize, i8, &i8, i16, &i16, i32, &i32, i64, &i64, i128, &i128, f16, &f16, f32, &f32, f64, &f64, f128, &f128, bool, &bool, char, &char, !, &!]);
+}
+
+fn probe_shl(found: &mut Vec<String>) {
+    probe_pairs!(found, probe_shl, "Shl<{1}> for {0}", [usize, &usize, u8, &u8, u16, &u16, u32, &u32, u64, &u64, u128, &u128, isize, &isize, i8, &i8, i16, &i16, i32, &i32, i64, &i64, i128, &i128, f16, &f16, f32, &f32, f64, &f64, f128, &f128, bool, &bool, char, &char, !, &!], [usize, &usize, u8, &u8, u16, &u16, u32, &u32, u64, &u64, u128, &u128, isize, &isize, i8, &i8, i16, &i16, i32, &i32, i64, &i64, i128, &i128, f16, &f16, f32, &f32, f64, &f64, f128, &f128, bool, &bool, char, &char, !, &!]);
+}
+
+fn probe_shr(found: &mut Vec<String>) {
+    probe_pairs!(found, probe_shr, "Shr<{1}> for {0}", [usize, &usize, u8, &u8, u16, &u16, u32, &u32, u64, &u64, u128, &u128, isize, &isize, i8, &i8, i16, &i16, i32, &i32, i64, &i64, i128, &i128, f16, &f16, f32, &f32, f64, &f64, f128, &f128, bool, &bool, char, &char, !, &!], [usize, &usize, u8, &u8, u16, &u16, u32, &u32, u64, &u64, u128, &u128, isize, &isize, i8, &i8, i16, &i16, i32, &i32, i64, &i64, i128, &i128, f16, &f16, f32, &f32, f64, &f64, f128, &f128, bool, &bool, char, &char, !, &!]);
+}
+
+fn probe_add_assign(found: &mut Vec<String>) {
+    probe_pairs!(found, probe_add_assign, "AddAssign<{1}> for {0}", [usize, u8, u16, u32, u64, u128, isize, i8, i16, i32, i64, i128, f16, f32, f64, f128, bool, char, !], [usize, &usize, u8, &u8, u16, &u16, u32, &u32, u64, &u64, u128, &u128, isize, &isize, i8, &i8, i16, &i16, i32, &i32, i64, &i64, i128, &i128, f16, &f16, f32, &f32, f64, &f64, f128, &f128, bool, &bool, char, &char, !, &!]);
+}
+
+fn probe_sub_assign(found: &mut Vec<String>) {
+    probe_pairs!(found, probe_sub_assign, "SubAssign<{1}> for {0}", [usize, u8, u16, u32, u64, u128, isize, i8, i16, i32, i64, i128, f16, f32, f64, f128, bool, char, !], [usize, &usize, u8, &u8, u16, &u16, u32, &u32, u64, &u64, u128, &u128, isize, &isize, i8, &i8, i16, &i16, i32, &i32, i64, &i64, i128, &i128, f16, &f16, f32, &f32, f64, &f64, f128, &f128, bool, &bool, char, &char, !, &!]);
+}
+
+fn probe_mul_assign(found: &mut Vec<String>) {
+    probe_pairs!(found, probe_mul_assign, "MulAssign<{1}> for {0}", [usize, u8, u16, u32, u64, u128, isize, i8, i16, i32, i64, i128, f16, f32, f64, f128, bool, char, !], [usize, &usize, u8, &u8, u16, &u16, u32, &u32, u64, &u64, u128, &u128, isize, &isize, i8, &i8, i16, &i16, i32, &i32, i64, &i64, i128, &i128, f16, &f16, f32, &f32, f64, &f64, f128, &f128, bool, &bool, char, &char, !, &!]);
+}
+
+fn probe_div_assign(found: &mut Vec<String>) {
+    probe_pairs!(found, probe_div_assign, "DivAssign<{1}> for {0}", [usize, u8, u16, u32, u64, u128, isize, i8, i16, i32, i64, i128, f16, f32, f64, f128, bool, char, !], [usize, &usize, u8, &u8, u16, &u16, u32, &u32, u64, &u64, u128, &u128, isize, &isize, i8, &i8, i16, &i16, i32, &i32, i64, &i64, i128, &i128, f16, &f16, f32, &f32, f64, &f64, f128, &f128, bool, &bool, char, &char, !, &!]);
+}
+
+fn probe_rem_assign(found: &mut Vec<String>) {
+    probe_pairs!(found, probe_rem_assign, "RemAssign<{1}> for {0}", [usize, u8, u16, u32, u64, u128, isize, i8, i16, i32, i64, i128, f16, f32, f64, f128, bool, char, !], [usize, &usize, u8, &u8, u16, &u16, u32, &u32, u64, &u64, u128, &u128, isize, &isize, i8, &i8, i16, &i16, i32, &i32, i64, &i64, i128, &i128, f16, &f16, f32, &f32, f64, &f64, f128, &f128, bool, &bool, char, &char, !, &!]);
+}
+
+fn probe_bit_and_assign(found: &mut Vec<String>) {
+    probe_pairs!(found, probe_bit_and_assign, "BitAndAssign<{1}> for {0}", [usize, u8, u16, u32, u64, u128, isize, i8, i16, i32, i64, i128, f16, f32, f64, f128, bool, char, !], [usize, &usize, u8, &u8, u16, &u16, u32, &u32, u64, &u64, u128, &u128, isize, &isize, i8, &i8, i16, &i16, i32, &i32, i64, &i64, i128, &i128, f16, &f16, f32, &f32, f64, &f64, f128, &f128, bool, &bool, char, &char, !, &!]);
+}
+
+fn probe_bit_or_assign(found: &mut Vec<String>) {
+    probe_pairs!(found, probe_bit_or_assign, "BitOrAssign<{1}> for {0}", [usize, u8, u16, u32, u64, u128, isize, i8, i16, i32, i64, i128, f16, f32, f64, f128, bool, char, !], [usize, &usize, u8, &u8, u16, &u16, u32, &u32, u64, &u64, u128, &u128, isize, &isize, i8, &i8, i16, &i16, i32, &i32, i64, &i64, i128, &i128, f16, &f16, f32, &f32, f64, &f64, f128, &f128, bool, &bool, char, &char, !, &!]);
+}
+
+fn probe_bit_xor_assign(found: &mut Vec<String>) {
+    probe_pairs!(found, probe_bit_xor_assign, "BitXorAssign<{1}> for {0}", [usize, u8, u16, u32, u64, u128, isize, i8, i16, i32, i64, i128, f16, f32, f64, f128, bool, char, !], [usize, &usize, u8, &u8, u16, &u16, u32, &u32, u64, &u64, u128, &u128, isize, &isize, i8, &i8, i16, &i16, i32, &i32, i64, &i64, i128, &i128, f16, &f16, f32, &f32, f64, &f64, f128, &f128, bool, &bool, char, &char, !, &!]);
+}
+
+fn probe_shl_assign(found: &mut Vec<String>) {
+    probe_pairs!(found, probe_shl_assign, "ShlAssign<{1}> for {0}", [usize, u8, u16, u32, u64, u128, isize, i8, i16, i32, i64, i128, f16, f32, f64, f128, bool, char, !], [usize, &usize, u8, &u8, u16, &u16, u32, &u32, u64, &u64, u128, &u128, isize, &isize, i8, &i8, i16, &i16, i32, &i32, i64, &i64, i128, &i128, f16, &f16, f32, &f32, f64, &f64, f128, &f128, bool, &bool, char, &char, !, &!]);
+}
+
+fn probe_shr_assign(found: &mut Vec<String>) {
+    probe_pairs!(found, probe_shr_assign, "ShrAssign<{1}> for {0}", [usize, u8, u16, u32, u64, u128, isize, i8, i16, i32, i64, i128, f16, f32, f64, f128, bool, char, !], [usize, &usize, u8, &u8, u16, &u16, u32, &u32, u64, &u64, u128, &u128, isize, &isize, i8, &i8, i16, &i16, i32, &i32, i64, &i64, i128, &i128, f16, &f16, f32, &f32, f64, &f64, f128, &f128, bool, &bool, char, &char, !, &!]);
+}
+
+#[test]
+fn operators_match_upstream() {
+    let mut found = Vec::new();
+    probe_add(&mut found);
+    probe_sub(&mut found);
+    probe_mul(&mut found);
+    probe_div(&mut found);
+    probe_rem(&mut found);
+    probe_neg(&mut found);
+    probe_not(&mut found);
+    probe_bit_and(&mut found);
+    probe_bit_or(&mut found);
+    probe_bit_xor(&mut found);
+    probe_shl(&mut found);
+    probe_shr(&mut found);
+    probe_add_assign(&mut found);
+    probe_sub_assign(&mut found);
+    probe_mul_assign(&mut found);
+    probe_div_assign(&mut found);
+    probe_rem_assign(&mut found);
+    probe_bit_and_assign(&mut found);
+    probe_bit_or_assign(&mut found);
+    probe_bit_xor_assign(&mut found);
+    probe_shl_assign(&mut found);
+    probe_shr_assign(&mut found);
+
+    // `stringify!` may space the `&` of the references differently
+    let normalize = |s: &str| s.replace("& ", "&");
+    let found: BTreeSet<String> = found.iter().map(|s| normalize(s)).collect();
+    let restd: BTreeSet<String> = RESTD.iter().map(|s| normalize(s)).collect();
+    let missing: Vec<_> = found.difference(&restd).collect();
+    let extra: Vec<_> = restd.difference(&found).collect();
+    assert!(
+        missing.is_empty() && extra.is_empty(),
+        "missing in restd: {missing:#?}\nnot in upstream core: {extra:#?}"
+    );
+}
@@ -0,0 +1,323 @@
+//! Generates `core/src/unicode/unicode_data.rs` from the checked-in snapshots
+//! of the Unicode Character Database: `UnicodeData.txt`, `SpecialCasing.txt`
+//! and `PropList.txt` (only the properties used here).
+//!
+//! ```text
+//! cargo run -p unicode-table-generator [output]
+//! ```
+//!
+//! The binary properties are stored in the skip list format of the upstream
+//! generator: the code points where the property changes are encoded as the
+//! differences between them, one byte each (`OFFSETS`), and the differences
+//! which do not fit in a byte end a run of `SHORT_OFFSET_RUNS`, which gives
+//! the index of the run in `OFFSETS` (the top 11 bits) and the code point it
+//! ends at (the low 21 bits). `core::unicode::skip_search` finds the run of a
+//! code point by dichotomy, then sums the offsets of the run; the parity of
+//! the number of changes crossed is the value of the property. The
+//! case mappings are stored as sorted tables of pairs, a second table holding
+//! the mappings to several code points, searched by dichotomy.
+//!
+//! `Alphabetic`, `Grapheme_Extend`, `Lowercase` and `Uppercase` are derived
+//! like `DerivedCoreProperties.txt` does, from the general category and the
+//! `Other_*` properties. `White_Space` is read from `PropList.txt`, `N` and
+//! `Printable` come from the general category.
+//!
+//! The case mappings are the simple ones of `UnicodeData.txt`, overridden by
+//! the unconditional full mappings of `SpecialCasing.txt` which map a code
+//! point to up to three code points. The snapshot of `SpecialCasing.txt` is
+//! the 14.0.0 one, its unconditional mappings have not changed since.
+
+mod ucd;
+
+use std::fmt::Write;
+use std::ops::RangeInclusive;
+use std::path::{Path, PathBuf};
+use std::{env, fs, process};
+
+use ucd::{CodePoint, SpecialCasing, UnicodeData};
+
+/// The version of the Unicode Character Database of `UnicodeData.txt`, to
+/// update with the snapshot.
+const UNICODE_VERSION: (u8, u8, u8) = (15, 1, 0);
+
+fn main() {
+    let manifest_dir = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+    let output = match env::args_os().nth(1) {
+        Some(output) => PathBuf::from(output),
+        None => manifest_dir.join("../../core/src/unicode/unicode_data.rs"),
+    };
+
+    let data = parse(&manifest_dir, "UnicodeData.txt", UnicodeData::parse);
+    let properties = parse(&manifest_dir, "PropList.txt", ucd::parse_properties);
+    let special_casing = parse(
+        &manifest_dir,
+        "SpecialCasing.txt",
+        ucd::parse_special_casing,
+    );
+    let property = |property: &str| {
+        properties.get(property).cloned().unwrap_or_else(|| {
+            eprintln!("`PropList.txt`: missing property `{property}`");
+            process::exit(1);
+        })
+    };
+    let properties = PropList {
+        white_space: property("White_Space"),
+        other_alphabetic: property("Other_Alphabetic"),
+        other_grapheme_extend: property("Other_Grapheme_Extend"),
+        other_lowercase: property("Other_Lowercase"),
+        other_uppercase: property("Other_Uppercase"),
+    };
+
+    fs::write(&output, generate(&data, &properties, &special_casing)).unwrap_or_else(|e| {
+        eprintln!("cannot write `{}`: {e}", output.display());
+        process::exit(1);
+    });
+}
+
+/// The properties of `PropList.txt` used by the tables: `White_Space` and the
+/// contributory properties added to the general categories to derive the core
+/// properties.
+struct PropList {
+    white_space: Vec<RangeInclusive<u32>>,
+    other_alphabetic: Vec<RangeInclusive<u32>>,
+    other_grapheme_extend: Vec<RangeInclusive<u32>>,
+    other_lowercase: Vec<RangeInclusive<u32>>,
+    other_uppercase: Vec<RangeInclusive<u32>>,
+}
+
+/// Reads and parses the file `name` of the generator directory, exiting on
+/// error.
+fn parse<T>(dir: &Path, name: &str, parse: impl Fn(&str) -> Result<T, String>) -> T {
+    let input = dir.join(name);
+    let content = fs::read_to_string(&input).unwrap_or_else(|e| {
+        eprintln!("cannot read `{}`: {e}", input.display());
+        process::exit(1);
+    });
+    parse(&content).unwrap_or_else(|e| {
+        eprintln!("`{}`: {e}", input.display());
+        process::exit(1);
+    })
+}
+
+fn generate(data: &UnicodeData, properties: &PropList, special_casing: &[SpecialCasing]) -> String {
+    let mut out = String::new();
+    let (major, minor, update) = UNICODE_VERSION;
+    out.push_str(
+        "//! Unicode tables generated by `tools/unicode-table-generator` from\n\
+         //! the Unicode Character Database, do not edit by hand.\n\n\
+         // No formatting: the tables are generated.\n\
+         #![cfg_attr(rustfmt, rustfmt::skip)]\n\n",
+    );
+    writeln!(
+        out,
+        "pub const UNICODE_VERSION: (u8, u8, u8) = ({major}, {minor}, {update});"
+    )
+    .unwrap();
+
+    let category = |categories: &'static [&'static str]| {
+        move |cp: &CodePoint| categories.contains(&cp.general_category.as_str())
+    };
+    write_skip_list(
+        &mut out,
+        "alphabetic",
+        &data.ranges(derived(
+            &["Lu", "Ll", "Lt", "Lm", "Lo", "Nl"],
+            &properties.other_alphabetic,
+        )),
+    );
+    write_skip_list(
+        &mut out,
+        "grapheme_extend",
+        &data.ranges(derived(&["Mn", "Me"], &properties.other_grapheme_extend)),
+    );
+    write_skip_list(
+        &mut out,
+        "lowercase",
+        &data.ranges(derived(&["Ll"], &properties.other_lowercase)),
+    );
+    write_skip_list(&mut out, "n", &data.ranges(category(&["Nd", "Nl", "No"])));
+    write_skip_list(
+        &mut out,
+        "printable",
+        &data.ranges(|cp| match cp.general_category.as_str() {
+            "Cc" | "Cf" | "Cs" | "Co" | "Zl" | "Zp" => false,
+            "Zs" => cp.code == 0x20,
+            _ => true,
+        }),
+    );
+    write_skip_list(
+        &mut out,
+        "uppercase",
+        &data.ranges(derived(&["Lu"], &properties.other_uppercase)),
+    );
+    write_skip_list(
+        &mut out,
+        "white_space",
+        &data.ranges(derived(&[], &properties.white_space)),
+    );
+
+    out.push_str("\npub mod conversions {\n");
+    let mappings = |mapping: fn(&CodePoint) -> Option<u32>| {
+        data.entries
+            .iter()
+            .filter_map(|(_, cp)| mapping(cp).map(|to| (cp.code, to)))
+            .collect::<Vec<_>>()
+    };
+    let multi_mappings = |mapping: fn(&SpecialCasing) -> &Vec<u32>| {
+        let mut mappings = special_casing
+            .iter()
+            .filter(|special| mapping(special).len() > 1)
+            .map(|special| (special.code, mapping(special).clone()))
+            .collect::<Vec<_>>();
+        // `SpecialCasing.txt` is grouped by script, not sorted
+        mappings.sort_unstable_by_key(|(code, _)| *code);
+        mappings
+    };
+    write_case_table(
+        &mut out,
+        "lower",
+        &mappings(|cp| cp.simple_lowercase),
+        &multi_mappings(|special| &special.lowercase),
+    );
+    write_case_table(
+        &mut out,
+        "upper",
+        &mappings(|cp| cp.simple_uppercase),
+        &multi_mappings(|special| &special.uppercase),
+    );
+    out.push_str("}\n");
+
+    out
+}
+
+/// The predicate of a core property: the code points of `categories` and the
+/// ones of the property `other` of `PropList.txt`.
+///
+/// The properties of `PropList.txt` used here are only given to single code
+/// points, never to the blocks of `UnicodeData.txt` which are represented by
+/// their first code point.
+fn derived<'a>(
+    categories: &'a [&str],
+    other: &'a [RangeInclusive<u32>],
+) -> impl Fn(&CodePoint) -> bool + 'a {
+    move |cp| {
+        categories.contains(&cp.general_category.as_str())
+            || other.iter().any(|range| range.contains(&cp.code))
+    }
+}
+
+/// Writes the module `name` holding the skip list of the code points of
+/// `ranges`, and its `lookup` function.
+fn write_skip_list(out: &mut String, name: &str, ranges: &[RangeInclusive<u32>]) {
+    // the differences between the code points where the property changes,
+    // the last one can't fit in a byte and ends the last run
+    let mut offsets = Vec::new();
+    let mut previous = 0;
+    for point in ranges
+        .iter()
+        .flat_map(|range| [*range.start(), *range.end() + 1])
+    {
+        offsets.push(point - previous);
+        previous = point;
+    }
+    offsets.push(char::MAX as u32 + 1);
+
+    let mut short_offset_runs = Vec::new();
+    let mut coded_offsets = Vec::new();
+    let mut run_start = 0;
+    let mut prefix_sum = 0;
+    for offset in offsets {
+        prefix_sum += offset;
+        match u8::try_from(offset) {
+            Ok(offset) => coded_offsets.push(offset),
+            Err(_) => {
+                assert!(run_start < 1 << 11 && prefix_sum < 1 << 21);
+                short_offset_runs.push((run_start << 21) | prefix_sum);
+                // keeps the parity of the indices, which gives the value
+                coded_offsets.push(0);
+                run_start = u32::try_from(coded_offsets.len()).unwrap();
+            }
+        }
+    }
+
+    writeln!(out, "\npub mod {name} {{").unwrap();
+    write_array(out, "SHORT_OFFSET_RUNS", "u32", &short_offset_runs);
+    write_array(out, "OFFSETS", "u8", &coded_offsets);
+    out.push_str(
+        "\n    pub fn lookup(c: char) -> bool {\n        \
+         super::super::skip_search(c as u32, &SHORT_OFFSET_RUNS, &OFFSETS)\n    \
+         }\n}\n",
+    );
+}
+
+/// Writes the static array `name` of `values`, wrapped at 100 columns.
+fn write_array<T: std::fmt::Display>(out: &mut String, name: &str, ty: &str, values: &[T]) {
+    writeln!(out, "    static {name}: [{ty}; {}] = [", values.len()).unwrap();
+    let mut line = String::new();
+    for value in values {
+        let value = format!("{value},");
+        if !line.is_empty() && line.len() + 1 + value.len() > 100 - 8 {
+            writeln!(out, "        {line}").unwrap();
+            line.clear();
+        }
+        if !line.is_empty() {
+            line.push(' ');
+        }
+        line.push_str(&value);
+    }
+    if !line.is_empty() {
+        writeln!(out, "        {line}").unwrap();
+    }
+    out.push_str("    ];\n");
+}
+
+/// Writes the `to_{case}` function of `conversions` and its tables, the full
+/// mappings of `multi` (of two or three code points) overriding the simple
+/// ones.
+fn write_case_table(
+    out: &mut String,
+    case: &str,
+    simple: &[(u32, u32)],
+    multi: &[(u32, Vec<u32>)],
+) {
+    let table = case.to_uppercase();
+    writeln!(
+        out,
+        "    pub fn to_{case}(c: char) -> [char; 3] {{\n        \
+         match super::super::case_search(c, &{table}CASE_MULTI_TABLE) {{\n            \
+         Some(chars) => chars,\n            \
+         None => [super::super::case_search(c, &{table}CASE_TABLE).unwrap_or(c), '\\0', '\\0'],\n        \
+         }}\n    \
+         }}"
+    )
+    .unwrap();
+
+    writeln!(
+        out,
+        "\n    static {table}CASE_TABLE: [(char, char); {}] = [",
+        simple.len()
+    )
+    .unwrap();
+    for (from, to) in simple {
+        writeln!(out, "        ('\\u{{{from:x}}}', '\\u{{{to:x}}}'),").unwrap();
+    }
+    out.push_str("    ];\n");
+
+    writeln!(
+        out,
+        "\n    static {table}CASE_MULTI_TABLE: [(char, [char; 3]); {}] = [",
+        multi.len()
+    )
+    .unwrap();
+    for (from, to) in multi {
+        let to = (0..3)
+            .map(|i| format!("'\\u{{{:x}}}'", to.get(i).copied().unwrap_or(0)))
+            .collect::<Vec<_>>()
+            .join(", ");
+        writeln!(out, "        ('\\u{{{from:x}}}', [{to}]),").unwrap();
+    }
+    out.push_str("    ];\n");
+    if case == "lower" {
+        out.push('\n');
+    }
+}
@@ -0,0 +1,177 @@
+//! Parsers for the files of the Unicode Character Database.
+//!
+//! The formats are described in the section 4.2 of the
+//! [UAX #44](https://www.unicode.org/reports/tr44/#UnicodeData.txt).
+//! `UnicodeData.txt` has one code point per line, made of 15 fields separated
+//! by `;`. Large blocks sharing the same properties (like the CJK ideographs)
+//! are given as two lines, the first one with a name ending by `, First>` and
+//! the second one by `, Last>`. The other files have fields separated by `;`
+//! too, followed by an optional comment starting with `#`.
+
+use std::collections::HashMap;
+use std::ops::RangeInclusive;
+
+/// The properties of a code point used to build the tables.
+#[derive(Debug, Clone)]
+pub struct CodePoint {
+    pub code: u32,
+    pub general_category: String,
+    pub simple_uppercase: Option<u32>,
+    pub simple_lowercase: Option<u32>,
+}
+
+/// The code points assigned by `UnicodeData.txt`, the ranges given by a
+/// `First`/`Last` pair are kept as one entry.
+pub struct UnicodeData {
+    pub entries: Vec<(RangeInclusive<u32>, CodePoint)>,
+}
+
+impl UnicodeData {
+    pub fn parse(content: &str) -> Result<Self, String> {
+        let mut entries = Vec::new();
+        let mut range_start = None;
+
+        for (index, line) in content.lines().enumerate() {
+            let line_number = index + 1;
+            if line.trim().is_empty() {
+                continue;
+            }
+
+            let fields: Vec<&str> = line.split(';').collect();
+            if fields.len() != 15 {
+                return Err(format!(
+                    "line {line_number}: expected 15 fields, found {}",
+                    fields.len()
+                ));
+            }
+
+            let code = parse_code(fields[0]).map_err(|e| format!("line {line_number}: {e}"))?;
+            let optional_code = |field: &str| match field {
+                "" => Ok(None),
+                field => parse_code(field)
+                    .map(Some)
+                    .map_err(|e| format!("line {line_number}: {e}")),
+            };
+            let code_point = CodePoint {
+                code,
+                general_category: fields[2].to_owned(),
+                simple_uppercase: optional_code(fields[12])?,
+                simple_lowercase: optional_code(fields[13])?,
+            };
+
+            let name = fields[1];
+            if name.ends_with(", First>") {
+                range_start = Some(code);
+            } else if name.ends_with(", Last>") {
+                let start = range_start
+                    .take()
+                    .ok_or_else(|| format!("line {line_number}: `Last` entry without `First`"))?;
+                entries.push((start..=code, code_point));
+            } else {
+                entries.push((code..=code, code_point));
+            }
+        }
+
+        Ok(UnicodeData { entries })
+    }
+
+    /// Returns the ranges of code points which satisfy `predicate`, merged
+    /// together when they are contiguous.
+    pub fn ranges(&self, predicate: impl Fn(&CodePoint) -> bool) -> Vec<RangeInclusive<u32>> {
+        let mut ranges: Vec<RangeInclusive<u32>> = Vec::new();
+        for (range, code_point) in &self.entries {
+            if !predicate(code_point) {
+                continue;
+            }
+
+            match ranges.last_mut() {
+                Some(last) if *last.end() + 1 == *range.start() => {
+                    *last = *last.start()..=*range.end();
+                }
+                _ => ranges.push(range.clone()),
+            }
+        }
+        ranges
+    }
+}
+
+/// Parses a file in the format of `PropList.txt`: a code point or a range of
+/// code points, then the name of the binary property they have.
+pub fn parse_properties(
+    content: &str,
+) -> Result<HashMap<String, Vec<RangeInclusive<u32>>>, String> {
+    let mut properties: HashMap<String, Vec<RangeInclusive<u32>>> = HashMap::new();
+    for (index, fields) in data_lines(content) {
+        let line_number = index + 1;
+        let [codes, property] = fields[..] else {
+            return Err(format!(
+                "line {line_number}: expected 2 fields, found {}",
+                fields.len()
+            ));
+        };
+        let code = |field| parse_code(field).map_err(|e| format!("line {line_number}: {e}"));
+        let range = match codes.split_once("..") {
+            Some((start, end)) => code(start)?..=code(end)?,
+            None => code(codes).map(|code| code..=code)?,
+        };
+        properties
+            .entry(property.to_owned())
+            .or_default()
+            .push(range);
+    }
+    Ok(properties)
+}
+
+/// The full case mappings of a code point, from `SpecialCasing.txt`.
+#[derive(Debug, Clone)]
+pub struct SpecialCasing {
+    pub code: u32,
+    pub lowercase: Vec<u32>,
+    pub uppercase: Vec<u32>,
+}
+
+/// Parses the unconditional mappings of `SpecialCasing.txt`, the ones which
+/// depend on the language or on the context (like the final sigma) are
+/// skipped.
+pub fn parse_special_casing(content: &str) -> Result<Vec<SpecialCasing>, String> {
+    let mut mappings = Vec::new();
+    for (index, fields) in data_lines(content) {
+        let line_number = index + 1;
+        let codes = |field: &str| {
+            field
+                .split_whitespace()
+                .map(parse_code)
+                .collect::<Result<Vec<_>, _>>()
+                .map_err(|e| format!("line {line_number}: {e}"))
+        };
+        match fields[..] {
+            // code; lower; title; upper; (condition;)
+            [code, lower, _, upper, ""] => mappings.push(SpecialCasing {
+                code: parse_code(code).map_err(|e| format!("line {line_number}: {e}"))?,
+                lowercase: codes(lower)?,
+                uppercase: codes(upper)?,
+            }),
+            [_, _, _, _, _, ""] => continue,
+            _ => {
+                return Err(format!(
+                    "line {line_number}: expected 5 or 6 fields, found {}",
+                    fields.len()
+                ));
+            }
+        }
+    }
+    Ok(mappings)
+}
+
+/// Returns the lines which are not empty once their comment is removed, with
+/// their index and their trimmed fields.
+fn data_lines(content: &str) -> impl Iterator<Item = (usize, Vec<&str>)> {
+    content.lines().enumerate().filter_map(|(index, line)| {
+        let data = line.split_once('#').map_or(line, |(data, _)| data).trim();
+        (!data.is_empty()).then(|| (index, data.split(';').map(str::trim).collect()))
+    })
+}
+
+fn parse_code(field: &str) -> Result<u32, String> {
+    u32::from_str_radix(field, 16).map_err(|e| format!("invalid code point `{field}`: {e}"))
+}